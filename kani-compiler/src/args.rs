@@ -1,6 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use kani_metadata::AssertionSemantics;
 use strum_macros::{AsRefStr, Display, EnumString, VariantNames};
 use tracing_subscriber::filter::Directive;
 
@@ -40,6 +41,9 @@ pub struct Arguments {
     /// Option used to disable asserting function contracts.
     #[clap(long)]
     pub no_assert_contracts: bool,
+    /// Option used to cover both outcomes of each precondition in contract harnesses.
+    #[clap(long)]
+    pub contract_negative: bool,
     /// Option name used to enable assertion reachability checks.
     #[clap(long = "assertion-reach-checks")]
     pub check_assertion_reachability: bool,
@@ -97,6 +101,23 @@ pub struct Arguments {
     check_version: Option<String>,
     #[clap(long)]
     pub ub_check: Vec<ExtraChecks>,
+    /// Don't check for undefined behavior in pointer arithmetic.
+    /// See kani_driver::args::CheckArgs for documentation.
+    #[clap(long)]
+    pub no_pointer_offset_checks: bool,
+    /// The property classes selected by the user. Checks that have their own property class
+    /// are only reported under it if it is selected.
+    /// See kani_driver::args::VerificationArgs for documentation.
+    #[clap(long)]
+    pub property_class: Vec<String>,
+    /// Replace the formatting arguments of panic messages by opaque values.
+    /// See kani_driver::args::VerificationArgs for documentation.
+    #[clap(long)]
+    pub no_fmt_args: bool,
+    /// Treat the public functions of the given crate as uninterpreted.
+    /// See kani_driver::args::VerificationArgs for documentation.
+    #[arg(long = "abstract-crate", num_args(1))]
+    pub abstract_crates: Vec<String>,
     /// Option name used to select which backend to use.
     #[clap(long = "backend", default_value_t = BackendOption::CProver)]
     pub backend: BackendOption,
@@ -111,6 +132,60 @@ pub struct Arguments {
     /// See kani_driver::autoharness_args for documentation.
     #[arg(long = "autoharness-exclude-pattern", num_args(1))]
     pub autoharness_excluded_patterns: Vec<String>,
+    /// Collect the mutations that can be applied to the local functions reachable from the
+    /// harnesses, and store them in the crate metadata.
+    /// See kani_driver::mutate_args for documentation.
+    #[clap(long)]
+    pub list_mutants: bool,
+    /// Apply the mutation with the given identifier before generating code.
+    /// The identifiers are the ones collected with `--list-mutants`.
+    #[clap(long, value_name = "MUTANT_ID")]
+    pub mutant: Option<String>,
+    /// Store the names of the functions reachable from each harness in the harness metadata.
+    /// See kani_driver::args::VerificationArgs for documentation.
+    #[clap(long)]
+    pub emit_reachable: bool,
+    /// Fail the compilation of the harnesses that introduce more nondeterministic bytes than this.
+    /// See kani_driver::args::VerificationArgs for documentation.
+    #[clap(long)]
+    pub max_nondet_bytes: Option<u64>,
+    /// Store the number of nondeterministic bytes of each harness in the harness metadata, so the
+    /// driver can print them with `--verbose`. Implied by `--max-nondet-bytes`.
+    #[clap(long)]
+    pub nondet_bytes_stats: bool,
+    /// How the `debug_assert!` family of macros is treated during codegen.
+    /// See kani_driver::args::VerificationArgs for documentation.
+    #[clap(long, value_enum, default_value_t)]
+    pub assertion_semantics: AssertionSemantics,
+    /// Also write the symbol table of each harness in CBMC's JSON symtab format.
+    /// See kani_driver::args::VerificationArgs for documentation.
+    #[clap(long)]
+    pub dump_symtab: bool,
+}
+
+impl Arguments {
+    /// Whether automatic harnesses may be generated for this crate, either by the autoharness
+    /// subcommand, for functions under contract with `-Z contracts-batch`, or for functions
+    /// annotated with `#[kani::verify_unsafe]`.
+    pub fn has_automatic_harnesses(&self) -> bool {
+        self.reachability_analysis == ReachabilityType::AllFns
+            || self.is_contracts_batch_enabled()
+            || self.is_verify_unsafe_enabled()
+    }
+
+    /// Whether we should generate harnesses for functions annotated with
+    /// `#[kani::verify_unsafe]`.
+    pub fn is_verify_unsafe_enabled(&self) -> bool {
+        self.reachability_analysis == ReachabilityType::Harnesses
+            && self.unstable_features.contains(&"verify-unsafe".to_string())
+    }
+
+    /// Whether we should generate contract harnesses for functions under contract that don't
+    /// have one.
+    pub fn is_contracts_batch_enabled(&self) -> bool {
+        self.reachability_analysis == ReachabilityType::Harnesses
+            && self.unstable_features.contains(&"contracts-batch".to_string())
+    }
 }
 
 #[derive(Debug, Clone, Copy, AsRefStr, EnumString, VariantNames, PartialEq, Eq)]
@@ -61,6 +61,12 @@ pub enum PropertyClass {
     ///
     /// SPECIAL BEHAVIOR: "Errors" for this type of assertion just mean "reachable" not failure.
     CodeCoverage,
+    /// A lock of the standard library that the harness thread tries to acquire while it already
+    /// holds it, e.g., by locking a `Mutex` twice, which would block forever.
+    ///
+    /// SPECIAL BEHAVIOR: Unlike Assertion, a deadlock is not a panic, since the program hangs
+    /// instead, so it doesn't satisfy `#[kani::should_panic]`.
+    Deadlock,
     /// Ordinary (Rust) assertions and panics.
     ///
     /// SPECIAL BEHAVIOR: These assertion failures should be observable during normal execution of Rust code.
@@ -75,6 +81,32 @@ pub enum PropertyClass {
     ///
     /// SPECIAL BEHAVIOR: Same as SafetyCheck. TODO: Replace this with `SafetyCheck`.
     FiniteCheck,
+    /// Checks that the argument of `float_to_int_unchecked` (and therefore of the
+    /// `to_int_unchecked` methods) is finite and that its truncated value fits in the target type.
+    ///
+    /// SPECIAL BEHAVIOR: Same as SafetyCheck. Kept separate so that users can tell
+    /// unchecked float-to-int UB apart from arithmetic overflows.
+    FloatToIntUnchecked,
+    /// A panic reached while checking the contract of a function annotated with
+    /// `#[kani::ensures_no_panic]`.
+    ///
+    /// SPECIAL BEHAVIOR: Same as Assertion. Kept separate so that panic freedom is reported
+    /// apart from the functional postconditions of the contract.
+    EnsuresNoPanic,
+    /// Checks for undefined behavior in pointer arithmetic, e.g., computing an offset that
+    /// doesn't stay within the bounds of the original allocation, even if the result is never
+    /// dereferenced.
+    ///
+    /// SPECIAL BEHAVIOR: Same as SafetyCheck. Only used if the user selects this class with
+    /// `--property-class pointer_offset`, so that pointer arithmetic UB can be told apart from
+    /// other safety violations. These checks can be turned off with `--no-pointer-offset-checks`.
+    PointerOffset,
+    /// The precondition of a function replaced by its contract (`#[kani::stub_verified]`),
+    /// which is checked every time the function is called.
+    ///
+    /// SPECIAL BEHAVIOR: Same as Assertion. Kept separate so that Kani can report the call site
+    /// that violates the precondition, instead of the location of the contract.
+    Precondition,
     /// Checks added by Kani compiler to determine whether a property (e.g.
     /// `PropertyClass::Assertion` or `PropertyClass:Cover`) is reachable
     ReachabilityCheck,
@@ -88,6 +120,13 @@ pub enum PropertyClass {
     ///
     /// SPECIAL BEHAVIOR: Should not be normally rendered as a checked assertion, as it's expected to succeed.
     SanityCheck,
+    /// Checks added by the uninitialized memory instrumentation, i.e., with `--check-uninit` or
+    /// `-Z uninit-checks`, that memory is initialized before it is read.
+    ///
+    /// SPECIAL BEHAVIOR: Same as SafetyCheck. Only used if the user selects this class with
+    /// `--property-class uninitialized_memory`, so that reads of uninitialized memory can be told
+    /// apart from other safety violations.
+    UninitializedMemory,
     /// See `codegen_unimplemented`. Used to indicate an unsupported construct was reachable.
     ///
     /// SPECIAL BEHAVIOR: Reachability of these assertions is notable, in order to measure Kani support.
@@ -108,6 +147,17 @@ pub fn as_str(&self) -> &str {
 }
 
 impl GotocCtx<'_> {
+    /// The property class of a safety check that can be reported under its own class.
+    /// Unless the user selected `class` with `--property-class`, the check is reported as a
+    /// regular `SafetyCheck`.
+    pub fn safety_check_class(&self, class: PropertyClass) -> PropertyClass {
+        if self.queries.args().property_class.iter().any(|selected| selected == class.as_str()) {
+            class
+        } else {
+            PropertyClass::SafetyCheck
+        }
+    }
+
     /// Generates a CBMC assertion. Note: Does _NOT_ assume.
     pub fn codegen_assert(
         &self,
@@ -239,7 +289,26 @@ pub fn codegen_panic(&self, span: SpanStable, fargs: Vec<Expr>) -> Stmt {
         let msg = self.extract_const_message(&fargs[0]).unwrap_or(String::from(
             "This is a placeholder message; Kani doesn't support message formatted at runtime",
         ));
-        self.codegen_fatal_error(PropertyClass::Assertion, &msg, span)
+        let (property_class, msg) = self.panic_property(&msg);
+        self.codegen_fatal_error(property_class, &msg, span)
+    }
+
+    /// The property class and message of a check that fails if a Rust panic is reachable.
+    ///
+    /// Panics are ordinary assertions, except in contract harnesses of functions annotated with
+    /// `#[kani::ensures_no_panic]`, where panics reachable from the function under contract
+    /// violate the contract.
+    pub fn panic_property(&self, msg: &str) -> (PropertyClass, String) {
+        if let Some((function, instances)) = &self.no_panic_contract
+            && self.current_fn.as_ref().is_some_and(|f| instances.contains(&f.instance_stable()))
+        {
+            (
+                PropertyClass::EnsuresNoPanic,
+                format!("ensures_no_panic: `{function}` must not panic: {msg}"),
+            )
+        } else {
+            (PropertyClass::Assertion, msg.to_string())
+        }
     }
 
     /// Kani does not currently support all MIR constructs.
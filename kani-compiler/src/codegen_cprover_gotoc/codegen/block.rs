@@ -19,6 +19,7 @@ impl GotocCtx<'_> {
     /// `self.current_fn_mut().push_onto_block(...)`
     pub fn codegen_block(&mut self, bb: BasicBlockIdx, bbd: &BasicBlock) {
         debug!(?bb, "codegen_block");
+        self.current_fn_mut().set_current_bb(bb);
         let label = bb_label(bb);
         // the first statement should be labelled. if there is no statements, then the
         // terminator should be labelled.
@@ -7,10 +7,14 @@
 //!
 //! All other functions will be replaced by an unimplemented check, due to current issues with
 //! linking and usability unless unstable C-FFI support is enabled.
+//!
+//! Functions declared in an `extern` block annotated with `#[kani::ffi_stubs(...)]` are replaced
+//! by their stubs before codegen. The ones without a stub are replaced by a failing check.
 use std::collections::HashSet;
 
 use crate::codegen_cprover_gotoc::GotocCtx;
 use crate::codegen_cprover_gotoc::codegen::PropertyClass;
+use crate::kani_middle::attributes::KaniAttributes;
 use crate::unwrap_or_return_codegen_unimplemented_stmt;
 use cbmc::goto_program::{Expr, Location, Stmt, Symbol, Type};
 use cbmc::{InternString, InternedString};
@@ -19,6 +23,7 @@
 use rustc_public::abi::{CallConvention, PassMode};
 use rustc_public::mir::Place;
 use rustc_public::mir::mono::Instance;
+use rustc_public::rustc_internal;
 use rustc_public::ty::{RigidTy, TyKind};
 use tracing::{debug, trace};
 
@@ -67,6 +72,16 @@ pub fn codegen_foreign_fn(&mut self, instance: Instance) -> &Symbol {
                 Symbol::function(trimmed_fn_name, typ, None, instance.name(), loc)
                     .with_is_extern(true)
             })
+        } else if self.is_declared_with_ffi_stubs(instance) {
+            // The stubs of the `extern` block have already been applied, so this function has no
+            // model. Fail verification even if C-FFI is enabled.
+            let shim_name = format!("{mangled_fn_name}_no_model");
+            trace!(?shim_name, "codegen_foreign_function");
+            self.ensure(&shim_name, |gcx, _| {
+                let typ = gcx.codegen_ffi_type(instance);
+                let body = gcx.codegen_ffi_no_model(instance, loc);
+                Symbol::function(&shim_name, typ, Some(body), instance.name(), loc)
+            })
         } else if self.is_cffi_enabled() && instance.fn_abi().unwrap().conv == CallConvention::C {
             // When C-FFI feature is enabled, we just trust the rust declaration.
             // TODO: Add proper casting and clashing definitions check.
@@ -133,6 +148,32 @@ fn is_cffi_enabled(&self) -> bool {
         self.queries.args().unstable_features.contains(&"c-ffi".to_string())
     }
 
+    /// Checks whether this function is declared in an `extern` block annotated with
+    /// `#[kani::ffi_stubs(...)]`.
+    fn is_declared_with_ffi_stubs(&self, instance: Instance) -> bool {
+        if !self.queries.args().unstable_features.contains(&"ffi-stubs".to_string()) {
+            return false;
+        }
+        let def_id = rustc_internal::internal(self.tcx, instance.def.def_id());
+        self.tcx
+            .opt_parent(def_id)
+            .is_some_and(|parent| KaniAttributes::for_item(self.tcx, parent).has_ffi_stubs())
+    }
+
+    /// Generate the body of a function declared in an `extern` block with `ffi_stubs` that
+    /// doesn't have a stub.
+    fn codegen_ffi_no_model(&mut self, instance: Instance, loc: Location) -> Stmt {
+        let fn_name = instance.trimmed_name();
+        debug!(?fn_name, ?loc, "codegen_ffi_no_model");
+        let check = self.codegen_assert_assume(
+            Expr::bool_false(),
+            PropertyClass::UnsupportedConstruct,
+            &format!("no model for extern fn `{fn_name}`"),
+            loc,
+        );
+        Stmt::block(vec![check], loc)
+    }
+
     /// Generate code for a foreign function shim.
     fn codegen_ffi_shim(&mut self, shim_name: InternedString, instance: Instance) -> Stmt {
         debug!(?shim_name, ?instance, sym=?self.symbol_table.lookup(shim_name), "generate_foreign_shim");
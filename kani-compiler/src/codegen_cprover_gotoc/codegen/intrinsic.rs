@@ -1832,7 +1832,7 @@ pub fn codegen_float_to_int_unchecked(
     ) -> Stmt {
         let finite_check = self.codegen_assert_assume(
             expr.clone().is_finite(),
-            PropertyClass::ArithmeticOverflow,
+            PropertyClass::FloatToIntUnchecked,
             format!("{intrinsic}: attempt to convert a non-finite value to an integer").as_str(),
             loc,
         );
@@ -1852,7 +1852,7 @@ pub fn codegen_float_to_int_unchecked(
 
         let range_check = self.codegen_assert_assume(
             in_range,
-            PropertyClass::ArithmeticOverflow,
+            PropertyClass::FloatToIntUnchecked,
             format!("{intrinsic}: attempt to convert a value out of range of the target integer")
                 .as_str(),
             loc,
@@ -4,7 +4,7 @@
 use crate::codegen_cprover_gotoc::codegen::PropertyClass;
 use crate::codegen_cprover_gotoc::codegen::place::ProjectedPlace;
 use crate::codegen_cprover_gotoc::codegen::ty_stable::pointee_type_stable;
-use crate::codegen_cprover_gotoc::utils::{dynamic_fat_ptr, slice_fat_ptr};
+use crate::codegen_cprover_gotoc::utils::{codegen_in_range_expr, dynamic_fat_ptr, slice_fat_ptr};
 use crate::codegen_cprover_gotoc::{GotocCtx, VtableCtx};
 use crate::kani_middle::abi::LayoutOf;
 use crate::kani_middle::coercion::{
@@ -944,7 +944,23 @@ pub fn codegen_rvalue_stable(&mut self, rv: &Rvalue, loc: Location) -> Expr {
             Rvalue::ThreadLocalRef(def_id) => {
                 // Since Kani is single-threaded, we treat a thread local like a static variable:
                 self.store_concurrent_construct("thread local (replaced by static variable)", loc);
-                self.codegen_thread_local_pointer(*def_id)
+                // That is only correct as long as no spawned thread relies on it.
+                let in_spawned_thread = self.in_spawned_thread_var(loc);
+                let construct = "accessing a thread-local variable in a spawned thread";
+                let msg = format!(
+                    "{}. Thread-local variables are modeled as global variables of the harness \
+                    thread",
+                    GotocCtx::unsupported_msg(construct, None)
+                );
+                let check = self.codegen_assert_assume(
+                    in_spawned_thread.not(),
+                    PropertyClass::UnsupportedConstruct,
+                    &msg,
+                    loc,
+                );
+                let ptr = self.codegen_thread_local_pointer(*def_id);
+                let typ = ptr.typ().clone();
+                Expr::statement_expression(vec![check, ptr.as_stmt(loc)], typ, loc)
             }
             // A CopyForDeref is equivalent to a read from a place at the codegen level.
             // https://github.com/rust-lang/rust/blob/1673f1450eeaf4a5452e086db0fe2ae274a0144f/compiler/rustc_middle/src/mir/syntax.rs#L1055
@@ -1098,6 +1114,37 @@ fn codegen_fat_ptr_to_thin_ptr_cast(&mut self, src: &Operand, dst_t: Ty) -> Expr
         src_goto_expr.member("data", &self.symbol_table).cast_to(dst_goto_typ)
     }
 
+    /// Generate the saturating semantics of a float to integer `as` cast:
+    ///     1. NaN is converted to `0`
+    ///     2. Values whose truncation fits in the target type are truncated
+    ///     3. Other values saturate to the target type's `MIN` or `MAX` depending on their sign
+    ///
+    /// The range check reuses the bounds of `float_to_int_unchecked`, so both kinds of cast
+    /// agree on which values are in range, including the rounding cases such as `i32::MAX as f32`.
+    fn codegen_float_to_int_saturating_cast(
+        &mut self,
+        src: &Operand,
+        src_ty: Ty,
+        dst_ty: Ty,
+    ) -> Expr {
+        let TyKind::RigidTy(RigidTy::Float(float_ty)) = src_ty.kind() else {
+            unreachable!("Expected float type, but found: `{src_ty:?}`")
+        };
+        let TyKind::RigidTy(integral_ty) = dst_ty.kind() else {
+            unreachable!("Expected integral type, but found: `{dst_ty:?}`")
+        };
+        let value = self.codegen_operand_stable(src);
+        let int_type = self.codegen_ty_stable(dst_ty);
+        let mm = self.symbol_table.machine_model();
+        let in_range = codegen_in_range_expr(&value, float_ty, integral_ty, mm);
+        let saturated = value
+            .clone()
+            .is_negative()
+            .ternary(int_type.min_int_expr(mm), int_type.max_int_expr(mm));
+        let is_nan = value.clone().neq(value.clone());
+        is_nan.ternary(int_type.zero(), in_range.ternary(value.cast_to(int_type), saturated))
+    }
+
     /// This handles all kinds of casts, except a limited subset that are instead
     /// handled by [`Self::codegen_pointer_cast`].
     fn codegen_misc_cast(&mut self, src: &Operand, dst_ty: Ty) -> Expr {
@@ -1109,6 +1156,11 @@ fn codegen_misc_cast(&mut self, src: &Operand, dst_ty: Ty) -> Expr {
         let src_ty_kind = src_ty.kind();
         let dst_ty_kind = dst_ty.kind();
 
+        // Float to integer `as` casts saturate in Rust, while a CBMC typecast has C semantics
+        if src_ty_kind.is_float() && dst_ty_kind.is_integral() {
+            return self.codegen_float_to_int_saturating_cast(src, src_ty, dst_ty);
+        }
+
         // number casting
         if src_ty_kind.is_numeric() && dst_ty_kind.is_numeric() {
             return self.codegen_operand_stable(src).cast_to(self.codegen_ty_stable(dst_ty));
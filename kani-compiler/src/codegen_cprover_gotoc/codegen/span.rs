@@ -10,6 +10,7 @@
 use rustc_public::rustc_internal;
 use rustc_public::ty::Span as SpanStable;
 use rustc_span::Span;
+use rustc_span::hygiene::ExpnKind;
 use std::collections::HashMap;
 
 lazy_static! {
@@ -31,6 +32,9 @@
          ("pointer-primitive", "disable:pointer-primitive-check")].iter().copied().collect();
 }
 
+/// The macros whose assertions are handled according to `--assertion-semantics`.
+const DEBUG_ASSERT_MACROS: [&str; 3] = ["debug_assert", "debug_assert_eq", "debug_assert_ne"];
+
 impl GotocCtx<'_> {
     pub fn codegen_span(&self, sp: &Span) -> Location {
         self.codegen_span_stable(rustc_internal::stable(sp))
@@ -89,6 +93,15 @@ pub fn codegen_caller_span(&self, span: &Span) -> Location {
         let topmost = span.ctxt().outer_expn().expansion_cause().unwrap_or(*span);
         self.codegen_span(&topmost)
     }
+
+    /// Whether the code at the given span was expanded from one of the `debug_assert!` macros.
+    pub fn is_debug_assertion(&self, sp: SpanStable) -> bool {
+        let span = rustc_internal::internal(self.tcx, sp);
+        span.macro_backtrace().any(|expn| {
+            matches!(expn.kind, ExpnKind::Macro(_, name)
+                if DEBUG_ASSERT_MACROS.contains(&name.as_str()))
+        })
+    }
 }
 
 /// Extracts the single argument from the attribute provided as a string.
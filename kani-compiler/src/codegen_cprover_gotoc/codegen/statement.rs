@@ -349,8 +349,12 @@ pub fn codegen_terminator(&mut self, term: &Terminator) -> Stmt {
                     }
                 };
 
-                let (msg_str, reach_stmt) =
-                    self.codegen_reachability_check(msg.to_owned(), term.span);
+                let (property_class, msg) = if property_class == PropertyClass::Assertion {
+                    self.panic_property(msg)
+                } else {
+                    (property_class, msg.to_string())
+                };
+                let (msg_str, reach_stmt) = self.codegen_reachability_check(msg, term.span);
 
                 Stmt::block(
                     vec![
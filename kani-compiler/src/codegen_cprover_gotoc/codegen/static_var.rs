@@ -5,10 +5,14 @@
 
 use crate::codegen_cprover_gotoc::GotocCtx;
 use crate::kani_middle::is_interior_mut;
+use cbmc::goto_program::{Expr, Location, Type};
 use rustc_public::CrateDef;
 use rustc_public::mir::mono::{Instance, StaticDef};
 use tracing::debug;
 
+/// The name of the ghost variable that is set while the closure of a spawned thread runs.
+const IN_SPAWNED_THREAD_VAR_NAME: &str = "__kani_in_spawned_thread";
+
 impl GotocCtx<'_> {
     /// Ensures a static variable is initialized.
     ///
@@ -47,4 +51,22 @@ pub fn declare_static(&mut self, def: StaticDef) {
             .set_is_hidden(false) // Static items are always user defined.
             .set_pretty_name(pretty_name);
     }
+
+    /// The ghost variable that is set while the closure of a spawned thread runs.
+    ///
+    /// Thread-local variables are modeled as global variables of the harness thread, so the
+    /// accesses to them while this is set are reported as unsupported. The variable itself is
+    /// thread-local, so it is only set for the spawned thread.
+    pub fn in_spawned_thread_var(&mut self, loc: Location) -> Expr {
+        let var = self.ensure_global_var_init(
+            IN_SPAWNED_THREAD_VAR_NAME,
+            true,
+            false,
+            Type::bool(),
+            loc,
+            |_, _| Expr::bool_false(),
+        );
+        var.is_thread_local = true;
+        var.to_expr()
+    }
 }
@@ -8,12 +8,15 @@
 use crate::codegen_cprover_gotoc::utils::file_writing_pool::{FileDataToWrite, ThreadPool};
 use crate::codegen_cprover_gotoc::{GotocCtx, context};
 use crate::kani_middle::analysis;
-use crate::kani_middle::attributes::KaniAttributes;
+use crate::kani_middle::attributes::{KaniAttributes, contract_harness_target};
 use crate::kani_middle::check_reachable_items;
 use crate::kani_middle::codegen_units::{CodegenUnit, CodegenUnits};
+use crate::kani_middle::metadata::{
+    has_nondet_source, nondet_bytes, reachable_function_names, uses_concretize,
+};
 use crate::kani_middle::provide;
-use crate::kani_middle::reachability::{collect_reachable_items, filter_crate_items};
-use crate::kani_middle::transform::{BodyTransformation, GlobalPasses};
+use crate::kani_middle::reachability::{CallGraph, collect_reachable_items, filter_crate_items};
+use crate::kani_middle::transform::{BodyTransformation, GlobalPasses, MutantCollector};
 use crate::kani_queries::QueryDb;
 use cbmc::goto_program::Location;
 use cbmc::{InternedString, MachineModel};
@@ -46,7 +49,7 @@
 use rustc_target::spec::PanicStrategy;
 use std::any::Any;
 use std::cmp::min;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write;
 use std::fs::File;
 use std::io::BufWriter;
@@ -119,6 +122,19 @@ fn codegen_items<'tcx>(
             })
             .collect();
 
+        // Panics only violate `#[kani::ensures_no_panic]` if they are reachable from the function
+        // under contract. Panics in the harness itself are still ordinary assertions.
+        let no_panic_contract = check_contract
+            .filter(|check_id| KaniAttributes::for_item(tcx, *check_id).ensures_no_panic());
+        let no_panic_scope = |call_graph: &CallGraph| {
+            no_panic_contract.map(|check_id| {
+                call_graph.reachable_from(|instance| {
+                    rustc_internal::internal(tcx, instance.def.def_id()) == check_id
+                })
+            })
+        };
+        let mut no_panic_instances = no_panic_scope(&call_graph);
+
         // Apply all transformation passes, including global passes.
         let any_pass_modified = global_passes.run_global_passes(
             &mut transformer,
@@ -131,10 +147,12 @@ fn codegen_items<'tcx>(
         // Re-collect reachable items after global transformations were applied. This is necessary
         // since global pass could add extra calls to instrumentation.
         if any_pass_modified {
-            (items, _) = with_timer(
+            let (new_items, call_graph) = with_timer(
                 || collect_reachable_items(tcx, &mut transformer, starting_items),
                 "codegen reachability analysis (second pass)",
             );
+            items = new_items;
+            no_panic_instances = no_panic_scope(&call_graph);
         }
 
         // Follow rustc naming convention (cx is abbrev for context).
@@ -142,6 +160,9 @@ fn codegen_items<'tcx>(
         let mut gcx =
             GotocCtx::new(tcx, (*self.queries.lock().unwrap()).clone(), machine_model, transformer);
         check_reachable_items(gcx.tcx, &gcx.queries, &items);
+        if let (Some(check_id), Some(instances)) = (no_panic_contract, no_panic_instances) {
+            gcx.no_panic_contract = Some((tcx.def_path_str(check_id), instances));
+        }
 
         let contract_info = with_timer(
             || {
@@ -221,7 +242,10 @@ fn codegen_items<'tcx>(
 
         // No output should be generated if user selected no_codegen.
         if !tcx.sess.opts.unstable_opts.no_codegen && tcx.sess.opts.output_types.should_codegen() {
-            let pretty = self.queries.lock().unwrap().args().output_pretty_json;
+            let (pretty, json_symtab) = {
+                let queries = self.queries.lock().unwrap();
+                (queries.args().output_pretty_json, queries.args().dump_symtab)
+            };
 
             // Save all the data needed to write this goto file
             // so another thread can handle it in parallel.
@@ -232,6 +256,7 @@ fn codegen_items<'tcx>(
                 type_map,
                 pretty_name_map,
                 pretty,
+                json_symtab,
             };
 
             // Package the file data with a copy of the string interner used to generate it.
@@ -261,7 +286,8 @@ fn thread_pool_size(known_num_harnesses: Option<usize>) -> usize {
     }
 
     /// Given a harness, return the DefId of its target if it's a contract harness.
-    /// For manual harnesses, extract it from the #[proof_for_contract] attribute.
+    /// For manual harnesses, extract it from the #[proof_for_contract] attribute, or from the
+    /// type a #[proof_for_contract_all_impls] harness is instantiated with.
     /// For automatic harnesses, extract the target from the harness's GenericArgs.
     fn target_if_contract_harness(
         &self,
@@ -275,8 +301,7 @@ fn target_if_contract_harness(
             let attrs = KaniAttributes::for_def_id(tcx, fn_to_verify_def.def_id());
             if attrs.has_contract() { Some(fn_to_verify_def) } else { None }
         } else {
-            let harness_attrs = KaniAttributes::for_def_id(tcx, harness.def.def_id());
-            harness_attrs.interpret_for_contract_attribute()
+            contract_harness_target(tcx, *harness)
         }
     }
 }
@@ -386,12 +411,26 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
             match reachability {
                 ReachabilityType::AllFns | ReachabilityType::Harnesses => {
                     let mut units = CodegenUnits::new(&queries, tcx);
+                    // Only report the harnesses that were found once their contract targets are
+                    // checked. Note that we skip codegen, so any metadata that depends on it,
+                    // like loop contracts, is missing.
+                    let metadata_only =
+                        queries.args().unstable_features.contains(&"metadata-only".to_string());
                     let mut modifies_instances = vec![];
                     let mut loop_contracts_instances = vec![];
+                    let mut loop_unwind_bounds = vec![];
+                    let mut concrete_harnesses = vec![];
+                    let mut concretized_harnesses = vec![];
+                    let mut reachable_functions = vec![];
+                    let mut nondet_bytes = vec![];
+                    let mut debug_assertions = vec![];
+                    let mut mutants = queries.args().list_mutants.then(MutantCollector::default);
 
                     // We know the # of harnesses here, so provide them to the thread_pool size calculation.
                     let num_harnesses: usize = units.iter().map(|unit| unit.harnesses.len()).sum();
-                    export_thread_pool.add_workers(Self::thread_pool_size(Some(num_harnesses)));
+                    if !metadata_only {
+                        export_thread_pool.add_workers(Self::thread_pool_size(Some(num_harnesses)));
+                    }
 
                     let template_passes = GlobalPasses::new(&queries, tcx);
 
@@ -404,11 +443,17 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
                         // (They all share the same options.)
                         let template_transformer = BodyTransformation::new(&queries, tcx, unit);
                         for harness in &unit.harnesses {
+                            if units.is_vacuous_harness(harness) {
+                                continue;
+                            }
                             let model_path = units.harness_model_path(*harness).unwrap();
                             let is_automatic_harness = units.is_automatic_harness(harness);
                             let contract_metadata =
                                 self.target_if_contract_harness(tcx, harness, is_automatic_harness);
-                            let (min_gcx, items, contract_info) = self.codegen_items(
+                            if metadata_only {
+                                continue;
+                            }
+                            let (mut min_gcx, items, contract_info) = self.codegen_items(
                                 tcx,
                                 &[MonoItem::Fn(*harness)],
                                 model_path,
@@ -422,6 +467,42 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
                             if min_gcx.has_loop_contracts {
                                 loop_contracts_instances.push(*harness);
                             }
+                            if !min_gcx.loop_unwind_bounds.is_empty() {
+                                loop_unwind_bounds
+                                    .push((*harness, min_gcx.loop_unwind_bounds.clone()));
+                            }
+                            if !has_nondet_source(&items) {
+                                concrete_harnesses.push(*harness);
+                            }
+                            if uses_concretize(&items) {
+                                concretized_harnesses.push(*harness);
+                            }
+                            if queries.args().emit_reachable {
+                                reachable_functions
+                                    .push((*harness, reachable_function_names(&items)));
+                            }
+                            // Counting the bytes walks every reachable body again, so only do
+                            // it if the count is bounded or printed.
+                            let max_nondet_bytes = queries.args().max_nondet_bytes;
+                            if max_nondet_bytes.is_some() || queries.args().nondet_bytes_stats {
+                                let sources = nondet_bytes(tcx, &mut min_gcx.transformer, *harness);
+                                let harness_nondet_bytes = sources.values().sum();
+                                if let Some(max) = max_nondet_bytes
+                                    && harness_nondet_bytes > max
+                                {
+                                    report_nondet_bytes(
+                                        tcx,
+                                        units.harness_pretty_name(*harness),
+                                        &sources,
+                                        max,
+                                    );
+                                }
+                                nondet_bytes.push((*harness, harness_nondet_bytes));
+                            }
+                            debug_assertions.push((*harness, min_gcx.debug_assertions));
+                            if let Some(mutants) = &mut mutants {
+                                mutants.collect(tcx, units.harness_pretty_name(*harness), &items);
+                            }
                             results.extend(min_gcx, items, None);
                             if let Some(assigns_contract) = contract_info {
                                 modifies_instances.push((*harness, assigns_contract));
@@ -430,7 +511,19 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
                     }
                     units.store_modifies(&modifies_instances);
                     units.store_loop_contracts(&loop_contracts_instances);
+                    units.store_loop_unwind_bounds(loop_unwind_bounds);
+                    units.store_concrete_harnesses(&concrete_harnesses);
+                    units.store_concretized_harnesses(&concretized_harnesses);
+                    units.store_reachable_functions(reachable_functions);
+                    units.store_nondet_bytes(nondet_bytes);
+                    units.store_debug_assertions(debug_assertions);
+                    if let Some(mutants) = mutants {
+                        units.store_mutants(mutants.into_mutants());
+                    }
                     units.write_metadata(&queries, tcx);
+                    if metadata_only {
+                        return codegen_results(tcx, &results.machine_model);
+                    }
                 }
                 ReachabilityType::None => unreachable!(),
                 ReachabilityType::PubFns => {
@@ -708,6 +801,7 @@ pub fn generate_metadata(&self) -> KaniMetadata {
             // which is the only ReachabilityType under which the compiler calls this function.
             contracted_functions: vec![],
             autoharness_md: None,
+            mutants: vec![],
         }
     }
 
@@ -883,6 +977,29 @@ fn new_machine_model(sess: &Session) -> MachineModel {
     }
 }
 
+/// The number of source locations listed when a harness exceeds `--max-nondet-bytes`.
+const MAX_NONDET_SOURCES: usize = 5;
+
+/// Report that a harness introduces more nondeterministic bytes than `max`, listing the source
+/// locations that contribute the most to it.
+fn report_nondet_bytes(tcx: TyCtxt, harness: &str, nondet_bytes: &HashMap<String, u64>, max: u64) {
+    let total: u64 = nondet_bytes.values().sum();
+    let mut sources: Vec<(String, u64)> =
+        nondet_bytes.iter().map(|(source, bytes)| (source.clone(), *bytes)).collect();
+    sources.sort_by(|(src1, bytes1), (src2, bytes2)| bytes2.cmp(bytes1).then(src1.cmp(src2)));
+    let mut msg = format!(
+        "harness `{harness}` introduces {total} nondeterministic bytes, which exceeds the limit of \
+        {max} set by `--max-nondet-bytes`. The top contributors are:\n"
+    );
+    for (source, bytes) in sources.iter().take(MAX_NONDET_SOURCES) {
+        writeln!(&mut msg, "    - {bytes} bytes: {source}").unwrap();
+    }
+    if sources.len() > MAX_NONDET_SOURCES {
+        writeln!(&mut msg, "    - ... and {} more", sources.len() - MAX_NONDET_SOURCES).unwrap();
+    }
+    tcx.dcx().err(msg);
+}
+
 /// Execute the provided function and measure the clock time it took for its execution.
 /// Log the time with the given description.
 pub fn with_timer<T, F>(func: F, description: &str) -> T
@@ -7,7 +7,9 @@
 use rustc_middle::ty::Instance as InstanceInternal;
 use rustc_public::CrateDef;
 use rustc_public::mir::mono::Instance;
-use rustc_public::mir::{Body, Local, LocalDecl, Rvalue, visit::Location, visit::MirVisitor};
+use rustc_public::mir::{
+    BasicBlockIdx, Body, Local, LocalDecl, Rvalue, visit::Location, visit::MirVisitor,
+};
 use rustc_public::rustc_internal;
 use std::collections::{HashMap, HashSet};
 
@@ -16,6 +18,8 @@
 pub struct CurrentFnCtx<'tcx> {
     /// The GOTO block we are compiling into
     block: Vec<Stmt>,
+    /// The MIR basic block we are currently compiling
+    current_bb: BasicBlockIdx,
     /// The codegen instance for the current function
     instance: Instance,
     /// The crate this function is from
@@ -70,6 +74,7 @@ pub fn new(instance: Instance, gcx: &GotocCtx<'tcx>, body: &Body) -> Self {
         visitor.visit_body(body);
         Self {
             block: vec![],
+            current_bb: 0,
             instance,
             instance_internal,
             krate: instance.def.krate().name,
@@ -99,6 +104,10 @@ pub fn get_and_incr_counter(&mut self) -> u64 {
     pub fn push_onto_block(&mut self, s: Stmt) {
         self.block.push(s)
     }
+
+    pub fn set_current_bb(&mut self, bb: BasicBlockIdx) {
+        self.current_bb = bb
+    }
 }
 
 /// Getters
@@ -117,6 +126,11 @@ pub fn name(&self) -> String {
         self.name.clone()
     }
 
+    /// The MIR basic block we are currently compiling
+    pub fn current_bb(&self) -> BasicBlockIdx {
+        self.current_bb
+    }
+
     /// The pretty name of the function we are currently compiling
     pub fn readable_name(&self) -> &str {
         &self.readable_name
@@ -27,6 +27,7 @@
 };
 use cbmc::utils::aggr_tag;
 use cbmc::{InternedString, MachineModel};
+use kani_metadata::LoopUnwindBound;
 use rustc_abi::{HasDataLayout, TargetDataLayout};
 use rustc_data_structures::fx::FxHashMap;
 use rustc_middle::span_bug;
@@ -60,6 +61,10 @@ pub struct MinimalGotocCtx {
     pub transformer: BodyTransformation,
     /// If there exist some usage of loop contracts int context.
     pub has_loop_contracts: bool,
+    /// The unwind bounds of the loops annotated with `#[kani::loop_unwind]`.
+    pub loop_unwind_bounds: Vec<LoopUnwindBound>,
+    /// The number of debug assertions handled according to `--assertion-semantics`.
+    pub debug_assertions: u64,
 }
 
 pub struct GotocCtx<'tcx> {
@@ -96,8 +101,16 @@ pub struct GotocCtx<'tcx> {
     pub transformer: BodyTransformation,
     /// If there exist some usage of loop contracts int context.
     pub has_loop_contracts: bool,
+    /// The unwind bounds of the loops annotated with `#[kani::loop_unwind]`.
+    pub loop_unwind_bounds: Vec<LoopUnwindBound>,
+    /// The number of debug assertions handled according to `--assertion-semantics`.
+    pub debug_assertions: u64,
     /// Track loop assign clause
     pub current_loop_modifies: Vec<Expr>,
+    /// The name of the function under contract if this is a contract harness for a function
+    /// annotated with `#[kani::ensures_no_panic]`, together with the instances reachable from it.
+    /// Panics inside those instances are reported as contract violations.
+    pub no_panic_contract: Option<(String, HashSet<Instance>)>,
 }
 
 /// Constructor
@@ -128,7 +141,10 @@ pub fn new(
             concurrent_constructs: FxHashMap::default(),
             transformer,
             has_loop_contracts: false,
+            loop_unwind_bounds: Vec::new(),
+            debug_assertions: 0,
             current_loop_modifies: Vec::new(),
+            no_panic_contract: None,
         }
     }
 
@@ -141,6 +157,8 @@ pub fn split(self) -> (MinimalGotocCtx, SymbolTable) {
                 concurrent_constructs: self.concurrent_constructs,
                 transformer: self.transformer,
                 has_loop_contracts: self.has_loop_contracts,
+                loop_unwind_bounds: self.loop_unwind_bounds,
+                debug_assertions: self.debug_assertions,
             },
             self.symbol_table,
         )
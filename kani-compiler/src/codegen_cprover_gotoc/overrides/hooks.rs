@@ -16,6 +16,7 @@
 use cbmc::goto_program::CIntType;
 use cbmc::goto_program::Symbol as GotoSymbol;
 use cbmc::goto_program::{BuiltinFn, Expr, Location, Stmt, Type};
+use kani_metadata::{AssertionSemantics, LoopUnwindBound};
 use rustc_middle::ty::TyCtxt;
 use rustc_public::mir::mono::Instance;
 use rustc_public::mir::{BasicBlockIdx, Place};
@@ -130,10 +131,27 @@ fn handle(
         assert_eq!(fargs.len(), 2);
         let cond = fargs.remove(0).cast_to(Type::bool());
         let msg = fargs.remove(0);
-        let msg = gcx.extract_const_message(&msg).unwrap();
         let target = target.unwrap();
         let caller_loc = gcx.codegen_caller_span_stable(span);
 
+        if gcx.is_debug_assertion(span) {
+            gcx.debug_assertions += 1;
+            match gcx.queries.args().assertion_semantics {
+                AssertionSemantics::DebugAsAssert => {}
+                AssertionSemantics::DebugAsAssume => {
+                    return Stmt::block(
+                        vec![
+                            gcx.codegen_assume(cond, caller_loc),
+                            Stmt::goto(bb_label(target), caller_loc),
+                        ],
+                        caller_loc,
+                    );
+                }
+                AssertionSemantics::DebugIgnore => return Stmt::goto(bb_label(target), caller_loc),
+            }
+        }
+
+        let msg = gcx.extract_const_message(&msg).unwrap();
         let (msg, reach_stmt) = gcx.codegen_reachability_check(msg, span);
 
         Stmt::block(
@@ -147,6 +165,38 @@ fn handle(
     }
 }
 
+struct AssertPrecondition;
+impl GotocHook for AssertPrecondition {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 2);
+        let cond = fargs.remove(0).cast_to(Type::bool());
+        let msg = fargs.remove(0);
+        let msg = gcx.extract_const_message(&msg).unwrap();
+        let target = target.unwrap();
+        let caller_loc = gcx.codegen_caller_span_stable(span);
+
+        Stmt::block(
+            vec![
+                gcx.codegen_assert_assume(cond, PropertyClass::Precondition, &msg, caller_loc),
+                Stmt::goto(bb_label(target), caller_loc),
+            ],
+            caller_loc,
+        )
+    }
+}
+
 struct UnsupportedCheck;
 impl GotocHook for UnsupportedCheck {
     fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
@@ -215,6 +265,82 @@ fn handle(
     }
 }
 
+/// A safety check for pointer arithmetic, which is reported under its own property class if the
+/// user selected it. The check is skipped if the user turned off pointer offset checks.
+struct PointerOffsetCheck;
+impl GotocHook for PointerOffsetCheck {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 2);
+        let msg = fargs.pop().unwrap();
+        let cond = fargs.pop().unwrap().cast_to(Type::bool());
+        let msg = gcx.extract_const_message(&msg).unwrap();
+        let target = target.unwrap();
+        let caller_loc = gcx.codegen_caller_span_stable(span);
+        if gcx.queries.args().no_pointer_offset_checks {
+            return Stmt::goto(bb_label(target), caller_loc);
+        }
+        Stmt::block(
+            vec![
+                gcx.codegen_assert_assume(
+                    cond,
+                    gcx.safety_check_class(PropertyClass::PointerOffset),
+                    &msg,
+                    caller_loc,
+                ),
+                Stmt::goto(bb_label(target), caller_loc),
+            ],
+            caller_loc,
+        )
+    }
+}
+
+/// A safety check that memory is initialized, which is reported under its own property class if
+/// the user selected it. The condition is only assumed after the check if `assume` is set.
+struct UninitCheck {
+    assume: bool,
+}
+impl GotocHook for UninitCheck {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 2);
+        let msg = fargs.pop().unwrap();
+        let cond = fargs.pop().unwrap().cast_to(Type::bool());
+        let msg = gcx.extract_const_message(&msg).unwrap();
+        let target = target.unwrap();
+        let caller_loc = gcx.codegen_caller_span_stable(span);
+        let class = gcx.safety_check_class(PropertyClass::UninitializedMemory);
+        let check = if self.assume {
+            gcx.codegen_assert_assume(cond, class, &msg, caller_loc)
+        } else {
+            gcx.codegen_assert(cond, class, &msg, caller_loc)
+        };
+        Stmt::block(vec![check, Stmt::goto(bb_label(target), caller_loc)], caller_loc)
+    }
+}
+
 struct SafetyCheckNoAssume;
 impl GotocHook for SafetyCheckNoAssume {
     fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
@@ -282,6 +408,56 @@ fn handle(
     }
 }
 
+/// A hook for `kani::internal::check_precondition`, which wraps every precondition in a
+/// contract harness. The function takes the precondition (bool) and its textual
+/// representation (&'static str), and returns the precondition.
+///
+/// With `--contract-negative`, the hook also emits two cover properties: one that checks the
+/// precondition can hold, and one that checks it can be violated. If the former is
+/// unsatisfiable, the contract is vacuous; if the latter is, the precondition is redundant.
+/// Otherwise, the call is simply replaced by the condition.
+struct CheckPrecondition;
+
+impl GotocHook for CheckPrecondition {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 2);
+        let cond = fargs.remove(0).cast_to(Type::bool());
+        let msg = fargs.remove(0);
+        let msg = gcx.extract_const_message(&msg).unwrap();
+        let target = target.unwrap();
+        let loc = gcx.codegen_span_stable(span);
+
+        let ret_place = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        );
+        let ret_type = ret_place.goto_expr.typ().clone();
+
+        let mut stmts = vec![];
+        if gcx.queries.args().contract_negative {
+            let satisfiable = format!("precondition is satisfiable: {msg}");
+            let violable = format!("precondition can be violated: {msg}");
+            stmts.push(gcx.codegen_cover(cond.clone(), &satisfiable, span));
+            stmts.push(gcx.codegen_cover(cond.clone().not(), &violable, span));
+        }
+        stmts.push(ret_place.goto_expr.assign(cond.cast_to(ret_type), loc));
+        stmts.push(Stmt::goto(bb_label(target), loc));
+        Stmt::block(stmts, loc)
+    }
+}
+
 struct Nondet;
 
 impl GotocHook for Nondet {
@@ -321,6 +497,61 @@ fn handle(
     }
 }
 
+/// Hook for `kani::concretize`, which constrains the bytes of the value to zero, the value the
+/// backend gives to static variables that have no initializer.
+///
+/// The constraint is followed by a cover property, which the driver reports as a failure if it's
+/// unsatisfiable, i.e., if zero isn't one of the values that the input can take, since the rest
+/// of the harness would be vacuous.
+struct Concretize;
+
+impl GotocHook for Concretize {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 1);
+        let loc = gcx.codegen_span_stable(span);
+        let target = target.unwrap();
+        let pt = gcx.place_ty_stable(assign_to);
+        let bits = gcx.codegen_ty_stable(pt).sizeof_in_bits(&gcx.symbol_table);
+        if bits == 0 {
+            // There is a single value of zero-sized types.
+            return Stmt::goto(bb_label(target), loc);
+        }
+        let pe = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        )
+        .goto_expr;
+        let bytes_typ = Type::unsigned_int(bits);
+        let bytes = pe.clone().address_of().cast_to(bytes_typ.clone().to_pointer()).dereference();
+        let msg = "kani::concretize: the value whose bytes are all zero is a possible value of the \
+            concretized input";
+        // The reachability check keeps an unreachable call from being reported as a failure.
+        let (msg, reach_stmt) = gcx.codegen_reachability_check(msg.to_string(), span);
+        Stmt::block(
+            vec![
+                reach_stmt,
+                pe.assign(fargs.remove(0), loc),
+                Stmt::assume(bytes.eq(bytes_typ.zero()), loc),
+                gcx.codegen_cover(Expr::bool_true(), &msg, span),
+                Stmt::goto(bb_label(target), loc),
+            ],
+            loc,
+        )
+    }
+}
+
 struct Panic;
 
 impl GotocHook for Panic {
@@ -350,6 +581,145 @@ fn handle(
     }
 }
 
+/// Models the standard library functions that never return and terminate the execution:
+///  - `std::process::exit` and `std::process::abort` end the execution. We codegen
+///    `assume(false)`, so no code after the call is reachable, instead of running the cleanup of
+///    the standard library runtime or raising a signal. Use `kani::cover!` or an assertion before
+///    the call to check whether a path terminates the process.
+///  - `core::hint::unreachable_unchecked` has undefined behavior if reached. We codegen a safety
+///    check that fails if the call is reachable.
+struct DivergentStdCall;
+
+impl GotocHook for DivergentStdCall {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        matches!(
+            instance.name().as_str(),
+            "std::process::exit" | "std::process::abort" | "core::hint::unreachable_unchecked"
+        )
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        _fargs: Vec<Expr>,
+        _assign_to: &Place,
+        _target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        let loc = gcx.codegen_span_stable(span);
+        match instance.name().as_str() {
+            "std::process::exit" | "std::process::abort" => {
+                gcx.codegen_assume(Expr::bool_false(), loc)
+            }
+            _ => gcx.codegen_assert_assume_false(
+                PropertyClass::SafetyCheck,
+                "`unreachable_unchecked` was reached, which has undefined behavior",
+                loc,
+            ),
+        }
+    }
+}
+
+/// Models the registration of the destructor of a `thread_local!` variable.
+///
+/// Kani treats thread-local variables as global variables of the harness thread, which never
+/// exits before the end of the harness. So the destructors would never run, and we codegen the
+/// registration as a no-op instead of calling into the platform specific runtime, e.g.
+/// `__cxa_thread_atexit_impl`, which Kani cannot model.
+struct ThreadLocalDtorRegister;
+
+impl GotocHook for ThreadLocalDtorRegister {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        let name = instance.name();
+        name.starts_with("std::sys::thread_local::destructors::") && name.ends_with("::register")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        _fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        let loc = gcx.codegen_span_stable(span);
+        Stmt::goto(bb_label(target.unwrap()), loc)
+    }
+}
+
+/// Records that the closure of a spawned thread is running.
+///
+/// The standard library runs the closure of a spawned thread with `__rust_begin_short_backtrace`,
+/// which is otherwise only used to run `main`. We set the ghost variable of
+/// [GotocCtx::in_spawned_thread_var] around the call, so the accesses of the spawned thread to
+/// the thread-local variables, which are modeled as global variables of the harness thread, are
+/// reported. Spawning a thread that doesn't use thread-local variables is not reported.
+struct SpawnedThreadBody;
+
+impl GotocHook for SpawnedThreadBody {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        instance.def.name() == "std::sys::backtrace::__rust_begin_short_backtrace"
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        let loc = gcx.codegen_span_stable(span);
+        let in_spawned_thread = gcx.in_spawned_thread_var(loc);
+        let place_expr = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        )
+        .goto_expr;
+        let call = place_expr.assign(gcx.codegen_func_expr(instance, loc).call(fargs), loc);
+        Stmt::block(
+            vec![
+                in_spawned_thread.clone().assign(Expr::bool_true(), loc),
+                call,
+                in_spawned_thread.assign(Expr::bool_false(), loc),
+                Stmt::goto(bb_label(target.unwrap()), loc),
+            ],
+            loc,
+        )
+    }
+}
+
+/// Reports that the harness thread waits for a lock that it already holds.
+///
+/// A harness runs a single thread, so such a lock would wait forever. The models of the locks of
+/// the standard library call this hook instead, with a constant message.
+struct Deadlock;
+
+impl GotocHook for Deadlock {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        _target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 1);
+        let msg = fargs.pop().unwrap();
+        let msg = gcx.extract_const_message(&msg).unwrap();
+        let loc = gcx.codegen_caller_span_stable(span);
+        gcx.codegen_assert_assume_false(PropertyClass::Deadlock, &msg, loc)
+    }
+}
+
 /// Encodes __CPROVER_r_ok(ptr, size)
 struct IsAllocated;
 impl GotocHook for IsAllocated {
@@ -774,6 +1144,55 @@ fn handle(
     }
 }
 
+/// A loop unwind register function call is assumed to be
+/// 1. of form `kani_register_loop_unwind(bound)` where `bound` is a constant
+/// 2. in the head of the loop annotated with `#[kani::loop_unwind(bound)]`
+///
+/// We record the bound of the loop, identified by the label of the current basic block,
+/// and translate the call to
+/// ```c
+/// assign_to = true
+/// goto target
+/// ```
+pub struct LoopUnwindRegister;
+
+impl GotocHook for LoopUnwindRegister {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        attributes::fn_marker(instance.def)
+            .is_some_and(|marker| marker == "kani_register_loop_unwind")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 1);
+        let loc = gcx.codegen_span_stable(span);
+        let bound = fargs[0].int_constant_value().expect("loop unwind bound should be constant");
+        let loop_id =
+            format!("{}.{}", gcx.current_fn().name(), bb_label(gcx.current_fn().current_bb()));
+        gcx.loop_unwind_bounds.push(LoopUnwindBound { loop_id, bound: bound.try_into().unwrap() });
+
+        Stmt::block(
+            vec![
+                unwrap_or_return_codegen_unimplemented_stmt!(
+                    gcx,
+                    gcx.codegen_place_stable(assign_to, loc)
+                )
+                .goto_expr
+                .assign(Expr::c_true(), loc),
+                Stmt::goto(bb_label(target.unwrap()), loc),
+            ],
+            loc,
+        )
+    }
+}
+
 struct Forall;
 struct Exists;
 
@@ -911,7 +1330,9 @@ fn find_closure_call_expr(instance: &Instance, gcx: &mut GotocCtx, loc: Location
 pub fn fn_hooks() -> GotocHooks {
     let kani_lib_hooks = [
         (KaniHook::Assert, Rc::new(Assert) as Rc<dyn GotocHook>),
+        (KaniHook::AssertPrecondition, Rc::new(AssertPrecondition)),
         (KaniHook::Assume, Rc::new(Assume)),
+        (KaniHook::Concretize, Rc::new(Concretize)),
         (KaniHook::Exists, Rc::new(Exists)),
         (KaniHook::Forall, Rc::new(Forall)),
         (KaniHook::Panic, Rc::new(Panic)),
@@ -923,18 +1344,27 @@ pub fn fn_hooks() -> GotocHooks {
         (KaniHook::IsAllocated, Rc::new(IsAllocated)),
         (KaniHook::PointerObject, Rc::new(PointerObject)),
         (KaniHook::PointerOffset, Rc::new(PointerOffset)),
+        (KaniHook::PointerOffsetCheck, Rc::new(PointerOffsetCheck)),
+        (KaniHook::UninitCheck, Rc::new(UninitCheck { assume: true })),
+        (KaniHook::UninitCheckNoAssume, Rc::new(UninitCheck { assume: false })),
         (KaniHook::UnsupportedCheck, Rc::new(UnsupportedCheck)),
+        (KaniHook::Deadlock, Rc::new(Deadlock)),
         (KaniHook::UntrackedDeref, Rc::new(UntrackedDeref)),
         (KaniHook::InitContracts, Rc::new(InitContracts)),
+        (KaniHook::CheckPrecondition, Rc::new(CheckPrecondition)),
         (KaniHook::FloatToIntInRange, Rc::new(FloatToIntInRange)),
     ];
     GotocHooks {
         kani_lib_hooks: HashMap::from(kani_lib_hooks),
         other_hooks: vec![
             Rc::new(Panic),
+            Rc::new(DivergentStdCall),
+            Rc::new(ThreadLocalDtorRegister),
+            Rc::new(SpawnedThreadBody),
             Rc::new(RustAlloc),
             Rc::new(MemCmp),
             Rc::new(LoopInvariantRegister),
+            Rc::new(LoopUnwindRegister),
         ],
     }
 }
@@ -23,6 +23,8 @@ pub(crate) struct FileDataToWrite {
     pub type_map: BTreeMap<InternedString, InternedString>,
     pub pretty_name_map: BTreeMap<InternedString, Option<InternedString>>,
     pub pretty: bool,
+    /// Also write the symbol table in CBMC's JSON symtab format, for `--dump-symtab`.
+    pub json_symtab: bool,
 }
 
 /// A thread pool of `N` worker threads specifically for writing Goto files in parallel.
@@ -98,9 +100,13 @@ fn handle_file(
         type_map,
         pretty_name_map,
         pretty,
+        json_symtab,
     }: FileDataToWrite,
 ) {
     write_file(&symtab_goto, ArtifactType::PrettyNameMap, &pretty_name_map, pretty);
+    if json_symtab {
+        write_file(&symtab_goto, ArtifactType::SymTab, &symbol_table, pretty);
+    }
     write_goto_binary_file(&symtab_goto, &symbol_table);
     write_file(&symtab_goto, ArtifactType::TypeMap, &type_map, pretty);
     // If they exist, write out vtable virtual call function pointer restrictions
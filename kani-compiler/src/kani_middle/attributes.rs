@@ -5,7 +5,10 @@
 use std::collections::{BTreeMap, HashSet};
 
 use fxhash::FxHashMap;
-use kani_metadata::{CbmcSolver, HarnessAttributes, HarnessKind, Stub};
+use kani_metadata::{
+    CbmcSolver, ContractClause, ContractClauseKind, ExpectedFailure, HarnessAttributes,
+    HarnessKind, Stub,
+};
 use quote::ToTokens;
 use rustc_ast::{LitKind, MetaItem, MetaItemKind};
 use rustc_errors::ErrorGuaranteed;
@@ -14,11 +17,12 @@
     def::DefKind,
     def_id::{DefId, LocalDefId},
 };
-use rustc_middle::ty::{Instance, TyCtxt, TyKind};
+use rustc_middle::mir::ConstValue;
+use rustc_middle::ty::{Instance, Ty, TyCtxt, TyKind};
 use rustc_public::crate_def::Attribute as AttributeStable;
 use rustc_public::mir::mono::Instance as InstanceStable;
 use rustc_public::rustc_internal;
-use rustc_public::ty::FnDef as FnDefStable;
+use rustc_public::ty::{FnDef as FnDefStable, GenericArgs as GenericArgsStable};
 use rustc_public::{CrateDef, DefId as DefIdStable, Symbol as SymbolStable};
 use rustc_session::Session;
 use rustc_span::{Span, Symbol};
@@ -26,9 +30,12 @@
 use strum_macros::{AsRefStr, EnumString};
 use syn::parse::Parser;
 use syn::punctuated::Punctuated;
-use syn::{Expr, ExprLit, Lit, PathSegment, TypePath};
+use syn::{
+    BinOp, Expr, ExprBinary, ExprLit, ExprPath, ExprUnary, Lit, MetaNameValue, PathSegment,
+    TypePath, UnOp,
+};
 
-use super::resolve::{FnResolution, ResolveError, resolve_fn_path};
+use super::resolve::{FnResolution, ResolveError, resolve_const_path, resolve_fn_path};
 use tracing::{debug, trace};
 
 #[derive(Debug, Clone, Copy, AsRefStr, EnumString, PartialEq, Eq, PartialOrd, Ord)]
@@ -48,6 +55,9 @@ enum KaniAttributeKind {
     /// contract, e.g. the contract check is substituted for the target function
     /// before the the verification runs.
     ProofForContract,
+    /// A generic harness that checks the contract of a trait method for every implementation of
+    /// the trait in the crate.
+    ProofForContractAllImpls,
     /// Internal attribute of the contracts implementation. Identifies the
     /// code implementing the function with its contract clauses asserted.
     AssertedWith,
@@ -82,6 +92,35 @@ enum KaniAttributeKind {
     /// Used to mark functions where generating automatic pointer checks should be disabled. This is
     /// used later to automatically attach pragma statements to locations.
     DisableChecks,
+    /// Attribute on a function with a contract that states that the function never panics
+    /// when its preconditions hold.
+    EnsuresNoPanic,
+    /// Internal attribute of the contracts implementation that records the condition of a
+    /// `requires` clause as written by the user. Only used to document the contract.
+    RequiresClause,
+    /// Same as [`Self::RequiresClause`] for the `ensures` clauses.
+    EnsuresClause,
+    /// Same as [`Self::RequiresClause`] for the `modifies` clauses.
+    ModifiesClause,
+    /// Free-form `key = "value"` metadata of a harness, added via `#[kani::proof(key = "value")]`.
+    Meta,
+    /// A harness that is expected to fail, added via `#[kani::proof(expect_fail)]`.
+    ExpectFail,
+    /// The expected verification time of a harness, added via
+    /// `#[kani::expected_time(secs = <seconds>)]`.
+    ExpectedTime,
+    /// The lints that are acknowledged for a harness, added via `#[kani::allow(<lint>, ...)]`.
+    Allow,
+    /// Attribute on a user function that may be called from contract conditions even though
+    /// Kani cannot show that it is free of side effects.
+    TrustedPure,
+    /// Attribute on a user function that is only part of the specification, i.e., it may only be
+    /// called from contract conditions, harnesses and other specification functions.
+    SpecFn,
+    /// Attribute on an `extern` block that maps each of its functions to a stub.
+    FfiStubs,
+    /// Attribute on an `unsafe` function that Kani should verify with an automatic harness.
+    VerifyUnsafe,
 }
 
 impl KaniAttributeKind {
@@ -93,8 +132,13 @@ pub fn is_harness_only(self) -> bool {
             | KaniAttributeKind::Solver
             | KaniAttributeKind::Stub
             | KaniAttributeKind::ProofForContract
+            | KaniAttributeKind::ProofForContractAllImpls
             | KaniAttributeKind::StubVerified
-            | KaniAttributeKind::Unwind => true,
+            | KaniAttributeKind::Unwind
+            | KaniAttributeKind::Meta
+            | KaniAttributeKind::ExpectFail
+            | KaniAttributeKind::ExpectedTime
+            | KaniAttributeKind::Allow => true,
             KaniAttributeKind::Unstable
             | KaniAttributeKind::FnMarker
             | KaniAttributeKind::Recursion
@@ -105,7 +149,15 @@ pub fn is_harness_only(self) -> bool {
             | KaniAttributeKind::ModifiesWrapper
             | KaniAttributeKind::AssertedWith
             | KaniAttributeKind::IsContractGenerated
-            | KaniAttributeKind::DisableChecks => false,
+            | KaniAttributeKind::DisableChecks
+            | KaniAttributeKind::EnsuresNoPanic
+            | KaniAttributeKind::RequiresClause
+            | KaniAttributeKind::EnsuresClause
+            | KaniAttributeKind::ModifiesClause
+            | KaniAttributeKind::TrustedPure
+            | KaniAttributeKind::SpecFn
+            | KaniAttributeKind::FfiStubs
+            | KaniAttributeKind::VerifyUnsafe => false,
         }
     }
 
@@ -117,7 +169,10 @@ pub fn is_harness_only(self) -> bool {
     /// If we find an "inactive" contract attribute we chose not to error,
     /// because it wouldn't have any effect anyway.
     pub fn demands_function_contract_use(self) -> bool {
-        matches!(self, KaniAttributeKind::ProofForContract)
+        matches!(
+            self,
+            KaniAttributeKind::ProofForContract | KaniAttributeKind::ProofForContractAllImpls
+        )
     }
 
     /// Is this a stubbing attribute that requires the experimental stubbing feature?
@@ -230,6 +285,206 @@ pub(crate) fn has_recursion(&self) -> bool {
         self.map.contains_key(&KaniAttributeKind::Recursion)
     }
 
+    /// The module of this item, where the paths given to its attributes are resolved.
+    fn current_module(&self) -> LocalDefId {
+        self.tcx.parent_module_from_def_id(self.item.expect_local()).to_local_def_id()
+    }
+
+    /// Whether the contract of this function states that it never panics.
+    pub(crate) fn ensures_no_panic(&self) -> bool {
+        self.map.contains_key(&KaniAttributeKind::EnsuresNoPanic)
+    }
+
+    /// The clauses of the contract of this function, as written by the user.
+    ///
+    /// The clauses of each kind are returned in the order they were written.
+    pub(crate) fn contract_clauses(&self) -> Vec<ContractClause> {
+        let mut clauses: Vec<ContractClause> = [
+            (KaniAttributeKind::RequiresClause, ContractClauseKind::Requires),
+            (KaniAttributeKind::EnsuresClause, ContractClauseKind::Ensures),
+            (KaniAttributeKind::ModifiesClause, ContractClauseKind::Modifies),
+        ]
+        .into_iter()
+        .flat_map(|(attr_kind, kind)| {
+            self.map.get(&attr_kind).into_iter().flatten().filter_map(move |attr| {
+                let expr = expect_key_string_value(self.tcx.sess, attr).ok()?;
+                Some(ContractClause { kind, expr: expr.to_string() })
+            })
+        })
+        .collect();
+        if self.ensures_no_panic() {
+            // `#[kani::ensures_no_panic]` is implemented with a trivial precondition, which
+            // is not part of the contract written by the user.
+            if let Some(idx) = clauses
+                .iter()
+                .position(|c| c.kind == ContractClauseKind::Requires && c.expr == "true")
+            {
+                clauses.remove(idx);
+            }
+            clauses.push(ContractClause {
+                kind: ContractClauseKind::EnsuresNoPanic,
+                expr: String::new(),
+            });
+        }
+        clauses
+    }
+
+    /// Whether the user marked this function as free of side effects, i.e., it can be called
+    /// from contract conditions.
+    pub(crate) fn is_trusted_pure(&self) -> bool {
+        self.map.contains_key(&KaniAttributeKind::TrustedPure)
+    }
+
+    /// Whether the user acknowledged the given lint for this harness with `#[kani::allow(...)]`.
+    pub(crate) fn allows(&self, lint: &str) -> bool {
+        self.map
+            .get(&KaniAttributeKind::Allow)
+            .into_iter()
+            .flatten()
+            .flat_map(|attr| attr.meta_item_list().unwrap_or_default())
+            .any(|arg| arg.is_word() && arg.ident().is_some_and(|ident| ident.as_str() == lint))
+    }
+
+    /// The span of the `#[kani::unwind(...)]` attribute of this harness, if any.
+    pub(crate) fn unwind_span(&self) -> Option<Span> {
+        Some(self.map.get(&KaniAttributeKind::Unwind)?.first()?.span())
+    }
+
+    /// Whether this function is a specification function, i.e., it was annotated with
+    /// `#[kani::spec_fn]`.
+    pub(crate) fn is_spec_fn(&self) -> bool {
+        self.map.contains_key(&KaniAttributeKind::SpecFn)
+    }
+
+    /// Whether this is an `extern` block annotated with `#[kani::ffi_stubs(...)]`.
+    pub(crate) fn has_ffi_stubs(&self) -> bool {
+        self.map.contains_key(&KaniAttributeKind::FfiStubs)
+    }
+
+    /// Check that the `ffi_stubs` attribute is applied to an `extern` block.
+    ///
+    /// The stubs themselves are only resolved once, by [Self::interpret_ffi_stubs_attribute],
+    /// when the stubs of the crate are collected.
+    fn check_ffi_stubs_target(&self, attrs: &[&Attribute]) {
+        if self.tcx.def_kind(self.item) != DefKind::ForeignMod {
+            self.tcx.dcx().span_err(
+                attrs[0].span(),
+                format!(
+                    "the `{}` attribute can only be applied to `extern` blocks",
+                    KaniAttributeKind::FfiStubs.as_ref()
+                ),
+            );
+        }
+    }
+
+    /// Parse and resolve the `ffi_stubs(ORIGINAL = REPLACEMENT, ...)` attributes of an `extern`
+    /// block, where each `ORIGINAL` is the name of a function declared in the block.
+    ///
+    /// Any error is emitted and the pair is filtered out. Attributes that are not applied to an
+    /// `extern` block are ignored, since they are reported by [Self::check_attributes].
+    pub(crate) fn interpret_ffi_stubs_attribute(&self) -> Vec<(FnDefStable, FnDefStable)> {
+        let Some(attrs) = self.map.get(&KaniAttributeKind::FfiStubs) else { return vec![] };
+        if self.tcx.def_kind(self.item) != DefKind::ForeignMod {
+            return vec![];
+        }
+        let current_module = self.current_module();
+        let mut stubs = vec![];
+        for attr in attrs {
+            let pairs = parse_path_pairs(self.tcx, attr).unwrap_or_else(|err| {
+                self.tcx.dcx().span_err(
+                    attr.span(),
+                    format!(
+                        "attribute `kani::{}` takes a list of `original = replacement` pairs; {err}",
+                        KaniAttributeKind::FfiStubs.as_ref()
+                    ),
+                );
+                vec![]
+            });
+            for (orig, replace) in pairs {
+                let orig_name = pretty_type_path(&orig);
+                let Some(orig_id) = self.foreign_fn(&orig_name) else {
+                    self.tcx.dcx().span_err(
+                        attr.span(),
+                        format!("`{orig_name}` is not a function declared in this `extern` block"),
+                    );
+                    continue;
+                };
+                let Ok(replace_res) = self.resolve_path(current_module, &replace, attr.span())
+                else {
+                    continue;
+                };
+                let orig_def = FnDefStable(rustc_internal::stable(orig_id));
+                stubs.push((orig_def, replace_res.def().to_owned()));
+            }
+        }
+        stubs
+    }
+
+    /// Whether this function is annotated with `#[kani::verify_unsafe]`.
+    pub(crate) fn is_verify_unsafe(&self) -> bool {
+        self.map.contains_key(&KaniAttributeKind::VerifyUnsafe)
+    }
+
+    /// Parse the `verify_unsafe(unwind = N, solver = "SOLVER")` options into the attributes of
+    /// the harness that verifies this function.
+    ///
+    /// This is only called once per function, when the metadata of its automatic harness is
+    /// generated. Any error is emitted and the option is ignored.
+    pub(crate) fn verify_unsafe_options(&self, harness: &mut HarnessAttributes) {
+        let Some(attr) = self.expect_maybe_one(KaniAttributeKind::VerifyUnsafe) else { return };
+        for arg in attr.meta_item_list().unwrap_or_default() {
+            let (name, value) = match arg.meta_item() {
+                Some(MetaItem { path, kind: MetaItemKind::NameValue(value), .. }) => {
+                    (path.segments[0].ident.as_str().to_string(), value)
+                }
+                _ => {
+                    self.tcx.dcx().span_err(
+                        arg.span(),
+                        "invalid argument for `verify_unsafe` attribute, expected `key = value`",
+                    );
+                    continue;
+                }
+            };
+            match (name.as_str(), &value.kind) {
+                ("unwind", LitKind::Int(unwind, ..)) => match u32::try_from(unwind.get()) {
+                    Ok(unwind) => harness.unwind_value = Some(unwind),
+                    Err(_) => {
+                        self.tcx
+                            .dcx()
+                            .span_err(arg.span(), "value above maximum permitted value - u32::MAX");
+                    }
+                },
+                ("solver", LitKind::Str(solver, _)) => {
+                    match CbmcSolver::from_str(solver.as_str()) {
+                        Ok(solver) => harness.solver = Some(solver),
+                        Err(_) => {
+                            self.tcx
+                                .dcx()
+                                .span_err(arg.span(), format!("unknown solver `{solver}`"));
+                        }
+                    }
+                }
+                _ => {
+                    self.tcx.dcx().span_err(
+                        arg.span(),
+                        format!("invalid option `{name}` for `verify_unsafe` attribute"),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Find the function with the given name in this `extern` block.
+    fn foreign_fn(&self, name: &str) -> Option<DefId> {
+        self.tcx.hir_crate_items(()).foreign_items().map(|id| id.owner_id.to_def_id()).find(
+            |def_id| {
+                self.tcx.parent(*def_id) == self.item
+                    && self.tcx.def_kind(*def_id) == DefKind::Fn
+                    && self.tcx.item_name(*def_id).as_str() == name
+            },
+        )
+    }
+
     /// Parse and extract the `proof_for_contract(TARGET)` attribute. The
     /// returned symbol and DefId are respectively the name and id of `TARGET`,
     /// the span in the span for the attribute (contents).
@@ -242,6 +497,19 @@ pub(crate) fn interpret_for_contract_attribute(&self) -> Option<FnDefStable> {
         })
     }
 
+    /// Parse and extract the `proof_for_contract_all_impls(TARGET)` attribute, where `TARGET` is
+    /// the path to a method in a trait definition.
+    ///
+    /// Resolution errors are emitted, but we return `None` silently if `TARGET` is not a trait
+    /// method, since that is reported by `check_attributes`.
+    pub(crate) fn interpret_for_contract_all_impls_attribute(&self) -> Option<FnDefStable> {
+        self.expect_maybe_one(KaniAttributeKind::ProofForContractAllImpls).and_then(|attr| {
+            let target = self.parse_single_path_attr(attr).ok()?.def();
+            let def_id = rustc_internal::internal(self.tcx, target.def_id());
+            self.tcx.trait_of_assoc(def_id).is_some().then_some(target)
+        })
+    }
+
     pub fn proof_for_contract(&self) -> Option<Result<Symbol, ErrorGuaranteed>> {
         self.expect_maybe_one(KaniAttributeKind::ProofForContract)
             .map(|target| expect_key_string_value(self.tcx.sess, target))
@@ -332,7 +600,10 @@ pub(super) fn check_attributes(&self) -> (FxHashMap<FnDefStable, Span>, HashSet<
                         expect_no_args(self.tcx, kind, attr);
                     })
                 }
-                KaniAttributeKind::Recursion => {
+                KaniAttributeKind::Recursion
+                | KaniAttributeKind::EnsuresNoPanic
+                | KaniAttributeKind::TrustedPure
+                | KaniAttributeKind::SpecFn => {
                     expect_single(self.tcx, kind, attrs);
                     attrs.iter().for_each(|attr| {
                         expect_no_args(self.tcx, kind, attr);
@@ -341,16 +612,41 @@ pub(super) fn check_attributes(&self) -> (FxHashMap<FnDefStable, Span>, HashSet<
                 KaniAttributeKind::Solver => {
                     expect_single(self.tcx, kind, attrs);
                     attrs.iter().for_each(|attr| {
-                        parse_solver(self.tcx, attr);
+                        parse_solver(self.tcx, self.current_module(), attr);
                     })
                 }
                 KaniAttributeKind::Stub => {
                     self.parse_stubs(attrs);
                 }
+                KaniAttributeKind::FfiStubs => {
+                    self.check_ffi_stubs_target(attrs);
+                }
+                KaniAttributeKind::VerifyUnsafe => {
+                    // The options are parsed when the metadata of the harness is generated.
+                    expect_single(self.tcx, kind, attrs);
+                }
+                KaniAttributeKind::Meta => {
+                    self.parse_metadata(attrs);
+                }
+                KaniAttributeKind::ExpectFail => {
+                    if self.map.contains_key(&KaniAttributeKind::ShouldPanic) {
+                        local_error(
+                            "`expect_fail` and `should_panic` may not be used on the same harness."
+                                .to_string(),
+                        );
+                    }
+                    parse_expect_fail(self.tcx, expect_single(self.tcx, kind, attrs));
+                }
+                KaniAttributeKind::ExpectedTime => {
+                    parse_expected_time(self.tcx, expect_single(self.tcx, kind, attrs));
+                }
+                KaniAttributeKind::Allow => {
+                    check_allowed_lints(self.tcx, attrs);
+                }
                 KaniAttributeKind::Unwind => {
                     expect_single(self.tcx, kind, attrs);
                     attrs.iter().for_each(|attr| {
-                        parse_unwind(self.tcx, attr);
+                        parse_unwind(self.tcx, self.current_module(), attr);
                     })
                 }
                 KaniAttributeKind::Proof => {
@@ -380,6 +676,17 @@ pub(super) fn check_attributes(&self) -> (FxHashMap<FnDefStable, Span>, HashSet<
                         }
                     })
                 }
+                KaniAttributeKind::ProofForContractAllImpls => {
+                    if self.map.contains_key(&KaniAttributeKind::Proof)
+                        || self.map.contains_key(&KaniAttributeKind::ProofForContract)
+                    {
+                        local_error(
+                            "`proof_for_contract_all_impls` may not be used together with `proof` or `proof_for_contract`.".to_string(),
+                        );
+                    }
+                    expect_single(self.tcx, kind, attrs);
+                    attrs.iter().for_each(|attr| self.check_all_impls_harness(attr));
+                }
                 KaniAttributeKind::StubVerified => {
                     attrs.iter().for_each(|attr| {
                         self.check_stub_verified(attr);
@@ -397,6 +704,13 @@ pub(super) fn check_attributes(&self) -> (FxHashMap<FnDefStable, Span>, HashSet<
                 | KaniAttributeKind::ReplacedWith => {
                     self.attribute_value(kind);
                 }
+                KaniAttributeKind::RequiresClause
+                | KaniAttributeKind::EnsuresClause
+                | KaniAttributeKind::ModifiesClause => {
+                    attrs.iter().for_each(|attr| {
+                        let _ = expect_key_string_value(self.tcx.sess, attr);
+                    });
+                }
                 KaniAttributeKind::IsContractGenerated => {
                     // Ignored here because this is only used by the proc macros
                     // to communicate with one another. So by the time it gets
@@ -501,9 +815,10 @@ fn report_unstable_forbidden(&self, unstable_attr: &UnstableAttribute) -> ErrorG
 
     /// Is this item a harness? (either `proof` or `proof_for_contract`
     /// attribute are present)
-    fn is_proof_harness(&self) -> bool {
+    pub(crate) fn is_proof_harness(&self) -> bool {
         self.map.contains_key(&KaniAttributeKind::Proof)
             || self.map.contains_key(&KaniAttributeKind::ProofForContract)
+            || self.map.contains_key(&KaniAttributeKind::ProofForContractAllImpls)
     }
 
     /// Check that the function specified in the `proof_for_contract` attribute
@@ -553,16 +868,35 @@ pub fn harness_attributes(&self) -> HarnessAttributes {
                     self.tcx.dcx().span_err(self.tcx.def_span(self.item), "The attribute `kani::recursion` should only be used in combination with function contracts.");
                 }
                 KaniAttributeKind::Solver => {
-                    harness.solver = parse_solver(self.tcx, attributes[0]);
+                    harness.solver = parse_solver(self.tcx, self.current_module(), attributes[0]);
                 }
                 KaniAttributeKind::Stub => {
                     harness.stubs.extend_from_slice(&self.parse_stubs(attributes));
                 }
+                KaniAttributeKind::Meta => {
+                    harness.metadata = self.parse_metadata(attributes);
+                }
+                KaniAttributeKind::ExpectFail => {
+                    harness.expect_fail = Some(parse_expect_fail(self.tcx, attributes[0]));
+                }
+                KaniAttributeKind::ExpectedTime => {
+                    harness.expected_time_secs = parse_expected_time(self.tcx, attributes[0]);
+                }
+                KaniAttributeKind::Allow => {
+                    // Lints are checked by the compiler and aren't part of the harness metadata.
+                }
+                KaniAttributeKind::EnsuresNoPanic => {
+                    self.tcx.dcx().span_err(self.tcx.def_span(self.item), "The attribute `kani::ensures_no_panic` should only be used in combination with function contracts.");
+                }
                 KaniAttributeKind::Unwind => {
-                    harness.unwind_value = parse_unwind(self.tcx, attributes[0])
+                    harness.unwind_value = parse_unwind(self.tcx, self.current_module(), attributes[0])
                 }
                 KaniAttributeKind::Proof => { /* no-op */ }
                 KaniAttributeKind::ProofForContract => self.handle_proof_for_contract(attributes[0]),
+                KaniAttributeKind::ProofForContractAllImpls => {
+                    // The target depends on the type that the harness is instantiated with, so
+                    // the harness kind is set when the harness is instantiated.
+                }
                 KaniAttributeKind::StubVerified => self.handle_stub_verified(&mut harness),
                 KaniAttributeKind::Unstable => {
                     // Internal attribute which shouldn't exist here.
@@ -574,14 +908,21 @@ pub fn harness_attributes(&self) -> HarnessAttributes {
                 | KaniAttributeKind::RecursionCheck
                 | KaniAttributeKind::RecursionTracker
                 | KaniAttributeKind::AssertedWith
-                | KaniAttributeKind::ReplacedWith => {
+                | KaniAttributeKind::ReplacedWith
+                | KaniAttributeKind::RequiresClause
+                | KaniAttributeKind::EnsuresClause
+                | KaniAttributeKind::ModifiesClause => {
                     self.tcx.dcx().span_err(self.tcx.def_span(self.item), format!("Contracts are not supported on harnesses. (Found the kani-internal contract attribute `{}`)", kind.as_ref()));
                 }
                 KaniAttributeKind::DisableChecks => {
                     // Internal attribute which shouldn't exist here.
                     unreachable!()
                 }
-                KaniAttributeKind::FnMarker => {
+                KaniAttributeKind::FnMarker
+                | KaniAttributeKind::TrustedPure
+                | KaniAttributeKind::SpecFn
+                | KaniAttributeKind::FfiStubs
+                | KaniAttributeKind::VerifyUnsafe => {
                     /* no-op */
                 }
             };
@@ -701,6 +1042,51 @@ fn check_proof_attribute(&self, kind: KaniAttributeKind, proof_attribute: &Attri
         }
     }
 
+    /// Check that a `proof_for_contract_all_impls` harness has a single type parameter and no
+    /// arguments, and that its target is a method in a trait definition.
+    fn check_all_impls_harness(&self, attr: &Attribute) {
+        let tcx = self.tcx;
+        let span = attr.span();
+        if tcx.def_kind(self.item) != DefKind::Fn {
+            tcx.dcx().span_err(
+                span,
+                "the '#[kani::proof_for_contract_all_impls]' attribute can only be applied to functions",
+            );
+            return;
+        }
+        let generics = tcx.generics_of(self.item);
+        let counts = generics.own_counts();
+        if counts.types != 1 || counts.consts != 0 {
+            tcx.dcx().span_err(
+                span,
+                "a `proof_for_contract_all_impls` harness must have exactly one type parameter",
+            );
+        }
+        if !tcx.fn_sig(self.item).skip_binder().inputs().skip_binder().is_empty() {
+            tcx.dcx().span_err(span, "functions used as harnesses cannot have any arguments");
+        }
+        let Ok(target) = self.parse_single_path_attr(attr) else { return };
+        let target_id = rustc_internal::internal(tcx, target.def().def_id());
+        if tcx.trait_of_assoc(target_id).is_none() {
+            tcx.dcx().span_err(
+                span,
+                format!(
+                    "the target of `proof_for_contract_all_impls` must be a trait method, but \
+                    `{}` is not",
+                    tcx.def_path_str(target_id)
+                ),
+            );
+        } else if tcx.generics_of(target_id).own_counts().types != 0 {
+            tcx.dcx().span_err(
+                span,
+                format!(
+                    "cannot check the contract of `{}`: generic trait methods are not supported",
+                    tcx.def_path_str(target_id)
+                ),
+            );
+        }
+    }
+
     fn resolve_path(
         &self,
         current_module: LocalDefId,
@@ -721,6 +1107,12 @@ fn resolve_path(
                         pretty_type_path(path)
                     ));
                 }
+                ResolveError::AmbiguousTraitMethod { name, .. } => {
+                    err = err.with_help(format!(
+                        "use a fully qualified path such as `<Type as Trait>::{name}` to select \
+                        an implementation."
+                    ));
+                }
                 ResolveError::MissingTraitImpl { tcx: _, trait_fn_id, ty: _ } => {
                     let generics = self.tcx.generics_of(trait_fn_id);
                     let parent_generics =
@@ -747,8 +1139,7 @@ fn parse_single_path_attr(
         &self,
         attr: &'tcx Attribute,
     ) -> Result<FnResolution, ResolveError<'tcx>> {
-        let current_module =
-            self.tcx.parent_module_from_def_id(self.item.expect_local()).to_local_def_id();
+        let current_module = self.current_module();
         let target = expect_key_string_value(self.tcx.sess, attr)
             .unwrap_or_else(|_| panic!("malformed attribute"));
         let target_str = target.as_str();
@@ -765,9 +1156,34 @@ fn parse_single_path_attr(
         }
     }
 
+    /// Parse the `#[kanitool::meta(key = "value")]` attributes of a harness.
+    /// Each key may only be used once.
+    fn parse_metadata(&self, attributes: &[&'tcx Attribute]) -> BTreeMap<String, String> {
+        let mut metadata = BTreeMap::new();
+        for attr in attributes {
+            match parse_key_values(attr) {
+                Ok(values) => {
+                    for (key, value) in values {
+                        if metadata.insert(key.clone(), value).is_some() {
+                            self.tcx.dcx().span_err(
+                                attr.span(),
+                                format!("duplicated harness metadata key `{key}`"),
+                            );
+                        }
+                    }
+                }
+                Err(msg) => {
+                    self.tcx
+                        .dcx()
+                        .span_err(attr.span(), format!("invalid harness metadata: {msg}"));
+                }
+            }
+        }
+        metadata
+    }
+
     fn parse_stubs(&self, attributes: &[&'tcx Attribute]) -> Vec<Stub> {
-        let current_module =
-            self.tcx.parent_module_from_def_id(self.item.expect_local()).to_local_def_id();
+        let current_module = self.current_module();
 
         attributes
         .iter()
@@ -861,10 +1277,30 @@ fn has_kani_attribute<F: Fn(KaniAttributeKind) -> bool>(
 pub fn is_proof_harness(tcx: TyCtxt, instance: InstanceStable) -> bool {
     let def_id = rustc_internal::internal(tcx, instance.def.def_id());
     has_kani_attribute(tcx, def_id, |a| {
-        matches!(a, KaniAttributeKind::Proof | KaniAttributeKind::ProofForContract)
+        matches!(
+            a,
+            KaniAttributeKind::Proof
+                | KaniAttributeKind::ProofForContract
+                | KaniAttributeKind::ProofForContractAllImpls
+        )
     })
 }
 
+/// Return the function whose contract is checked by the given manual harness, if any.
+///
+/// For an instance of a `proof_for_contract_all_impls` harness, this is the implementation of
+/// the target trait method for the type the harness was instantiated with.
+pub fn contract_harness_target(tcx: TyCtxt, harness: InstanceStable) -> Option<FnDefStable> {
+    let attributes = KaniAttributes::for_instance(tcx, harness);
+    if let Some(target) = attributes.interpret_for_contract_attribute() {
+        return Some(target);
+    }
+    let trait_fn = attributes.interpret_for_contract_all_impls_attribute()?;
+    let self_ty = harness.args().0.first()?.clone();
+    let impl_fn = InstanceStable::resolve(trait_fn, &GenericArgsStable(vec![self_ty])).ok()?;
+    impl_fn.ty().kind().fn_def().map(|(def, _)| def)
+}
+
 /// Expect the contents of this attribute to be of the format #[attribute =
 /// "value"] and return the `"value"`.
 fn expect_key_string_value(
@@ -975,30 +1411,186 @@ fn expect_no_args(tcx: TyCtxt, kind: KaniAttributeKind, attr: &Attribute) {
     }
 }
 
+/// The lint for harnesses whose unwind bound is not larger than the number of elements of the
+/// collections generated by a bounded generator, such as `kani::bounded_any`.
+pub(crate) const UNWIND_SMALLER_THAN_BOUND: &str = "unwind_smaller_than_bound";
+
+/// The lints that can be acknowledged with `#[kani::allow(...)]`.
+const KNOWN_LINTS: [&str; 1] = [UNWIND_SMALLER_THAN_BOUND];
+
+/// Check that `#[kanitool::allow(<lint>, ...)]` attributes only list known lints.
+fn check_allowed_lints(tcx: TyCtxt, attrs: &[&Attribute]) {
+    for attr in attrs {
+        let args = attr.meta_item_list().unwrap_or_default();
+        if args.is_empty() {
+            tcx.dcx().span_err(attr.span(), "the `allow` attribute expects at least one lint");
+        }
+        for arg in args {
+            let known = arg.is_word()
+                && arg.ident().is_some_and(|ident| KNOWN_LINTS.contains(&ident.as_str()));
+            if !known {
+                tcx.dcx()
+                    .struct_span_err(arg.span(), "unknown lint for the `allow` attribute")
+                    .with_help(format!("the known lints are: `{}`", KNOWN_LINTS.join("`, `")))
+                    .emit();
+            }
+        }
+    }
+}
+
+/// Parse `#[kanitool::expect_fail]` and `#[kanitool::expect_fail(property = "...")]`.
+fn parse_expect_fail(tcx: TyCtxt, attr: &Attribute) -> ExpectedFailure {
+    if attr.is_word() {
+        return ExpectedFailure { property: None };
+    }
+    match parse_key_values(attr) {
+        Ok(mut values) if values.len() == 1 && values.contains_key("property") => {
+            ExpectedFailure { property: values.remove("property") }
+        }
+        Ok(_) => {
+            tcx.dcx().span_err(attr.span(), "`expect_fail` only accepts a `property` argument");
+            ExpectedFailure::default()
+        }
+        Err(msg) => {
+            tcx.dcx().span_err(attr.span(), format!("invalid `expect_fail` attribute: {msg}"));
+            ExpectedFailure::default()
+        }
+    }
+}
+
+/// Parse `#[kanitool::expected_time(secs = <seconds>)]`.
+fn parse_expected_time(tcx: TyCtxt, attr: &Attribute) -> Option<u64> {
+    let secs = match parse_key_values(attr) {
+        Ok(values) if values.len() == 1 => {
+            values.get("secs").and_then(|secs| secs.parse().ok()).filter(|secs| *secs > 0)
+        }
+        _ => None,
+    };
+    if secs.is_none() {
+        tcx.dcx().span_err(
+            attr.span(),
+            "invalid argument for `expected_time` attribute, expected `secs = <seconds>` with a \
+            positive integer",
+        );
+    }
+    secs
+}
+
 /// Return the unwind value from the given attribute.
-fn parse_unwind(tcx: TyCtxt, attr: &Attribute) -> Option<u32> {
-    // Get Attribute value and if it's not none, assign it to the metadata
-    match parse_integer(attr) {
+///
+/// The value is either an integer literal or a constant expression, e.g., `BUF_SIZE + 1`, which
+/// is evaluated here.
+fn parse_unwind(tcx: TyCtxt, current_module: LocalDefId, attr: &Attribute) -> Option<u32> {
+    let unwind_value = match parse_integer(attr) {
+        Some(unwind_integer_value) => i128::try_from(unwind_integer_value).unwrap_or(i128::MAX),
         None => {
-            // There are no integers or too many arguments given to the attribute
-            tcx.dcx().span_err(
-                attr.span(),
-                "invalid argument for `unwind` attribute, expected an integer",
-            );
-            None
+            // There are too many arguments, or the argument is not an integer literal.
+            let Ok(expr) = syn_attr(tcx, attr).parse_args::<Expr>() else {
+                tcx.dcx().span_err(
+                    attr.span(),
+                    "invalid argument for `unwind` attribute, expected an integer or a constant \
+                    expression",
+                );
+                return None;
+            };
+            match eval_int_expr(tcx, current_module, &expr) {
+                Ok(value) => value,
+                Err(msg) => {
+                    tcx.dcx().span_err(
+                        attr.span(),
+                        format!("invalid argument for `unwind` attribute: {msg}"),
+                    );
+                    return None;
+                }
+            }
         }
-        Some(unwind_integer_value) => {
-            if let Ok(val) = unwind_integer_value.try_into() {
-                Some(val)
+    };
+    if unwind_value < 0 {
+        tcx.dcx().span_err(attr.span(), format!("negative unwind value `{unwind_value}`"));
+        None
+    } else if let Ok(val) = unwind_value.try_into() {
+        Some(val)
+    } else {
+        tcx.dcx().span_err(attr.span(), "value above maximum permitted value - u32::MAX");
+        None
+    }
+}
+
+/// Evaluate an integer constant expression given as the argument of an attribute.
+///
+/// Only integer literals, paths to (associated) constants of an integer type, parentheses,
+/// negations and arithmetic operators are supported. The arithmetic is checked, so an expression
+/// that overflows is reported instead of wrapping around.
+fn eval_int_expr(tcx: TyCtxt, current_module: LocalDefId, expr: &Expr) -> Result<i128, String> {
+    let overflow = || format!("`{}` overflows", expr.to_token_stream());
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse().map_err(|_| overflow()),
+        Expr::Paren(paren) => eval_int_expr(tcx, current_module, &paren.expr),
+        Expr::Group(group) => eval_int_expr(tcx, current_module, &group.expr),
+        Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr: operand, .. }) => {
+            eval_int_expr(tcx, current_module, operand)?.checked_neg().ok_or_else(overflow)
+        }
+        Expr::Binary(ExprBinary { left, op, right, .. }) => {
+            let lhs = eval_int_expr(tcx, current_module, left)?;
+            let rhs = eval_int_expr(tcx, current_module, right)?;
+            if matches!(op, BinOp::Div(_) | BinOp::Rem(_)) && rhs == 0 {
+                return Err(format!("`{}` divides by zero", expr.to_token_stream()));
+            }
+            let result = match op {
+                BinOp::Add(_) => lhs.checked_add(rhs),
+                BinOp::Sub(_) => lhs.checked_sub(rhs),
+                BinOp::Mul(_) => lhs.checked_mul(rhs),
+                BinOp::Div(_) => lhs.checked_div(rhs),
+                BinOp::Rem(_) => lhs.checked_rem(rhs),
+                _ => return Err(format!("unsupported operator `{}`", op.to_token_stream())),
+            };
+            result.ok_or_else(overflow)
+        }
+        Expr::Path(ExprPath { qself: None, path, .. }) => {
+            let (value, ty) = eval_const(tcx, current_module, path)?;
+            let scalar = value
+                .try_to_scalar_int()
+                .filter(|_| ty.is_integral())
+                .ok_or_else(|| format!("`{}` is not an integer constant", pretty_path(path)))?;
+            if ty.is_signed() {
+                Ok(scalar.to_int(scalar.size()))
             } else {
-                tcx.dcx().span_err(attr.span(), "value above maximum permitted value - u32::MAX");
-                None
+                i128::try_from(scalar.to_uint(scalar.size())).map_err(|_| overflow())
             }
         }
+        _ => Err(format!("`{}` is not a constant expression", expr.to_token_stream())),
     }
 }
 
-fn parse_solver(tcx: TyCtxt, attr: &Attribute) -> Option<CbmcSolver> {
+/// Evaluate a string constant given as the argument of an attribute.
+fn eval_str_const(
+    tcx: TyCtxt,
+    current_module: LocalDefId,
+    path: &syn::Path,
+) -> Result<String, String> {
+    let (value, ty) = eval_const(tcx, current_module, path)?;
+    let bytes = matches!(ty.kind(), TyKind::Ref(_, inner, _) if inner.is_str())
+        .then(|| value.try_get_slice_bytes_for_diagnostics(tcx))
+        .flatten()
+        .ok_or_else(|| format!("`{}` is not a string constant", pretty_path(path)))?;
+    Ok(String::from_utf8_lossy(bytes).into_owned())
+}
+
+/// Resolve and evaluate the constant at the given path.
+fn eval_const<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    current_module: LocalDefId,
+    path: &syn::Path,
+) -> Result<(ConstValue<'tcx>, Ty<'tcx>), String> {
+    let def_id = resolve_const_path(tcx, current_module, path)
+        .map_err(|err| format!("failed to resolve `{}`: {err}", pretty_path(path)))?;
+    let value = tcx
+        .const_eval_poly(def_id)
+        .map_err(|_| format!("failed to evaluate constant `{}`", pretty_path(path)))?;
+    Ok((value, tcx.type_of(def_id).instantiate_identity()))
+}
+
+fn parse_solver(tcx: TyCtxt, current_module: LocalDefId, attr: &Attribute) -> Option<CbmcSolver> {
     // TODO: Argument validation should be done as part of the `kani_macros` crate
     // <https://github.com/model-checking/kani/issues/2192>
     const ATTRIBUTE: &str = "#[kani::solver]";
@@ -1009,6 +1601,24 @@ fn parse_solver(tcx: TyCtxt, attr: &Attribute) -> Option<CbmcSolver> {
         )
     };
 
+    // The solver binary can also be given by a string constant, e.g., `bin = SOLVER_BINARY`.
+    if let Ok(MetaNameValue {
+        path,
+        value: Expr::Path(ExprPath { qself: None, path: value, .. }),
+        ..
+    }) = syn_attr(tcx, attr).parse_args::<MetaNameValue>()
+        && path.is_ident("bin")
+    {
+        return match eval_str_const(tcx, current_module, &value) {
+            Ok(binary) => Some(CbmcSolver::Binary(binary)),
+            Err(msg) => {
+                tcx.dcx()
+                    .span_err(attr.span(), format!("invalid argument for `{ATTRIBUTE}`: {msg}"));
+                None
+            }
+        };
+    }
+
     let attr_args = attr.meta_item_list().unwrap();
     if attr_args.len() != 1 {
         tcx.dcx().span_err(
@@ -1079,6 +1689,22 @@ fn parse_paths(tcx: TyCtxt, attr: &Attribute) -> Result<Vec<TypePath>, syn::Erro
     Ok(paths.into_iter().collect())
 }
 
+/// Parse the arguments of an attribute of the form `#[kanitool::name(a = path::b, ...)]`.
+fn parse_path_pairs(tcx: TyCtxt, attr: &Attribute) -> Result<Vec<(TypePath, TypePath)>, String> {
+    let syn_attr = syn_attr(tcx, attr);
+    let parser = Punctuated::<MetaNameValue, syn::Token![,]>::parse_terminated;
+    let pairs = syn_attr.parse_args_with(parser).map_err(|err| err.to_string())?;
+    pairs
+        .into_iter()
+        .map(|pair| match pair.value {
+            Expr::Path(ExprPath { qself, path, .. }) => {
+                Ok((TypePath { qself: None, path: pair.path }, TypePath { qself, path }))
+            }
+            value => Err(format!("expected a path, but found `{}`", value.to_token_stream())),
+        })
+        .collect()
+}
+
 /// Parse the arguments of the attribute into a (key, value) map.
 fn parse_key_values(attr: &Attribute) -> Result<BTreeMap<String, String>, String> {
     trace!(list=?attr.meta_item_list(), ?attr, "parse_key_values");
@@ -1135,6 +1761,11 @@ fn syn_attr_stable(attr: &AttributeStable) -> syn::Attribute {
     parser.parse_str(attr.as_str()).unwrap().pop().unwrap()
 }
 
+/// Same as [pretty_type_path] for a path without a qualified self type.
+fn pretty_path(path: &syn::Path) -> String {
+    pretty_type_path(&TypePath { qself: None, path: path.clone() })
+}
+
 /// Return a more user-friendly string for path by trying to remove unneeded whitespace.
 ///
 /// `quote!()` and `TokenString::to_string()` introduce unnecessary space around separators.
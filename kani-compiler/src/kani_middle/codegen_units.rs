@@ -7,24 +7,25 @@
 //! Today, only stub / contracts can affect the harness codegen. Thus, we group the harnesses
 //! according to their stub configuration.
 
-use crate::args::{Arguments, ReachabilityType};
-use crate::kani_middle::attributes::{KaniAttributes, is_proof_harness};
+use crate::args::{Arguments, ExtraChecks, ReachabilityType};
+use crate::kani_middle::attributes::{KaniAttributes, contract_harness_target, is_proof_harness};
 use crate::kani_middle::kani_functions::{KaniIntrinsic, KaniModel};
 use crate::kani_middle::metadata::{
     gen_automatic_proof_metadata, gen_contracts_metadata, gen_proof_metadata,
 };
 use crate::kani_middle::reachability::filter_crate_items;
-use crate::kani_middle::stubbing::{check_compatibility, harness_stub_map};
+use crate::kani_middle::stubbing::{check_compatibility, ffi_stub_map, harness_stub_map};
 use crate::kani_middle::{can_derive_arbitrary, implements_arbitrary};
 use crate::kani_queries::QueryDb;
 use fxhash::{FxHashMap, FxHashSet};
 use kani_metadata::{
-    ArtifactType, AssignsContract, AutoHarnessMetadata, AutoHarnessSkipReason, HarnessMetadata,
-    KaniMetadata, find_proof_harnesses,
+    ArtifactType, AssignsContract, AutoHarnessMetadata, AutoHarnessSkipReason, HarnessKind,
+    HarnessMetadata, KaniMetadata, LoopUnwindBound, MutantMetadata, find_proof_harnesses,
 };
 use regex::RegexSet;
 use rustc_hir::def_id::DefId;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{TyCtxt, TypeVisitableExt};
+use rustc_public::mir::Body;
 use rustc_public::mir::mono::Instance;
 use rustc_public::rustc_internal;
 use rustc_public::ty::{FnDef, GenericArgKind, GenericArgs, RigidTy, Ty, TyKind};
@@ -58,12 +59,26 @@ pub struct CodegenUnits {
     crate_info: CrateInfo,
     harness_info: HashMap<Harness, HarnessMetadata>,
     units: Vec<CodegenUnit>,
+    mutants: Vec<MutantMetadata>,
 }
 
 #[derive(Clone, Default, Debug)]
 pub struct CodegenUnit {
     pub harnesses: Vec<Harness>,
     pub stubs: Stubs,
+    /// The functions that are abstracted, i.e., treated as uninterpreted, because their crate was
+    /// given to `--abstract-crate`.
+    pub abstracted: HashSet<FnDef>,
+    /// The undefined behavior checks that are enabled for this unit in addition to the ones given
+    /// to `--ub-check`. These are the checks of the harness of a `#[kani::verify_unsafe]` function.
+    pub ub_checks: Vec<ExtraChecks>,
+}
+
+impl CodegenUnit {
+    /// Whether the given undefined behavior check is enabled for the harnesses of this unit.
+    pub fn has_ub_check(&self, args: &Arguments, check: ExtraChecks) -> bool {
+        args.ub_check.contains(&check) || self.ub_checks.contains(&check)
+    }
 }
 
 impl CodegenUnits {
@@ -74,16 +89,61 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt) -> Self {
         let args = queries.args();
         match args.reachability_analysis {
             ReachabilityType::Harnesses => {
-                let all_harnesses = determine_targets(
-                    get_all_manual_harnesses(tcx, base_filename),
-                    &args.harnesses,
-                    args.exact,
-                );
+                let mut all_harnesses = get_all_manual_harnesses(tcx, base_filename);
+                let mut automatic_harnesses = HashMap::default();
+                let kani_fns = queries.kani_functions();
+                let kani_any = kani_fns.get(&KaniModel::Any.into()).copied();
+                all_harnesses.extend(get_all_impls_harnesses(tcx, kani_any, base_filename));
+                let verify_unsafe = verify_unsafe_targets(tcx, args, kani_any);
+                let verify_unsafe_tys: HashSet<Ty> =
+                    verify_unsafe.iter().map(|target| target.ty()).collect();
+                let mut targets = verify_unsafe;
+                if args.is_contracts_batch_enabled() {
+                    targets.extend(contracts_batch_targets(tcx, &all_harnesses, kani_any.unwrap()));
+                }
+                if !targets.is_empty() {
+                    automatic_harnesses = determine_targets(
+                        get_all_automatic_harnesses(
+                            tcx,
+                            targets,
+                            *kani_fns.get(&KaniIntrinsic::AutomaticHarness.into()).unwrap(),
+                            base_filename,
+                        ),
+                        &args.harnesses,
+                        args.exact,
+                    );
+                }
+                all_harnesses = determine_targets(all_harnesses, &args.harnesses, args.exact);
+                if args.check_coverage {
+                    // Vacuous harnesses are skipped, but coverage runs must report every line.
+                    all_harnesses.values_mut().for_each(|metadata| metadata.is_vacuous = false);
+                }
                 // Even if no_stubs is empty we still need to store rustc metadata.
-                let units = group_by_stubs(tcx, &all_harnesses);
+                let mut units = group_by_stubs(tcx, &all_harnesses);
                 validate_units(tcx, &units);
+                // Like in the autoharness mode, each automatic harness is in its own unit.
+                // The harness of a `#[kani::verify_unsafe]` function checks for every kind of
+                // undefined behavior that Kani can detect, but only in its own unit.
+                units.extend(automatic_harnesses.keys().map(|harness| {
+                    let is_verify_unsafe = matches!(
+                        harness.args().0.first(),
+                        Some(GenericArgKind::Type(ty)) if verify_unsafe_tys.contains(ty)
+                    );
+                    CodegenUnit {
+                        harnesses: vec![*harness],
+                        ub_checks: if is_verify_unsafe {
+                            vec![ExtraChecks::Validity, ExtraChecks::Uninit]
+                        } else {
+                            vec![]
+                        },
+                        ..Default::default()
+                    }
+                }));
+                all_harnesses.extend(automatic_harnesses);
+                add_ffi_stubs(tcx, args, &mut units);
+                add_abstracted_fns(tcx, args, &mut units);
                 debug!(?units, "CodegenUnits::new");
-                CodegenUnits { units, harness_info: all_harnesses, crate_info }
+                CodegenUnits { units, harness_info: all_harnesses, crate_info, mutants: vec![] }
             }
             ReachabilityType::AllFns => {
                 let mut all_harnesses = determine_targets(
@@ -124,19 +184,26 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt) -> Self {
                         .keys()
                         .map(|harness| CodegenUnit {
                             harnesses: vec![*harness],
-                            stubs: HashMap::default(),
+                            ..Default::default()
                         })
                         .collect::<Vec<_>>(),
                 );
                 all_harnesses.extend(automatic_harnesses.clone());
+                add_ffi_stubs(tcx, args, &mut units);
+                add_abstracted_fns(tcx, args, &mut units);
 
                 // No need to validate the units again because validation only checks stubs, and we haven't added any stubs.
                 debug!(?units, "CodegenUnits::new");
-                CodegenUnits { units, harness_info: all_harnesses, crate_info }
+                CodegenUnits { units, harness_info: all_harnesses, crate_info, mutants: vec![] }
             }
             _ => {
                 // Leave other reachability type handling as is for now.
-                CodegenUnits { units: vec![], harness_info: HashMap::default(), crate_info }
+                CodegenUnits {
+                    units: vec![],
+                    harness_info: HashMap::default(),
+                    crate_info,
+                    mutants: vec![],
+                }
             }
         }
     }
@@ -164,6 +231,57 @@ pub fn store_loop_contracts(&mut self, harnesses: &[Harness]) {
         }
     }
 
+    /// We store the unwind bounds of the annotated loops that are reachable from each harness.
+    pub fn store_loop_unwind_bounds(&mut self, bounds: Vec<(Harness, Vec<LoopUnwindBound>)>) {
+        for (harness, mut bounds) in bounds {
+            bounds.sort();
+            bounds.dedup();
+            self.harness_info.get_mut(&harness).unwrap().loop_unwind_bounds = bounds;
+        }
+    }
+
+    /// We flag that no source of non-determinism is reachable from the harness.
+    pub fn store_concrete_harnesses(&mut self, harnesses: &[Harness]) {
+        for harness in harnesses {
+            let metadata = self.harness_info.get_mut(harness).unwrap();
+            metadata.is_concrete = true;
+        }
+    }
+
+    /// We flag that `kani::concretize` is reachable from the harness.
+    pub fn store_concretized_harnesses(&mut self, harnesses: &[Harness]) {
+        for harness in harnesses {
+            let metadata = self.harness_info.get_mut(harness).unwrap();
+            metadata.uses_concretize = true;
+        }
+    }
+
+    /// We store the names of the functions reachable from each harness.
+    pub fn store_reachable_functions(&mut self, reachable: Vec<(Harness, Vec<String>)>) {
+        for (harness, names) in reachable {
+            self.harness_info.get_mut(&harness).unwrap().reachable_functions = names;
+        }
+    }
+
+    /// We store the number of nondeterministic bytes introduced by each harness.
+    pub fn store_nondet_bytes(&mut self, nondet_bytes: Vec<(Harness, u64)>) {
+        for (harness, bytes) in nondet_bytes {
+            self.harness_info.get_mut(&harness).unwrap().nondet_bytes = bytes;
+        }
+    }
+
+    /// We store the number of debug assertions reachable from each harness.
+    pub fn store_debug_assertions(&mut self, debug_assertions: Vec<(Harness, u64)>) {
+        for (harness, count) in debug_assertions {
+            self.harness_info.get_mut(&harness).unwrap().debug_assertions = count;
+        }
+    }
+
+    /// We store the mutations that can be applied to the functions reachable from the harnesses.
+    pub fn store_mutants(&mut self, mutants: Vec<MutantMetadata>) {
+        self.mutants = mutants;
+    }
+
     /// Write compilation metadata into a file.
     pub fn write_metadata(&self, queries: &QueryDb, tcx: TyCtxt) {
         let metadata = self.generate_metadata(tcx);
@@ -175,6 +293,16 @@ pub fn harness_model_path(&self, harness: Harness) -> Option<&PathBuf> {
         self.harness_info[&harness].goto_file.as_ref()
     }
 
+    /// Whether the harness starts with a contradictory assumption, in which case we skip its
+    /// code generation and Kani reports it as skipped.
+    pub fn is_vacuous_harness(&self, harness: &Harness) -> bool {
+        self.harness_info.get(harness).is_some_and(|md| md.is_vacuous)
+    }
+
+    pub fn harness_pretty_name(&self, harness: Harness) -> &str {
+        &self.harness_info[&harness].pretty_name
+    }
+
     /// Generate [KaniMetadata] for the target crate.
     fn generate_metadata(&self, tcx: TyCtxt) -> KaniMetadata {
         let (proof_harnesses, test_harnesses) =
@@ -186,6 +314,7 @@ fn generate_metadata(&self, tcx: TyCtxt) -> KaniMetadata {
             test_harnesses,
             contracted_functions: gen_contracts_metadata(tcx, &self.harness_info),
             autoharness_md: AUTOHARNESS_MD.get().cloned(),
+            mutants: self.mutants.clone(),
         }
     }
 }
@@ -222,7 +351,10 @@ fn group_by_stubs(
                 .map(|(from, to)| (stub_def(tcx, *from), stub_def(tcx, *to)))
                 .collect::<HashMap<_, _>>();
             let stubs = apply_transitivity(tcx, *harness, stubs);
-            per_stubs.insert(key, CodegenUnit { stubs, harnesses: vec![*harness] });
+            per_stubs.insert(
+                key,
+                CodegenUnit { stubs, harnesses: vec![*harness], ..Default::default() },
+            );
         }
     }
     per_stubs.into_values().collect()
@@ -242,7 +374,7 @@ fn extract_contracts(tcx: TyCtxt, harness: Harness) -> BTreeSet<ContractUsage> {
     let def = harness.def;
     let mut result = BTreeSet::new();
     let attributes = KaniAttributes::for_def_id(tcx, def.def_id());
-    if let Some(target) = attributes.interpret_for_contract_attribute() {
+    if let Some(target) = contract_harness_target(tcx, harness) {
         result.insert(ContractUsage::Check(target.def_id().to_index()));
     }
     for stub in attributes.interpret_stub_verified_attribute() {
@@ -284,6 +416,55 @@ fn validate_units(tcx: TyCtxt, units: &[CodegenUnit]) {
     tcx.dcx().abort_if_errors();
 }
 
+/// Add the stubs of the annotated `extern` blocks to every unit.
+///
+/// The stubs specified by a harness take precedence over the ones of the `extern` block.
+fn add_ffi_stubs(tcx: TyCtxt, args: &Arguments, units: &mut [CodegenUnit]) {
+    let is_enabled = args.unstable_features.contains(&"ffi-stubs".to_string());
+    let ffi_stubs = ffi_stub_map(tcx, is_enabled);
+    tcx.dcx().abort_if_errors();
+    for unit in units {
+        for (from, to) in &ffi_stubs {
+            unit.stubs.entry(*from).or_insert(*to);
+        }
+    }
+}
+
+/// Add the public functions of the crates given to `--abstract-crate` to the functions
+/// abstracted by every unit.
+///
+/// Functions that have a contract are not abstracted, so their contract can be used instead,
+/// e.g., with `#[kani::stub_verified]`. The stubs specified by a harness take precedence too.
+fn add_abstracted_fns(tcx: TyCtxt, args: &Arguments, units: &mut [CodegenUnit]) {
+    let mut abstracted = HashSet::new();
+    for name in &args.abstract_crates {
+        let name = name.replace('-', "_");
+        let crates = rustc_public::find_crates(&name);
+        if crates.is_empty() {
+            tcx.dcx().err(format!(
+                "cannot abstract crate `{name}`, since it is not a dependency of this crate"
+            ));
+            continue;
+        }
+        let fns: Vec<FnDef> = crates
+            .iter()
+            .flat_map(|krate| krate.fn_defs())
+            .filter(|def| {
+                let def_id = rustc_internal::internal(tcx, def.def_id());
+                tcx.visibility(def_id).is_public()
+                    && !KaniAttributes::for_def_id(tcx, def.def_id()).has_contract()
+            })
+            .collect();
+        tcx.dcx().note(format!("abstracting {} public function(s) of crate `{name}`", fns.len()));
+        abstracted.extend(fns);
+    }
+    tcx.dcx().abort_if_errors();
+    for unit in units {
+        unit.abstracted =
+            abstracted.iter().filter(|def| !unit.stubs.contains_key(def)).copied().collect();
+    }
+}
+
 /// Apply stub transitivity operations.
 ///
 /// If `fn1` is stubbed by `fn2`, and `fn2` is stubbed by `fn3`, `f1` is in fact stubbed by `fn3`.
@@ -328,6 +509,64 @@ fn get_all_manual_harnesses(
         .collect::<HashMap<_, _>>()
 }
 
+/// Instantiate every `proof_for_contract_all_impls` harness with the type of each implementation
+/// of the target trait in the crate, and generate the metadata of these harnesses.
+///
+/// We emit a warning for every implementation that we skip, i.e., generic implementations, types
+/// that don't implement `Arbitrary`, implementations that the harness cannot be instantiated
+/// with, and implementations of the target that have no contract.
+fn get_all_impls_harnesses(
+    tcx: TyCtxt,
+    kani_any_def: Option<FnDef>,
+    base_filename: &Path,
+) -> HashMap<Harness, HarnessMetadata> {
+    let mut ty_arbitrary_cache: FxHashMap<Ty, bool> = FxHashMap::default();
+    let mut harnesses = HashMap::default();
+    for def in rustc_public::local_crate().fn_defs() {
+        let attributes = KaniAttributes::for_def_id(tcx, def.def_id());
+        let Some(trait_fn) = attributes.interpret_for_contract_all_impls_attribute() else {
+            continue;
+        };
+        let trait_fn_id = rustc_internal::internal(tcx, trait_fn.def_id());
+        let trait_id = tcx.trait_of_assoc(trait_fn_id).unwrap();
+        let span = rustc_internal::internal(tcx, def.span());
+        for impl_id in tcx.all_impls(trait_id).filter(|impl_id| impl_id.is_local()) {
+            let self_ty = tcx.type_of(impl_id).instantiate_identity();
+            let skip = |reason: String| {
+                tcx.dcx()
+                    .span_warn(span, format!("skipping `{}::<{self_ty}>`: {reason}", def.name()))
+            };
+            if self_ty.has_param() {
+                skip("generic implementations are not supported".to_string());
+                continue;
+            }
+            let ty = rustc_internal::stable(self_ty);
+            let kani_any_def = kani_any_def.expect("the Kani library should define `kani::any`");
+            if !implements_arbitrary(ty, kani_any_def, &mut ty_arbitrary_cache) {
+                skip(format!("`{self_ty}` doesn't implement `Arbitrary`"));
+                continue;
+            }
+            let Ok(harness) = Instance::resolve(def, &GenericArgs(vec![GenericArgKind::Type(ty)]))
+            else {
+                skip(format!("the harness cannot be instantiated with `{self_ty}`"));
+                continue;
+            };
+            let Some(target) = contract_harness_target(tcx, harness) else {
+                skip(format!("`{self_ty}` has no implementation of `{}`", trait_fn.name()));
+                continue;
+            };
+            if !KaniAttributes::for_def_id(tcx, target.def_id()).has_contract() {
+                skip(format!("`{}` has no contract", target.name()));
+                continue;
+            }
+            let mut metadata = gen_proof_metadata(tcx, harness, base_filename);
+            metadata.attributes.kind = HarnessKind::ProofForContract { target_fn: target.name() };
+            harnesses.insert(harness, metadata);
+        }
+    }
+    harnesses
+}
+
 /// Filter which harnesses to codegen based on user filters. Shares use of `find_proof_harnesses` with the `determine_targets` function
 /// in `kani-driver/src/metadata.rs` to ensure the filter is consistent and thus codegen is always done for the subset of harnesses we want
 /// to analyze.
@@ -468,30 +707,7 @@ fn automatic_harness_partition(
 
         // Each argument of `instance` must implement Arbitrary.
         // Note that we've already filtered out generic functions, so we know that each of these arguments has a concrete type.
-        let mut problematic_args = vec![];
-        for (idx, arg) in body.arg_locals().iter().enumerate() {
-            if !ty_arbitrary_cache.contains_key(&arg.ty) {
-                let impls_arbitrary =
-                    implements_arbitrary(arg.ty, kani_any_def, &mut ty_arbitrary_cache)
-                        || can_derive_arbitrary(arg.ty, kani_any_def, &mut ty_arbitrary_cache);
-                ty_arbitrary_cache.insert(arg.ty, impls_arbitrary);
-            }
-            let impls_arbitrary = ty_arbitrary_cache.get(&arg.ty).unwrap();
-
-            if !impls_arbitrary {
-                // Find the name of the argument by referencing var_debug_info.
-                // Note that enumerate() starts at 0, while rustc_public argument_index starts at 1, hence the idx+1.
-                let arg_name = body
-                    .var_debug_info
-                    .iter()
-                    .find(|var| {
-                        var.argument_index.is_some_and(|arg_idx| idx + 1 == usize::from(arg_idx))
-                    })
-                    .map_or("_".to_string(), |debug_info| debug_info.name.to_string());
-                let arg_type = format!("{}", arg.ty);
-                problematic_args.push((arg_name, arg_type))
-            }
-        }
+        let problematic_args = args_without_arbitrary(&body, kani_any_def, &mut ty_arbitrary_cache);
         if !problematic_args.is_empty() {
             return Some(AutoHarnessSkipReason::MissingArbitraryImpl(problematic_args));
         }
@@ -512,6 +728,161 @@ fn automatic_harness_partition(
     (chosen, skipped)
 }
 
+/// Return the name and type of every argument of `body` that neither implements nor can derive
+/// `Arbitrary`.
+fn args_without_arbitrary(
+    body: &Body,
+    kani_any_def: FnDef,
+    ty_arbitrary_cache: &mut FxHashMap<Ty, bool>,
+) -> Vec<(String, String)> {
+    let mut problematic_args = vec![];
+    for (idx, arg) in body.arg_locals().iter().enumerate() {
+        if !ty_arbitrary_cache.contains_key(&arg.ty) {
+            let impls_arbitrary = implements_arbitrary(arg.ty, kani_any_def, ty_arbitrary_cache)
+                || can_derive_arbitrary(arg.ty, kani_any_def, ty_arbitrary_cache);
+            ty_arbitrary_cache.insert(arg.ty, impls_arbitrary);
+        }
+        let impls_arbitrary = ty_arbitrary_cache.get(&arg.ty).unwrap();
+
+        if !impls_arbitrary {
+            // Find the name of the argument by referencing var_debug_info.
+            // Note that enumerate() starts at 0, while rustc_public argument_index starts at 1, hence the idx+1.
+            let arg_name = body
+                .var_debug_info
+                .iter()
+                .find(|var| {
+                    var.argument_index.is_some_and(|arg_idx| idx + 1 == usize::from(arg_idx))
+                })
+                .map_or("_".to_string(), |debug_info| debug_info.name.to_string());
+            let arg_type = format!("{}", arg.ty);
+            problematic_args.push((arg_name, arg_type))
+        }
+    }
+    problematic_args
+}
+
+/// Find the functions annotated with `#[kani::verify_unsafe]`, which are verified by an automatic
+/// harness.
+///
+/// Since the user explicitly asked for these harnesses, we emit an error for every function that
+/// we cannot generate a harness for.
+fn verify_unsafe_targets(
+    tcx: TyCtxt,
+    args: &Arguments,
+    kani_any_def: Option<FnDef>,
+) -> Vec<Instance> {
+    let mut ty_arbitrary_cache: FxHashMap<Ty, bool> = FxHashMap::default();
+    let mut targets = vec![];
+    for def in rustc_public::local_crate().fn_defs() {
+        if !KaniAttributes::for_def_id(tcx, def.def_id()).is_verify_unsafe() {
+            continue;
+        }
+        let span = rustc_internal::internal(tcx, def.span());
+        if !args.is_verify_unsafe_enabled() {
+            tcx.dcx().span_err(
+                span,
+                "Using the verify_unsafe attribute requires activating the unstable `verify-unsafe` feature",
+            );
+            continue;
+        }
+        let Ok(instance) = Instance::try_from(CrateItem(def.def_id())) else {
+            tcx.dcx().span_err(
+                span,
+                format!(
+                    "cannot verify `{}`: generic functions are not supported by `#[kani::verify_unsafe]`",
+                    def.name()
+                ),
+            );
+            continue;
+        };
+        let problematic_args = args_without_arbitrary(
+            &instance.body().unwrap(),
+            kani_any_def.expect("the Kani library should define `kani::any`"),
+            &mut ty_arbitrary_cache,
+        );
+        if problematic_args.is_empty() {
+            targets.push(instance);
+        } else {
+            let args = problematic_args
+                .iter()
+                .map(|(name, ty)| format!("`{name}: {ty}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            tcx.dcx()
+                .struct_span_err(
+                    span,
+                    format!(
+                        "cannot verify `{}`: the following arguments don't implement `Arbitrary`: {args}",
+                        def.name()
+                    ),
+                )
+                .with_help("write a harness for this function with `#[kani::proof]` instead")
+                .emit();
+        }
+    }
+    tcx.dcx().abort_if_errors();
+    targets
+}
+
+/// Find the functions under contract that should be verified by an automatic contract harness
+/// when `-Z contracts-batch` is enabled.
+///
+/// Functions that already have a manual contract harness are not included. We emit a warning for
+/// every function that we cannot generate a harness for, i.e., generic functions and functions
+/// whose arguments don't implement `Arbitrary`.
+fn contracts_batch_targets(
+    tcx: TyCtxt,
+    manual_harnesses: &HashMap<Harness, HarnessMetadata>,
+    kani_any_def: FnDef,
+) -> Vec<Instance> {
+    let checked_fns = manual_harnesses
+        .keys()
+        .filter_map(|harness| contract_harness_target(tcx, *harness))
+        .collect::<HashSet<_>>();
+    let mut ty_arbitrary_cache: FxHashMap<Ty, bool> = FxHashMap::default();
+    let mut targets = vec![];
+    for def in rustc_public::local_crate().fn_defs() {
+        if checked_fns.contains(&def)
+            || !KaniAttributes::for_def_id(tcx, def.def_id()).has_contract()
+        {
+            continue;
+        }
+        let span = rustc_internal::internal(tcx, def.span());
+        let Ok(instance) = Instance::try_from(CrateItem(def.def_id())) else {
+            tcx.dcx().span_warn(
+                span,
+                format!(
+                    "skipping contract verification of `{}`: generic functions are not supported by `-Z contracts-batch`",
+                    def.name()
+                ),
+            );
+            continue;
+        };
+        let problematic_args = args_without_arbitrary(
+            &instance.body().unwrap(),
+            kani_any_def,
+            &mut ty_arbitrary_cache,
+        );
+        if problematic_args.is_empty() {
+            targets.push(instance);
+        } else {
+            let args = problematic_args
+                .iter()
+                .map(|(name, ty)| format!("`{name}: {ty}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            tcx.dcx().span_warn(
+                span,
+                format!(
+                    "skipping contract verification of `{}`: the following arguments don't implement `Arbitrary`: {args}",
+                    def.name()
+                ),
+            );
+        }
+    }
+    targets
+}
+
 #[cfg(test)]
 mod autoharness_filter_tests {
     use super::*;
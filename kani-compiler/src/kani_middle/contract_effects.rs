@@ -0,0 +1,541 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that contract conditions are free of side effects.
+//!
+//! Contract conditions (`requires`, `ensures` and loop invariants) are expanded into closures
+//! that are passed to Kani marker functions. A condition that modifies the program state changes
+//! the semantics of the code under verification, so we reject conditions that:
+//!    1. Assign to a captured variable or to memory behind a raw pointer.
+//!    2. Mutably borrow a captured variable or memory behind a raw pointer.
+//!    3. Call a function that is not known to be pure. Known pure functions are Kani functions,
+//!       `const` functions, functions marked with `#[kani::trusted_pure]`, and an allowlist of
+//!       standard library functions. The calls made by specification functions, i.e., functions
+//!       marked with `#[kani::spec_fn]`, are checked as if they were made by the condition.
+//!       So are the calls made by the implementations of trait methods outside of the standard
+//!       library, e.g., a user implementation of `PartialEq::eq`, unless they are marked with
+//!       `#[kani::trusted_pure]`. Specification functions and such implementations must not
+//!       write through raw pointers either, e.g., to a `static mut`.
+//!    4. Pass a reference to a type with interior mutability to a function.
+//!
+//! Closures created inside a condition, e.g., the predicate of `iter().all()`, are checked too.
+
+use crate::kani_middle::attributes::{KaniAttributes, fn_marker};
+use rustc_middle::ty::{TyCtxt, TypeVisitableExt, TypingEnv};
+use rustc_public::mir::mono::Instance;
+use rustc_public::mir::{
+    AggregateKind, Body, BorrowKind, Operand, Place, ProjectionElem, RawPtrKind, Rvalue,
+    StatementKind, TerminatorKind,
+};
+use rustc_public::rustc_internal;
+use rustc_public::ty::{ClosureDef, FnDef, GenericArgs, RigidTy, Span, Ty, TyKind};
+use rustc_public::{CrateDef, CrateItem};
+use std::collections::HashSet;
+
+/// Markers of the functions that receive a contract condition closure as their first argument.
+const CONDITION_MARKERS: [&str; 3] =
+    ["ContractConditionModel", "ApplyClosureModel", "kani_register_loop_contract"];
+
+/// The local that holds the closure environment, i.e., the captured variables.
+const ENV_LOCAL: usize = 1;
+
+const WRITE_OUTSIDE: &str = "assignment to memory outside the condition";
+
+/// Crates whose functions may be allowed by [PURE_STD_FNS].
+const STD_CRATES: [&str; 3] = ["core", "alloc", "std"];
+
+/// Standard library functions that are side effect free for all the types they are implemented
+/// for in the standard library. The implementations of these trait methods in other crates are
+/// checked like specification functions, see [EffectChecker::check_trait_call].
+///
+/// The paths are the ones printed by the compiler, without the crate, generic arguments, or the
+/// self type of inherent implementations on primitive types, e.g., `slice::<impl>::len` is
+/// `core::slice::<impl [T]>::len`. See [normalized_std_path]. Matching the whole path makes sure
+/// that methods with the same name, like `Iterator::take` and `Cell::take`, are not confused.
+const PURE_STD_FNS: &[&str] = &[
+    "clone::Clone::clone",
+    "cmp::Ord::cmp",
+    "cmp::Ord::max",
+    "cmp::Ord::min",
+    "cmp::PartialEq::eq",
+    "cmp::PartialEq::ne",
+    "cmp::PartialOrd::ge",
+    "cmp::PartialOrd::gt",
+    "cmp::PartialOrd::le",
+    "cmp::PartialOrd::lt",
+    "cmp::PartialOrd::partial_cmp",
+    "cmp::max",
+    "cmp::min",
+    "collections::HashMap::contains_key",
+    "collections::HashMap::get",
+    "collections::HashMap::is_empty",
+    "collections::HashMap::len",
+    "convert::AsRef::as_ref",
+    "convert::From::from",
+    "convert::Into::into",
+    "convert::TryFrom::try_from",
+    "convert::TryInto::try_into",
+    "iter::IntoIterator::into_iter",
+    "iter::Iterator::all",
+    "iter::Iterator::any",
+    "iter::Iterator::count",
+    "iter::Iterator::enumerate",
+    "iter::Iterator::filter",
+    "iter::Iterator::fold",
+    "iter::Iterator::last",
+    "iter::Iterator::map",
+    "iter::Iterator::max",
+    "iter::Iterator::min",
+    "iter::Iterator::next",
+    "iter::Iterator::rev",
+    "iter::Iterator::skip",
+    "iter::Iterator::take",
+    "iter::Iterator::zip",
+    "num::<impl>::abs",
+    "num::<impl>::checked_add",
+    "num::<impl>::checked_div",
+    "num::<impl>::checked_mul",
+    "num::<impl>::checked_sub",
+    "num::<impl>::wrapping_add",
+    "num::<impl>::wrapping_mul",
+    "num::<impl>::wrapping_sub",
+    "ops::Add::add",
+    "ops::BitAnd::bitand",
+    "ops::BitOr::bitor",
+    "ops::BitXor::bitxor",
+    "ops::Deref::deref",
+    "ops::Div::div",
+    "ops::Fn::call",
+    "ops::Index::index",
+    "ops::Mul::mul",
+    "ops::Neg::neg",
+    "ops::Not::not",
+    "ops::Rem::rem",
+    "ops::Shl::shl",
+    "ops::Shr::shr",
+    "ops::Sub::sub",
+    "option::Option::as_deref",
+    "option::Option::as_ref",
+    "option::Option::is_none",
+    "option::Option::is_none_or",
+    "option::Option::is_some",
+    "option::Option::is_some_and",
+    "option::Option::iter",
+    "option::Option::map",
+    "option::Option::map_or",
+    "option::Option::unwrap",
+    "option::Option::unwrap_or",
+    "result::Result::as_ref",
+    "result::Result::is_err",
+    "result::Result::is_ok",
+    "result::Result::map",
+    "result::Result::unwrap",
+    "result::Result::unwrap_or",
+    "slice::<impl>::as_ptr",
+    "slice::<impl>::contains",
+    "slice::<impl>::ends_with",
+    "slice::<impl>::first",
+    "slice::<impl>::get",
+    "slice::<impl>::is_empty",
+    "slice::<impl>::iter",
+    "slice::<impl>::last",
+    "slice::<impl>::len",
+    "slice::<impl>::starts_with",
+    "str::<impl>::as_ptr",
+    "str::<impl>::contains",
+    "str::<impl>::ends_with",
+    "str::<impl>::is_empty",
+    "str::<impl>::len",
+    "str::<impl>::starts_with",
+    "string::String::as_str",
+    "string::String::is_empty",
+    "string::String::len",
+    "vec::Vec::as_ptr",
+    "vec::Vec::as_slice",
+    "vec::Vec::is_empty",
+    "vec::Vec::len",
+];
+
+/// Report every contract condition in the local crate that may have side effects.
+pub fn check_contract_effects(tcx: TyCtxt) {
+    let mut checker = EffectChecker {
+        tcx,
+        visited: HashSet::new(),
+        visited_spec_fns: HashSet::new(),
+        visited_impls: HashSet::new(),
+        reported: HashSet::new(),
+    };
+    let mut conditions = vec![];
+    let mut closures = HashSet::new();
+    let mut bodies: Vec<Body> =
+        rustc_public::all_local_items().into_iter().filter_map(|item| item.body()).collect();
+    while let Some(body) = bodies.pop() {
+        conditions.extend(find_conditions(&body));
+        for closure in nested_closures(&body) {
+            if closures.insert(closure)
+                && let Some(body) = CrateItem(closure.def_id()).body()
+            {
+                bodies.push(body);
+            }
+        }
+    }
+
+    for condition in conditions {
+        checker.check_closure(condition);
+    }
+}
+
+struct EffectChecker<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    visited: HashSet<ClosureDef>,
+    visited_spec_fns: HashSet<FnDef>,
+    visited_impls: HashSet<Instance>,
+    /// Conditions are duplicated by the contract instrumentation, so only report each issue once.
+    reported: HashSet<(rustc_span::Span, String)>,
+}
+
+impl EffectChecker<'_> {
+    fn check_closure(&mut self, closure: ClosureDef) {
+        if !self.visited.insert(closure) {
+            return;
+        }
+        let Some(body) = CrateItem(closure.def_id()).body() else { return };
+        let escaping = escaping_pointers(&body);
+        let writes_outside = |place: &Place| {
+            place.local == ENV_LOCAL
+                || (place.projection.contains(&ProjectionElem::Deref)
+                    && (escaping.contains(&place.local)
+                        || matches!(
+                            body.locals()[place.local].ty.kind(),
+                            TyKind::RigidTy(RigidTy::RawPtr(..))
+                        )))
+        };
+
+        for bb in &body.blocks {
+            for stmt in &bb.statements {
+                match &stmt.kind {
+                    StatementKind::Assign(place, rvalue) => {
+                        if writes_outside(place) {
+                            self.report(stmt.span, WRITE_OUTSIDE, None);
+                        }
+                        match rvalue {
+                            Rvalue::Ref(_, BorrowKind::Mut { .. }, borrowed)
+                            | Rvalue::AddressOf(RawPtrKind::Mut, borrowed)
+                                if writes_outside(borrowed) =>
+                            {
+                                self.report(
+                                    stmt.span,
+                                    "mutable borrow of memory outside the condition",
+                                    None,
+                                );
+                            }
+                            Rvalue::Aggregate(AggregateKind::Closure(def, _), _) => {
+                                self.check_closure(*def);
+                            }
+                            _ => {}
+                        }
+                    }
+                    StatementKind::SetDiscriminant { place, .. } | StatementKind::Deinit(place)
+                        if writes_outside(place) =>
+                    {
+                        self.report(stmt.span, WRITE_OUTSIDE, None);
+                    }
+                    _ => {}
+                }
+            }
+            if let TerminatorKind::Call { func, args, .. } = &bb.terminator.kind {
+                self.check_call(&body, func, args, bb.terminator.span);
+            }
+        }
+    }
+
+    fn check_call(&mut self, body: &Body, func: &Operand, args: &[Operand], span: Span) {
+        let TyKind::RigidTy(RigidTy::FnDef(def, generic_args)) =
+            func.ty(body.locals()).unwrap().kind()
+        else {
+            self.report(span, "call through a function pointer", None);
+            return;
+        };
+        let attributes = KaniAttributes::for_def_id(self.tcx, def.def_id());
+        if is_kani_function(def) || attributes.is_trusted_pure() {
+            return;
+        }
+        if attributes.is_spec_fn() {
+            self.check_spec_fn(def);
+            return;
+        }
+        if self.check_trait_call(def, &generic_args) {
+            return;
+        }
+        let interior_mut = args.iter().find_map(|arg| {
+            pointee_ty(arg.ty(body.locals()).unwrap()).filter(|ty| self.is_interior_mut(*ty))
+        });
+        if let Some(pointee) = interior_mut {
+            // Being `const` is not enough, since `const` functions may mutate through a shared
+            // reference to a type with interior mutability, e.g., `Cell::replace`.
+            if !is_pure_std_fn(def) {
+                let reason = format!("access to `{pointee}`, which has interior mutability");
+                self.report(span, &reason, None);
+            }
+        } else if !self.is_const_fn(def) && !is_pure_std_fn(def) {
+            let name = def.trimmed_name();
+            let reason = format!("call to `{name}`, which is not known to be pure");
+            let help = format!(
+                "if `{name}` has no side effects, annotate it with `#[kani::trusted_pure]`"
+            );
+            self.report(span, &reason, Some(help));
+        }
+    }
+
+    /// Check the calls made by a specification function, and the closures it creates.
+    fn check_spec_fn(&mut self, def: FnDef) {
+        if !self.visited_spec_fns.insert(def) {
+            return;
+        }
+        if let Some(body) = def.body() {
+            self.check_fn_body(&body);
+        }
+    }
+
+    /// Resolve the implementation of the trait method `def` that is called with the given
+    /// arguments, if `def` is one of the trait methods in [PURE_STD_FNS]. These are only known to
+    /// be pure for the implementations of the standard library. Returns `true` if the call was
+    /// checked as a call to an implementation outside of the standard library.
+    ///
+    /// Provided methods, e.g., `PartialEq::ne`, are part of the standard library, but they may
+    /// call the implementations of the other methods of the trait, so their calls are resolved
+    /// too. Calls whose implementation cannot be resolved, because it depends on generic
+    /// parameters, are accepted, since we cannot tell.
+    fn check_trait_call(&mut self, def: FnDef, args: &GenericArgs) -> bool {
+        if !is_pure_std_fn(def) {
+            return false;
+        }
+        match self.resolve_trait_call(def, args) {
+            Some(callee) if !is_std_instance(callee) => {
+                self.check_user_impl(callee);
+                true
+            }
+            Some(callee) if callee.def.def_id() == def.def_id() => {
+                self.check_provided_method(callee);
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// The instance that is called by a call to the trait method `def`, if it is one.
+    fn resolve_trait_call(&self, def: FnDef, args: &GenericArgs) -> Option<Instance> {
+        self.tcx.trait_of_assoc(rustc_internal::internal(self.tcx, def.def_id()))?;
+        Instance::resolve(def, args).ok().filter(|callee| !is_kani_function(callee.def))
+    }
+
+    /// Check the implementations outside of the standard library that are called by a provided
+    /// method of a standard library trait. The rest of its body is part of the standard library.
+    fn check_provided_method(&mut self, method: Instance) {
+        if !self.visited_impls.insert(method) {
+            return;
+        }
+        let Some(body) = method.body() else { return };
+        for bb in &body.blocks {
+            let TerminatorKind::Call { func, .. } = &bb.terminator.kind else { continue };
+            let TyKind::RigidTy(RigidTy::FnDef(def, args)) = func.ty(body.locals()).unwrap().kind()
+            else {
+                continue;
+            };
+            match self.resolve_trait_call(def, &args) {
+                Some(callee) if !is_std_instance(callee) => self.check_user_impl(callee),
+                Some(callee) if callee.def.def_id() == def.def_id() => {
+                    self.check_provided_method(callee)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Check the implementation of a trait method outside of the standard library like a
+    /// specification function, unless it is marked with `#[kani::trusted_pure]`.
+    fn check_user_impl(&mut self, callee: Instance) {
+        let attributes = KaniAttributes::for_def_id(self.tcx, callee.def.def_id());
+        if attributes.is_trusted_pure() || !self.visited_impls.insert(callee) {
+            return;
+        }
+        if let Some(body) = callee.body() {
+            self.check_fn_body(&body);
+        }
+    }
+
+    /// Check the writes through raw pointers, the calls, and the closures of the body of a
+    /// function called by a condition. Writes to its own locals are not side effects.
+    fn check_fn_body(&mut self, body: &Body) {
+        for closure in nested_closures(body) {
+            self.check_closure(closure);
+        }
+        for bb in &body.blocks {
+            for stmt in &bb.statements {
+                if let StatementKind::Assign(place, _) = &stmt.kind
+                    && place.projection.contains(&ProjectionElem::Deref)
+                    && matches!(
+                        body.locals()[place.local].ty.kind(),
+                        TyKind::RigidTy(RigidTy::RawPtr(..))
+                    )
+                {
+                    self.report(stmt.span, WRITE_OUTSIDE, None);
+                }
+            }
+            if let TerminatorKind::Call { func, args, .. } = &bb.terminator.kind {
+                self.check_call(body, func, args, bb.terminator.span);
+            }
+        }
+    }
+
+    fn is_const_fn(&self, def: FnDef) -> bool {
+        self.tcx.is_const_fn(rustc_internal::internal(self.tcx, def.def_id()))
+    }
+
+    /// Whether the type has interior mutability.
+    ///
+    /// Types that depend on generic parameters are accepted, since we cannot tell.
+    fn is_interior_mut(&self, ty: Ty) -> bool {
+        let internal_ty = rustc_internal::internal(self.tcx, ty);
+        !internal_ty.has_param()
+            && !internal_ty.is_freeze(self.tcx, TypingEnv::fully_monomorphized())
+    }
+
+    fn report(&mut self, span: Span, reason: &str, help: Option<String>) {
+        let span = rustc_internal::internal(self.tcx, span);
+        let msg = format!("contract conditions must not have side effects: {reason}");
+        if self.reported.insert((span, msg.clone())) {
+            let mut diag = self.tcx.dcx().struct_span_err(span, msg);
+            if let Some(help) = help {
+                diag = diag.with_help(help);
+            }
+            diag.emit();
+        }
+    }
+}
+
+/// Find the condition closures passed to contract markers in the given body.
+pub(super) fn find_conditions(body: &Body) -> Vec<ClosureDef> {
+    body.blocks
+        .iter()
+        .filter_map(|bb| {
+            let TerminatorKind::Call { func, args, .. } = &bb.terminator.kind else { return None };
+            let TyKind::RigidTy(RigidTy::FnDef(def, _)) = func.ty(body.locals()).ok()?.kind()
+            else {
+                return None;
+            };
+            let marker = fn_marker(def)?;
+            if !CONDITION_MARKERS.contains(&marker.as_str()) {
+                return None;
+            }
+            let ty = args.first()?.ty(body.locals()).ok()?;
+            let ty = pointee_ty(ty).unwrap_or(ty);
+            if let TyKind::RigidTy(RigidTy::Closure(closure, _)) = ty.kind() {
+                Some(closure)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Find the closures created in the given body.
+pub(super) fn nested_closures(body: &Body) -> Vec<ClosureDef> {
+    body.blocks
+        .iter()
+        .flat_map(|bb| &bb.statements)
+        .filter_map(|stmt| match &stmt.kind {
+            StatementKind::Assign(_, Rvalue::Aggregate(AggregateKind::Closure(def, _), _)) => {
+                Some(*def)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collect the pointer locals that may point to memory outside the closure, i.e., pointers that
+/// are copied or derived from the closure environment.
+fn escaping_pointers(body: &Body) -> HashSet<usize> {
+    let mut escaping = HashSet::from([ENV_LOCAL]);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for stmt in body.blocks.iter().flat_map(|bb| &bb.statements) {
+            let StatementKind::Assign(place, rvalue) = &stmt.kind else { continue };
+            let source = match rvalue {
+                Rvalue::Use(Operand::Copy(source) | Operand::Move(source))
+                | Rvalue::CopyForDeref(source)
+                | Rvalue::Ref(_, _, source)
+                | Rvalue::AddressOf(_, source) => source,
+                _ => continue,
+            };
+            if place.projection.is_empty()
+                && escaping.contains(&source.local)
+                && pointee_ty(body.locals()[place.local].ty).is_some()
+            {
+                changed |= escaping.insert(place.local);
+            }
+        }
+    }
+    escaping
+}
+
+/// Return the pointee type if the given type is a reference or a raw pointer.
+fn pointee_ty(ty: Ty) -> Option<Ty> {
+    match ty.kind() {
+        TyKind::RigidTy(RigidTy::Ref(_, pointee, _) | RigidTy::RawPtr(pointee, _)) => Some(pointee),
+        _ => None,
+    }
+}
+
+/// Whether the instance is part of the standard library.
+fn is_std_instance(instance: Instance) -> bool {
+    STD_CRATES.contains(&instance.def.krate().name.as_str())
+}
+
+/// Whether this is a standard library function that we know to be pure.
+fn is_pure_std_fn(def: FnDef) -> bool {
+    let name = def.name();
+    STD_CRATES.contains(&def.krate().name.as_str())
+        && (name.starts_with("core::panicking::")
+            || PURE_STD_FNS.contains(&normalized_std_path(&name).as_str()))
+}
+
+/// The path of a standard library function in the form used by [PURE_STD_FNS], i.e., without
+/// the crate, the generic arguments, and the self type of inherent implementations.
+/// For example, `std::option::Option::<T>::is_some` becomes `option::Option::is_some`, and
+/// `core::num::<impl u32>::checked_add` becomes `num::<impl>::checked_add`.
+fn normalized_std_path(name: &str) -> String {
+    // Split the path at the `::` that are not within angle brackets.
+    let mut segments = vec![];
+    let (mut depth, mut start) = (0, 0);
+    for (idx, c) in name.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ':' if depth == 0 && name[idx..].starts_with("::") && idx >= start => {
+                segments.push(&name[start..idx]);
+                start = idx + 2;
+            }
+            _ => {}
+        }
+    }
+    segments.push(&name[start..]);
+    segments
+        .into_iter()
+        .skip(1)
+        .filter_map(|segment| {
+            if segment.starts_with("<impl ") {
+                Some("<impl>")
+            } else {
+                // Drop the generic arguments, e.g., `<T>` in `Option::<T>::is_some`.
+                (!segment.starts_with('<')).then_some(segment)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Whether `def` is part of the Kani library.
+pub(crate) fn is_kani_function(def: impl CrateDef) -> bool {
+    fn_marker(def).is_some() || def.krate().name == "kani" || def.name().starts_with("core::kani::")
+}
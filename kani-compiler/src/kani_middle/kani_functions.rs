@@ -67,6 +67,10 @@ pub enum KaniModel {
     AlignOfVal,
     #[strum(serialize = "AnyModel")]
     Any,
+    #[strum(serialize = "ApplyClosureModel")]
+    ApplyClosure,
+    #[strum(serialize = "ContractConditionModel")]
+    ContractCondition,
     #[strum(serialize = "CopyInitStateModel")]
     CopyInitState,
     #[strum(serialize = "CopyInitStateSingleModel")]
@@ -83,6 +87,18 @@ pub enum KaniModel {
     IsSliceChunkPtrInitialized,
     #[strum(serialize = "IsSlicePtrInitializedModel")]
     IsSlicePtrInitialized,
+    #[strum(serialize = "MutexClearPoisonModel")]
+    MutexClearPoison,
+    #[strum(serialize = "MutexGetMutModel")]
+    MutexGetMut,
+    #[strum(serialize = "MutexIntoInnerModel")]
+    MutexIntoInner,
+    #[strum(serialize = "MutexIsPoisonedModel")]
+    MutexIsPoisoned,
+    #[strum(serialize = "MutexLockModel")]
+    MutexLock,
+    #[strum(serialize = "MutexTryLockModel")]
+    MutexTryLock,
     #[strum(serialize = "OffsetModel")]
     Offset,
     #[strum(serialize = "PtrOffsetFromModel")]
@@ -93,6 +109,22 @@ pub enum KaniModel {
     RunContract,
     #[strum(serialize = "RunLoopContractModel")]
     RunLoopContract,
+    #[strum(serialize = "RwLockClearPoisonModel")]
+    RwLockClearPoison,
+    #[strum(serialize = "RwLockGetMutModel")]
+    RwLockGetMut,
+    #[strum(serialize = "RwLockIntoInnerModel")]
+    RwLockIntoInner,
+    #[strum(serialize = "RwLockIsPoisonedModel")]
+    RwLockIsPoisoned,
+    #[strum(serialize = "RwLockReadModel")]
+    RwLockRead,
+    #[strum(serialize = "RwLockTryReadModel")]
+    RwLockTryRead,
+    #[strum(serialize = "RwLockTryWriteModel")]
+    RwLockTryWrite,
+    #[strum(serialize = "RwLockWriteModel")]
+    RwLockWrite,
     #[strum(serialize = "SetPtrInitializedModel")]
     SetPtrInitialized,
     #[strum(serialize = "SetSliceChunkPtrInitializedModel")]
@@ -111,6 +143,12 @@ pub enum KaniModel {
     SizeOfVal,
     #[strum(serialize = "StoreArgumentModel")]
     StoreArgument,
+    #[strum(serialize = "SysMutexLockModel")]
+    SysMutexLock,
+    #[strum(serialize = "SysMutexTryLockModel")]
+    SysMutexTryLock,
+    #[strum(serialize = "SysMutexUnlockModel")]
+    SysMutexUnlock,
     #[strum(serialize = "WriteAnySliceModel")]
     WriteAnySlice,
     #[strum(serialize = "WriteAnySlimModel")]
@@ -129,10 +167,18 @@ pub enum KaniHook {
     AnyRaw,
     #[strum(serialize = "AssertHook")]
     Assert,
+    #[strum(serialize = "AssertPreconditionHook")]
+    AssertPrecondition,
     #[strum(serialize = "AssumeHook")]
     Assume,
     #[strum(serialize = "CheckHook")]
     Check,
+    #[strum(serialize = "CheckPreconditionHook")]
+    CheckPrecondition,
+    #[strum(serialize = "ConcretizeHook")]
+    Concretize,
+    #[strum(serialize = "DeadlockHook")]
+    Deadlock,
     #[strum(serialize = "CoverHook")]
     Cover,
     #[strum(serialize = "ExistsHook")]
@@ -152,10 +198,16 @@ pub enum KaniHook {
     PointerObject,
     #[strum(serialize = "PointerOffsetHook")]
     PointerOffset,
+    #[strum(serialize = "PointerOffsetCheckHook")]
+    PointerOffsetCheck,
     #[strum(serialize = "SafetyCheckHook")]
     SafetyCheck,
     #[strum(serialize = "SafetyCheckNoAssumeHook")]
     SafetyCheckNoAssume,
+    #[strum(serialize = "UninitCheckHook")]
+    UninitCheck,
+    #[strum(serialize = "UninitCheckNoAssumeHook")]
+    UninitCheckNoAssume,
     #[strum(serialize = "UnsupportedCheckHook")]
     UnsupportedCheck,
     #[strum(serialize = "UntrackedDerefHook")]
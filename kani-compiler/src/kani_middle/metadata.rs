@@ -3,15 +3,22 @@
 //! This module handles Kani metadata generation. For example, generating HarnessMetadata for a
 //! given function.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::Path;
 
+use crate::kani_middle::attributes::{UNWIND_SMALLER_THAN_BOUND, contract_harness_target};
 use crate::kani_middle::codegen_units::Harness;
+use crate::kani_middle::contract_effects::is_kani_function;
+use crate::kani_middle::kani_functions::{KaniFunction, KaniHook, KaniIntrinsic, KaniModel};
+use crate::kani_middle::transform::BodyTransformation;
 use crate::kani_middle::{KaniAttributes, SourceLocation};
 use kani_metadata::ContractedFunction;
 use kani_metadata::{ArtifactType, HarnessAttributes, HarnessKind, HarnessMetadata};
 use rustc_middle::ty::TyCtxt;
-use rustc_public::mir::mono::Instance;
+use rustc_public::mir::mono::{Instance, MonoItem};
+use rustc_public::mir::{Body, Operand, TerminatorKind};
+use rustc_public::rustc_internal;
+use rustc_public::ty::{GenericArgKind, RigidTy, Span, TyKind};
 use rustc_public::{CrateDef, CrateItems, DefId};
 
 /// Create the harness metadata for a proof harness for a given function.
@@ -23,7 +30,26 @@ pub fn gen_proof_metadata(tcx: TyCtxt, instance: Instance, base_name: &Path) ->
 
     // We get the body span to include the entire function definition.
     // This is required for concrete playback to properly position the generated test.
-    let loc = SourceLocation::new(instance.body().unwrap().span);
+    let body = instance.body().unwrap();
+    let loc = SourceLocation::new(body.span);
+    let attributes = kani_attributes.harness_attributes();
+    // Harnesses that expect a panic must still be verified, since their verification must fail.
+    let vacuous_assumption =
+        if attributes.should_panic { None } else { leading_false_assumption(&body) };
+    if let Some(span) = vacuous_assumption {
+        tcx.dcx()
+            .struct_span_warn(
+                rustc_internal::internal(tcx, span),
+                format!("vacuous harness: `{pretty_name}` starts with `kani::assume(false)`"),
+            )
+            .with_note("no property can fail after this assumption, so its verification is vacuous")
+            .emit();
+    }
+    if let Some(unwind) = attributes.unwind_value
+        && !kani_attributes.allows(UNWIND_SMALLER_THAN_BOUND)
+    {
+        check_generator_bounds(tcx, &kani_attributes, &pretty_name, unwind, &body);
+    }
     let file_stem = format!("{}_{mangled_name}", base_name.file_stem().unwrap().to_str().unwrap());
     let model_file = base_name.with_file_name(file_stem).with_extension(ArtifactType::SymTabGoto);
 
@@ -34,15 +60,266 @@ pub fn gen_proof_metadata(tcx: TyCtxt, instance: Instance, base_name: &Path) ->
         original_file: loc.filename,
         original_start_line: loc.start_line,
         original_end_line: loc.end_line,
-        attributes: kani_attributes.harness_attributes(),
+        attributes,
         // TODO: This no longer needs to be an Option.
         goto_file: Some(model_file),
         contract: Default::default(),
         has_loop_contracts: false,
         is_automatically_generated: false,
+        is_vacuous: vacuous_assumption.is_some(),
+        is_concrete: false,
+        uses_concretize: false,
+        loop_unwind_bounds: vec![],
+        reachable_functions: vec![],
+        nondet_bytes: 0,
+        debug_assertions: 0,
+    }
+}
+
+/// Return the location of a `kani::assume(false)` that is executed at the beginning of every
+/// execution of the harness, if any.
+///
+/// This only inspects the straight-line code at the start of the harness, and only detects
+/// assumptions whose argument is a literal `false`, i.e., contradictions that are statically
+/// evident.
+fn leading_false_assumption(body: &Body) -> Option<Span> {
+    let mut visited = vec![false; body.blocks.len()];
+    let mut bb = 0;
+    loop {
+        if std::mem::replace(&mut visited[bb], true) {
+            // We found a loop without any assumption.
+            return None;
+        }
+        let terminator = &body.blocks[bb].terminator;
+        match &terminator.kind {
+            TerminatorKind::Goto { target } => bb = *target,
+            TerminatorKind::Call { func, args, target, .. } => {
+                let ty = func.ty(body.locals()).ok()?;
+                let RigidTy::FnDef(def, _) = ty.kind().rigid()?.clone() else { return None };
+                if !matches!(KaniFunction::try_from(def), Ok(KaniFunction::Hook(KaniHook::Assume)))
+                {
+                    // Stop at the first call that isn't an assumption, since it may diverge.
+                    return None;
+                }
+                if let [Operand::Constant(cond)] = args.as_slice()
+                    && cond.const_.eval_target_bool().ok() == Some(false)
+                {
+                    return Some(terminator.span);
+                }
+                bb = (*target)?;
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// The Kani generators whose const generic argument bounds the length of the collections they
+/// generate, relative to the `kani` crate.
+const BOUNDED_GENERATORS: [&str; 5] = [
+    "bounded_any",
+    "slice::any_slice_of_array",
+    "slice::any_slice_of_array_mut",
+    "vec::any_vec",
+    "vec::exact_vec",
+];
+
+/// Warn about the bounded generators called by the harness whose collections have more elements
+/// than the harness unwind bound allows loops to iterate over, since the loops over these
+/// collections cannot reach their longest lengths.
+fn check_generator_bounds(
+    tcx: TyCtxt,
+    attributes: &KaniAttributes,
+    harness: &str,
+    unwind: u32,
+    body: &Body,
+) {
+    for bb in &body.blocks {
+        let TerminatorKind::Call { func, .. } = &bb.terminator.kind else { continue };
+        let Ok(ty) = func.ty(body.locals()) else { continue };
+        let Some(RigidTy::FnDef(def, args)) = ty.kind().rigid().cloned() else { continue };
+        let name = def.name();
+        let generator = name.strip_prefix("kani::").or_else(|| name.strip_prefix("core::kani::"));
+        if !generator.is_some_and(|generator| BOUNDED_GENERATORS.contains(&generator)) {
+            continue;
+        }
+        let Some(bound) = args.0.iter().find_map(|arg| match arg {
+            GenericArgKind::Const(bound) => bound.eval_target_usize().ok(),
+            _ => None,
+        }) else {
+            continue;
+        };
+        // A loop over `bound` elements needs `bound + 1` unwindings to terminate.
+        if u64::from(unwind) > bound {
+            continue;
+        }
+        let mut diag = tcx.dcx().struct_span_warn(
+            rustc_internal::internal(tcx, bb.terminator.span),
+            format!(
+                "the unwind bound of harness `{harness}` is not larger than the bound of `{name}`"
+            ),
+        );
+        let note = format!(
+            "loops over the {bound} elements generated by `{name}` need an unwind bound of at \
+            least {}, but the harness has an unwind bound of {unwind}",
+            bound + 1
+        );
+        diag = match attributes.unwind_span() {
+            Some(span) => diag.with_span_note(span, note),
+            None => diag.with_note(note),
+        };
+        diag.with_help(format!(
+            "increase the unwind bound, or add `#[kani::allow({UNWIND_SMALLER_THAN_BOUND})]` to \
+            the harness if this is intended"
+        ))
+        .emit();
+    }
+}
+
+/// Whether any of the given items is a source of non-determinism, i.e., a Kani function that
+/// generates symbolic values. This includes `kani::any()`, the symbolic slice and string helpers,
+/// and the havoc of the arguments modified by a contract that was replaced.
+///
+/// Note that we don't take foreign functions into account, even though CBMC models their
+/// return value as non-deterministic, since their symbolic values are rarely intentional.
+pub fn has_nondet_source(items: &[MonoItem]) -> bool {
+    items.iter().any(|item| {
+        let MonoItem::Fn(instance) = item else { return false };
+        matches!(
+            KaniFunction::try_from(*instance),
+            Ok(KaniFunction::Hook(KaniHook::AnyRaw)
+                | KaniFunction::Model(
+                    KaniModel::Any
+                        | KaniModel::WriteAnySlice
+                        | KaniModel::WriteAnySlim
+                        | KaniModel::WriteAnyStr
+                )
+                | KaniFunction::Intrinsic(KaniIntrinsic::AnyModifies | KaniIntrinsic::WriteAny))
+        )
+    })
+}
+
+/// The number of nondeterministic bytes introduced by each call from outside the Kani library to
+/// the Kani library, e.g., by each call to `kani::any()` in the harness, indexed by the caller and
+/// the location of the call.
+///
+/// A call is counted once for each call that reaches the function that contains it, e.g., calling
+/// twice a function that calls `kani::any::<u32>()` introduces 8 bytes. However, a call is counted
+/// once even if it is executed many times, e.g., inside a loop, and calls through function
+/// pointers are not taken into account.
+pub fn nondet_bytes(
+    tcx: TyCtxt,
+    transformer: &mut BodyTransformation,
+    harness: Instance,
+) -> HashMap<String, u64> {
+    let mut visitor =
+        NondetBytes { tcx, transformer, bytes: HashMap::new(), sources: HashMap::new() };
+    visitor.sources(harness)
+}
+
+struct NondetBytes<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    transformer: &'a mut BodyTransformation,
+    /// The number of bytes introduced by one call to each instance.
+    bytes: HashMap<Instance, u64>,
+    /// The bytes introduced by one call to each instance outside of the Kani library, by source.
+    sources: HashMap<Instance, HashMap<String, u64>>,
+}
+
+impl NondetBytes<'_, '_> {
+    /// The instances called by `instance`, with the span of each call.
+    fn calls(&mut self, instance: Instance) -> Vec<(Span, Instance)> {
+        // The calls to hooks are replaced during codegen.
+        if !instance.has_body()
+            || matches!(KaniFunction::try_from(instance), Ok(KaniFunction::Hook(_)))
+        {
+            return vec![];
+        }
+        let body = self.transformer.body(self.tcx, instance);
+        body.blocks
+            .iter()
+            .filter_map(|block| {
+                let TerminatorKind::Call { func, .. } = &block.terminator.kind else {
+                    return None;
+                };
+                let TyKind::RigidTy(RigidTy::FnDef(def, args)) =
+                    func.ty(body.locals()).ok()?.kind()
+                else {
+                    return None;
+                };
+                Some((block.terminator.span, Instance::resolve(def, &args).ok()?))
+            })
+            .collect()
+    }
+
+    /// The number of bytes introduced by one call to `instance`.
+    fn bytes(&mut self, instance: Instance) -> u64 {
+        if let Some(bytes) = self.bytes.get(&instance) {
+            return *bytes;
+        }
+        // Break recursive calls, which are only counted once.
+        self.bytes.insert(instance, 0);
+        // `kani::any()`, the symbolic slices and the contract havocs are all implemented on top
+        // of this hook, so this is where the bytes are introduced.
+        let bytes =
+            if matches!(KaniFunction::try_from(instance), Ok(KaniFunction::Hook(KaniHook::AnyRaw)))
+            {
+                instance.fn_abi().unwrap().ret.layout.shape().size.bytes() as u64
+            } else {
+                self.calls(instance).into_iter().map(|(_, callee)| self.bytes(callee)).sum()
+            };
+        self.bytes.insert(instance, bytes);
+        bytes
+    }
+
+    /// The bytes introduced by one call to `instance`, which is outside of the Kani library,
+    /// attributed to its calls to the Kani library or to the calls of the functions it calls.
+    fn sources(&mut self, instance: Instance) -> HashMap<String, u64> {
+        if let Some(sources) = self.sources.get(&instance) {
+            return sources.clone();
+        }
+        self.sources.insert(instance, HashMap::new());
+        let mut sources = HashMap::new();
+        for (span, callee) in self.calls(instance) {
+            if is_kani_function(callee.def) {
+                let bytes = self.bytes(callee);
+                if bytes > 0 {
+                    let lines = span.get_lines();
+                    let source = format!(
+                        "`{}` at {}:{}",
+                        instance.name(),
+                        span.get_filename(),
+                        lines.start_line
+                    );
+                    *sources.entry(source).or_default() += bytes;
+                }
+            } else {
+                for (source, bytes) in self.sources(callee) {
+                    *sources.entry(source).or_default() += bytes;
+                }
+            }
+        }
+        self.sources.insert(instance, sources.clone());
+        sources
     }
 }
 
+/// Whether `kani::concretize` is one of the items, which weakens the verification of the harness.
+pub fn uses_concretize(items: &[MonoItem]) -> bool {
+    items.iter().any(|item| {
+        let MonoItem::Fn(instance) = item else { return false };
+        matches!(KaniFunction::try_from(*instance), Ok(KaniFunction::Hook(KaniHook::Concretize)))
+    })
+}
+
+/// The sorted fully qualified names of the functions in `items`.
+pub fn reachable_function_names(items: &[MonoItem]) -> Vec<String> {
+    let names = items.iter().filter_map(|item| match item {
+        MonoItem::Fn(instance) => Some(instance.name()),
+        MonoItem::Static(_) | MonoItem::GlobalAsm(_) => None,
+    });
+    names.collect::<BTreeSet<_>>().into_iter().collect()
+}
+
 /// Collects contract and contract harness metadata.
 ///
 /// For each function with contracts (or that is a target of a contract harness),
@@ -62,8 +339,11 @@ pub fn gen_contracts_metadata(
         let attributes = KaniAttributes::for_def_id(tcx, item.def_id());
 
         if attributes.has_contract() {
-            fn_to_data
-                .insert(item.def_id(), ContractedFunction { function, file, harnesses: vec![] });
+            let clauses = attributes.contract_clauses();
+            fn_to_data.insert(
+                item.def_id(),
+                ContractedFunction { function, file, harnesses: vec![], clauses },
+            );
         // This logic finds manual contract harnesses only (automatic harnesses are a Kani intrinsic, not crate items annotated with the proof_for_contract attribute).
         } else if let Some(def) = attributes.interpret_for_contract_attribute() {
             let target_def_id = def.def_id();
@@ -78,6 +358,7 @@ pub fn gen_contracts_metadata(
                         function: item.name(),
                         file,
                         harnesses: vec![function],
+                        clauses: vec![],
                     },
                 );
             }
@@ -102,6 +383,18 @@ pub fn gen_contracts_metadata(
         }
     }
 
+    // Find the instances of `proof_for_contract_all_impls` harnesses, which are not crate items.
+    for (harness, metadata) in harness_info {
+        if metadata.is_automatically_generated || harness.args().0.is_empty() {
+            continue;
+        }
+        if let Some(target) = contract_harness_target(tcx, *harness)
+            && let Some(cf) = fn_to_data.get_mut(&target.def_id())
+        {
+            cf.harnesses.push(metadata.pretty_name.clone());
+        }
+    }
+
     fn_to_data.into_values().collect()
 }
 
@@ -132,6 +425,8 @@ pub fn gen_automatic_proof_metadata(
     } else {
         HarnessKind::Proof
     };
+    let mut attributes = HarnessAttributes::new(harness_kind);
+    kani_attributes.verify_unsafe_options(&mut attributes);
 
     HarnessMetadata {
         // pretty_name is what gets displayed to the user, and that should be the name of the function being verified, hence using fn_to_verify name
@@ -142,11 +437,18 @@ pub fn gen_automatic_proof_metadata(
         original_file: loc.filename,
         original_start_line: loc.start_line,
         original_end_line: loc.end_line,
-        attributes: HarnessAttributes::new(harness_kind),
+        attributes,
         // TODO: This no longer needs to be an Option.
         goto_file: Some(model_file),
         contract: Default::default(),
         has_loop_contracts: false,
         is_automatically_generated: true,
+        is_vacuous: false,
+        is_concrete: false,
+        uses_concretize: false,
+        loop_unwind_bounds: vec![],
+        reachable_functions: vec![],
+        nondet_bytes: 0,
+        debug_assertions: 0,
     }
 }
@@ -26,6 +26,7 @@
 pub mod attributes;
 pub mod codegen_units;
 pub mod coercion;
+mod contract_effects;
 mod intrinsics;
 pub mod kani_functions;
 pub mod metadata;
@@ -33,6 +34,7 @@
 pub mod provide;
 pub mod reachability;
 pub mod resolve;
+mod spec_fns;
 pub mod stubbing;
 pub mod transform;
 
@@ -81,6 +83,8 @@ pub fn check_crate_items(tcx: TyCtxt, ignore_asm: bool) {
         }
     }
 
+    contract_effects::check_contract_effects(tcx);
+    spec_fns::check_spec_fn_calls(tcx);
     tcx.dcx().abort_if_errors();
 }
 
@@ -40,6 +40,7 @@
     io::{BufWriter, Write},
 };
 
+use crate::kani_middle::attributes::KaniAttributes;
 use crate::kani_middle::coercion;
 use crate::kani_middle::coercion::CoercionBase;
 use crate::kani_middle::is_anon_static;
@@ -75,6 +76,9 @@ pub fn collect_reachable_items(
 
 /// Collect all (top-level) items in the crate that matches the given predicate.
 /// An item can only be a root if they are a non-generic function.
+///
+/// Specification functions, i.e., functions marked with `#[kani::spec_fn]`, are never roots, since
+/// they are only part of the program when a specification that uses them is verified.
 pub fn filter_crate_items<F>(tcx: TyCtxt, predicate: F) -> Vec<Instance>
 where
     F: Fn(TyCtxt, Instance) -> bool,
@@ -85,13 +89,14 @@ pub fn filter_crate_items<F>(tcx: TyCtxt, predicate: F) -> Vec<Instance>
         .iter()
         .filter_map(|item| {
             // Only collect monomorphic items.
-            matches!(item.kind(), ItemKind::Fn)
-                .then(|| {
-                    Instance::try_from(*item)
-                        .ok()
-                        .and_then(|instance| predicate(tcx, instance).then_some(instance))
-                })
-                .flatten()
+            (matches!(item.kind(), ItemKind::Fn)
+                && !KaniAttributes::for_def_id(tcx, item.def_id()).is_spec_fn())
+            .then(|| {
+                Instance::try_from(*item)
+                    .ok()
+                    .and_then(|instance| predicate(tcx, instance).then_some(instance))
+            })
+            .flatten()
         })
         .collect::<Vec<_>>()
 }
@@ -578,6 +583,35 @@ fn add_edges(&mut self, from: MonoItem, to: &[CollectedItem]) {
         }
     }
 
+    /// Collect the function instances that are reachable from the functions that satisfy
+    /// `is_root`, including the roots themselves.
+    pub fn reachable_from(&self, is_root: impl Fn(&Instance) -> bool) -> HashSet<Instance> {
+        let mut queue: Vec<&Node> = self
+            .nodes
+            .iter()
+            .filter(|node| matches!(&node.0, MonoItem::Fn(instance) if is_root(instance)))
+            .collect();
+        let mut visited: HashSet<&Node> = HashSet::default();
+        while let Some(node) = queue.pop() {
+            if visited.insert(node) {
+                queue.extend(
+                    self.edges
+                        .get(node)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|succ| self.nodes.get(&Node(succ.0.item.clone()))),
+                );
+            }
+        }
+        visited
+            .into_iter()
+            .filter_map(|node| match node.0 {
+                MonoItem::Fn(instance) => Some(instance),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Print the graph in DOT format to a file.
     /// See <https://graphviz.org/doc/info/lang.html> for more information.
     fn dump_dot(&self, tcx: TyCtxt, initial: Option<MonoItem>) -> std::io::Result<()> {
@@ -115,6 +115,17 @@ pub fn resolve_fn<'tcx>(
     Ok(rustc_internal::internal(tcx, result.def().def_id()))
 }
 
+/// Resolve a path to a constant or to an associated constant of a type, e.g., `Buffer::CAPACITY`.
+pub fn resolve_const_path<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    current_module: LocalDefId,
+    path: &syn::Path,
+) -> Result<DefId, ResolveError<'tcx>> {
+    let _span = debug_span!("resolve_const_path", ?path).entered();
+    let def_id = resolve_path(tcx, current_module, path)?;
+    validate_kind!(tcx, def_id, "constant", DefKind::Const | DefKind::AssocConst)
+}
+
 /// Attempts to resolve a simple path (in the form of a string) to a `DefId`.
 /// The current module is provided as an argument in order to resolve relative
 /// paths.
@@ -133,6 +144,7 @@ fn resolve_path<'tcx>(
             DefKind::Struct | DefKind::Enum | DefKind::Union => {
                 resolve_in_type_def(tcx, base, &path.base_path_args, &name)
             }
+            DefKind::TyAlias => resolve_in_type_alias(tcx, base, &path.base_path_args, &name),
             DefKind::Trait => resolve_in_trait_def(tcx, base, &name),
             kind => {
                 debug!(?base, ?kind, "resolve_path: unexpected item");
@@ -149,6 +161,8 @@ pub enum ResolveError<'tcx> {
     AmbiguousGlob { tcx: TyCtxt<'tcx>, name: String, base: DefId, candidates: Vec<DefId> },
     /// Ambiguous partial path (multiple inherent impls, c.f. https://github.com/model-checking/kani/issues/3773)
     AmbiguousPartialPath { tcx: TyCtxt<'tcx>, name: String, base: DefId, candidates: Vec<DefId> },
+    /// Ambiguous method of a type (no inherent method and multiple trait implementations).
+    AmbiguousTraitMethod { tcx: TyCtxt<'tcx>, name: String, base: DefId, candidates: Vec<DefId> },
     /// Use super past the root of a crate.
     ExtraSuper,
     /// Invalid path.
@@ -201,6 +215,19 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                         .collect::<String>()
                 )
             }
+            ResolveError::AmbiguousTraitMethod { tcx, base, name, candidates } => {
+                let location = description(*tcx, *base);
+                write!(
+                    f,
+                    "`{name}` is ambiguous because multiple traits implemented by {location} \
+                    define it. Found:\n{}",
+                    candidates
+                        .iter()
+                        .map(|def_id| tcx.def_path_str(*def_id))
+                        .intersperse("\n".to_string())
+                        .collect::<String>()
+                )
+            }
             ResolveError::InvalidPath { msg } => write!(f, "{msg}"),
             ResolveError::UnexpectedType { tcx, item: def_id, expected } => write!(
                 f,
@@ -389,8 +416,8 @@ fn resolve_in_foreign_module(tcx: TyCtxt, foreign_mod: DefId, name: &str) -> Opt
 }
 
 /// Scan all traits in the local crate to check if any of their associated items match `name`.
-/// If so, check if `ty` implements the trait, and return a match if so.
-fn resolve_in_any_trait<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty, name: &str) -> Option<FnResolution> {
+/// If so, check if `ty` implements the trait, and return all the matches.
+fn resolve_in_any_trait<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty, name: &str) -> Vec<FnResolution> {
     let matches: Vec<FnResolution> = rustc_public::all_trait_decls()
         .into_iter()
         .filter_map(|trait_def| {
@@ -401,7 +428,7 @@ fn resolve_in_any_trait<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty, name: &str) -> Option<F
         })
         .collect();
     debug!(?name, ?ty, ?matches, "resolve_in_any_trait");
-    if matches.len() == 1 { Some(matches[0]) } else { None }
+    matches
 }
 
 /// Resolves a trait method implementation by checking if there exists an Instance of the trait method for `ty`.
@@ -590,6 +617,21 @@ fn generic_args_to_string<T: ToTokens>(args: &T) -> String {
     args.to_token_stream().to_string().chars().filter(|c| !c.is_whitespace()).collect::<String>()
 }
 
+/// Resolves a function in the type that a type alias refers to.
+/// Only aliases of structs, enums and unions are supported.
+fn resolve_in_type_alias<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    alias_id: DefId,
+    base_path_args: &PathArguments,
+    name: &str,
+) -> Result<DefId, ResolveError<'tcx>> {
+    debug!(?name, ?alias_id, "resolve_in_type_alias");
+    match tcx.type_of(alias_id).instantiate_identity().kind() {
+        rustc_middle::ty::Adt(def, _) => resolve_in_type_def(tcx, def.did(), base_path_args, name),
+        _ => Err(ResolveError::UnsupportedPath { kind: "type alias of a non-user type" }),
+    }
+}
+
 /// Resolves a function in a type given its `def_id`.
 fn resolve_in_type_def<'tcx>(
     tcx: TyCtxt<'tcx>,
@@ -611,13 +653,24 @@ fn resolve_in_type_def<'tcx>(
         0 => {
             // Try to find the method in any trait implementation
             let ty = rustc_internal::stable(tcx.type_of(type_id)).value;
-            resolve_in_any_trait(tcx, ty, name)
+            let trait_candidates: Vec<DefId> = resolve_in_any_trait(tcx, ty, name)
+                .iter()
                 .map(|res| rustc_internal::internal(tcx, res.def().def_id()))
-                .ok_or_else(|| ResolveError::MissingItem {
+                .collect();
+            match trait_candidates.as_slice() {
+                [] => Err(ResolveError::MissingItem {
                     tcx,
                     base: type_id,
                     unresolved: name.to_string(),
-                })
+                }),
+                [item] => Ok(*item),
+                _ => Err(ResolveError::AmbiguousTraitMethod {
+                    tcx,
+                    name: name.into(),
+                    base: type_id,
+                    candidates: trait_candidates,
+                }),
+            }
         }
         1 => Ok(candidates[0]),
         _ => {
@@ -742,9 +795,9 @@ fn resolve_in_primitive<'tcx, 'a, I>(
                     .copied()
                     .find(|item| is_item_name(tcx, *item, &name))
             })
-            .or_else(|| {
-                resolve_in_any_trait(tcx, ty, &name)
-                    .map(|res| rustc_internal::internal(tcx, res.def().def_id()))
+            .or_else(|| match resolve_in_any_trait(tcx, ty, &name).as_slice() {
+                [res] => Some(rustc_internal::internal(tcx, res.def().def_id())),
+                _ => None,
             })
             .ok_or_else(|| ResolveError::MissingPrimitiveItem {
                 base: ty,
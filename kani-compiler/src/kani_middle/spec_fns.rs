@@ -0,0 +1,214 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that specification functions are only used by specifications.
+//!
+//! Functions annotated with `#[kani::spec_fn]` are removed from builds without `--cfg kani`, and
+//! they are never roots of the reachability analysis, so they may only
+//! be called from:
+//!    1. Contract conditions, i.e., `requires`, `ensures` and loop invariants.
+//!    2. Proof harnesses.
+//!    3. Other specification functions.
+//!
+//! Closures created by any of the above are part of the specification too.
+//!
+//! Other code may also call specification functions if their result is only used by
+//! `kani::assert` and `kani::cover`, e.g., `kani::assert(is_sorted(&v), "v is sorted")`.
+
+use crate::kani_middle::attributes::{KaniAttributes, fn_marker};
+use crate::kani_middle::contract_effects::{find_conditions, nested_closures};
+use crate::kani_middle::kani_functions::KaniHook;
+use rustc_middle::ty::TyCtxt;
+use rustc_public::mir::visit::{Location, PlaceContext};
+use rustc_public::mir::{
+    Body, Local, MirVisitor, Operand, Place, Rvalue, Statement, StatementKind, Terminator,
+    TerminatorKind, VarDebugInfo,
+};
+use rustc_public::rustc_internal;
+use rustc_public::ty::{RigidTy, TyKind};
+use rustc_public::{CrateDef, CrateItem, DefId};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+/// Report every call to a specification function from code that is not part of a specification.
+pub fn check_spec_fn_calls(tcx: TyCtxt) {
+    let mut specs = vec![];
+    let mut pending = vec![];
+    for item in rustc_public::all_local_items() {
+        let attributes = KaniAttributes::for_def_id(tcx, item.def_id());
+        if attributes.is_proof_harness() || attributes.is_spec_fn() {
+            specs.push(item.def_id());
+        }
+        pending.push(item.def_id());
+    }
+
+    // Collect the bodies of all local items and of the closures they create.
+    let mut bodies: Vec<(DefId, Body)> = vec![];
+    let mut closures: HashMap<DefId, Vec<DefId>> = HashMap::new();
+    let mut visited = HashSet::new();
+    while let Some(def_id) = pending.pop() {
+        if !visited.insert(def_id) {
+            continue;
+        }
+        let Some(body) = CrateItem(def_id).body() else { continue };
+        specs.extend(find_conditions(&body).into_iter().map(|closure| closure.def_id()));
+        let nested: Vec<DefId> =
+            nested_closures(&body).into_iter().map(|closure| closure.def_id()).collect();
+        pending.extend(nested.iter().copied());
+        closures.insert(def_id, nested);
+        bodies.push((def_id, body));
+    }
+
+    let mut allowed = HashSet::new();
+    while let Some(def_id) = specs.pop() {
+        if allowed.insert(def_id) {
+            specs.extend(closures.get(&def_id).into_iter().flatten().copied());
+        }
+    }
+
+    // Contract instrumentation duplicates function bodies, so only report each call once.
+    let mut reported = HashSet::new();
+    for (_, body) in bodies.iter().filter(|(def_id, _)| !allowed.contains(def_id)) {
+        let check_uses = CheckUses::new(body);
+        for bb in &body.blocks {
+            let TerminatorKind::Call { func, destination, .. } = &bb.terminator.kind else {
+                continue;
+            };
+            let Ok(ty) = func.ty(body.locals()) else { continue };
+            let TyKind::RigidTy(RigidTy::FnDef(def, _)) = ty.kind() else { continue };
+            if !KaniAttributes::for_def_id(tcx, def.def_id()).is_spec_fn()
+                || (destination.projection.is_empty()
+                    && check_uses.is_check_only(destination.local))
+            {
+                continue;
+            }
+            let span = rustc_internal::internal(tcx, bb.terminator.span);
+            if !reported.insert(span) {
+                continue;
+            }
+            let name = def.trimmed_name();
+            let def_span = tcx.def_span(rustc_internal::internal(tcx, def.def_id()));
+            tcx.dcx()
+                .struct_span_err(
+                    span,
+                    format!(
+                        "`{name}` is a specification function, which can only be called from \
+                        contract conditions, proof harnesses and other specification functions"
+                    ),
+                )
+                .with_span_note(
+                    def_span,
+                    format!("`{name}` is marked with `#[kani::spec_fn]` here"),
+                )
+                .with_help(
+                    "specification functions are removed from builds without `--cfg kani`, and \
+                    other code may only use their result in `kani::assert` or `kani::cover`",
+                )
+                .emit();
+        }
+    }
+}
+
+/// Whether the given function is `kani::assert` or `kani::cover`.
+fn is_check(func: &Operand, body: &Body) -> bool {
+    let Ok(ty) = func.ty(body.locals()) else { return false };
+    let TyKind::RigidTy(RigidTy::FnDef(def, _)) = ty.kind() else { return false };
+    fn_marker(def).is_some_and(|marker| {
+        matches!(KaniHook::from_str(&marker), Ok(KaniHook::Assert | KaniHook::Cover))
+    })
+}
+
+/// Find the locals whose value is only used by the condition of `kani::assert` and
+/// `kani::cover`, either directly or through other such locals, e.g., `!is_sorted(&v)`.
+struct CheckUses<'a> {
+    body: &'a Body,
+    /// The locals that are used by something other than a check or a copy to another local.
+    other_uses: HashSet<Local>,
+    /// The locals that each local is copied to, possibly negated or compared.
+    copies: HashMap<Local, Vec<Local>>,
+}
+
+impl<'a> CheckUses<'a> {
+    fn new(body: &'a Body) -> Self {
+        let mut check_uses = CheckUses { body, other_uses: HashSet::new(), copies: HashMap::new() };
+        check_uses.visit_body(body);
+        check_uses
+    }
+
+    fn is_check_only(&self, local: Local) -> bool {
+        self.is_check_only_rec(local, &mut HashSet::new())
+    }
+
+    fn is_check_only_rec(&self, local: Local, visited: &mut HashSet<Local>) -> bool {
+        if !visited.insert(local) {
+            return true;
+        }
+        !self.other_uses.contains(&local)
+            && self
+                .copies
+                .get(&local)
+                .into_iter()
+                .flatten()
+                .all(|copy| self.is_check_only_rec(*copy, visited))
+    }
+
+    /// The local copied by the operand, if it is a plain copy or move of a local.
+    fn copied_local(operand: &Operand) -> Option<Local> {
+        match operand {
+            Operand::Copy(place) | Operand::Move(place) if place.projection.is_empty() => {
+                Some(place.local)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl MirVisitor for CheckUses<'_> {
+    fn visit_statement(&mut self, stmt: &Statement, location: Location) {
+        match &stmt.kind {
+            StatementKind::StorageLive(_) | StatementKind::StorageDead(_) => {}
+            StatementKind::Assign(dest, rvalue) => {
+                let operands = match rvalue {
+                    Rvalue::Use(operand) | Rvalue::UnaryOp(_, operand) => vec![operand],
+                    Rvalue::BinaryOp(_, lhs, rhs) => vec![lhs, rhs],
+                    _ => vec![],
+                };
+                if dest.projection.is_empty() && !operands.is_empty() {
+                    for operand in operands {
+                        if let Some(local) = Self::copied_local(operand) {
+                            self.copies.entry(local).or_default().push(dest.local);
+                        } else {
+                            self.visit_operand(operand, location);
+                        }
+                    }
+                } else {
+                    // Writing to a local doesn't use its value.
+                    self.visit_rvalue(rvalue, location);
+                }
+            }
+            _ => self.super_statement(stmt, location),
+        }
+    }
+
+    fn visit_terminator(&mut self, term: &Terminator, location: Location) {
+        match &term.kind {
+            TerminatorKind::Call { func, args, .. } => {
+                let is_check = is_check(func, self.body);
+                for (idx, arg) in args.iter().enumerate() {
+                    // The condition is the first argument of the checks.
+                    if !(is_check && idx == 0 && Self::copied_local(arg).is_some()) {
+                        self.visit_operand(arg, location);
+                    }
+                }
+            }
+            _ => self.super_terminator(term, location),
+        }
+    }
+
+    fn visit_place(&mut self, place: &Place, _ptx: PlaceContext, _location: Location) {
+        self.other_uses.insert(place.local);
+    }
+
+    fn visit_var_debug_info(&mut self, _var_debug_info: &VarDebugInfo) {
+        // Debug information doesn't use the value of the locals.
+    }
+}
@@ -9,6 +9,7 @@
 use std::collections::HashMap;
 use tracing::{debug, trace};
 
+use crate::kani_middle::attributes::KaniAttributes;
 use kani_metadata::HarnessMetadata;
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::Const;
@@ -37,6 +38,29 @@ pub fn harness_stub_map(
     stub_pairs
 }
 
+/// Collects the stubs declared with `#[kani::ffi_stubs(...)]` on the `extern` blocks of a crate.
+///
+/// These stubs apply to every harness of the crate.
+pub fn ffi_stub_map(tcx: TyCtxt, is_enabled: bool) -> HashMap<FnDef, FnDef> {
+    let mut stubs = HashMap::default();
+    for item in tcx.hir_free_items() {
+        let def_id = item.owner_id.to_def_id();
+        let attributes = KaniAttributes::for_item(tcx, def_id);
+        if !attributes.has_ffi_stubs() {
+            continue;
+        }
+        if !is_enabled {
+            tcx.dcx().span_err(
+                tcx.def_span(def_id),
+                "Using the ffi_stubs attribute requires activating the unstable `ffi-stubs` feature",
+            );
+            continue;
+        }
+        stubs.extend(attributes.interpret_ffi_stubs_attribute());
+    }
+    stubs
+}
+
 /// For the purpose of checking generic argument length, don't consider the `Self` generic argument.
 /// The purpose is to allow stubbing out:
 /// ```rust
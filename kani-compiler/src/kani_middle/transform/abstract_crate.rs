@@ -0,0 +1,161 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! This module contains the pass that abstracts the functions of the crates given to
+//! `--abstract-crate`.
+//!
+//! An abstracted function is treated as uninterpreted: its body is replaced by one that havocs the
+//! memory behind its `&mut` parameters and returns a non-deterministic value, i.e.:
+//! ```ignore
+//! fn abstracted(a: &mut T, b: &mut [U], c: V) -> R {
+//!     unsafe { kani::internal::write_any_slim(a as *mut T) };
+//!     unsafe { kani::internal::write_any_slice(b as *mut [U]) };
+//!     kani::any::<R>()
+//! }
+//! ```
+//! This assumes that the function doesn't modify any other memory, e.g., global variables or
+//! memory behind raw pointers.
+
+use crate::kani_middle::codegen_units::CodegenUnit;
+use crate::kani_middle::implements_arbitrary;
+use crate::kani_middle::kani_functions::KaniModel;
+use crate::kani_middle::transform::body::{InsertPosition, MutableBody, SourceInstruction};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use fxhash::FxHashMap;
+use rustc_middle::ty::TyCtxt;
+use rustc_public::mir::mono::Instance;
+use rustc_public::mir::{
+    Body, Mutability, Operand, Place, ProjectionElem, RawPtrKind, Rvalue, TerminatorKind,
+};
+use rustc_public::rustc_internal;
+use rustc_public::ty::{FnDef, GenericArgKind, GenericArgs, RigidTy, Ty, TyKind};
+use std::collections::HashSet;
+use tracing::debug;
+
+/// Replace the body of the abstracted functions by a non-deterministic one.
+#[derive(Debug, Clone)]
+pub struct AbstractCratePass {
+    /// The functions to abstract in this codegen unit.
+    abstracted: HashSet<FnDef>,
+    kani_any: Option<FnDef>,
+    kani_write_any_slim: Option<FnDef>,
+    kani_write_any_slice: Option<FnDef>,
+}
+
+impl TransformPass for AbstractCratePass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Stubbing
+    }
+
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        !self.abstracted.is_empty() && self.kani_any.is_some()
+    }
+
+    fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
+        let TyKind::RigidTy(RigidTy::FnDef(def, _)) = instance.ty().kind() else {
+            return (false, body);
+        };
+        if !self.abstracted.contains(&def) {
+            return (false, body);
+        }
+        debug!(function=?instance.name(), "AbstractCratePass::transform");
+        match self.abstract_body(&body) {
+            Ok(new_body) => (true, new_body),
+            Err(reason) => {
+                let span = rustc_internal::internal(tcx, body.span);
+                tcx.dcx()
+                    .struct_span_err(
+                        span,
+                        format!("cannot abstract `{}`: {reason}", instance.name()),
+                    )
+                    .with_help("provide a stub for this function with `#[kani::stub]`")
+                    .emit();
+                (false, body)
+            }
+        }
+    }
+}
+
+impl AbstractCratePass {
+    pub fn new(unit: &CodegenUnit, queries: &QueryDb) -> Self {
+        let kani_fns = queries.kani_functions();
+        AbstractCratePass {
+            abstracted: unit.abstracted.clone(),
+            kani_any: kani_fns.get(&KaniModel::Any.into()).copied(),
+            kani_write_any_slim: kani_fns.get(&KaniModel::WriteAnySlim.into()).copied(),
+            kani_write_any_slice: kani_fns.get(&KaniModel::WriteAnySlice.into()).copied(),
+        }
+    }
+
+    /// Build the abstract body of a function, or return why the function cannot be abstracted.
+    fn abstract_body(&self, body: &Body) -> Result<Body, String> {
+        let kani_any = self.kani_any.unwrap();
+        let mut cache = FxHashMap::default();
+        let mut is_arbitrary = |ty: Ty| {
+            !matches!(ty.kind(), TyKind::RigidTy(RigidTy::Ref(..)))
+                && implements_arbitrary(ty, kani_any, &mut cache)
+        };
+
+        let ret_ty = body.ret_local().ty;
+        if !is_arbitrary(ret_ty) {
+            return Err(format!("its return type `{ret_ty}` does not implement `Arbitrary`"));
+        }
+        let mut havocs = vec![];
+        for local in 1..=body.arg_locals().len() {
+            let ty = body.locals()[local].ty;
+            let TyKind::RigidTy(RigidTy::Ref(_, pointee, Mutability::Mut)) = ty.kind() else {
+                continue;
+            };
+            let (write_any, ty) = match pointee.kind() {
+                TyKind::RigidTy(RigidTy::Slice(elem)) if is_arbitrary(elem) => {
+                    (self.kani_write_any_slice.unwrap(), elem)
+                }
+                _ if is_arbitrary(pointee) => (self.kani_write_any_slim.unwrap(), pointee),
+                _ => {
+                    return Err(format!(
+                        "the type `{pointee}` of its mutable parameters cannot be generated with \
+                        `kani::any()`"
+                    ));
+                }
+            };
+            havocs.push((local, write_any, ty));
+        }
+
+        let mut new_body = MutableBody::from(body.clone());
+        new_body.clear_body(TerminatorKind::Return);
+        let mut source = SourceInstruction::Terminator { bb: 0 };
+        let span = body.span;
+        let unit_ty = Ty::new_tuple(&[]);
+        for (local, write_any, ty) in havocs {
+            let pointer = new_body.insert_assignment(
+                Rvalue::AddressOf(
+                    RawPtrKind::Mut,
+                    Place { local, projection: vec![ProjectionElem::Deref] },
+                ),
+                &mut source,
+                InsertPosition::Before,
+            );
+            let write_any =
+                Instance::resolve(write_any, &GenericArgs(vec![GenericArgKind::Type(ty)])).unwrap();
+            let unit = new_body.new_local(unit_ty, span, Mutability::Not);
+            new_body.insert_call(
+                &write_any,
+                &mut source,
+                InsertPosition::Before,
+                vec![Operand::Move(Place::from(pointer))],
+                Place::from(unit),
+            );
+        }
+        let any =
+            Instance::resolve(kani_any, &GenericArgs(vec![GenericArgKind::Type(ret_ty)])).unwrap();
+        new_body.insert_call(&any, &mut source, InsertPosition::Before, vec![], Place::from(0));
+        Ok(new_body.into())
+    }
+}
@@ -6,7 +6,6 @@
 //! 2. `AutomaticArbitraryPass`, which creates `T::any()` implementations for `T`s that do not implement Arbitrary in source code,
 //!    but we have determined can derive it.
 
-use crate::args::ReachabilityType;
 use crate::kani_middle::attributes::KaniAttributes;
 use crate::kani_middle::codegen_units::CodegenUnit;
 use crate::kani_middle::implements_arbitrary;
@@ -56,7 +55,7 @@ fn is_enabled(&self, query_db: &QueryDb) -> bool
     where
         Self: Sized,
     {
-        matches!(query_db.args().reachability_analysis, ReachabilityType::AllFns)
+        query_db.args().has_automatic_harnesses()
     }
 
     /// Transform the body of a kani::any::<T>() call if `T` does not implement `Arbitrary`.
@@ -314,7 +313,7 @@ fn is_enabled(&self, query_db: &QueryDb) -> bool
     where
         Self: Sized,
     {
-        matches!(query_db.args().reachability_analysis, ReachabilityType::AllFns)
+        query_db.args().has_automatic_harnesses()
     }
 
     fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
@@ -472,6 +472,20 @@ pub fn new_safety_check_assert_no_assume(queries: &QueryDb) -> CheckType {
         CheckType::SafetyCheckNoAssume(Instance::resolve(fn_def, &GenericArgs(vec![])).unwrap())
     }
 
+    /// Create a check that memory is initialized, which generates an assertion of its own
+    /// property class followed by an assumption of the same assertion.
+    pub fn new_uninit_check_assert_assume(queries: &QueryDb) -> CheckType {
+        let fn_def = queries.kani_functions()[&KaniHook::UninitCheck.into()];
+        CheckType::SafetyCheck(Instance::resolve(fn_def, &GenericArgs(vec![])).unwrap())
+    }
+
+    /// Create a check that memory is initialized, which generates an assertion of its own
+    /// property class, but not followed by an assumption.
+    pub fn new_uninit_check_assert_no_assume(queries: &QueryDb) -> CheckType {
+        let fn_def = queries.kani_functions()[&KaniHook::UninitCheckNoAssume.into()];
+        CheckType::SafetyCheckNoAssume(Instance::resolve(fn_def, &GenericArgs(vec![])).unwrap())
+    }
+
     /// This will create the type of operation-unsupported check that is available in the current
     /// crate, attempting to create a check that generates an assertion following by an assumption
     /// of the same assertion.
@@ -55,7 +55,8 @@ pub fn new(
 impl GlobalPass for DelayedUbPass {
     fn is_enabled(&self, query_db: &QueryDb) -> bool {
         let args = query_db.args();
-        args.ub_check.contains(&ExtraChecks::Uninit)
+        // The harness of a `#[kani::verify_unsafe]` function may enable the checks for its unit.
+        args.ub_check.contains(&ExtraChecks::Uninit) || args.is_verify_unsafe_enabled()
     }
 
     fn transform(
@@ -66,6 +67,9 @@ fn transform(
         instances: Vec<Instance>,
         transformer: &mut BodyTransformation,
     ) -> bool {
+        if !transformer.check_uninit() {
+            return false;
+        }
         let mut modified = false;
         // Collect all analysis targets (pointers to places reading and writing from which should be
         // tracked).
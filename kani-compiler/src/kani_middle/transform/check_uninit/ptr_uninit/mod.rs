@@ -4,7 +4,6 @@
 //! A transformation pass that instruments the code to detect possible UB due to the accesses to
 //! uninitialized memory via raw pointers.
 
-use crate::args::ExtraChecks;
 use crate::kani_middle::kani_functions::{KaniFunction, KaniModel};
 use crate::kani_middle::transform::{
     TransformPass, TransformationType,
@@ -30,6 +29,9 @@
 /// pointers.
 #[derive(Debug, Clone)]
 pub struct UninitPass {
+    /// Whether uninitialized memory checks are enabled for the codegen unit, either with
+    /// `--ub-check` or for the harness of a `#[kani::verify_unsafe]` function.
+    pub enabled: bool,
     pub safety_check_type: CheckType,
     pub unsupported_check_type: CheckType,
     pub mem_init_fn_cache: HashMap<KaniFunction, FnDef>,
@@ -43,12 +45,11 @@ fn transformation_type() -> TransformationType
         TransformationType::Instrumentation
     }
 
-    fn is_enabled(&self, query_db: &QueryDb) -> bool
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
     where
         Self: Sized,
     {
-        let args = query_db.args();
-        args.ub_check.contains(&ExtraChecks::Uninit)
+        self.enabled
     }
 
     fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
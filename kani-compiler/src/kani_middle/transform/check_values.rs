@@ -13,7 +13,6 @@
 //! Note: There is some redundancy in the checks that could be optimized. Example:
 //!   1. We could merge the invalid values by the offset.
 //!   2. We could avoid checking places that have been checked before.
-use crate::args::ExtraChecks;
 use crate::kani_middle::transform::body::{
     CheckType, InsertPosition, MutableBody, SourceInstruction,
 };
@@ -40,6 +39,9 @@
 /// Instrument the code with checks for invalid values.
 #[derive(Debug, Clone)]
 pub struct ValidValuePass {
+    /// Whether validity checks are enabled for the codegen unit, either with `--ub-check` or for
+    /// the harness of a `#[kani::verify_unsafe]` function.
+    pub enabled: bool,
     pub safety_check_type: CheckType,
     pub unsupported_check_type: CheckType,
 }
@@ -52,12 +54,11 @@ fn transformation_type() -> TransformationType
         TransformationType::Instrumentation
     }
 
-    fn is_enabled(&self, query_db: &QueryDb) -> bool
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
     where
         Self: Sized,
     {
-        let args = query_db.args();
-        args.ub_check.contains(&ExtraChecks::Validity)
+        self.enabled
     }
 
     /// Transform the function body by inserting checks one-by-one.
@@ -1,8 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //! This module contains code related to the MIR-to-MIR pass to enable contracts.
-use crate::args::ReachabilityType;
-use crate::kani_middle::attributes::KaniAttributes;
+use crate::kani_middle::attributes::{KaniAttributes, contract_harness_target};
 use crate::kani_middle::codegen_units::CodegenUnit;
 use crate::kani_middle::kani_functions::{KaniIntrinsic, KaniModel};
 use crate::kani_middle::transform::body::{InsertPosition, MutableBody, SourceInstruction};
@@ -340,12 +339,11 @@ impl FunctionWithContractPass {
     pub fn new(tcx: TyCtxt, queries: &QueryDb, unit: &CodegenUnit) -> FunctionWithContractPass {
         if let Some(harness) = unit.harnesses.first() {
             let (check_fn, replace_fns) = {
-                let harness_generic_args = harness.args().0;
-                // Manual harnesses have no arguments, so if there are generic arguments,
-                // we know this is an automatic harness
-                if matches!(queries.args().reachability_analysis, ReachabilityType::AllFns)
-                    && !harness_generic_args.is_empty()
-                {
+                let automatic_harness =
+                    queries.kani_functions().get(&KaniIntrinsic::AutomaticHarness.into());
+                // Manual harnesses may also have generic arguments, e.g. the instances of a
+                // `proof_for_contract_all_impls` harness, so we check the harness definition.
+                if automatic_harness == Some(&harness.def) {
                     let kind = harness.args().0[0].expect_ty().kind();
                     let (fn_to_verify_def, _) = kind.fn_def().unwrap();
                     // For automatic harnesses, the target is the function to verify,
@@ -353,7 +351,7 @@ pub fn new(tcx: TyCtxt, queries: &QueryDb, unit: &CodegenUnit) -> FunctionWithCo
                     (Some(fn_to_verify_def), HashSet::default())
                 } else {
                     let attrs = KaniAttributes::for_instance(tcx, *harness);
-                    let check_fn = attrs.interpret_for_contract_attribute();
+                    let check_fn = contract_harness_target(tcx, *harness);
                     let replace_fns: HashSet<_> =
                         attrs.interpret_stub_verified_attribute().into_iter().collect();
                     (check_fn, replace_fns)
@@ -0,0 +1,145 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! This module removes the formatting machinery of panic messages.
+//!
+//! Kani doesn't evaluate the formatted message of a panic, but the arguments of `format_args!`
+//! still store a function pointer to their `Display` / `Debug` implementation. This makes the
+//! formatting code of every type that is printed by a panic reachable, which can account for a
+//! large fraction of the goto program.
+//!
+//! This pass replaces the constructors of `core::fmt::rt::Argument` by a non-deterministic value
+//! when they are only reachable from panic paths, i.e., all paths from them end in a call to one of
+//! the functions that start a panic. The panic call itself is preserved, so the panic properties
+//! are unaffected.
+//! The driver enables this pass by default with `--no-fmt-args`, and users that need the formatting
+//! code can disable it with `--keep-fmt`.
+
+use crate::kani_middle::kani_functions::{KaniFunction, KaniHook, KaniModel};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::TyCtxt;
+use rustc_public::CrateDef;
+use rustc_public::mir::mono::Instance;
+use rustc_public::mir::{BasicBlockIdx, Body, ConstOperand, Operand, TerminatorKind};
+use rustc_public::ty::{FnDef, GenericArgKind, GenericArgs, MirConst, RigidTy, TyKind};
+use std::collections::HashSet;
+use tracing::debug;
+
+/// Replace the formatting arguments of panic messages by an opaque value.
+#[derive(Debug, Clone)]
+pub struct FmtArgsPass {
+    /// The function used to create the opaque value, i.e., `kani::any_raw`.
+    any_raw: Option<FnDef>,
+}
+
+impl TransformPass for FmtArgsPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Optimization
+    }
+
+    fn is_enabled(&self, query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        query_db.args().no_fmt_args && self.any_raw.is_some()
+    }
+
+    fn transform(&mut self, _tcx: TyCtxt, mut body: Body, instance: Instance) -> (bool, Body) {
+        debug!(function=?instance.name(), "transform");
+        let panic_blocks = panic_only_blocks(&body);
+        let mut changed = false;
+        for bb in panic_blocks {
+            let terminator = &body.blocks[bb].terminator;
+            let TerminatorKind::Call { func, destination, .. } = &terminator.kind else {
+                continue;
+            };
+            let TyKind::RigidTy(RigidTy::FnDef(def, _)) = func.ty(body.locals()).unwrap().kind()
+            else {
+                continue;
+            };
+            if !is_fmt_argument_constructor(def) {
+                continue;
+            }
+            let arg_ty = destination.ty(body.locals()).unwrap();
+            let any_raw = Instance::resolve(
+                self.any_raw.unwrap(),
+                &GenericArgs(vec![GenericArgKind::Type(arg_ty)]),
+            )
+            .unwrap();
+            let span = terminator.span;
+            let TerminatorKind::Call { func, args, .. } = &mut body.blocks[bb].terminator.kind
+            else {
+                unreachable!()
+            };
+            let const_ = MirConst::try_new_zero_sized(any_raw.ty()).unwrap();
+            *func = Operand::Constant(ConstOperand { span, user_ty: None, const_ });
+            args.clear();
+            changed = true;
+        }
+        (changed, body)
+    }
+}
+
+impl FmtArgsPass {
+    pub fn new(queries: &QueryDb) -> Self {
+        let any_raw = queries.kani_functions().get(&KaniFunction::Hook(KaniHook::AnyRaw)).copied();
+        FmtArgsPass { any_raw }
+    }
+}
+
+/// Whether this function creates a `core::fmt::rt::Argument`, e.g., `Argument::new_display`.
+fn is_fmt_argument_constructor(def: FnDef) -> bool {
+    def.krate().name == "core" && def.name().starts_with("core::fmt::rt::Argument")
+}
+
+/// Whether this function starts a panic, e.g., `core::panicking::panic_fmt` or `kani::panic`.
+///
+/// Other functions that don't return, e.g., `std::process::exit`, may still print the
+/// formatted arguments, so they are not included.
+fn is_panic_fn(def: FnDef) -> bool {
+    let name = def.name();
+    matches!(
+        KaniFunction::try_from(def),
+        Ok(KaniFunction::Hook(KaniHook::Panic) | KaniFunction::Model(KaniModel::PanicStub))
+    ) || (def.krate().name == "core" && name.starts_with("core::panicking::"))
+        || (def.krate().name == "std"
+            && (name.starts_with("std::panicking::") || name == "std::rt::begin_panic"))
+}
+
+/// Collect the blocks from which all paths end in a call to a function that starts a panic.
+///
+/// Kani uses the `abort` panic strategy, so we don't have to worry about unwind edges.
+fn panic_only_blocks(body: &Body) -> HashSet<BasicBlockIdx> {
+    let mut panic_only: HashSet<BasicBlockIdx> = body
+        .blocks
+        .iter()
+        .enumerate()
+        .filter(|(_, bb)| {
+            let TerminatorKind::Call { func, target: None, .. } = &bb.terminator.kind else {
+                return false;
+            };
+            matches!(func.ty(body.locals()).map(|ty| ty.kind()),
+                Ok(TyKind::RigidTy(RigidTy::FnDef(def, _))) if is_panic_fn(def))
+        })
+        .map(|(idx, _)| idx)
+        .collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (idx, bb) in body.blocks.iter().enumerate() {
+            if panic_only.contains(&idx) {
+                continue;
+            }
+            let targets = bb.terminator.successors();
+            if !targets.is_empty() && targets.iter().all(|target| panic_only.contains(target)) {
+                panic_only.insert(idx);
+                changed = true;
+            }
+        }
+    }
+    panic_only
+}
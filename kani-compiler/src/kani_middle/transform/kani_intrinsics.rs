@@ -10,6 +10,7 @@
 use crate::args::ExtraChecks;
 use crate::kani_middle::abi::LayoutOf;
 use crate::kani_middle::attributes::KaniAttributes;
+use crate::kani_middle::codegen_units::CodegenUnit;
 use crate::kani_middle::kani_functions::{KaniFunction, KaniIntrinsic, KaniModel};
 use crate::kani_middle::transform::body::{
     CheckType, InsertPosition, MutableBody, SourceInstruction,
@@ -42,7 +43,7 @@ pub struct IntrinsicGeneratorPass {
     unsupported_check_type: CheckType,
     /// Used to cache FnDef lookups for models and Kani intrinsics.
     kani_defs: HashMap<KaniFunction, FnDef>,
-    /// Whether the user enabled uninitialized memory checks when they invoked Kani.
+    /// Whether uninitialized memory checks are enabled for the codegen unit.
     enable_uninit: bool,
 }
 
@@ -86,8 +87,8 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
 }
 
 impl IntrinsicGeneratorPass {
-    pub fn new(unsupported_check_type: CheckType, queries: &QueryDb) -> Self {
-        let enable_uninit = queries.args().ub_check.contains(&ExtraChecks::Uninit);
+    pub fn new(unsupported_check_type: CheckType, queries: &QueryDb, unit: &CodegenUnit) -> Self {
+        let enable_uninit = unit.has_ub_check(queries.args(), ExtraChecks::Uninit);
         let kani_defs = queries.kani_functions().clone();
         debug!(?kani_defs, ?enable_uninit, "IntrinsicGeneratorPass::new");
         IntrinsicGeneratorPass { unsupported_check_type, enable_uninit, kani_defs }
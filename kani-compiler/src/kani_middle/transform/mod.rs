@@ -16,6 +16,7 @@
 //!
 //! For all instrumentation passes, always use exhaustive matches to ensure soundness in case a new
 //! case is added.
+use crate::args::ExtraChecks;
 use crate::kani_middle::codegen_units::CodegenUnit;
 use crate::kani_middle::reachability::CallGraph;
 use crate::kani_middle::transform::body::CheckType;
@@ -27,8 +28,11 @@
 use crate::kani_middle::transform::loop_contracts::LoopContractPass;
 use crate::kani_middle::transform::stubs::{ExternFnStubPass, FnStubPass};
 use crate::kani_queries::QueryDb;
+use abstract_crate::AbstractCratePass;
 use automatic::{AutomaticArbitraryPass, AutomaticHarnessPass};
 use dump_mir_pass::DumpMirPass;
+use fmt_args::FmtArgsPass;
+use mutation::MutationPass;
 use rustc_middle::ty::TyCtxt;
 use rustc_public::mir::Body;
 use rustc_public::mir::mono::{Instance, MonoItem};
@@ -37,16 +41,20 @@
 
 use crate::kani_middle::transform::rustc_intrinsics::RustcIntrinsicsPass;
 pub use internal_mir::RustcInternalMir;
+pub use mutation::MutantCollector;
 
+mod abstract_crate;
 mod automatic;
 pub(crate) mod body;
 mod check_uninit;
 mod check_values;
 mod contracts;
 mod dump_mir_pass;
+mod fmt_args;
 mod internal_mir;
 mod kani_intrinsics;
 mod loop_contracts;
+mod mutation;
 mod rustc_intrinsics;
 mod stubs;
 
@@ -57,13 +65,17 @@
 /// after.
 #[derive(Debug)]
 pub struct BodyTransformation {
-    /// The passes that may change the function body according to harness configuration.
-    /// The stubbing passes should be applied before so user stubs take precedence.
+    /// The passes that may change the function body according to harness configuration, as well
+    /// as the mutation and optimization passes. The stubbing passes should be applied before so
+    /// user stubs take precedence.
     stub_passes: Vec<Box<dyn ClonableTransformPass>>,
     /// The passes that may add safety checks to the function body.
     inst_passes: Vec<Box<dyn ClonableTransformPass>>,
     /// Cache transformation results.
     cache: HashMap<Instance, TransformationResult>,
+    /// Whether the codegen unit checks for uninitialized memory, which also requires the
+    /// `DelayedUbPass` global pass.
+    check_uninit: bool,
 }
 
 impl BodyTransformation {
@@ -72,14 +84,20 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
             stub_passes: vec![],
             inst_passes: vec![],
             cache: Default::default(),
+            check_uninit: unit.has_ub_check(queries.args(), ExtraChecks::Uninit),
         };
         let safety_check_type = CheckType::new_safety_check_assert_assume(queries);
         let unsupported_check_type = CheckType::new_unsupported_check_assert_assume_false(queries);
         // This has to come first, since creating harnesses affects later stubbing and contract passes.
         transformer.add_pass(queries, AutomaticHarnessPass::new(queries));
         transformer.add_pass(queries, AutomaticArbitraryPass::new(unit, queries));
+        // Mutations must be applied to the original body, since they are identified by the
+        // position of the mutated instruction.
+        transformer.add_pass(queries, MutationPass::new(tcx, queries));
         transformer.add_pass(queries, FnStubPass::new(&unit.stubs));
         transformer.add_pass(queries, ExternFnStubPass::new(&unit.stubs));
+        transformer.add_pass(queries, AbstractCratePass::new(unit, queries));
+        transformer.add_pass(queries, FmtArgsPass::new(queries));
         transformer.add_pass(queries, FunctionWithContractPass::new(tcx, queries, unit));
         // This has to come after the contract pass since we want this to only replace the closure
         // body that is relevant for this harness.
@@ -87,6 +105,7 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
         transformer.add_pass(
             queries,
             ValidValuePass {
+                enabled: unit.has_ub_check(queries.args(), ExtraChecks::Validity),
                 safety_check_type,
                 unsupported_check_type: unsupported_check_type.clone(),
             },
@@ -99,13 +118,15 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
         transformer.add_pass(
             queries,
             UninitPass {
+                enabled: transformer.check_uninit,
                 // Since this uses demonic non-determinism under the hood, should not assume the assertion.
-                safety_check_type: CheckType::new_safety_check_assert_no_assume(queries),
+                safety_check_type: CheckType::new_uninit_check_assert_no_assume(queries),
                 unsupported_check_type: unsupported_check_type.clone(),
                 mem_init_fn_cache: queries.kani_functions().clone(),
             },
         );
-        transformer.add_pass(queries, IntrinsicGeneratorPass::new(unsupported_check_type, queries));
+        transformer
+            .add_pass(queries, IntrinsicGeneratorPass::new(unsupported_check_type, queries, unit));
         transformer.add_pass(queries, LoopContractPass::new(tcx, queries, unit));
         transformer.add_pass(queries, RustcIntrinsicsPass::new(queries));
         transformer
@@ -148,14 +169,22 @@ pub fn clone_empty(&self) -> Self {
             stub_passes: self.stub_passes.to_vec(),
             inst_passes: self.inst_passes.to_vec(),
             cache: HashMap::new(),
+            check_uninit: self.check_uninit,
         }
     }
 
+    /// Whether the codegen unit checks for uninitialized memory.
+    pub fn check_uninit(&self) -> bool {
+        self.check_uninit
+    }
+
     fn add_pass<P: ClonableTransformPass + 'static>(&mut self, query_db: &QueryDb, pass: P) {
         if pass.is_enabled(query_db) {
             match P::transformation_type() {
                 TransformationType::Instrumentation => self.inst_passes.push(Box::new(pass)),
-                TransformationType::Stubbing => self.stub_passes.push(Box::new(pass)),
+                TransformationType::Stubbing
+                | TransformationType::Mutation
+                | TransformationType::Optimization => self.stub_passes.push(Box::new(pass)),
             }
         }
     }
@@ -168,6 +197,11 @@ pub(crate) enum TransformationType {
     Instrumentation,
     /// Apply some sort of stubbing.
     Stubbing,
+    /// Inject a bug into the code under verification, for mutation testing.
+    Mutation,
+    /// Remove code that is not needed to check the properties of the harness, e.g., the code
+    /// that formats panic messages.
+    Optimization,
 }
 
 /// A trait to represent transformation passes that can be used to modify the body of a function.
@@ -224,7 +258,7 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt) -> Self {
         global_passes.add_global_pass(
             queries,
             DelayedUbPass::new(
-                CheckType::new_safety_check_assert_assume(queries),
+                CheckType::new_uninit_check_assert_assume(queries),
                 CheckType::new_unsupported_check_assert_assume_false(queries),
                 queries,
             ),
@@ -0,0 +1,581 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Module responsible for the mutations used by the `mutate` subcommand.
+//!
+//! A mutation is a small change to a function body that simulates a bug, such as negating a
+//! comparison. The driver first compiles the crate with `--list-mutants` to collect the mutations
+//! that can be applied to the local functions reachable from each harness. It then recompiles the
+//! crate once per mutation with `--mutant <ID>`, and checks whether the harnesses detect it.
+//!
+//! Mutations are applied to the MIR in memory, so the source files are never modified.
+//! A mutation is identified by the definition path hash of the mutated function and by the
+//! position of the mutated instruction in its original body, which are stable across compilations.
+
+use crate::kani_middle::SourceLocation;
+use crate::kani_middle::attributes::{KaniAttributes, is_proof_harness};
+use crate::kani_middle::kani_functions::KaniFunction;
+use crate::kani_middle::transform::body::{InsertPosition, MutableBody, SourceInstruction};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use kani_metadata::MutantMetadata;
+use rustc_middle::ty::TyCtxt;
+use rustc_public::CrateDef;
+use rustc_public::mir::mono::{Instance, InstanceKind, MonoItem};
+use rustc_public::mir::{
+    BasicBlockIdx, BinOp, Body, CastKind, ConstOperand, Operand, Place, Rvalue, Statement,
+    StatementKind, SwitchTargets, Terminator, TerminatorKind,
+};
+use rustc_public::rustc_internal;
+use rustc_public::ty::{ConstantKind, MirConst, RigidTy, Span, TyKind, UintTy};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tracing::debug;
+
+/// Apply the mutation selected with `--mutant` to the function it targets.
+#[derive(Debug, Clone)]
+pub struct MutationPass {
+    mutant: Option<MutantId>,
+}
+
+impl TransformPass for MutationPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Mutation
+    }
+
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        self.mutant.is_some()
+    }
+
+    /// Apply the mutation if this is the mutated function.
+    fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
+        let mutant = self.mutant.as_ref().unwrap();
+        if !is_mutable(tcx, instance) || function_id(tcx, instance) != mutant.function {
+            return (false, body);
+        }
+        debug!(function=?instance.name(), ?mutant, "transform");
+        // Only apply mutations that we would have listed for this body.
+        if !find_mutations(tcx, &body).iter().any(|site| site.mutation == mutant.mutation) {
+            return (false, body);
+        }
+        (true, apply_mutation(body, mutant.mutation))
+    }
+}
+
+impl MutationPass {
+    pub fn new(tcx: TyCtxt, queries: &QueryDb) -> Self {
+        let mutant = queries.args().mutant.as_ref().map(|id| {
+            id.parse().unwrap_or_else(|err: String| {
+                tcx.dcx().fatal(format!("{err} passed to `--mutant`"))
+            })
+        });
+        MutationPass { mutant }
+    }
+}
+
+/// Collects the mutations that can be applied to the functions reachable from each harness.
+#[derive(Debug, Default)]
+pub struct MutantCollector {
+    /// The mutants found so far indexed by their identifier.
+    mutants: BTreeMap<String, MutantMetadata>,
+    /// Cache the identifiers of the mutants of each function, since most functions are reachable
+    /// from more than one harness.
+    functions: HashMap<Instance, Vec<String>>,
+}
+
+impl MutantCollector {
+    /// Collect the mutations of the local functions in `items`, which are reachable from the
+    /// harness with the given name.
+    pub fn collect(&mut self, tcx: TyCtxt, harness: &str, items: &[MonoItem]) {
+        for item in items {
+            let MonoItem::Fn(instance) = item else { continue };
+            if !is_mutable(tcx, *instance) {
+                continue;
+            }
+            let mutants = &mut self.mutants;
+            let ids = self.functions.entry(*instance).or_insert_with(|| {
+                let function = function_id(tcx, *instance);
+                let body = instance.body().unwrap();
+                find_mutations(tcx, &body)
+                    .into_iter()
+                    .map(|site| {
+                        let id = MutantId { function: function.clone(), mutation: site.mutation }
+                            .to_string();
+                        mutants.entry(id.clone()).or_insert_with(|| {
+                            let location = SourceLocation::new(site.span);
+                            MutantMetadata {
+                                id: id.clone(),
+                                function: instance.def.name(),
+                                description: site.description,
+                                file: location.filename,
+                                line: location.start_line,
+                                column: location.start_col,
+                                harnesses: BTreeSet::new(),
+                            }
+                        });
+                        id
+                    })
+                    .collect()
+            });
+            for id in ids.iter() {
+                self.mutants.get_mut(id).unwrap().harnesses.insert(harness.to_string());
+            }
+        }
+    }
+
+    /// Return the mutants collected, sorted by their location.
+    pub fn into_mutants(self) -> Vec<MutantMetadata> {
+        let mut mutants = self.mutants.into_values().collect::<Vec<_>>();
+        mutants.sort_by(|a, b| {
+            (&a.file, a.line, a.column, &a.id).cmp(&(&b.file, b.line, b.column, &b.id))
+        });
+        mutants
+    }
+}
+
+/// The identifier of a mutant, in the format `<function>:<bb>:<idx>:<kind>`.
+///
+/// The identifier is passed as a compiler argument, so it cannot contain any whitespace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MutantId {
+    /// The stable identifier of the mutated function. See [function_id].
+    function: String,
+    mutation: Mutation,
+}
+
+impl Display for MutantId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Mutation { bb, idx, kind } = self.mutation;
+        write!(f, "{}:{bb}:{idx}:{kind}", self.function)
+    }
+}
+
+impl FromStr for MutantId {
+    type Err = String;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid mutant identifier `{id}`");
+        let parts = id.split(':').collect::<Vec<_>>();
+        let &[function, bb, idx, kind] = parts.as_slice() else { return Err(invalid()) };
+        let mutation = Mutation {
+            bb: bb.parse().map_err(|_| invalid())?,
+            idx: idx.parse().map_err(|_| invalid())?,
+            kind: kind.parse().map_err(|_| invalid())?,
+        };
+        Ok(MutantId { function: function.to_string(), mutation })
+    }
+}
+
+/// A mutation of a function body.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Mutation {
+    /// The basic block of the mutated instruction.
+    bb: BasicBlockIdx,
+    /// The index of the mutated statement, or the number of statements in the basic block if the
+    /// terminator is mutated.
+    idx: usize,
+    kind: MutationKind,
+}
+
+/// The mutations that we support.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationKind {
+    /// Replace a comparison by its negation, e.g.: `<` by `>=`.
+    NegateComparison,
+    /// Add one to the integer constant used as the operand with the given index.
+    PlusOne(usize),
+    /// Subtract one from the integer constant used as the operand with the given index.
+    MinusOne(usize),
+    /// Replace `&&` by `||` and vice-versa. This also swaps `&` and `|` over booleans.
+    SwapLogicalOperator,
+    /// Delete an assignment to memory, or a call to a function that returns `()`.
+    DeleteStatement,
+}
+
+impl Display for MutationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MutationKind::NegateComparison => write!(f, "negate-comparison"),
+            MutationKind::PlusOne(operand) => write!(f, "plus-one-{operand}"),
+            MutationKind::MinusOne(operand) => write!(f, "minus-one-{operand}"),
+            MutationKind::SwapLogicalOperator => write!(f, "swap-logical-operator"),
+            MutationKind::DeleteStatement => write!(f, "delete-statement"),
+        }
+    }
+}
+
+impl FromStr for MutationKind {
+    type Err = ();
+
+    fn from_str(kind: &str) -> Result<Self, Self::Err> {
+        match kind {
+            "negate-comparison" => Ok(MutationKind::NegateComparison),
+            "swap-logical-operator" => Ok(MutationKind::SwapLogicalOperator),
+            "delete-statement" => Ok(MutationKind::DeleteStatement),
+            _ => {
+                if let Some(operand) = kind.strip_prefix("plus-one-") {
+                    operand.parse().map(MutationKind::PlusOne).map_err(|_| ())
+                } else if let Some(operand) = kind.strip_prefix("minus-one-") {
+                    operand.parse().map(MutationKind::MinusOne).map_err(|_| ())
+                } else {
+                    Err(())
+                }
+            }
+        }
+    }
+}
+
+/// A mutation that can be applied to a body, with its location and a description for the user.
+struct MutationSite {
+    mutation: Mutation,
+    span: Span,
+    description: String,
+}
+
+/// Whether we should mutate the given instance, i.e., whether it is a local function that isn't
+/// a harness or generated by Kani.
+fn is_mutable(tcx: TyCtxt, instance: Instance) -> bool {
+    matches!(instance.kind, InstanceKind::Item)
+        && instance.def.krate().is_local
+        && instance.has_body()
+        && !is_proof_harness(tcx, instance)
+        && !KaniAttributes::for_instance(tcx, instance).is_kani_instrumentation()
+}
+
+/// A stable identifier of the function definition, so we can find the mutated function when
+/// the crate is recompiled.
+fn function_id(tcx: TyCtxt, instance: Instance) -> String {
+    let def_id = rustc_internal::internal(tcx, instance.def.def_id());
+    let (high, low) = tcx.def_path_hash(def_id).0.as_value();
+    format!("{high:016x}{low:016x}")
+}
+
+/// Whether this span comes from a macro expansion, which the user cannot act on.
+fn is_from_expansion(tcx: TyCtxt, span: Span) -> bool {
+    rustc_internal::internal(tcx, span).from_expansion()
+}
+
+/// Find all the mutations that can be applied to the given body.
+fn find_mutations(tcx: TyCtxt, body: &Body) -> Vec<MutationSite> {
+    let mut sites = vec![];
+    for (bb, block) in body.blocks.iter().enumerate() {
+        for (idx, stmt) in block.statements.iter().enumerate() {
+            let StatementKind::Assign(place, rvalue) = &stmt.kind else { continue };
+            if is_from_expansion(tcx, stmt.span) {
+                continue;
+            }
+            let mut add = |kind, description| {
+                let mutation = Mutation { bb, idx, kind };
+                sites.push(MutationSite { mutation, span: stmt.span, description })
+            };
+            if let Rvalue::BinaryOp(op, lhs, _) | Rvalue::CheckedBinaryOp(op, lhs, _) = rvalue {
+                if let Some(negated) = negated_comparison(*op) {
+                    add(
+                        MutationKind::NegateComparison,
+                        format!("replace `{}` with `{}`", operator_str(*op), operator_str(negated)),
+                    );
+                }
+                if let Some(swapped) = swapped_logical_operator(body, *op, lhs) {
+                    add(
+                        MutationKind::SwapLogicalOperator,
+                        format!("replace `{}` with `{}`", operator_str(*op), operator_str(swapped)),
+                    );
+                }
+            }
+            for (operand, value) in operands(rvalue)
+                .into_iter()
+                .enumerate()
+                .filter_map(|(operand, value)| Some((operand, int_constant(value)?)))
+            {
+                add(MutationKind::PlusOne(operand), format!("add one to `{value}`"));
+                add(MutationKind::MinusOne(operand), format!("subtract one from `{value}`"));
+            }
+            if !place.projection.is_empty() {
+                add(MutationKind::DeleteStatement, "delete assignment".to_string());
+            }
+        }
+
+        let terminator = &block.terminator;
+        if is_from_expansion(tcx, terminator.span) {
+            continue;
+        }
+        let mut add = |kind, description| {
+            let mutation = Mutation { bb, idx: block.statements.len(), kind };
+            sites.push(MutationSite { mutation, span: terminator.span, description })
+        };
+        match &terminator.kind {
+            TerminatorKind::SwitchInt { .. } => {
+                if let Some(lazy_op) = lazy_bool_op(body, bb) {
+                    let (from, to) =
+                        if lazy_op.short_circuit_value { ("||", "&&") } else { ("&&", "||") };
+                    add(MutationKind::SwapLogicalOperator, format!("replace `{from}` with `{to}`"));
+                }
+            }
+            TerminatorKind::Call { func, destination, target: Some(_), .. } => {
+                if let Some(callee) = deletable_callee(body, func, destination) {
+                    add(MutationKind::DeleteStatement, format!("delete call to `{callee}`"));
+                }
+            }
+            _ => {}
+        }
+    }
+    sites
+}
+
+/// Apply a mutation returned by [find_mutations] for this body.
+fn apply_mutation(body: Body, mutation: Mutation) -> Body {
+    let Mutation { bb, idx, kind } = mutation;
+    let lazy_op = lazy_bool_op(&body, bb);
+    let mut body = MutableBody::from(body);
+    if idx == body.blocks()[bb].statements.len() {
+        mutate_terminator(&mut body, bb, kind, lazy_op);
+    } else {
+        mutate_statement(&mut body, SourceInstruction::Statement { idx, bb }, kind);
+    }
+    body.into()
+}
+
+fn mutate_statement(body: &mut MutableBody, mut source: SourceInstruction, kind: MutationKind) {
+    match kind {
+        MutationKind::NegateComparison => {
+            update_statement(body, source, |stmt| replace_operator(stmt, negated_comparison))
+        }
+        MutationKind::SwapLogicalOperator => update_statement(body, source, |stmt| {
+            replace_operator(stmt, |op| match op {
+                BinOp::BitAnd => Some(BinOp::BitOr),
+                BinOp::BitOr => Some(BinOp::BitAnd),
+                _ => None,
+            })
+        }),
+        MutationKind::PlusOne(operand) | MutationKind::MinusOne(operand) => {
+            let SourceInstruction::Statement { idx, bb } = source else { unreachable!() };
+            let StatementKind::Assign(_, rvalue) = &body.blocks()[bb].statements[idx].kind else {
+                unreachable!()
+            };
+            let constant = operands(rvalue)[operand].clone();
+            let ty = constant.ty(body.locals()).unwrap();
+            let span = source.span(body.blocks());
+            // Build the constant `1` with a cast, so we don't need to handle each integer type.
+            let one = body.new_uint_operand(1, UintTy::U8, span);
+            let one = body.insert_assignment(
+                Rvalue::Cast(CastKind::IntToInt, one, ty),
+                &mut source,
+                InsertPosition::Before,
+            );
+            let op = if matches!(kind, MutationKind::PlusOne(_)) { BinOp::Add } else { BinOp::Sub };
+            let new_value = body.insert_binary_op(
+                op,
+                constant,
+                Operand::Copy(Place::from(one)),
+                &mut source,
+                InsertPosition::Before,
+            );
+            update_statement(body, source, |stmt| {
+                if let StatementKind::Assign(_, rvalue) = stmt {
+                    *operands_mut(rvalue).swap_remove(operand) =
+                        Operand::Copy(Place::from(new_value));
+                }
+            })
+        }
+        MutationKind::DeleteStatement => {
+            update_statement(body, source, |stmt| *stmt = StatementKind::Nop)
+        }
+    }
+}
+
+fn mutate_terminator(
+    body: &mut MutableBody,
+    bb: BasicBlockIdx,
+    kind: MutationKind,
+    lazy_op: Option<LazyBoolOp>,
+) {
+    let source = SourceInstruction::Terminator { bb };
+    let terminator = body.blocks()[bb].terminator.clone();
+    let new_kind = match (kind, terminator.kind) {
+        (MutationKind::SwapLogicalOperator, TerminatorKind::SwitchInt { discr, targets }) => {
+            // Swap the branches, and flip the value assigned when the operator short circuits.
+            let lazy_op = lazy_op.unwrap();
+            let const_source = SourceInstruction::Statement { idx: 0, bb: lazy_op.const_bb };
+            let span = const_source.span(body.blocks());
+            update_statement(body, const_source, |stmt| {
+                if let StatementKind::Assign(_, rvalue) = stmt {
+                    *rvalue = Rvalue::Use(Operand::Constant(ConstOperand {
+                        span,
+                        user_ty: None,
+                        const_: MirConst::from_bool(!lazy_op.short_circuit_value),
+                    }));
+                }
+            });
+            let (value, branch) = targets.branches().next().unwrap();
+            TerminatorKind::SwitchInt {
+                discr,
+                targets: SwitchTargets::new(vec![(value, targets.otherwise())], branch),
+            }
+        }
+        (MutationKind::DeleteStatement, TerminatorKind::Call { target: Some(target), .. }) => {
+            TerminatorKind::Goto { target }
+        }
+        (kind, term_kind) => unreachable!("unexpected mutation {kind} of {term_kind:?}"),
+    };
+    body.replace_terminator(&source, Terminator { kind: new_kind, span: terminator.span });
+}
+
+/// Update the kind of the given statement.
+fn update_statement(
+    body: &mut MutableBody,
+    source: SourceInstruction,
+    update: impl FnOnce(&mut StatementKind),
+) {
+    let SourceInstruction::Statement { idx, bb } = source else { unreachable!() };
+    let mut statements = body.blocks()[bb].statements.clone();
+    update(&mut statements[idx].kind);
+    body.replace_statements(&source, statements);
+}
+
+/// Replace the operator of a binary operation.
+fn replace_operator(stmt: &mut StatementKind, new_op: impl FnOnce(BinOp) -> Option<BinOp>) {
+    if let StatementKind::Assign(_, Rvalue::BinaryOp(op, ..) | Rvalue::CheckedBinaryOp(op, ..)) =
+        stmt
+    {
+        *op = new_op(*op).unwrap();
+    }
+}
+
+/// The operands that may be mutated by an off-by-one mutation.
+fn operands(rvalue: &Rvalue) -> Vec<&Operand> {
+    match rvalue {
+        Rvalue::BinaryOp(_, lhs, rhs) | Rvalue::CheckedBinaryOp(_, lhs, rhs) => vec![lhs, rhs],
+        Rvalue::Use(operand) => vec![operand],
+        _ => vec![],
+    }
+}
+
+fn operands_mut(rvalue: &mut Rvalue) -> Vec<&mut Operand> {
+    match rvalue {
+        Rvalue::BinaryOp(_, lhs, rhs) | Rvalue::CheckedBinaryOp(_, lhs, rhs) => vec![lhs, rhs],
+        Rvalue::Use(operand) => vec![operand],
+        _ => vec![],
+    }
+}
+
+/// The value of an integer constant operand.
+fn int_constant(operand: &Operand) -> Option<String> {
+    let Operand::Constant(ConstOperand { const_, .. }) = operand else { return None };
+    let ConstantKind::Allocated(alloc) = const_.kind() else { return None };
+    match const_.ty().kind() {
+        TyKind::RigidTy(RigidTy::Int(_)) => alloc.read_int().ok().map(|value| value.to_string()),
+        TyKind::RigidTy(RigidTy::Uint(_)) => alloc.read_uint().ok().map(|value| value.to_string()),
+        _ => None,
+    }
+}
+
+/// The negation of the given operator, if it's a comparison.
+fn negated_comparison(op: BinOp) -> Option<BinOp> {
+    match op {
+        BinOp::Lt => Some(BinOp::Ge),
+        BinOp::Ge => Some(BinOp::Lt),
+        BinOp::Le => Some(BinOp::Gt),
+        BinOp::Gt => Some(BinOp::Le),
+        BinOp::Eq => Some(BinOp::Ne),
+        BinOp::Ne => Some(BinOp::Eq),
+        _ => None,
+    }
+}
+
+/// The swapped operator, if this is a non-lazy logical operator over booleans.
+fn swapped_logical_operator(body: &Body, op: BinOp, lhs: &Operand) -> Option<BinOp> {
+    let swapped = match op {
+        BinOp::BitAnd => BinOp::BitOr,
+        BinOp::BitOr => BinOp::BitAnd,
+        _ => return None,
+    };
+    let is_bool = lhs.ty(body.locals()).is_ok_and(|ty| is_bool(ty.kind()));
+    is_bool.then_some(swapped)
+}
+
+fn is_bool(kind: TyKind) -> bool {
+    matches!(kind, TyKind::RigidTy(RigidTy::Bool))
+}
+
+/// The source representation of the operators that we mutate.
+fn operator_str(op: BinOp) -> &'static str {
+    match op {
+        BinOp::Lt => "<",
+        BinOp::Ge => ">=",
+        BinOp::Le => "<=",
+        BinOp::Gt => ">",
+        BinOp::Eq => "==",
+        BinOp::Ne => "!=",
+        BinOp::BitAnd => "&",
+        BinOp::BitOr => "|",
+        _ => unreachable!("unexpected operator {op:?}"),
+    }
+}
+
+/// The lowering of a lazy boolean operator, i.e., `a && b` or `a || b`.
+///
+/// The compiler lowers `a && b` to a switch on `a`, where the `false` branch assigns the constant
+/// `false` to the result, and the other branch evaluates `b`. Similarly, the `true` branch of
+/// `a || b` assigns `true`.
+///
+/// Note that the result of an operator used as an `if` condition is never materialized, so we
+/// don't detect those.
+struct LazyBoolOp {
+    /// The block that assigns the result when the operator short circuits.
+    const_bb: BasicBlockIdx,
+    /// The result when the operator short circuits, i.e., `false` for `&&`.
+    short_circuit_value: bool,
+}
+
+/// Return the lazy boolean operator whose switch terminates the given block, if any.
+fn lazy_bool_op(body: &Body, bb: BasicBlockIdx) -> Option<LazyBoolOp> {
+    let TerminatorKind::SwitchInt { discr, targets } = &body.blocks[bb].terminator.kind else {
+        return None;
+    };
+    if !is_bool(discr.ty(body.locals()).ok()?.kind()) {
+        return None;
+    }
+    let [(0, false_bb)] = targets.branches().collect::<Vec<_>>()[..] else { return None };
+    let true_bb = targets.otherwise();
+    if const_bool_block(body, false_bb) == Some(false) {
+        Some(LazyBoolOp { const_bb: false_bb, short_circuit_value: false })
+    } else if const_bool_block(body, true_bb) == Some(true) {
+        Some(LazyBoolOp { const_bb: true_bb, short_circuit_value: true })
+    } else {
+        None
+    }
+}
+
+/// The boolean assigned by a block that only assigns a constant and jumps to another block.
+fn const_bool_block(body: &Body, bb: BasicBlockIdx) -> Option<bool> {
+    let block = &body.blocks[bb];
+    let [
+        Statement {
+            kind: StatementKind::Assign(_, Rvalue::Use(Operand::Constant(constant))), ..
+        },
+    ] = block.statements.as_slice()
+    else {
+        return None;
+    };
+    if !matches!(block.terminator.kind, TerminatorKind::Goto { .. }) {
+        return None;
+    }
+    constant.const_.eval_target_bool().ok()
+}
+
+/// The name of the function called, if the call can be deleted, i.e., if the function returns
+/// `()` and isn't a Kani function.
+fn deletable_callee(body: &Body, func: &Operand, destination: &Place) -> Option<String> {
+    let RigidTy::FnDef(def, _) = func.ty(body.locals()).ok()?.kind().rigid()?.clone() else {
+        return None;
+    };
+    let returns_unit = destination.ty(body.locals()).is_ok_and(|ty| ty.kind().is_unit());
+    (returns_unit && KaniFunction::try_from(def).is_err()).then(|| def.trimmed_name())
+}
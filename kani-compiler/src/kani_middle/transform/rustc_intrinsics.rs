@@ -13,11 +13,12 @@
 };
 use crate::kani_middle::transform::{TransformPass, TransformationType};
 use crate::kani_queries::QueryDb;
+use rustc_hir::def::DefKind;
 use rustc_middle::ty::TyCtxt;
 use rustc_public::mir::mono::Instance;
 use rustc_public::mir::{
-    BasicBlockIdx, BinOp, Body, ConstOperand, LocalDecl, Operand, Rvalue, StatementKind,
-    Terminator, TerminatorKind,
+    BasicBlockIdx, BinOp, Body, ConstOperand, LocalDecl, Operand, Place, RawPtrKind, Rvalue,
+    StatementKind, Terminator, TerminatorKind,
 };
 use rustc_public::rustc_internal;
 use rustc_public::ty::{
@@ -57,7 +58,11 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
         let mut visitor =
             ReplaceIntrinsicCallVisitor::new(&self.models, new_body.locals().to_vec(), tcx);
         visitor.visit_body(&mut new_body);
-        let changed = self.replace_lowered_intrinsics(tcx, &mut new_body);
+        let mut changed = self.replace_lowered_intrinsics(tcx, &mut new_body);
+        // The models of the locks use the functions that they replace.
+        if !matches!(KaniFunction::try_from(instance), Ok(KaniFunction::Model(_))) {
+            changed |= self.replace_lock_calls(tcx, &mut new_body);
+        }
         (visitor.changed || changed, new_body.into())
     }
 }
@@ -139,6 +144,93 @@ fn replace_offset(
         body.remove_stmt(bb, stmt);
         true
     }
+
+    /// Replace the calls to the locks of the standard library by their models.
+    ///
+    /// The models of `into_inner` take an extra argument with the address of the place that the
+    /// lock is moved out of, since Kani identifies a poisoned lock by its address.
+    fn replace_lock_calls(&self, tcx: TyCtxt, body: &mut MutableBody) -> bool {
+        let mut changed = false;
+        for bb in 0..body.blocks().len() {
+            let mut terminator = body.blocks()[bb].terminator.clone();
+            let TerminatorKind::Call { func, args, .. } = &mut terminator.kind else { continue };
+            let TyKind::RigidTy(RigidTy::FnDef(def, fn_args)) =
+                func.ty(body.locals()).unwrap().kind()
+            else {
+                continue;
+            };
+            // The models of the locks are only defined in the `kani` library, not in `kani_core`.
+            let Some((model, model_def)) =
+                lock_model(tcx, def).and_then(|model| Some((model, *self.models.get(&model)?)))
+            else {
+                continue;
+            };
+            let model_args = match model {
+                // The system mutex is not generic, so its model is generic over the mutex type.
+                KaniModel::SysMutexLock
+                | KaniModel::SysMutexTryLock
+                | KaniModel::SysMutexUnlock => {
+                    let mutex_ty = args[0].ty(body.locals()).unwrap();
+                    let mutex_ty = mutex_ty.kind().builtin_deref(true).unwrap().ty;
+                    GenericArgs(vec![GenericArgKind::Type(mutex_ty)])
+                }
+                _ => fn_args,
+            };
+            let mut source = SourceInstruction::Terminator { bb };
+            if matches!(model, KaniModel::MutexIntoInner | KaniModel::RwLockIntoInner) {
+                // A lock that isn't moved out of a place, e.g. a constant, cannot be poisoned.
+                let Operand::Move(lock) = &args[0] else { continue };
+                let rvalue = Rvalue::AddressOf(RawPtrKind::Const, lock.clone());
+                let original = body.insert_assignment(rvalue, &mut source, InsertPosition::Before);
+                args.push(Operand::Move(Place::from(original)));
+            }
+            let instance = Instance::resolve(model_def, &model_args).unwrap();
+            let literal = MirConst::try_new_zero_sized(instance.ty()).unwrap();
+            let span = terminator.span;
+            *func = Operand::Constant(ConstOperand { span, user_ty: None, const_: literal });
+            body.replace_terminator(&source, terminator);
+            changed = true;
+        }
+        changed
+    }
+}
+
+/// Get the model of a function of `Mutex` or `RwLock`, or of the system mutex that implements
+/// `Mutex` on each platform.
+fn lock_model(tcx: TyCtxt, def: FnDef) -> Option<KaniModel> {
+    if let Some(path) = def.name().strip_prefix("std::sys::sync::mutex::") {
+        return match path.rsplit_once("::Mutex::")?.1 {
+            "lock" => Some(KaniModel::SysMutexLock),
+            "try_lock" => Some(KaniModel::SysMutexTryLock),
+            "unlock" => Some(KaniModel::SysMutexUnlock),
+            _ => None,
+        };
+    }
+    let def_id = rustc_internal::internal(tcx, def.def_id());
+    let parent = tcx.opt_parent(def_id)?;
+    if !matches!(tcx.def_kind(parent), DefKind::Impl { of_trait: false }) {
+        return None;
+    }
+    let adt = tcx.type_of(parent).instantiate_identity().ty_adt_def()?;
+    let model = match (tcx.get_diagnostic_name(adt.did())?.as_str(), tcx.item_name(def_id).as_str())
+    {
+        ("Mutex", "lock") => KaniModel::MutexLock,
+        ("Mutex", "try_lock") => KaniModel::MutexTryLock,
+        ("Mutex", "is_poisoned") => KaniModel::MutexIsPoisoned,
+        ("Mutex", "clear_poison") => KaniModel::MutexClearPoison,
+        ("Mutex", "into_inner") => KaniModel::MutexIntoInner,
+        ("Mutex", "get_mut") => KaniModel::MutexGetMut,
+        ("RwLock", "read") => KaniModel::RwLockRead,
+        ("RwLock", "try_read") => KaniModel::RwLockTryRead,
+        ("RwLock", "write") => KaniModel::RwLockWrite,
+        ("RwLock", "try_write") => KaniModel::RwLockTryWrite,
+        ("RwLock", "is_poisoned") => KaniModel::RwLockIsPoisoned,
+        ("RwLock", "clear_poison") => KaniModel::RwLockClearPoison,
+        ("RwLock", "into_inner") => KaniModel::RwLockIntoInner,
+        ("RwLock", "get_mut") => KaniModel::RwLockGetMut,
+        _ => return None,
+    };
+    Some(model)
 }
 
 struct ReplaceIntrinsicCallVisitor<'a, 'tcx> {
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use std::env::var;
+use std::process::Command;
 
 fn main() {
     // We want to know what target triple we were built with, but this isn't normally provided to us.
@@ -10,4 +11,19 @@ fn main() {
     // https://doc.rust-lang.org/cargo/reference/environment-variables.html#environment-variables-cargo-sets-for-build-scripts
     // So "repeat" the info from build script (here) to our crate's build environment.
     println!("cargo:rustc-env=TARGET={}", var("TARGET").unwrap());
+
+    // Record the version of the compiler we were built with, so we can detect when cargo uses a
+    // different toolchain at runtime.
+    let rustc = var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let output = Command::new(rustc).arg("-vV").output().expect("failed to run `rustc -vV`");
+    let version = String::from_utf8(output.stdout).unwrap();
+    for (key, env_var) in
+        [("release", "KANI_RUSTC_RELEASE"), ("commit-hash", "KANI_RUSTC_COMMIT_HASH")]
+    {
+        let value = version
+            .lines()
+            .find_map(|line| line.strip_prefix(key)?.strip_prefix(": "))
+            .unwrap_or("unknown");
+        println!("cargo:rustc-env={env_var}={value}");
+    }
 }
@@ -1,7 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //! Define arguments that should be common to all subcommands in Kani.
-use crate::args::{ValidateArgs, print_stabilized_feature_warning};
+use crate::args::{Timeout, ValidateArgs, print_stabilized_feature_warning};
 use clap::{error::Error, error::ErrorKind};
 pub use kani_metadata::{EnabledUnstableFeatures, UnstableFeature};
 
@@ -10,17 +10,36 @@
 #[clap(next_help_heading = "Common Options")]
 pub struct CommonArgs {
     /// Produce full debug information
-    #[arg(long)]
+    #[arg(long, default_value_if("verbosity", "4", Some("true")))]
     pub debug: bool,
     /// Produces no output, just an exit code and requested artifacts; overrides --verbose
-    #[arg(long, short, conflicts_with_all(["debug", "verbose"]))]
+    #[arg(long, short, conflicts_with_all(["debug", "verbose"]), default_value_if("verbosity", "0", Some("true")))]
     pub quiet: bool,
     /// Output processing stages and commands, along with minor debug information
-    #[arg(long, short, default_value_if("debug", "true", Some("true")))]
+    #[arg(long, short, default_value_ifs([
+        ("debug", "true", Some("true")),
+        ("verbosity", "3", Some("true")),
+        ("verbosity", "4", Some("true")),
+    ]))]
     pub verbose: bool,
+    /// Set the amount of output: 0 (silent, same as `--quiet`), 1 (final summary only),
+    /// 2 (results of each harness, the default), 3 (commands and timing, same as `--verbose`),
+    /// or 4 (debug information, same as `--debug`)
+    #[arg(
+        long,
+        value_name = "LEVEL",
+        value_parser = clap::value_parser!(u8).range(0..=4),
+        conflicts_with_all(["debug", "quiet", "verbose"])
+    )]
+    pub verbosity: Option<u8>,
     /// Enable usage of unstable options
     #[arg(long, hide = true)]
     pub enable_unstable: bool,
+    /// If another Kani run holds the lock on the target directory, wait for it to be released
+    /// for up to this duration instead of failing. Accepts an optional suffix ('s': seconds,
+    /// 'm': minutes, 'h': hours). Default is seconds.
+    #[arg(long, value_name = "DURATION")]
+    pub wait_for_lock: Option<Timeout>,
 
     /// We no longer support dry-run. Use `--verbose` to see the commands being printed during
     /// Kani execution.
@@ -79,6 +98,33 @@ pub fn check_unstable(
     }
 }
 
+/// The amount of output produced by Kani, ordered from the least to the most verbose.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VerbosityLevel {
+    /// No output, just an exit code and requested artifacts.
+    Silent,
+    /// Only print the final summary.
+    Summary,
+    /// Print the results of each harness.
+    Harness,
+    /// Also print the commands being executed and how long they took.
+    Commands,
+    /// Also print debug information.
+    Debug,
+}
+
+impl From<u8> for VerbosityLevel {
+    fn from(level: u8) -> Self {
+        match level {
+            0 => VerbosityLevel::Silent,
+            1 => VerbosityLevel::Summary,
+            2 => VerbosityLevel::Harness,
+            3 => VerbosityLevel::Commands,
+            _ => VerbosityLevel::Debug,
+        }
+    }
+}
+
 /// The verbosity level to be used in Kani.
 pub trait Verbosity {
     /// Whether we should be quiet.
@@ -89,8 +135,8 @@ pub trait Verbosity {
     /// Whether we should emit debug messages.
     #[allow(unused)]
     fn debug(&self) -> bool;
-    /// Whether any verbosity was selected.
-    fn is_set(&self) -> bool;
+    /// The selected verbosity level.
+    fn level(&self) -> VerbosityLevel;
 }
 
 impl Verbosity for CommonArgs {
@@ -106,7 +152,17 @@ fn debug(&self) -> bool {
         self.debug
     }
 
-    fn is_set(&self) -> bool {
-        self.quiet || self.verbose || self.debug
+    fn level(&self) -> VerbosityLevel {
+        if let Some(level) = self.verbosity {
+            level.into()
+        } else if self.debug {
+            VerbosityLevel::Debug
+        } else if self.verbose {
+            VerbosityLevel::Commands
+        } else if self.quiet {
+            VerbosityLevel::Silent
+        } else {
+            VerbosityLevel::Harness
+        }
     }
 }
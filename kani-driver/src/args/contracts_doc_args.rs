@@ -0,0 +1,107 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the subcommand handling of the contracts-doc subcommand
+
+use std::path::PathBuf;
+
+use crate::args::{CommonArgs, ValidateArgs};
+use clap::{Error, Parser, ValueEnum, error::ErrorKind};
+
+#[derive(Debug, Parser)]
+pub struct CommonContractsDocArgs {
+    /// Output format
+    #[arg(long, default_value = "markdown")]
+    pub format: DocFormat,
+
+    /// Where to write the documentation: a directory with one fragment per function for the
+    /// `markdown` format, or a file for the `json` format. Defaults to `kani-contracts-doc`
+    /// (`kani-contracts-doc.json` for the `json` format).
+    #[arg(long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// The run summary that provides the verification status of the contract harnesses, as
+    /// written by `--summary-json`. Defaults to the summary of the last verification run.
+    #[arg(long, value_name = "FILE")]
+    pub report: Option<PathBuf>,
+}
+
+/// Document the contracts of a crate and the verification status of their harnesses
+#[derive(Debug, Parser)]
+pub struct CargoContractsDocArgs {
+    #[command(flatten)]
+    pub common_args: CommonArgs,
+
+    #[command(flatten)]
+    pub doc_args: CommonContractsDocArgs,
+}
+
+/// Document the contracts of a crate and the verification status of their harnesses
+#[derive(Debug, Parser)]
+pub struct StandaloneContractsDocArgs {
+    /// Rust file to document
+    #[arg(required = true)]
+    pub input: PathBuf,
+
+    #[arg(long, hide = true)]
+    pub crate_name: Option<String>,
+
+    #[command(flatten)]
+    pub common_args: CommonArgs,
+
+    #[command(flatten)]
+    pub doc_args: CommonContractsDocArgs,
+}
+
+/// Output formats available for the subcommand.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, strum_macros::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DocFormat {
+    /// Write one Markdown fragment per function under contract, which can be included in the
+    /// documentation of the function.
+    Markdown,
+    /// Write a JSON map from the path of each function under contract to its documentation.
+    Json,
+}
+
+impl ValidateArgs for CommonContractsDocArgs {
+    fn validate(&self) -> Result<(), Error> {
+        if let Some(report) = &self.report
+            && !report.is_file()
+        {
+            return Err(Error::raw(
+                ErrorKind::InvalidValue,
+                format!(
+                    "Invalid argument: `--report` expects a run summary, but `{}` is not a \
+                    regular file.",
+                    report.display()
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl ValidateArgs for CargoContractsDocArgs {
+    fn validate(&self) -> Result<(), Error> {
+        self.common_args.validate()?;
+        self.doc_args.validate()
+    }
+}
+
+impl ValidateArgs for StandaloneContractsDocArgs {
+    fn validate(&self) -> Result<(), Error> {
+        self.common_args.validate()?;
+        self.doc_args.validate()?;
+        if self.input.is_file() {
+            Ok(())
+        } else {
+            Err(Error::raw(
+                ErrorKind::InvalidValue,
+                format!(
+                    "Invalid argument: Input invalid. `{}` is not a regular file.",
+                    self.input.display()
+                ),
+            ))
+        }
+    }
+}
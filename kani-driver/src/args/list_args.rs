@@ -16,6 +16,17 @@ pub struct CargoListArgs {
     /// Output format
     #[clap(long, default_value = "pretty")]
     pub format: Format,
+
+    /// Only list harnesses whose metadata has the given value, e.g. `--meta owner=team-storage`.
+    /// This option can be provided multiple times, in which case harnesses must match all of them.
+    #[arg(long, value_name = "KEY=VALUE", value_parser = parse_meta_filter)]
+    pub meta: Vec<(String, String)>,
+
+    /// Collect the harnesses without generating code for them, which is much faster.
+    /// The results are based only on the harness attributes, so they do not include any
+    /// information that requires reachability analysis, such as the use of loop contracts.
+    #[arg(long)]
+    pub fast: bool,
 }
 
 /// List information relevant to verification
@@ -35,12 +46,27 @@ pub struct StandaloneListArgs {
     #[clap(long, default_value = "pretty")]
     pub format: Format,
 
+    /// Only list harnesses whose metadata has the given value, e.g. `--meta owner=team-storage`.
+    /// This option can be provided multiple times, in which case harnesses must match all of them.
+    #[arg(long, value_name = "KEY=VALUE", value_parser = parse_meta_filter)]
+    pub meta: Vec<(String, String)>,
+
     /// Pass this flag to run the `list` command on the standard library.
     /// Ensure that the provided `path` is the `library` folder.
     #[arg(long)]
     pub std: bool,
 }
 
+/// Parse a `KEY=VALUE` metadata filter.
+fn parse_meta_filter(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("expected a metadata filter of the form `KEY=VALUE`, but found `{arg}`")),
+    }
+}
+
 /// Output formats available for the subcommand.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, strum_macros::Display)]
 #[strum(serialize_all = "kebab-case")]
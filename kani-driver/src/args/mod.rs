@@ -5,17 +5,20 @@
 pub mod autoharness_args;
 pub mod cargo;
 pub mod common;
+pub mod contracts_doc_args;
 pub mod list_args;
+pub mod mutate_args;
 pub mod playback_args;
 pub mod std_args;
 
 use self::common::*;
 use crate::args::cargo::CargoTargetArgs;
+use crate::run_summary::{failed_harnesses, last_run_file};
 use crate::util::warning;
 use cargo::CargoCommonArgs;
 use clap::builder::{PossibleValue, TypedValueParser};
 use clap::{ValueEnum, error::ContextKind, error::ContextValue, error::Error, error::ErrorKind};
-use kani_metadata::CbmcSolver;
+use kani_metadata::{AssertionSemantics, CbmcSolver};
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -138,6 +141,94 @@ fn from(timeout: Timeout) -> Self {
     }
 }
 
+/// The crates that are part of the Rust standard library or Kani. Their steps are hidden from
+/// counterexample traces by the `user-code` trace filter.
+const LIBRARY_CRATES: &[&str] = &[
+    "alloc",
+    "compiler_builtins",
+    "core",
+    "hashbrown",
+    "kani",
+    "kani_core",
+    "panic_abort",
+    "panic_unwind",
+    "proc_macro",
+    "std",
+    "std_detect",
+    "test",
+];
+
+/// Selects the steps of a counterexample trace that are shown for a failed check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TraceFilter {
+    /// Only show the steps in the user crates, i.e., hide the steps in [LIBRARY_CRATES].
+    UserCode,
+    /// Show the steps in all crates.
+    All,
+    /// Only show the steps in the given crates.
+    Crates(Vec<String>),
+}
+
+impl TraceFilter {
+    /// Whether the steps in the given crate should be shown.
+    pub fn shows(&self, krate: &str) -> bool {
+        match self {
+            TraceFilter::UserCode => !LIBRARY_CRATES.contains(&krate),
+            TraceFilter::All => true,
+            TraceFilter::Crates(crates) => crates.iter().any(|c| c == krate),
+        }
+    }
+}
+
+impl FromStr for TraceFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user-code" => Ok(TraceFilter::UserCode),
+            "all" => Ok(TraceFilter::All),
+            _ => {
+                let crates = s
+                    .strip_prefix("crates:")
+                    .ok_or("Invalid trace filter. Use `user-code`, `all`, or `crates:<list>`")?;
+                let crates: Vec<String> = crates
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|krate| !krate.is_empty())
+                    .map(String::from)
+                    .collect();
+                if crates.is_empty() {
+                    return Err("Expected a comma-separated list of crates after `crates:`".into());
+                }
+                Ok(TraceFilter::Crates(crates))
+            }
+        }
+    }
+}
+
+/// Selects one of several disjoint shards of the harnesses, so that they can be verified by
+/// parallel jobs. The shards are numbered from 1 to `count`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Partition {
+    pub index: u32,
+    pub count: u32,
+}
+
+impl FromStr for Partition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (index, count) =
+            s.split_once('/').ok_or("Invalid partition. Use `N/M` to select shard N out of M")?;
+        let index = index.trim().parse::<u32>().map_err(|_| "Invalid partition index")?;
+        let count = count.trim().parse::<u32>().map_err(|_| "Invalid partition count")?;
+        if count == 0 || index == 0 || index > count {
+            return Err(format!("Invalid partition `{s}`. Expected `N/M` with 1 <= N <= M"));
+        }
+        Ok(Partition { index, count })
+    }
+}
+
 #[derive(Debug, clap::Parser)]
 #[command(
     version,
@@ -150,7 +241,7 @@ fn from(timeout: Timeout) -> Self {
 )]
 pub struct StandaloneArgs {
     /// Rust file to verify
-    #[arg(required = true)]
+    #[arg(required_unless_present = "version_info")]
     pub input: Option<PathBuf>,
 
     #[command(flatten)]
@@ -161,6 +252,11 @@ pub struct StandaloneArgs {
 
     #[arg(long, hide = true)]
     pub crate_name: Option<String>,
+
+    /// Print the versions of Kani, its toolchain, CBMC and the available solvers, e.g., to
+    /// include them in a bug report. Use `--version-info=json` for a JSON output.
+    #[arg(long, value_name = "FORMAT", num_args(0..=1), default_missing_value = "pretty")]
+    pub version_info: Option<VersionInfoFormat>,
 }
 
 /// Kani takes optional subcommands to request specialized behavior.
@@ -169,8 +265,12 @@ pub struct StandaloneArgs {
 pub enum StandaloneSubcommand {
     /// Create and run harnesses automatically for eligible functions. Implies -Z function-contracts and -Z loop-contracts.
     Autoharness(Box<autoharness_args::StandaloneAutoharnessArgs>),
+    /// Document the contracts of a crate and the verification status of their harnesses.
+    ContractsDoc(Box<contracts_doc_args::StandaloneContractsDocArgs>),
     /// List contracts and harnesses.
     List(Box<list_args::StandaloneListArgs>),
+    /// Measure how well the harnesses detect bugs by verifying them against mutated code.
+    Mutate(Box<mutate_args::StandaloneMutateArgs>),
     /// Execute concrete playback testcases of a local crate.
     Playback(Box<playback_args::KaniPlaybackArgs>),
     /// Verify the rust standard library.
@@ -190,6 +290,11 @@ pub struct CargoKaniArgs {
 
     #[command(flatten)]
     pub verify_opts: VerificationArgs,
+
+    /// Print the versions of Kani, its toolchain, CBMC and the available solvers, e.g., to
+    /// include them in a bug report. Use `--version-info=json` for a JSON output.
+    #[arg(long, value_name = "FORMAT", num_args(0..=1), default_missing_value = "pretty")]
+    pub version_info: Option<VersionInfoFormat>,
 }
 
 /// cargo-kani takes optional subcommands to request specialized behavior
@@ -199,9 +304,15 @@ pub enum CargoKaniSubcommand {
     /// See https://model-checking.github.io/kani/reference/experimental/autoharness.html for documentation.
     Autoharness(Box<autoharness_args::CargoAutoharnessArgs>),
 
+    /// Document the contracts of a crate and the verification status of their harnesses.
+    ContractsDoc(Box<contracts_doc_args::CargoContractsDocArgs>),
+
     /// List contracts and harnesses.
     List(Box<list_args::CargoListArgs>),
 
+    /// Measure how well the harnesses detect bugs by verifying them against mutated code.
+    Mutate(Box<mutate_args::CargoMutateArgs>),
+
     /// Execute concrete playback testcases of a local package.
     Playback(Box<playback_args::CargoPlaybackArgs>),
 }
@@ -212,6 +323,32 @@ pub enum CargoKaniSubcommand {
 #[derive(Debug, clap::Args)]
 #[clap(next_help_heading = "Verification Options")]
 pub struct VerificationArgs {
+    /// Treat the public functions of the given crate as uninterpreted: they return
+    /// non-deterministic values and havoc the memory behind their `&mut` parameters.
+    /// Functions with a contract and functions stubbed by a harness are not abstracted.
+    /// This option can be provided multiple times.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, value_name = "CRATE", num_args(1), hide_short_help = true)]
+    pub abstract_crate: Vec<String>,
+
+    /// Slice away the parts of the goto program that cannot reach any property before running
+    /// CBMC. With `--verbose`, Kani reports how many instructions were removed.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, hide_short_help = true)]
+    pub aggressive_slicing: bool,
+
+    /// Proceed even if the Rust toolchain used by cargo is not the one Kani was built with.
+    /// Kani may fail to compile the crate or crash in that case.
+    #[arg(long, hide_short_help = true)]
+    pub allow_toolchain_skew: bool,
+
+    /// How to treat the `debug_assert!`, `debug_assert_eq!` and `debug_assert_ne!` macros:
+    /// check them as assertions, assume that they hold, or ignore them. Kani reports how many
+    /// debug assertions each harness has. This has no effect on builds without debug assertions,
+    /// e.g., with the release profile, since they are compiled out.
+    #[arg(long, value_enum, default_value_t, hide_short_help = true)]
+    pub assertion_semantics: AssertionSemantics,
+
     /// Link external C files referenced by Rust code.
     /// This is an experimental feature and requires `-Z c-ffi` to be used
     #[arg(long, hide = true, num_args(1..))]
@@ -234,6 +371,11 @@ pub struct VerificationArgs {
     #[arg(long, ignore_case = true, value_enum)]
     pub concrete_playback: Option<ConcretePlaybackMode>,
 
+    /// Check that the preconditions of functions under contract are neither unsatisfiable nor
+    /// trivially true. Requires -Z function-contracts.
+    #[arg(long, hide_short_help = true)]
+    pub contract_negative: bool,
+
     /// Enable Kani coverage output alongside verification result
     #[arg(long, hide_short_help = true)]
     pub coverage: bool,
@@ -242,6 +384,49 @@ pub struct VerificationArgs {
     #[arg(long)]
     pub default_unwind: Option<u32>,
 
+    /// Fail if any of the selected harnesses has no symbolic inputs, i.e., if it doesn't reach
+    /// any source of non-determinism such as `kani::any()`. By default, Kani only prints a note
+    /// for these harnesses.
+    #[arg(long, hide_short_help = true)]
+    pub deny_concrete_harnesses: bool,
+
+    /// Fail if any of the verified harnesses has no properties to check, i.e., if no assertion,
+    /// cover statement or automatic check is reachable from it. By default, Kani only prints a
+    /// warning for these harnesses, since they succeed trivially.
+    #[arg(long, hide_short_help = true)]
+    pub deny_unchecked_harnesses: bool,
+
+    /// Write the symbol table that Kani generates for the selected harness to the given file, in
+    /// CBMC's JSON symtab format, and stop without verifying it. This helps diagnosing type or
+    /// ABI mismatches in the generated code. Exactly one harness must be selected.
+    #[arg(long, hide_short_help = true, value_name = "FILE", conflicts_with = "only_codegen")]
+    pub dump_symtab: Option<PathBuf>,
+
+    /// Write a badge with the number of verified harnesses to the given file, in the JSON format
+    /// of the shields.io endpoint badges. The badge is also rendered as an SVG image, written next
+    /// to it with the `svg` extension. Failed runs produce a red badge.
+    #[arg(long, hide_short_help = true, value_name = "FILE")]
+    pub emit_badge: Option<PathBuf>,
+
+    /// Record how long each phase, harness and command of the run takes, and write it to the
+    /// given file in the Chrome Trace Event Format. The file can be opened with Perfetto or
+    /// `chrome://tracing`.
+    #[arg(long, hide_short_help = true, value_name = "FILE")]
+    pub emit_chrome_trace: Option<PathBuf>,
+
+    /// Write the fully qualified names of the functions reachable from each selected harness to
+    /// the given JSON file. Diffing the files of two runs shows how a change affected the code
+    /// that each harness verifies.
+    #[arg(long, hide_short_help = true, value_name = "FILE")]
+    pub emit_reachable: Option<PathBuf>,
+
+    /// Write a timeline of the run to the given file as JSON lines: the start and end of each
+    /// stage, the commands executed with their exit codes, the temporary files created and
+    /// removed, and the state of each harness. With `--debug`, the timeline is written to
+    /// `.kani/events.jsonl` in the target directory by default.
+    #[arg(long, hide_short_help = true, value_name = "FILE")]
+    pub event_log: Option<PathBuf>,
+
     /// When specified, the harness filter will only match the exact fully qualified name of a harness
     #[arg(long, requires("harnesses"))]
     pub exact: bool,
@@ -272,6 +457,12 @@ pub struct VerificationArgs {
     #[arg(long = "harness", num_args(1), value_name = "HARNESS_FILTER")]
     pub harnesses: Vec<String>,
 
+    /// Read the fully qualified names of the harnesses to run from the given file, one per line.
+    /// Empty lines and everything after a `#` are ignored.
+    /// Kani runs exactly the listed harnesses, and fails if any of them cannot be found.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["harnesses", "exact"])]
+    pub harness_file: Option<PathBuf>,
+
     /// Timeout for each harness with optional suffix ('s': seconds, 'm': minutes, 'h': hours). Default is seconds. This option is experimental and requires `-Z unstable-options` to be used.
     #[arg(long)]
     pub harness_timeout: Option<Timeout>,
@@ -288,11 +479,27 @@ pub struct VerificationArgs {
     #[arg(short, long, hide_short_help = true)]
     jobs: Option<Option<usize>>,
 
+    /// Keep the code that formats the arguments of panic messages. By default, Kani replaces
+    /// these arguments by opaque values, since the formatted message is never evaluated.
+    #[arg(long, hide_short_help = true)]
+    pub keep_fmt: bool,
+
+    /// Replace the arguments of panic messages by opaque values when they are only used on the
+    /// paths that end in a panic. This is the default; use `--keep-fmt` to disable it.
+    #[arg(long, hide_short_help = true, default_value_t = true, conflicts_with = "keep_fmt")]
+    pub no_fmt_args: bool,
+
     /// Keep temporary files generated throughout Kani process. This is already the default
     /// behavior for `cargo-kani`.
     #[arg(long, hide_short_help = true)]
     pub keep_temps: bool,
 
+    /// Fail the compilation of the harnesses that introduce more than the given number of
+    /// nondeterministic bytes, e.g., with `kani::any()`, symbolic slices or contract havocs.
+    /// The error lists the call sites in the user code that contribute the most to the count.
+    #[arg(long, hide_short_help = true, value_name = "N")]
+    pub max_nondet_bytes: Option<u64>,
+
     /// Do not assert the function contracts of dependencies. Requires -Z function-contracts.
     #[arg(long, hide_short_help = true)]
     pub no_assert_contracts: bool,
@@ -326,10 +533,24 @@ pub struct VerificationArgs {
     #[arg(long, hide_short_help = true)]
     pub output_into_files: bool,
 
+    /// Only verify the harnesses of shard N out of M, e.g., `--partition 2/4`. Each harness is
+    /// assigned to a shard based on a hash of its name, so the shards are disjoint, cover all
+    /// harnesses, and stay the same across runs.
+    #[arg(long, hide_short_help = true, value_name = "N/M")]
+    pub partition: Option<Partition>,
+
     /// Print final LLBC for Lean backend. This requires the `-Z lean` option.
     #[arg(long, hide = true)]
     pub print_llbc: bool,
 
+    /// Only report the properties of the given class, e.g., `assertion` or `pointer_offset`, and
+    /// ignore the other properties when computing the verification results. This option can be
+    /// repeated. Pointer arithmetic checks (`pointer_offset`) and uninitialized memory checks
+    /// (`uninitialized_memory`) are reported as `safety_check` unless their class is selected.
+    /// This option is experimental and requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, value_name = "CLASS")]
+    pub property_class: Vec<String>,
+
     /// Compute verification results under the assumption that no panic occurs.
     /// This feature is unstable, and it requires `-Z unstable-options` to be used
     #[arg(long, hide_short_help = true)]
@@ -347,15 +568,38 @@ pub struct VerificationArgs {
     #[arg(long, hide = true, conflicts_with = "no_restrict_vtable")]
     pub restrict_vtable: bool,
 
+    /// Append the result of each harness to the given JSON Lines file, which is created if
+    /// needed. Each line records the harness, its status, duration and solver, as well as the
+    /// time and the git commit of the run.
+    #[arg(long, hide_short_help = true, value_name = "FILE")]
+    pub results_db: Option<PathBuf>,
+
+    /// Only run the harnesses that failed or timed out in the previous run. The previous run is
+    /// read from the `--summary-json` file if one is given, and from `.kani/last-run.json` in
+    /// the target directory otherwise.
+    #[arg(long, conflicts_with_all = ["harnesses", "exact", "harness_file"])]
+    pub rerun_failed: bool,
+
     /// Execute CBMC's sanity checks to ensure the goto-program we generate is correct.
     #[arg(long, hide_short_help = true)]
     pub run_sanity_checks: bool,
 
+    /// Show the value of the given harness metadata key (e.g. `owner`) next to each failing
+    /// harness in the verification summary. This option can be provided multiple times.
+    #[arg(long = "show-meta", num_args(1), value_name = "KEY")]
+    pub show_meta: Vec<String>,
+
     /// Specify the CBMC solver to use. Overrides the harness `solver` attribute.
     /// If no solver is specified (with --solver or harness attribute), Kani will use CaDiCaL.
     #[arg(long, value_parser = CbmcSolverValueParser::new(CbmcSolver::VARIANTS))]
     pub solver: Option<CbmcSolver>,
 
+    /// Write the status of each harness of this run to the given file in JSON format.
+    /// The status of the last run is also always recorded in `.kani/last-run.json` in the target
+    /// directory.
+    #[arg(long, hide_short_help = true, value_name = "FILE")]
+    pub summary_json: Option<PathBuf>,
+
     /// Synthesize loop contracts for all loops.
     #[arg(
         long,
@@ -373,6 +617,60 @@ pub struct VerificationArgs {
     #[arg(long)]
     pub tests: bool,
 
+    /// Warn about the packages whose features resolved for verification differ from the ones
+    /// `cargo test` would resolve with the same options, e.g., because a dev-dependency enables
+    /// more features of a dependency.
+    /// This option is experimental and requires `-Z unstable-options` to be used.
+    #[arg(long)]
+    pub check_feature_parity: bool,
+
+    /// Print the counterexample trace of each failed check, only showing the steps in the selected
+    /// crates: `user-code` (the default) hides the steps in the standard library and Kani, `all`
+    /// shows every step, including the ones that CBMC marks as hidden, and `crates:<list>` only
+    /// shows the steps in the given comma-separated crates. The failing step is always shown.
+    /// This option is experimental and requires `-Z unstable-options` to be used.
+    #[arg(long, num_args(0..=1), default_missing_value = "user-code", value_name = "FILTER")]
+    pub trace_filter: Option<TraceFilter>,
+
+    /// Only print the last N steps of each counterexample trace, i.e., the N steps that lead to
+    /// the failure. The omitted steps are replaced by a marker. Without `--trace-filter`, the
+    /// traces are printed with its default filter, `user-code`.
+    /// This option is experimental and requires `-Z unstable-options` to be used.
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub trace_depth_limit: Option<u32>,
+
+    /// Report the location of the call that violates the precondition of a function replaced by
+    /// its contract with `#[kani::stub_verified]`. Finding the call site requires CBMC to produce
+    /// the counterexample traces of the harnesses with verified stubs, which makes their
+    /// verification slower.
+    /// This option is experimental and requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true)]
+    pub precondition_call_sites: bool,
+
+    /// Instead of verifying the harnesses, print an estimate of how long verifying them would take
+    /// with the given `--jobs`. The estimate is based on the runtime of each harness recorded in
+    /// `.kani/timings.json` in the target directory, which every run updates with the harnesses
+    /// that it verified. No code is generated for the harnesses.
+    /// This option is experimental and requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, conflicts_with = "only_codegen")]
+    pub estimate: bool,
+
+    /// Warn when the verification time of a harness annotated with
+    /// `#[kani::expected_time(secs = <seconds>)]` is more than this percentage over or under the
+    /// annotated time.
+    #[arg(
+        long,
+        hide_short_help = true,
+        default_value_t = 50,
+        value_name = "PERCENT",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub time_deviation_pct: u32,
+
     /// Specify the value used for loop unwinding for the specified harness in CBMC
     #[arg(long, requires("harnesses"))]
     pub unwind: Option<u32>,
@@ -428,6 +726,11 @@ pub fn assertion_reach_checks(&self) -> bool {
         !self.no_assertion_reach_checks
     }
 
+    /// Whether the formatting arguments of panic messages should be removed
+    pub fn no_fmt_args(&self) -> bool {
+        self.no_fmt_args && !self.keep_fmt
+    }
+
     /// Suppress our default value, if the user has supplied it explicitly in --cbmc-args
     pub fn cbmc_object_bits(&self) -> Option<u32> {
         if self.cbmc_args.contains(&OsString::from("--object-bits")) {
@@ -486,8 +789,52 @@ pub fn is_function_contracts_enabled(&self) -> bool {
     /// Is experimental stubbing enabled?
     pub fn is_stubbing_enabled(&self) -> bool {
         self.common_args.unstable_features.contains(UnstableFeature::Stubbing)
+            || self.common_args.unstable_features.contains(UnstableFeature::FfiStubs)
             || self.is_function_contracts_enabled()
     }
+
+    /// Replace `--harness-file <FILE>` by the equivalent `--harness <NAME> --exact` arguments,
+    /// so the rest of Kani only has to handle the latter.
+    pub fn load_harness_file(&mut self) -> anyhow::Result<()> {
+        use anyhow::Context;
+        let Some(path) = self.harness_file.take() else { return Ok(()) };
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read harness file `{}`", path.display()))?;
+        let harnesses = parse_harness_list(&content);
+        if harnesses.is_empty() {
+            anyhow::bail!("The harness file `{}` does not list any harness", path.display());
+        }
+        self.harnesses = harnesses;
+        self.exact = true;
+        Ok(())
+    }
+
+    /// Replace `--rerun-failed` by the `--harness <NAME> --exact` arguments that select the
+    /// harnesses that didn't succeed in the previous run.
+    pub fn load_failed_harnesses(&mut self) -> anyhow::Result<()> {
+        if !self.rerun_failed {
+            return Ok(());
+        }
+        let path = self.summary_json.clone().unwrap_or_else(|| {
+            last_run_file(self.target_dir.as_deref(), self.cargo.manifest_path.as_deref())
+        });
+        self.harnesses = failed_harnesses(&path)?;
+        self.exact = true;
+        Ok(())
+    }
+}
+
+/// Parse the content of a harness file: one harness name per line, where empty lines and
+/// anything following a `#` are ignored. Duplicated names are only kept once.
+fn parse_harness_list(content: &str) -> Vec<String> {
+    let mut harnesses: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let name = line.split('#').next().unwrap().trim();
+        if !name.is_empty() && !harnesses.iter().any(|h| h == name) {
+            harnesses.push(name.to_string());
+        }
+    }
+    harnesses
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -498,6 +845,13 @@ pub enum ConcretePlaybackMode {
     InPlace,
 }
 
+/// Output formats of `--version-info`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum VersionInfoFormat {
+    Pretty,
+    Json,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, ValueEnum)]
 pub enum OutputFormat {
     Regular,
@@ -508,6 +862,12 @@ pub enum OutputFormat {
 #[derive(Debug, clap::Args)]
 #[clap(next_help_heading = "Memory Checks")]
 pub struct CheckArgs {
+    /// Check that uninitialized memory is never read. This is the same as `-Z uninit-checks`.
+    /// Use `--property-class uninitialized_memory` to report these checks under their own class.
+    /// This option is experimental and requires `-Z unstable-options` to be used.
+    #[arg(long)]
+    pub check_uninit: bool,
+
     /// Turn off all default checks
     #[arg(long)]
     pub no_default_checks: bool,
@@ -520,6 +880,12 @@ pub struct CheckArgs {
     #[arg(long)]
     pub no_overflow_checks: bool,
 
+    /// Turn off pointer offset checks, i.e., the checks for undefined behavior in pointer
+    /// arithmetic, such as computing an offset outside of the original allocation.
+    /// These checks are not affected by `--no-default-checks`.
+    #[arg(long)]
+    pub no_pointer_offset_checks: bool,
+
     /// Turn off undefined function checks
     #[arg(long)]
     pub no_undefined_function_checks: bool,
@@ -536,6 +902,9 @@ pub fn memory_safety_on(&self) -> bool {
     pub fn overflow_on(&self) -> bool {
         !self.no_default_checks && !self.no_overflow_checks
     }
+    pub fn pointer_offset_on(&self) -> bool {
+        !self.no_pointer_offset_checks
+    }
     pub fn undefined_function_on(&self) -> bool {
         !self.no_default_checks && !self.no_undefined_function_checks
     }
@@ -567,7 +936,9 @@ fn validate(&self) -> Result<(), Error> {
         match &self.command {
             Some(StandaloneSubcommand::VerifyStd(args)) => args.validate()?,
             Some(StandaloneSubcommand::List(args)) => args.validate()?,
+            Some(StandaloneSubcommand::ContractsDoc(args)) => args.validate()?,
             Some(StandaloneSubcommand::Autoharness(args)) => args.validate()?,
+            Some(StandaloneSubcommand::Mutate(args)) => args.validate()?,
             // TODO: Invoke PlaybackArgs::validate()
             None | Some(StandaloneSubcommand::Playback(..)) => {}
         };
@@ -584,6 +955,7 @@ fn validate(&self) -> Result<(), Error> {
         check_no_cargo_opt(!self.verify_opts.cargo.exclude.is_empty(), "--exclude")?;
         check_no_cargo_opt(self.verify_opts.cargo.workspace, "--workspace")?;
         check_no_cargo_opt(self.verify_opts.cargo.manifest_path.is_some(), "--manifest-path")?;
+        check_no_cargo_opt(self.verify_opts.check_feature_parity, "--check-feature-parity")?;
         if let Some(input) = &self.input
             && !input.is_file()
         {
@@ -614,6 +986,8 @@ fn validate(&self) -> Result<(), Error> {
             CargoKaniSubcommand::Autoharness(autoharness) => autoharness.validate(),
             CargoKaniSubcommand::Playback(playback) => playback.validate(),
             CargoKaniSubcommand::List(list) => list.validate(),
+            CargoKaniSubcommand::ContractsDoc(contracts_doc) => contracts_doc.validate(),
+            CargoKaniSubcommand::Mutate(mutate) => mutate.validate(),
         }
     }
 }
@@ -668,6 +1042,18 @@ fn validate(&self) -> Result<(), Error> {
                 UnstableFeature::UnstableOptions,
             )?;
 
+            self.common_args.check_unstable(
+                self.checks.check_uninit,
+                "check-uninit",
+                UnstableFeature::UnstableOptions,
+            )?;
+
+            self.common_args.check_unstable(
+                !self.property_class.is_empty(),
+                "property-class",
+                UnstableFeature::UnstableOptions,
+            )?;
+
             self.common_args.check_unstable(
                 self.ignore_global_asm,
                 "ignore-asm",
@@ -686,6 +1072,18 @@ fn validate(&self) -> Result<(), Error> {
                 UnstableFeature::UnstableOptions,
             )?;
 
+            self.common_args.check_unstable(
+                !self.abstract_crate.is_empty(),
+                "abstract-crate",
+                UnstableFeature::UnstableOptions,
+            )?;
+
+            self.common_args.check_unstable(
+                self.aggressive_slicing,
+                "aggressive-slicing",
+                UnstableFeature::UnstableOptions,
+            )?;
+
             self.common_args.check_unstable(
                 self.synthesize_loop_contracts,
                 "synthesize-loop-contracts",
@@ -708,6 +1106,31 @@ fn validate(&self) -> Result<(), Error> {
                 "output-into-files",
                 UnstableFeature::UnstableOptions,
             )?;
+            self.common_args.check_unstable(
+                self.trace_filter.is_some(),
+                "trace-filter",
+                UnstableFeature::UnstableOptions,
+            )?;
+            self.common_args.check_unstable(
+                self.check_feature_parity,
+                "check-feature-parity",
+                UnstableFeature::UnstableOptions,
+            )?;
+            self.common_args.check_unstable(
+                self.trace_depth_limit.is_some(),
+                "trace-depth-limit",
+                UnstableFeature::UnstableOptions,
+            )?;
+            self.common_args.check_unstable(
+                self.estimate,
+                "estimate",
+                UnstableFeature::UnstableOptions,
+            )?;
+            self.common_args.check_unstable(
+                self.precondition_call_sites,
+                "precondition-call-sites",
+                UnstableFeature::UnstableOptions,
+            )?;
             self.common_args.check_unstable(
                 self.print_llbc,
                 "print-llbc",
@@ -723,6 +1146,11 @@ fn validate(&self) -> Result<(), Error> {
                 "no-assert",
                 UnstableFeature::FunctionContracts,
             )?;
+            self.common_args.check_unstable(
+                self.contract_negative,
+                "contract-negative",
+                UnstableFeature::FunctionContracts,
+            )?;
 
             self.common_args.check_unstable(
                 self.prove_safety_only,
@@ -753,6 +1181,18 @@ fn validate(&self) -> Result<(), Error> {
                     "Invalid flag: --function is not supported in Kani.",
                 ));
             }
+            if self.common_args.unstable_features.contains(UnstableFeature::ContractsBatch)
+                && !self.is_function_contracts_enabled()
+            {
+                return Err(Error::raw(
+                    ErrorKind::MissingRequiredArgument,
+                    format!(
+                        "`{}` requires `{}` to be used.",
+                        UnstableFeature::ContractsBatch.as_argument_string(),
+                        UnstableFeature::FunctionContracts.as_argument_string()
+                    ),
+                ));
+            }
             if self.common_args.quiet && self.concrete_playback == Some(ConcretePlaybackMode::Print)
             {
                 return Err(Error::raw(
@@ -767,13 +1207,6 @@ fn validate(&self) -> Result<(), Error> {
                 --output-format=old.",
                 ));
             }
-            if self.concrete_playback.is_some() && self.jobs().will_multithread() {
-                // Concrete playback currently embeds a lot of assumptions about the order in which harnesses get called.
-                return Err(Error::raw(
-                    ErrorKind::ArgumentConflict,
-                    "Conflicting options: --concrete-playback isn't compatible with --jobs specifying multiple threads.",
-                ));
-            }
             if self.jobs().will_multithread() && self.output_format != OutputFormat::Terse {
                 // More verbose output formats make it hard to interpret output right now when run in parallel.
                 // This can be removed when we change up how results are printed.
@@ -985,6 +1418,74 @@ fn check_multiple_harnesses() {
         assert_eq!(args.verify_opts.harnesses, vec!["a".to_owned(), "b".to_owned()]);
     }
 
+    #[test]
+    fn check_version_info() {
+        let args = StandaloneArgs::try_parse_from(["kani", "--version-info"]).unwrap();
+        assert_eq!(args.version_info, Some(VersionInfoFormat::Pretty));
+        assert!(args.input.is_none());
+        let args = StandaloneArgs::try_parse_from(["kani", "--version-info=json"]).unwrap();
+        assert_eq!(args.version_info, Some(VersionInfoFormat::Json));
+        let args = CargoKaniArgs::try_parse_from(["cargo-kani", "--version-info"]).unwrap();
+        assert_eq!(args.version_info, Some(VersionInfoFormat::Pretty));
+        assert!(StandaloneArgs::try_parse_from(["kani"]).is_err());
+    }
+
+    #[test]
+    fn check_harness_file() {
+        let content =
+            "# Shard 1\nmod_a::check_one\n\n  mod_b::check_two  # flaky\nmod_a::check_one\n";
+        assert_eq!(parse_harness_list(content), vec!["mod_a::check_one", "mod_b::check_two"]);
+        assert!(parse_harness_list("# nothing here\n\n").is_empty());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("harnesses.txt");
+        std::fs::write(&path, content).unwrap();
+        let mut args = StandaloneArgs::try_parse_from([
+            OsString::from("kani"),
+            OsString::from("input.rs"),
+            OsString::from("--harness-file"),
+            path.into_os_string(),
+        ])
+        .unwrap()
+        .verify_opts;
+        args.load_harness_file().unwrap();
+        assert!(args.exact);
+        assert_eq!(args.harnesses, vec!["mod_a::check_one", "mod_b::check_two"]);
+        assert!(args.harness_file.is_none());
+    }
+
+    #[test]
+    fn check_harness_file_conflicts() {
+        for args in [
+            "kani input.rs --harness-file f.txt --harness a",
+            "kani input.rs --harness-file f.txt --exact",
+        ] {
+            let err = StandaloneArgs::try_parse_from(args.split_whitespace()).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+        }
+    }
+
+    #[test]
+    fn check_fmt_args() {
+        let parse = |args: &str| StandaloneArgs::try_parse_from(args.split_whitespace());
+        assert!(parse("kani input.rs").unwrap().verify_opts.no_fmt_args());
+        assert!(parse("kani input.rs --no-fmt-args").unwrap().verify_opts.no_fmt_args());
+        assert!(!parse("kani input.rs --keep-fmt").unwrap().verify_opts.no_fmt_args());
+        let err = parse("kani input.rs --no-fmt-args --keep-fmt").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn check_rerun_failed_conflicts() {
+        for args in [
+            "kani input.rs --rerun-failed --harness a",
+            "kani input.rs --rerun-failed --harness-file f.txt",
+        ] {
+            let err = StandaloneArgs::try_parse_from(args.split_whitespace()).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+        }
+    }
+
     #[test]
     fn check_multiple_harnesses_without_flag_fail() {
         let result = StandaloneArgs::try_parse_from(
@@ -1103,6 +1604,36 @@ fn check_disable_slicing_unstable() {
         check_unstable_flag!("--no-slice-formula", no_slice_formula);
     }
 
+    #[test]
+    fn check_abstract_crate_unstable() {
+        check(
+            "--abstract-crate ring --abstract-crate rand",
+            Some(UnstableFeature::UnstableOptions),
+            |p| p.verify_opts.abstract_crate == ["ring", "rand"],
+        );
+    }
+
+    #[test]
+    fn check_aggressive_slicing_unstable() {
+        check_unstable_flag!("--aggressive-slicing", aggressive_slicing);
+    }
+
+    #[test]
+    fn check_check_uninit_unstable() {
+        check("--check-uninit", Some(UnstableFeature::UnstableOptions), |p| {
+            p.verify_opts.checks.check_uninit
+        });
+    }
+
+    #[test]
+    fn check_property_class_unstable() {
+        check(
+            "--property-class pointer_offset --property-class assertion",
+            Some(UnstableFeature::UnstableOptions),
+            |p| p.verify_opts.property_class == ["pointer_offset", "assertion"],
+        );
+    }
+
     #[test]
     fn check_concrete_playback_unstable() {
         let check = |input: &str| {
@@ -1211,4 +1742,105 @@ fn check_no_assert_contracts() {
         let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
         assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
     }
+
+    #[test]
+    fn check_contract_negative() {
+        let args = "kani input.rs --contract-negative".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn check_verbosity_levels() {
+        use crate::args::common::{Verbosity, VerbosityLevel};
+        let common_args = |args: &str| {
+            StandaloneArgs::try_parse_from(args.split_whitespace()).unwrap().verify_opts.common_args
+        };
+        assert_eq!(common_args("kani input.rs").level(), VerbosityLevel::Harness);
+        assert_eq!(common_args("kani input.rs --quiet").level(), VerbosityLevel::Silent);
+        assert_eq!(common_args("kani input.rs --verbose").level(), VerbosityLevel::Commands);
+        assert_eq!(common_args("kani input.rs --debug").level(), VerbosityLevel::Debug);
+
+        let silent = common_args("kani input.rs --verbosity 0");
+        assert!(silent.quiet && !silent.verbose);
+        let summary = common_args("kani input.rs --verbosity 1");
+        assert_eq!(summary.level(), VerbosityLevel::Summary);
+        assert!(!summary.quiet && !summary.verbose);
+        let commands = common_args("kani input.rs --verbosity 3");
+        assert!(commands.verbose() && !commands.debug);
+        let debug = common_args("kani input.rs --verbosity 4");
+        assert!(debug.verbose && debug.debug);
+    }
+
+    #[test]
+    fn check_verbosity_conflicts() {
+        for flag in ["--quiet", "--verbose", "--debug"] {
+            let args = ["kani", "input.rs", "--verbosity", "2", flag];
+            let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+        }
+        let err =
+            StandaloneArgs::try_parse_from(["kani", "input.rs", "--verbosity", "5"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn check_contracts_batch_requires_contracts() {
+        let args = "kani input.rs -Z contracts-batch".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs -Z contracts-batch -Z function-contracts".split_whitespace();
+        assert!(StandaloneArgs::try_parse_from(args).unwrap().verify_opts.validate().is_ok());
+    }
+
+    #[test]
+    fn check_trace_filter() {
+        let parse = |args: &str| StandaloneArgs::try_parse_from(args.split_whitespace());
+        let args = parse("kani input.rs -Z unstable-options --trace-filter").unwrap();
+        assert_eq!(args.verify_opts.trace_filter, Some(TraceFilter::UserCode));
+        let args =
+            parse("kani input.rs -Z unstable-options --trace-filter=crates:core,foo").unwrap();
+        let filter = args.verify_opts.trace_filter.unwrap();
+        assert!(filter.shows("foo") && filter.shows("core") && !filter.shows("std"));
+        assert!(!TraceFilter::UserCode.shows("alloc") && TraceFilter::UserCode.shows("foo"));
+
+        assert!(parse("kani input.rs -Z unstable-options --trace-filter=crates:").is_err());
+        assert!(parse("kani input.rs -Z unstable-options --trace-filter=none").is_err());
+        let err = parse("kani input.rs --trace-filter=all").unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn check_partition() {
+        let parse = |args: &str| StandaloneArgs::try_parse_from(args.split_whitespace());
+        let args = parse("kani input.rs --partition 2/4").unwrap();
+        assert_eq!(args.verify_opts.partition, Some(Partition { index: 2, count: 4 }));
+        assert!(parse("kani input.rs --partition 1/1").is_ok());
+        assert!(parse("kani input.rs --partition 0/4").is_err());
+        assert!(parse("kani input.rs --partition 5/4").is_err());
+        assert!(parse("kani input.rs --partition 1/0").is_err());
+        assert!(parse("kani input.rs --partition 2").is_err());
+    }
+
+    #[test]
+    fn check_time_deviation_pct() {
+        let parse = |args: &str| StandaloneArgs::try_parse_from(args.split_whitespace());
+        assert_eq!(parse("kani input.rs").unwrap().verify_opts.time_deviation_pct, 50);
+        let args = parse("kani input.rs --time-deviation-pct 20").unwrap();
+        assert_eq!(args.verify_opts.time_deviation_pct, 20);
+        assert!(parse("kani input.rs --time-deviation-pct 0").is_err());
+    }
+
+    #[test]
+    fn check_trace_depth_limit() {
+        let parse = |args: &str| StandaloneArgs::try_parse_from(args.split_whitespace());
+        let args = parse("kani input.rs -Z unstable-options --trace-filter --trace-depth-limit 20");
+        assert_eq!(args.unwrap().verify_opts.trace_depth_limit, Some(20));
+        let args = parse("kani input.rs -Z unstable-options --trace-depth-limit 20").unwrap();
+        assert_eq!(args.verify_opts.trace_filter, None);
+        assert_eq!(args.verify_opts.trace_depth_limit, Some(20));
+        let args = "kani input.rs -Z unstable-options --trace-filter --trace-depth-limit 0";
+        assert!(parse(args).is_err());
+    }
 }
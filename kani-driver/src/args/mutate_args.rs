@@ -0,0 +1,110 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the subcommand handling of the mutate subcommand
+
+use std::path::PathBuf;
+
+use crate::args::{Timeout, ValidateArgs, VerificationArgs};
+use clap::{Error, Parser, error::ErrorKind};
+use kani_metadata::UnstableFeature;
+
+#[derive(Debug, Parser)]
+pub struct CommonMutateArgs {
+    /// The maximum number of mutants to verify. Mutants are selected in the order of their
+    /// source location.
+    #[arg(long, value_name = "N")]
+    pub max_mutants: Option<usize>,
+
+    /// Timeout for verifying each harness against a mutant with optional suffix ('s': seconds,
+    /// 'm': minutes, 'h': hours). Default is seconds.
+    /// Mutants whose verification times out are reported separately, and count against the
+    /// mutation score since the harnesses did not detect the mutation.
+    #[arg(long, default_value = "30s", value_name = "TIMEOUT")]
+    pub mutant_timeout: Timeout,
+}
+
+/// Evaluate how well the harnesses of a crate detect bugs by verifying them against mutated
+/// versions of the code.
+#[derive(Debug, Parser)]
+pub struct CargoMutateArgs {
+    #[command(flatten)]
+    pub common_mutate_args: CommonMutateArgs,
+
+    #[command(flatten)]
+    pub verify_opts: VerificationArgs,
+}
+
+/// Evaluate how well the harnesses of a file detect bugs by verifying them against mutated
+/// versions of the code.
+#[derive(Debug, Parser)]
+pub struct StandaloneMutateArgs {
+    /// Rust crate's top file location.
+    #[arg(required = true)]
+    pub input: PathBuf,
+
+    #[arg(long, hide = true)]
+    pub crate_name: Option<String>,
+
+    #[command(flatten)]
+    pub common_mutate_args: CommonMutateArgs,
+
+    #[command(flatten)]
+    pub verify_opts: VerificationArgs,
+}
+
+impl ValidateArgs for CommonMutateArgs {
+    fn validate(&self) -> Result<(), Error> {
+        if self.max_mutants == Some(0) {
+            return Err(Error::raw(
+                ErrorKind::InvalidValue,
+                "The `--max-mutants` option must be greater than zero.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Validate the verification options that apply to every `mutate` invocation.
+fn validate_verify_opts(verify_opts: &VerificationArgs) -> Result<(), Error> {
+    verify_opts.validate()?;
+    if !verify_opts.common_args.unstable_features.contains(UnstableFeature::Mutate) {
+        return Err(Error::raw(
+            ErrorKind::MissingRequiredArgument,
+            format!(
+                "The `mutate` subcommand is unstable and requires -Z {}",
+                UnstableFeature::Mutate
+            ),
+        ));
+    }
+    if verify_opts.concrete_playback.is_some() {
+        return Err(Error::raw(
+            ErrorKind::ArgumentConflict,
+            "The mutate subcommand does not support concrete playback",
+        ));
+    }
+    Ok(())
+}
+
+impl ValidateArgs for CargoMutateArgs {
+    fn validate(&self) -> Result<(), Error> {
+        self.common_mutate_args.validate()?;
+        validate_verify_opts(&self.verify_opts)
+    }
+}
+
+impl ValidateArgs for StandaloneMutateArgs {
+    fn validate(&self) -> Result<(), Error> {
+        self.common_mutate_args.validate()?;
+        validate_verify_opts(&self.verify_opts)?;
+        if !self.input.is_file() {
+            return Err(Error::raw(
+                ErrorKind::InvalidValue,
+                format!(
+                    "Invalid argument: Input invalid. `{}` is not a regular file.",
+                    self.input.display()
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
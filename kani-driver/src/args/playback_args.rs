@@ -41,8 +41,13 @@ pub struct PlaybackArgs {
     #[arg(long)]
     pub only_codegen: bool,
 
+    /// The test runner used to build and run the tests. Only supported by `cargo kani`.
+    #[arg(long, default_value = "libtest")]
+    pub runner: PlaybackRunner,
+
     // TODO: We should make this a common option to all subcommands.
-    /// Control the subcommand output.
+    /// Control the subcommand output. With `json`, the standard output only contains JSON
+    /// messages, and the rest of the output is printed to the standard error.
     #[arg(long, default_value = "human")]
     pub message_format: MessageFormat,
 
@@ -61,6 +66,16 @@ pub enum MessageFormat {
     Json,
 }
 
+/// Test runners available for the subcommand.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, strum_macros::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PlaybackRunner {
+    /// Run the tests with `cargo test`.
+    Libtest,
+    /// Run the tests with `cargo nextest run`.
+    Nextest,
+}
+
 impl ValidateArgs for CargoPlaybackArgs {
     fn validate(&self) -> Result<(), Error> {
         self.playback.validate()?;
@@ -71,6 +86,15 @@ fn validate(&self) -> Result<(), Error> {
 impl ValidateArgs for KaniPlaybackArgs {
     fn validate(&self) -> Result<(), Error> {
         self.playback.validate()?;
+        if self.playback.runner != PlaybackRunner::Libtest {
+            return Err(Error::raw(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "The `--runner {}` option is only supported by `cargo kani playback`.",
+                    self.playback.runner
+                ),
+            ));
+        }
         if !self.input.is_file() {
             return Err(Error::raw(
                 ErrorKind::InvalidValue,
@@ -148,6 +172,23 @@ fn check_kani_parse_extra_args() {
         assert_eq!(args.playback.test_args, ["TEST_NAME", "--exact", "--nocapture"])
     }
 
+    #[test]
+    fn check_runner() {
+        let input = "playback -Z concrete-playback".split_whitespace();
+        let args = CargoPlaybackArgs::try_parse_from(input).unwrap();
+        assert_eq!(args.playback.runner, PlaybackRunner::Libtest);
+
+        let input = "playback -Z concrete-playback --runner nextest".split_whitespace();
+        let args = CargoPlaybackArgs::try_parse_from(input).unwrap();
+        args.validate().unwrap();
+        assert_eq!(args.playback.runner, PlaybackRunner::Nextest);
+
+        let input = "playback -Z concrete-playback --runner nextest input.rs".split_whitespace();
+        let args = KaniPlaybackArgs::try_parse_from(input).unwrap();
+        let err = args.validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn check_kani_no_unstable_fails() {
         let input = "playback input.rs".split_whitespace();
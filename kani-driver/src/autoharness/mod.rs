@@ -7,7 +7,7 @@
 use crate::args::autoharness_args::{
     CargoAutoharnessArgs, CommonAutoharnessArgs, StandaloneAutoharnessArgs,
 };
-use crate::args::common::UnstableFeature;
+use crate::args::common::{UnstableFeature, Verbosity, VerbosityLevel};
 use crate::call_cbmc::VerificationStatus;
 use crate::harness_runner::HarnessResult;
 use crate::list::collect_metadata::process_metadata;
@@ -26,7 +26,7 @@ pub fn autoharness_cargo(args: CargoAutoharnessArgs) -> Result<()> {
     let mut session = KaniSession::new(args.verify_opts)?;
     setup_session(&mut session, &args.common_autoharness_args);
 
-    if !session.args.common_args.quiet {
+    if session.args.common_args.level() >= VerbosityLevel::Harness {
         print_kani_version(InvocationType::CargoKani(vec![]));
     }
     let project = project::cargo_project(&mut session, false)?;
@@ -37,7 +37,7 @@ pub fn autoharness_standalone(args: StandaloneAutoharnessArgs) -> Result<()> {
     let mut session = KaniSession::new(args.verify_opts)?;
     setup_session(&mut session, &args.common_autoharness_args);
 
-    if !session.args.common_args.quiet {
+    if session.args.common_args.level() >= VerbosityLevel::Harness {
         print_kani_version(InvocationType::Standalone);
     }
 
@@ -66,15 +66,16 @@ fn postprocess_project(
     session: KaniSession,
     common_autoharness_args: CommonAutoharnessArgs,
 ) -> Result<()> {
-    if !session.args.common_args.quiet {
+    if session.args.common_args.level() >= VerbosityLevel::Harness {
         print_autoharness_metadata(project.metadata.clone());
     }
     if common_autoharness_args.list {
-        let list_metadata = process_metadata(project.metadata.clone());
+        let list_metadata = process_metadata(project.metadata.clone(), &[]);
         return output_list_results(
             list_metadata,
             common_autoharness_args.format,
-            session.args.common_args.quiet,
+            session.args.common_args.level() < VerbosityLevel::Summary,
+            false,
         );
     }
     if session.args.only_codegen { Ok(()) } else { verify_project(project, session) }
@@ -0,0 +1,197 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--emit-badge`, which writes a "verified with Kani" badge that reflects the result
+//! of the run.
+//!
+//! The badge is written in the JSON format of the shields.io endpoint badges
+//! (<https://shields.io/badges/endpoint-badge>), so it can be served by a repository, and as a
+//! standalone SVG image for the documentation that cannot reach shields.io.
+
+use crate::call_cbmc::VerificationStatus;
+use crate::harness_runner::HarnessResult;
+use crate::session::KaniSession;
+use anyhow::{Context, Result};
+use kani_metadata::HarnessKind;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// The label on the left side of the badge.
+const LABEL: &str = "kani";
+
+/// The endpoint badge, as specified by shields.io.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Badge {
+    schema_version: u32,
+    label: &'static str,
+    message: String,
+    color: BadgeColor,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum BadgeColor {
+    /// Every harness was verified.
+    BrightGreen,
+    /// At least one harness failed.
+    Red,
+    /// There was no harness to verify.
+    LightGrey,
+}
+
+impl BadgeColor {
+    /// The color used for the SVG image, which is the one shields.io uses for the same name.
+    fn hex(self) -> &'static str {
+        match self {
+            BadgeColor::BrightGreen => "#4c1",
+            BadgeColor::Red => "#e05d44",
+            BadgeColor::LightGrey => "#9f9f9f",
+        }
+    }
+}
+
+/// The number of passing harnesses out of the total, for one kind of harness.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct PassCount {
+    passed: usize,
+    total: usize,
+}
+
+impl PassCount {
+    fn add(&mut self, passed: bool) {
+        self.passed += passed as usize;
+        self.total += 1;
+    }
+}
+
+impl KaniSession {
+    /// Write the badge of this run to `path`, and its SVG image next to it, with the `svg`
+    /// extension.
+    pub fn save_badge(&self, path: &Path, results: &[HarnessResult]) -> Result<()> {
+        let mut regular = PassCount::default();
+        let mut contracts = PassCount::default();
+        for harness_result in results {
+            // Harnesses that fail as expected have a successful status.
+            let passed = harness_result.result.status == VerificationStatus::Success;
+            match harness_result.harness.attributes.kind {
+                HarnessKind::ProofForContract { .. } => contracts.add(passed),
+                HarnessKind::Proof | HarnessKind::Test => regular.add(passed),
+            }
+        }
+        let badge = badge(regular, contracts);
+
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&badge)?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write badge `{}`", path.display()))?;
+        let svg_path = path.with_extension("svg");
+        fs::write(&svg_path, badge_svg(&badge))
+            .with_context(|| format!("Failed to write badge `{}`", svg_path.display()))
+    }
+}
+
+/// The badge for the given counts of regular and contract harnesses. The contract harnesses are
+/// only counted separately if the run has both kinds of harnesses.
+fn badge(regular: PassCount, contracts: PassCount) -> Badge {
+    let message = match (regular.total, contracts.total) {
+        (0, 0) => "no harnesses".to_string(),
+        (_, 0) => format!("{}/{} harnesses", regular.passed, regular.total),
+        (0, _) => format!("{}/{} contract harnesses", contracts.passed, contracts.total),
+        _ => format!(
+            "{}/{} harnesses, {}/{} contract harnesses",
+            regular.passed, regular.total, contracts.passed, contracts.total
+        ),
+    };
+    let passed = regular.passed + contracts.passed;
+    let total = regular.total + contracts.total;
+    let color = if total == 0 {
+        BadgeColor::LightGrey
+    } else if passed == total {
+        BadgeColor::BrightGreen
+    } else {
+        BadgeColor::Red
+    };
+    Badge { schema_version: 1, label: LABEL, message, color }
+}
+
+/// Render the badge as a flat SVG image that looks like the shields.io ones.
+///
+/// The width of the text is approximated from the number of characters, since we don't have the
+/// font metrics.
+fn badge_svg(badge: &Badge) -> String {
+    let text_width = |text: &str| text.chars().count() * 7 + 10;
+    let label_width = text_width(badge.label);
+    let message_width = text_width(&badge.message);
+    let width = label_width + message_width;
+    let title = format!("{}: {}", badge.label, badge.message);
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{title}">
+  <title>{title}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{message_width}" height="20" fill="{color}"/>
+    <rect width="{width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="14">{label}</text>
+    <text x="{message_x}" y="14">{message}</text>
+  </g>
+</svg>
+"##,
+        color = badge.color.hex(),
+        label = badge.label,
+        message = badge.message,
+        label_x = label_width / 2,
+        message_x = label_width + message_width / 2,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(passed: usize, total: usize) -> PassCount {
+        PassCount { passed, total }
+    }
+
+    #[test]
+    fn check_badge_message() {
+        let result = badge(count(34, 34), count(0, 0));
+        assert_eq!(result.message, "34/34 harnesses");
+        assert_eq!(result.color, BadgeColor::BrightGreen);
+
+        let result = badge(count(30, 30), count(3, 4));
+        assert_eq!(result.message, "30/30 harnesses, 3/4 contract harnesses");
+        assert_eq!(result.color, BadgeColor::Red);
+
+        let result = badge(count(0, 0), count(4, 4));
+        assert_eq!(result.message, "4/4 contract harnesses");
+
+        let result = badge(count(0, 0), count(0, 0));
+        assert_eq!(result.message, "no harnesses");
+        assert_eq!(result.color, BadgeColor::LightGrey);
+    }
+
+    #[test]
+    fn check_badge_json() {
+        let json = serde_json::to_value(badge(count(1, 2), count(0, 0))).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "schemaVersion": 1,
+                "label": "kani",
+                "message": "1/2 harnesses",
+                "color": "red",
+            })
+        );
+    }
+}
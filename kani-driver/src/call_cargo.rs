@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::args::VerificationArgs;
+use crate::args::common::{Verbosity, VerbosityLevel};
 use crate::call_single_file::LibConfig;
+use crate::feature_parity::{FeatureSet, add_built_features};
 use crate::project::Artifact;
 use crate::session::{
     KaniSession, get_cargo_path, lib_folder, lib_no_core_folder, setup_cargo_command,
@@ -35,6 +37,8 @@ pub struct CargoOutputs {
     pub metadata: Vec<Artifact>,
     /// Recording the cargo metadata from the build
     pub cargo_metadata: Metadata,
+    /// The features that each package was built with.
+    pub features: FeatureSet,
 }
 
 impl KaniSession {
@@ -208,6 +212,7 @@ pub fn cargo_build(&mut self, keep_going: bool) -> Result<CargoOutputs> {
         let mut found_target = false;
         let packages = self.packages_to_verify(&self.args, &metadata)?;
         let mut artifacts = vec![];
+        let mut features = FeatureSet::new();
         let mut failed_targets = vec![];
         for package in packages {
             for verification_target in package_targets(&self.args, package) {
@@ -224,7 +229,9 @@ pub fn cargo_build(&mut self, keep_going: bool) -> Result<CargoOutputs> {
                     .env("RUSTC_BOOTSTRAP", "1")
                     .env("CARGO_TERM_PROGRESS_WHEN", "never");
 
-                match self.run_build_target(cmd, verification_target.target()) {
+                match self.run_build_target(cmd, verification_target.target(), |built| {
+                    add_built_features(&mut features, &metadata, built)
+                }) {
                     Err(err) => {
                         if keep_going {
                             let target_str = format!("{verification_target}");
@@ -245,7 +252,7 @@ pub fn cargo_build(&mut self, keep_going: bool) -> Result<CargoOutputs> {
             bail!("No supported targets were found.");
         }
 
-        Ok(CargoOutputs { outdir, metadata: artifacts, cargo_metadata: metadata })
+        Ok(CargoOutputs { outdir, metadata: artifacts, cargo_metadata: metadata, features })
     }
 
     pub fn cargo_metadata(&self, build_target: &str) -> Result<Metadata> {
@@ -285,6 +292,7 @@ pub fn cargo_metadata(&self, build_target: &str) -> Result<Metadata> {
     fn run_build(&self, cargo_cmd: Command) -> Result<Vec<RustcArtifact>> {
         let support_color = std::io::stdout().is_terminal();
         let mut artifacts = vec![];
+        let program = cargo_cmd.get_program().to_string_lossy().to_string();
         let mut cargo_process = self.run_piped(cargo_cmd)?;
         let reader = BufReader::new(cargo_process.stdout.take().unwrap());
         let mut error_count = 0;
@@ -301,14 +309,15 @@ fn run_build(&self, cargo_cmd: Command) -> Result<Vec<RustcArtifact>> {
                     }
                     DiagnosticLevel::Ice => {
                         print_msg(&msg.message, support_color)?;
-                        let _ = cargo_process.wait();
+                        let status = cargo_process.wait().ok();
+                        self.emit_piped_exit(&program, status);
                         return Err(anyhow::Error::msg(msg.message).context(format!(
                             "Failed to compile `{}` due to an internal compiler error.",
                             msg.target.name
                         )));
                     }
                     _ => {
-                        if !self.args.common_args.quiet {
+                        if self.args.common_args.level() >= VerbosityLevel::Harness {
                             print_msg(&msg.message, support_color)?;
                         }
                     }
@@ -322,20 +331,21 @@ fn run_build(&self, cargo_cmd: Command) -> Result<Vec<RustcArtifact>> {
                     // do nothing
                 }
                 Message::TextLine(msg) => {
-                    if !self.args.common_args.quiet {
+                    if self.args.common_args.level() >= VerbosityLevel::Harness {
                         println!("{msg}");
                     }
                 }
 
                 // Non-exhaustive enum.
                 _ => {
-                    if !self.args.common_args.quiet {
+                    if self.args.common_args.level() >= VerbosityLevel::Harness {
                         println!("{message:?}");
                     }
                 }
             }
         }
         let status = cargo_process.wait()?;
+        self.emit_piped_exit(&program, Some(status));
         if !status.success() {
             bail!("Failed to execute cargo ({status}). Found {error_count} compilation errors.");
         }
@@ -344,7 +354,13 @@ fn run_build(&self, cargo_cmd: Command) -> Result<Vec<RustcArtifact>> {
 
     /// Run cargo and collect any error found.
     /// We also collect the metadata file generated during compilation if any for the given target.
-    fn run_build_target(&self, cargo_cmd: Command, target: &Target) -> Result<Option<Artifact>> {
+    /// All the artifacts that were built are passed to `on_built`.
+    fn run_build_target(
+        &self,
+        cargo_cmd: Command,
+        target: &Target,
+        on_built: impl FnOnce(&[RustcArtifact]),
+    ) -> Result<Option<Artifact>> {
         /// This used to be `rustc_artifact == *target`, but it
         /// started to fail after the `cargo` change in
         /// <https://github.com/rust-lang/cargo/pull/12783>
@@ -372,6 +388,7 @@ fn same_target(t1: &Target, t2: &Target) -> bool {
             println!("BUILT {} IN {:?}μs", target.name, compile_start.elapsed().as_micros());
         }
         debug!(?artifacts, "run_build_target");
+        on_built(&artifacts);
 
         // We generate kani specific artifacts only for the build target. The build target is
         // always the last artifact generated in a build, and all the other artifacts are related
@@ -396,10 +413,12 @@ fn to_package_ids<'a>(
                     cmd.arg(path);
                 }
                 cmd.arg(pkg);
+                let program = cmd.get_program().to_string_lossy().to_string();
                 // For some reason clippy cannot see that we are invoking wait() in the next line.
                 #[allow(clippy::zombie_processes)]
                 let mut process = self.run_piped(cmd)?;
                 let result = process.wait()?;
+                self.emit_piped_exit(&program, Some(result));
                 if !result.success() {
                     bail!("Failed to retrieve information for `{pkg}`");
                 }
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use anyhow::{Result, bail};
-use kani_metadata::{CbmcSolver, HarnessMetadata};
+use kani_metadata::{CbmcSolver, ExpectedFailure, HarnessMetadata};
 use regex::Regex;
 use rustc_demangle::demangle;
 use std::collections::BTreeMap;
@@ -15,14 +15,15 @@
 use strum_macros::Display;
 use tokio::process::Command as TokioCommand;
 
-use crate::args::common::Verbosity;
-use crate::args::{OutputFormat, VerificationArgs};
+use crate::args::common::{Verbosity, VerbosityLevel};
+use crate::args::{OutputFormat, TraceFilter, VerificationArgs};
 use crate::cbmc_output_parser::{
     CheckStatus, Property, VerificationOutput, extract_results, process_cbmc_output,
 };
 use crate::cbmc_property_renderer::{format_coverage, format_result, kani_cbmc_output_filter};
 use crate::coverage::cov_results::{CoverageCheck, CoverageResults};
 use crate::coverage::cov_results::{CoverageRegion, CoverageTerm};
+use crate::event_log::Event;
 use crate::session::KaniSession;
 use crate::util::render_command;
 
@@ -57,6 +58,18 @@ pub enum ExitStatus {
     Other(i32),
 }
 
+/// The outcome of a harness that is expected to fail, i.e., annotated with
+/// `#[kani::proof(expect_fail)]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpectFailOutcome {
+    /// The harness failed as expected, which counts as a success.
+    ExpectedFailure,
+    /// The harness failed, but none of its failed checks matches the expected property.
+    UnmatchedFailure(String),
+    /// The harness was verified successfully, which counts as a failure.
+    UnexpectedPass,
+}
+
 /// Our (kani-driver) notions of CBMC results.
 #[derive(Debug)]
 pub struct VerificationResult {
@@ -75,6 +88,8 @@ pub struct VerificationResult {
     pub generated_concrete_test: bool,
     /// The coverage results
     pub coverage_results: Option<CoverageResults>,
+    /// The outcome of the harness if it is expected to fail.
+    pub expect_fail: Option<ExpectFailOutcome>,
 }
 
 impl KaniSession {
@@ -113,10 +128,15 @@ async fn run_cbmc_piped(
             println!("[Kani] Running: `{}`", render_command(cmd.as_std()).to_string_lossy());
         }
         // Spawn the CBMC process and process its output below
-        let mut cbmc_process = cmd
-            .stdout(std::process::Stdio::piped())
-            .spawn()
-            .map_err(|_| anyhow::Error::msg("Failed to run cbmc"))?;
+        self.events.emit(Event::process_spawn(cmd.as_std()));
+        let program = cmd.as_std().get_program().to_string_lossy().to_string();
+        let mut cbmc_process = match cmd.stdout(std::process::Stdio::piped()).spawn() {
+            Ok(process) => process,
+            Err(_) => {
+                self.events.emit(Event::ProcessExit { program, exit_code: None, timed_out: false });
+                bail!("Failed to run cbmc");
+            }
+        };
 
         let start_time = Instant::now();
 
@@ -127,7 +147,8 @@ async fn run_cbmc_piped(
                     kani_cbmc_output_filter(
                         i,
                         self.args.extra_pointer_checks,
-                        self.args.common_args.quiet,
+                        &self.args.property_class,
+                        self.args.common_args.level() < VerbosityLevel::Harness,
                         &self.args.output_format,
                     )
                 }),
@@ -138,7 +159,8 @@ async fn run_cbmc_piped(
                 kani_cbmc_output_filter(
                     i,
                     self.args.extra_pointer_checks,
-                    self.args.common_args.quiet,
+                    &self.args.property_class,
+                    self.args.common_args.level() < VerbosityLevel::Harness,
                     &self.args.output_format,
                 )
             })
@@ -150,6 +172,7 @@ async fn run_cbmc_piped(
 
             // Kill the process
             cbmc_process.kill().await?;
+            self.events.emit(Event::ProcessExit { program, exit_code: None, timed_out: true });
 
             VerificationResult {
                 status: VerificationStatus::Failure,
@@ -158,11 +181,20 @@ async fn run_cbmc_piped(
                 runtime: start_time.elapsed(),
                 generated_concrete_test: false,
                 coverage_results: None,
+                expect_fail: None,
             }
         } else {
             // The timeout wasn't reached
-            let output = res.unwrap()?;
-            VerificationResult::from(output, harness.attributes.should_panic, start_time)
+            let output = res.unwrap();
+            let exit_code = output.as_ref().ok().map(|output| output.process_status);
+            self.events.emit(Event::ProcessExit { program, exit_code, timed_out: false });
+            let output = output?;
+            let mut result =
+                VerificationResult::from(output, harness.attributes.should_panic, start_time);
+            if let Some(expected) = &harness.attributes.expect_fail {
+                result.check_expected_failure(expected);
+            }
+            result
         };
 
         Ok(verification_results)
@@ -174,7 +206,13 @@ pub fn cbmc_flags(
         file: &Path,
         harness_metadata: &HarnessMetadata,
     ) -> Result<Vec<OsString>> {
-        let mut args = self.cbmc_check_flags();
+        let mut args = if self.args.aggressive_slicing {
+            // Property checks were already instrumented before slicing the goto program.
+            // See `KaniSession::aggressive_slicing`.
+            self.sliced_model_check_flags()
+        } else {
+            self.cbmc_check_flags()
+        };
 
         if let Some(object_bits) = self.args.cbmc_object_bits() {
             args.push("--object-bits".into());
@@ -186,6 +224,18 @@ pub fn cbmc_flags(
             args.push(unwind_value.to_string().into());
         }
 
+        // The bounds of loops annotated with `#[kani::loop_unwind]` override the harness value.
+        if !harness_metadata.loop_unwind_bounds.is_empty() {
+            let unwindset = harness_metadata
+                .loop_unwind_bounds
+                .iter()
+                .map(|loop_bound| format!("{}:{}", loop_bound.loop_id, loop_bound.bound))
+                .collect::<Vec<_>>()
+                .join(",");
+            args.push("--unwindset".into());
+            args.push(unwindset.into());
+        }
+
         self.handle_solver_args(&harness_metadata.attributes.solver, &mut args)?;
 
         if self.args.run_sanity_checks {
@@ -197,7 +247,13 @@ pub fn cbmc_flags(
             args.push("--slice-formula".into());
         }
 
-        if self.args.concrete_playback.is_some() {
+        // The trace of a failed precondition of a verified stub is needed to find its call site.
+        if self.args.concrete_playback.is_some()
+            || self.args.trace_filter.is_some()
+            || self.args.trace_depth_limit.is_some()
+            || (self.args.precondition_call_sites
+                && !harness_metadata.attributes.verified_stubs.is_empty())
+        {
             args.push("--trace".into());
         }
 
@@ -246,11 +302,7 @@ pub fn cbmc_check_flags(&self) -> Vec<OsString> {
             args.push("--no-div-by-zero-check".into());
         }
 
-        if !self.args.checks.unwinding_on() {
-            args.push("--no-unwinding-assertions".into());
-        } else {
-            args.push("--no-self-loops-to-assumptions".into());
-        }
+        args.extend(self.unwinding_check_flags());
 
         if self.args.extra_pointer_checks {
             // This was adding a lot of false positives with std dangling pointer. We should
@@ -264,21 +316,66 @@ pub fn cbmc_check_flags(&self) -> Vec<OsString> {
         args
     }
 
-    pub fn handle_solver_args(
-        &self,
-        harness_solver: &Option<CbmcSolver>,
-        args: &mut Vec<OsString>,
-    ) -> Result<()> {
-        let solver = if let Some(solver) = &self.args.solver {
+    /// The flags that control CBMC's unwinding assertions.
+    fn unwinding_check_flags(&self) -> Vec<OsString> {
+        if !self.args.checks.unwinding_on() {
+            vec!["--no-unwinding-assertions".into()]
+        } else {
+            vec!["--no-self-loops-to-assumptions".into()]
+        }
+    }
+
+    /// The flags that instrument the same property checks as [Self::cbmc_check_flags] using
+    /// `goto-instrument`, which, unlike CBMC, doesn't enable any check by default.
+    pub fn goto_instrument_check_flags(&self) -> Vec<OsString> {
+        let mut args: Vec<OsString> = Vec::new();
+        if self.args.checks.memory_safety_on() {
+            args.push("--bounds-check".into());
+            args.push("--pointer-check".into());
+        }
+        if self.args.checks.overflow_on() {
+            args.push("--div-by-zero-check".into());
+            args.push("--nan-check".into());
+        }
+        if self.args.extra_pointer_checks {
+            args.push("--pointer-primitive-check".into());
+            args.push("--pointer-overflow-check".into());
+        }
+        args
+    }
+
+    /// The check flags for CBMC when the property checks were already instrumented in the goto
+    /// program, so CBMC must not add them a second time.
+    fn sliced_model_check_flags(&self) -> Vec<OsString> {
+        let mut args: Vec<OsString> =
+            vec!["--no-standard-checks".into(), "--no-malloc-may-fail".into()];
+        // Unwinding assertions are added by CBMC during symbolic execution, and they are part of
+        // the standard checks that we just disabled.
+        if self.args.checks.unwinding_on() {
+            args.push("--unwinding-assertions".into());
+        }
+        args.extend(self.unwinding_check_flags());
+        args
+    }
+
+    /// The solver used for a harness with the given `#[kani::solver]` attribute.
+    pub fn solver<'a>(&'a self, harness_solver: &'a Option<CbmcSolver>) -> &'a CbmcSolver {
+        if let Some(solver) = &self.args.solver {
             // `--solver` option takes precedence over attributes
             solver
         } else if let Some(solver) = harness_solver {
             solver
         } else {
             &DEFAULT_SOLVER
-        };
+        }
+    }
 
-        match solver {
+    pub fn handle_solver_args(
+        &self,
+        harness_solver: &Option<CbmcSolver>,
+        args: &mut Vec<OsString>,
+    ) -> Result<()> {
+        match self.solver(harness_solver) {
             CbmcSolver::Bitwuzla => {
                 args.push("--bitwuzla".into());
             }
@@ -313,6 +410,18 @@ pub fn handle_solver_args(
     }
 }
 
+/// The executable of an external solver, or `None` if the solver is built into CBMC.
+pub fn solver_binary(solver: &CbmcSolver) -> Option<&str> {
+    match solver {
+        CbmcSolver::Bitwuzla => Some("bitwuzla"),
+        CbmcSolver::Cvc5 => Some("cvc5"),
+        CbmcSolver::Kissat => Some("kissat"),
+        CbmcSolver::Z3 => Some("z3"),
+        CbmcSolver::Binary(solver_binary) => Some(solver_binary),
+        CbmcSolver::Cadical | CbmcSolver::Minisat => None,
+    }
+}
+
 impl VerificationResult {
     /// Computes a `VerificationResult` (kani-driver's notion of the result of a CBMC call) from a
     /// `VerificationOutput` (cbmc_output_parser's idea of CBMC results).
@@ -341,6 +450,7 @@ fn from(
                 runtime,
                 generated_concrete_test: false,
                 coverage_results,
+                expect_fail: None,
             }
         } else {
             // We never got results from CBMC - something went wrong (e.g. crash) so it's failure
@@ -356,10 +466,36 @@ fn from(
                 runtime,
                 generated_concrete_test: false,
                 coverage_results: None,
+                expect_fail: None,
             }
         }
     }
 
+    /// Invert the status of a harness that is expected to fail: it succeeds if at least one of its
+    /// checks failed, and one of them matches the expected property if there is one.
+    fn check_expected_failure(&mut self, expected: &ExpectedFailure) {
+        let Ok(results) = &self.results else { return };
+        let failed: Vec<&Property> = results
+            .iter()
+            .filter(|prop| prop.status == CheckStatus::Failure && !prop.is_code_coverage_property())
+            .collect();
+        let outcome = if failed.is_empty() {
+            ExpectFailOutcome::UnexpectedPass
+        } else if let Some(property) = &expected.property
+            && !failed.iter().any(|prop| prop.description.contains(property.as_str()))
+        {
+            ExpectFailOutcome::UnmatchedFailure(property.clone())
+        } else {
+            ExpectFailOutcome::ExpectedFailure
+        };
+        self.status = if outcome == ExpectFailOutcome::ExpectedFailure {
+            VerificationStatus::Success
+        } else {
+            VerificationStatus::Failure
+        };
+        self.expect_fail = Some(outcome);
+    }
+
     pub fn mock_success() -> VerificationResult {
         VerificationResult {
             status: VerificationStatus::Success,
@@ -368,6 +504,7 @@ pub fn mock_success() -> VerificationResult {
             runtime: Duration::from_secs(0),
             generated_concrete_test: false,
             coverage_results: None,
+            expect_fail: None,
         }
     }
 
@@ -382,10 +519,17 @@ fn mock_failure() -> VerificationResult {
             runtime: Duration::from_secs(0),
             generated_concrete_test: false,
             coverage_results: None,
+            expect_fail: None,
         }
     }
 
-    pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String {
+    pub fn render(
+        &self,
+        output_format: &OutputFormat,
+        should_panic: bool,
+        trace_filter: Option<&TraceFilter>,
+        trace_depth_limit: Option<u32>,
+    ) -> String {
         match &self.results {
             Ok(results) => {
                 let status = self.status;
@@ -398,11 +542,23 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
                         cov_results,
                         status,
                         should_panic,
+                        self.expect_fail.as_ref(),
                         failed_properties,
                         show_checks,
+                        trace_filter,
+                        trace_depth_limit,
                     )
                 } else {
-                    format_result(results, status, should_panic, failed_properties, show_checks)
+                    format_result(
+                        results,
+                        status,
+                        should_panic,
+                        self.expect_fail.as_ref(),
+                        failed_properties,
+                        show_checks,
+                        trace_filter,
+                        trace_depth_limit,
+                    )
                 };
                 writeln!(result, "Verification Time: {}s", self.runtime.as_secs_f32()).unwrap();
                 result
@@ -463,10 +619,12 @@ fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
     if failed_properties.is_empty() {
         FailedProperties::None
     } else {
-        // Check if all failed properties correspond to the `assertion` class.
+        // Check if all failed properties correspond to the `assertion` class, or to the
+        // `precondition` class of verified stubs, which behaves the same.
         // Note: Panics caused by `panic!` and `assert!` fall into this class.
-        let all_failed_checks_are_panics =
-            failed_properties.iter().all(|prop| prop.property_class() == "assertion");
+        let all_failed_checks_are_panics = failed_properties
+            .iter()
+            .all(|prop| matches!(prop.property_class().as_str(), "assertion" | "precondition"));
         if all_failed_checks_are_panics {
             FailedProperties::PanicsOnly
         } else {
@@ -1,18 +1,19 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use std::ffi::{OsStr, OsString};
 use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
 use std::process::Command;
 
+use crate::args::common::{Verbosity, VerbosityLevel};
 use crate::metadata::collect_and_link_function_pointer_restrictions;
 use crate::project::Project;
-use crate::session::KaniSession;
-use crate::util::alter_extension;
+use crate::session::{KaniSession, harness_file};
 use kani_metadata::{ArtifactType, HarnessMetadata};
+use tracing::debug;
 
 impl KaniSession {
     /// Instrument and optimize a goto binary in-place.
@@ -54,21 +55,25 @@ pub fn instrument_model(
 
         self.rewrite_back_edges(output)?;
 
+        if self.args.aggressive_slicing {
+            self.aggressive_slicing(output)?;
+        }
+
         if self.args.gen_c {
-            let c_outfile = alter_extension(output, "c");
+            let c_outfile = harness_file(output, "c");
             // We don't put the C file into temporaries to be deleted.
 
             self.gen_c(output, &c_outfile)?;
 
-            if !self.args.common_args.quiet {
+            if self.args.common_args.level() >= VerbosityLevel::Harness {
                 println!("Generated C code written to {}", c_outfile.to_string_lossy());
             }
 
-            let c_demangled = alter_extension(output, "demangled.c");
+            let c_demangled = harness_file(output, "demangled.c");
             let prett_name_map =
                 project.get_harness_artifact(harness, ArtifactType::PrettyNameMap).unwrap();
             self.demangle_c(prett_name_map, &c_outfile, &c_demangled)?;
-            if !self.args.common_args.quiet {
+            if self.args.common_args.level() >= VerbosityLevel::Harness {
                 println!("Demangled GotoC code written to {}", c_demangled.to_string_lossy())
             }
         }
@@ -78,8 +83,8 @@ pub fn instrument_model(
 
     /// Apply -Z restrict-vtable to a goto binary.
     pub fn apply_vtable_restrictions(&self, goto_file: &Path, restrictions: &Path) -> Result<()> {
-        let linked_restrictions = alter_extension(goto_file, "linked-restrictions.json");
-        self.record_temporary_file(&linked_restrictions);
+        let linked_restrictions =
+            self.harness_temporary_file(goto_file, "linked-restrictions.json");
         collect_and_link_function_pointer_restrictions(restrictions, &linked_restrictions)?;
 
         let args: Vec<OsString> = vec![
@@ -148,6 +153,56 @@ fn rewrite_back_edges(&self, file: &Path) -> Result<()> {
         self.call_goto_instrument(args)
     }
 
+    /// Instrument the property checks and remove every instruction that cannot reach a property.
+    ///
+    /// CBMC would only add its property checks when solving, so we instrument them here first to
+    /// ensure that the slicing doesn't remove any code that may violate them. CBMC is then
+    /// invoked without its standard checks (see `KaniSession::cbmc_flags`).
+    ///
+    /// The reachability slicer only removes instructions from which no property is reachable in
+    /// the control flow graph, so every `kani::any()` call executed before a property failure is
+    /// preserved, and concrete playback keeps working on the sliced program.
+    fn aggressive_slicing(&self, file: &Path) -> Result<()> {
+        let before = self.args.common_args.verbose().then(|| self.count_instructions(file));
+
+        let mut args = self.goto_instrument_check_flags();
+        args.push("--reachability-slice".into());
+        args.push(file.to_owned().into_os_string()); // input
+        args.push(file.to_owned().into_os_string()); // output
+        self.call_goto_instrument(args)?;
+
+        if let Some(before) = before {
+            match (before, self.count_instructions(file)) {
+                (Ok(before), Ok(after)) => {
+                    let removed = before.saturating_sub(after);
+                    let percentage =
+                        if before == 0 { 0.0 } else { removed as f64 * 100.0 / before as f64 };
+                    println!(
+                        "Aggressive slicing removed {removed} of {before} instructions \
+                        ({percentage:.1}%) from {}",
+                        file.display()
+                    );
+                }
+                (Err(err), _) | (_, Err(err)) => {
+                    debug!(?err, "Failed to count the instructions of the goto program")
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Count the instructions of every function in a goto binary.
+    fn count_instructions(&self, file: &Path) -> Result<usize> {
+        let output = Command::new("goto-instrument")
+            .args([OsStr::new("--show-goto-functions"), OsStr::new("--json-ui"), file.as_os_str()])
+            .output()?;
+        if !output.status.success() {
+            bail!("`goto-instrument --show-goto-functions` failed on `{}`", file.display());
+        }
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        Ok(count_json_instructions(&json))
+    }
+
     fn goto_sanity_check(&self, file: &Path) -> Result<()> {
         let args: Vec<OsString> = vec![
             "--validate-goto-model".into(),
@@ -252,3 +307,39 @@ fn call_goto_instrument<S: AsRef<OsStr>>(
         self.run_suppress(cmd)
     }
 }
+
+/// Count the entries of every `instructions` array in the JSON output of
+/// `goto-instrument --show-goto-functions --json-ui`.
+fn count_json_instructions(value: &serde_json::Value) -> usize {
+    match value {
+        serde_json::Value::Array(items) => items.iter().map(count_json_instructions).sum(),
+        serde_json::Value::Object(fields) => fields
+            .iter()
+            .map(|(key, value)| match value {
+                serde_json::Value::Array(instructions) if key == "instructions" => {
+                    instructions.len()
+                }
+                _ => count_json_instructions(value),
+            })
+            .sum(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_count_json_instructions() {
+        let output = serde_json::json!([
+            { "program": "goto-instrument" },
+            { "functions": [
+                { "name": "main", "instructions": [{ "instructionId": "1" }, { "instructionId": "2" }] },
+                { "name": "foo", "instructions": [{ "instructionId": "1" }] },
+                { "name": "bar", "instructions": [] },
+            ] },
+        ]);
+        assert_eq!(count_json_instructions(&output), 3);
+    }
+}
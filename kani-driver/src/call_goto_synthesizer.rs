@@ -1,6 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::args::common::{Verbosity, VerbosityLevel};
 use crate::util::warning;
 use anyhow::Result;
 use kani_metadata::HarnessMetadata;
@@ -21,7 +22,7 @@ pub fn synthesize_loop_contracts(
         output: &Path,
         harness_metadata: &HarnessMetadata,
     ) -> Result<()> {
-        if !self.args.common_args.quiet {
+        if self.args.common_args.level() >= VerbosityLevel::Harness {
             println!("Running loop contract synthesizer.");
             warning("This process may not terminate.");
             warning(
@@ -2,10 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use anyhow::Result;
-use kani_metadata::UnstableFeature;
+use kani_metadata::{AssertionSemantics, UnstableFeature};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::args::common::{Verbosity, VerbosityLevel};
 use crate::session::{KaniSession, lib_folder};
 use crate::util::args::{CommandWrapper, KaniArg, PassTo, RustcArg, encode_as_rustc_arg};
 
@@ -86,7 +87,7 @@ pub fn compile_single_rust_file(
         // This is only required for stable but is a no-op for nightly channels
         cmd.env("RUSTC_BOOTSTRAP", "1");
 
-        if self.args.common_args.quiet {
+        if self.args.common_args.level() < VerbosityLevel::Harness {
             self.run_suppress(cmd)?;
         } else {
             self.run_terminal(cmd)?;
@@ -131,6 +132,12 @@ pub fn kani_compiler_local_flags(&self) -> Vec<KaniArg> {
         if self.args.assertion_reach_checks() {
             flags.push("--assertion-reach-checks".into());
         }
+        if self.args.no_fmt_args() {
+            flags.push("--no-fmt-args".into());
+        }
+        for krate in &self.args.abstract_crate {
+            flags.push(format!("--abstract-crate={krate}").into());
+        }
 
         if self.args.is_stubbing_enabled() {
             flags.push("--enable-stubbing".into());
@@ -140,15 +147,28 @@ pub fn kani_compiler_local_flags(&self) -> Vec<KaniArg> {
             flags.push("--coverage-checks".into());
         }
 
+        if !self.args.checks.pointer_offset_on() {
+            flags.push("--no-pointer-offset-checks".into());
+        }
+        for class in &self.args.property_class {
+            flags.push(format!("--property-class={class}").into());
+        }
+
         if self.args.common_args.unstable_features.contains(UnstableFeature::ValidValueChecks) {
             flags.push("--ub-check=validity".into())
         }
 
-        if self.args.common_args.unstable_features.contains(UnstableFeature::UninitChecks) {
+        if self.args.checks.check_uninit
+            || self.args.common_args.unstable_features.contains(UnstableFeature::UninitChecks)
+        {
             // Automatically enable shadow memory, since the version of uninitialized memory checks
             // without non-determinism depends on it.
             flags.push("-Z ghost-state".into());
             flags.push("--ub-check=uninit".into());
+        } else if self.args.common_args.unstable_features.contains(UnstableFeature::VerifyUnsafe) {
+            // The compiler enables the uninitialized memory checks for the harnesses of
+            // `#[kani::verify_unsafe]` functions, which also depend on shadow memory.
+            flags.push("-Z ghost-state".into());
         }
 
         if self.args.common_args.unstable_features.contains(UnstableFeature::Lean) {
@@ -163,6 +183,10 @@ pub fn kani_compiler_local_flags(&self) -> Vec<KaniArg> {
             flags.push("--no-assert-contracts".into());
         }
 
+        if self.args.contract_negative {
+            flags.push("--contract-negative".into());
+        }
+
         for harness in &self.args.harnesses {
             flags.push(format!("--harness {harness}").into());
         }
@@ -179,6 +203,38 @@ pub fn kani_compiler_local_flags(&self) -> Vec<KaniArg> {
             flags.push("--prove-safety-only".into());
         }
 
+        if self.metadata_only {
+            flags.push("-Z metadata-only".into());
+        }
+
+        if self.list_mutants {
+            flags.push("--list-mutants".into());
+        }
+
+        if self.args.emit_reachable.is_some() {
+            flags.push("--emit-reachable".into());
+        }
+
+        if self.args.dump_symtab.is_some() {
+            flags.push("--dump-symtab".into());
+        }
+
+        if self.args.assertion_semantics != AssertionSemantics::default() {
+            flags.push(format!("--assertion-semantics={}", self.args.assertion_semantics).into());
+        }
+
+        if let Some(max) = self.args.max_nondet_bytes {
+            flags.push(format!("--max-nondet-bytes={max}").into());
+        }
+
+        if self.args.common_args.verbose() {
+            flags.push("--nondet-bytes-stats".into());
+        }
+
+        if let Some(mutant) = &self.mutant {
+            flags.push(format!("--mutant {mutant}").into());
+        }
+
         flags.extend(self.args.common_args.unstable_features.as_arguments().map(KaniArg::from));
 
         flags
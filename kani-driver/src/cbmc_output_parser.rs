@@ -17,25 +17,31 @@
 //! ```
 //!
 //! The parser included in this file reads from buffered input line by line, and
-//! determines if an item can be processed after reading certain lines.
+//! determines if an item can be processed after reading certain lines. The shape of
+//! the items is checked as they are parsed (see `parse_item`), and records that don't
+//! have the expected shape are reported as warnings instead of aborting.
 //!
 //! The rest of code in this file is related to result postprocessing.
 
 // NOTE: This module should be entirely "about" CBMC, so we should need to import
 // anything from other modules of this crate, these should only be std + dependencies.
-use anyhow::Result;
+use anyhow::{Context, Result};
 use console::style;
 use pathdiff::diff_paths;
 use rustc_demangle::demangle;
+use serde::de::{DeserializeOwned, Error as _};
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
+use std::collections::VecDeque;
 use std::env;
 use std::os::unix::process::ExitStatusExt;
 use std::path::PathBuf;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, ChildStdout};
 
-const RESULT_ITEM_PREFIX: &str = "  {\n    \"result\":";
+/// The maximum number of characters of a record quoted in a warning.
+const MAX_QUOTED_RECORD_LEN: usize = 200;
 
 /// A parser item is a top-level unit of output from the CBMC json format.
 /// See the parser for more information on how they are processed.
@@ -59,17 +65,6 @@ pub enum ParserItem {
     },
 }
 
-/// Struct that is equivalent to `ParserItem::Result`.
-///
-/// Note: this struct is only used to provide better error messages when there
-/// are issues deserializing a `ParserItem::Result`. See `Parser::parse_item`
-/// for more details.
-#[allow(unused)]
-#[derive(Debug, Deserialize)]
-struct ResultStruct {
-    result: Vec<Property>,
-}
-
 /// Struct that represents a single property in the set of CBMC results.
 ///
 /// Note: `reach` is not part of the parsed data, but it's useful to annotate
@@ -97,6 +92,7 @@ pub struct PropertyId {
 impl Property {
     const COVER_PROPERTY_CLASS: &'static str = "cover";
     const COVERAGE_PROPERTY_CLASS: &'static str = "code_coverage";
+    const PRECONDITION_PROPERTY_CLASS: &'static str = "precondition";
 
     pub fn property_class(&self) -> String {
         self.property_id.class.clone()
@@ -112,6 +108,11 @@ pub fn is_cover_property(&self) -> bool {
         self.property_id.class == Self::COVER_PROPERTY_CLASS
     }
 
+    /// Returns true if this is the precondition of a function replaced by its contract
+    pub fn is_precondition_property(&self) -> bool {
+        self.property_id.class == Self::PRECONDITION_PROPERTY_CLASS
+    }
+
     pub fn property_name(&self) -> String {
         let class = &self.property_id.class;
         let id = self.property_id.id;
@@ -194,21 +195,20 @@ fn deserialize<D>(d: D) -> Result<Self, D::Error>
                 }
             }
             // The case we don't expect. It's best to fail with an informative message.
-            _ => unreachable!("Found property which doesn't have 2 or 3 attributes"),
+            _ => {
+                return Err(D::Error::custom(format!(
+                    "found property `{id_str}` which doesn't have 2 or 3 attributes"
+                )));
+            }
         };
-        // Do more sanity checks, just in case.
-        assert!(
-            attributes_tuple.2.chars().all(|c| c.is_ascii_digit()),
-            "Found property counter that doesn't match number format"
-        );
-        // Return tuple after converting counter from string into number.
-        // Safe to do because we've checked the format earlier.
+        // Convert the counter from string into number.
+        let id = attributes_tuple.2.parse().map_err(|_| {
+            D::Error::custom(format!(
+                "found property `{id_str}` whose counter doesn't match number format"
+            ))
+        })?;
         let class = String::from(attributes_tuple.1);
-        Ok(PropertyId {
-            fn_name: attributes_tuple.0,
-            class,
-            id: attributes_tuple.2.parse().unwrap(),
-        })
+        Ok(PropertyId { fn_name: attributes_tuple.0, class, id })
     }
 }
 
@@ -216,7 +216,7 @@ fn deserialize<D>(d: D) -> Result<Self, D::Error>
 ///
 /// Source locations may be completely empty, which is why
 /// all members are optional.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize)]
 pub struct SourceLocation {
     pub column: Option<String>,
     pub file: Option<String>,
@@ -288,6 +288,9 @@ fn filepath(file: String) -> String {
 #[serde(rename_all = "camelCase")]
 pub struct TraceItem {
     pub step_type: String,
+    /// Whether CBMC considers this step an internal detail that shouldn't be shown to users.
+    #[serde(default)]
+    pub hidden: bool,
     pub lhs: Option<String>,
     pub source_location: Option<SourceLocation>,
     pub value: Option<TraceValue>,
@@ -362,6 +365,131 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
+/// A version of CBMC, as reported in the `Program` item at the start of its output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CbmcVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl CbmcVersion {
+    /// Parse the version from the program description, e.g., `CBMC 6.4.1 (cbmc-6.4.1)`.
+    pub fn from_program(program: &str) -> Option<CbmcVersion> {
+        let version = program.strip_prefix("CBMC ")?.split_whitespace().next()?;
+        let mut numbers = version.split('.').map(|number| number.parse::<u32>().ok());
+        let major = numbers.next()??;
+        let minor = numbers.next()??;
+        let patch = numbers.next().unwrap_or(Some(0))?;
+        Some(CbmcVersion { major, minor, patch })
+    }
+}
+
+impl std::fmt::Display for CbmcVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The major version of the newest CBMC release whose output Kani understands.
+const LATEST_CBMC_MAJOR: u32 = 6;
+
+/// Check the version of CBMC reported by the program item at the start of the output. Returns a
+/// warning if the version is unknown or newer than the ones Kani supports.
+fn check_cbmc_version(program: &str) -> Option<String> {
+    match CbmcVersion::from_program(program) {
+        Some(version) if version.major <= LATEST_CBMC_MAJOR => None,
+        Some(version) => Some(format!(
+            "CBMC {version} is newer than the versions supported by Kani, so its output may only \
+            be partially understood"
+        )),
+        None => Some(format!("failed to determine the version of CBMC from `{program}`")),
+    }
+}
+
+/// Convert a top-level record of the output into a `ParserItem`. Returns `None` if the record
+/// doesn't have the shape of any item.
+///
+/// Records that don't have the expected shape don't abort the parsing: they are ignored, or
+/// replaced by checks with an `UNDETERMINED` status, and a warning that names them is added to
+/// `warnings`.
+fn parse_item(record: Value, warnings: &mut Vec<String>) -> Option<ParserItem> {
+    if let Some(checks) = record.get("result") {
+        let Some(checks) = checks.as_array() else {
+            warnings.push("ignoring the results of CBMC, which aren't a list of checks".into());
+            return None;
+        };
+        let result = checks
+            .iter()
+            .enumerate()
+            .map(|(idx, check)| parse_property(idx, check, warnings))
+            .collect();
+        return Some(ParserItem::Result { result });
+    }
+    match ParserItem::deserialize(&record) {
+        Ok(item) => Some(item),
+        Err(_) => {
+            let record = quote_record(&record.to_string());
+            warnings.push(format!("ignoring unexpected record in CBMC's output: {record}"));
+            None
+        }
+    }
+}
+
+/// Convert the `idx`-th check of the results. Malformed checks are kept with an `UNDETERMINED`
+/// status, so they can't make the verification succeed.
+fn parse_property(idx: usize, check: &Value, warnings: &mut Vec<String>) -> Property {
+    match Property::deserialize(check) {
+        Ok(property) => property,
+        Err(error) => {
+            let name = check
+                .get("property")
+                .and_then(Value::as_str)
+                .map_or_else(|| format!("#{idx}"), |name| format!("`{name}`"));
+            warnings.push(format!(
+                "the check {name} in CBMC's output is malformed ({error}), so it's reported as \
+                UNDETERMINED"
+            ));
+            Property::malformed(idx, check)
+        }
+    }
+}
+
+impl Property {
+    /// Build an `UNDETERMINED` check for the `idx`-th check of the results, which couldn't be
+    /// parsed, from the fields that could be parsed.
+    fn malformed(idx: usize, check: &Value) -> Property {
+        Property {
+            description: field(check, "description")
+                .unwrap_or_else(|| String::from("malformed check in CBMC's output")),
+            property_id: field(check, "property").unwrap_or_else(|| PropertyId {
+                fn_name: None,
+                class: String::from("malformed"),
+                id: idx as u32,
+            }),
+            source_location: field(check, "sourceLocation").unwrap_or_default(),
+            status: CheckStatus::Undetermined,
+            reach: None,
+            trace: None,
+        }
+    }
+}
+
+/// Quote a record in a warning, truncating it if it's too long.
+fn quote_record(record: &str) -> String {
+    if record.chars().count() > MAX_QUOTED_RECORD_LEN {
+        let prefix: String = record.chars().take(MAX_QUOTED_RECORD_LEN).collect();
+        format!("`{prefix}...`")
+    } else {
+        format!("`{record}`")
+    }
+}
+
+/// Deserialize the field `name` of `record`, if it has the expected shape.
+fn field<T: DeserializeOwned>(record: &Value, name: &str) -> Option<T> {
+    record.get(name).and_then(|value| T::deserialize(value).ok())
+}
+
 #[derive(PartialEq)]
 enum Action {
     ClearInput,
@@ -379,18 +507,21 @@ enum Action {
 ///     our postprocessing, this is not used.
 ///
 /// The parser reads the output line by line. A line may trigger one action, and
-/// the action may return a parsed item.
+/// the action may produce parsed items, which are queued until they are read.
+/// Problems with the output are reported as warning messages in the same queue.
 ///
 /// There is a feature request for serde_json which would obsolete this if
 /// it ever lands: <https://github.com/serde-rs/json/issues/404>
 /// (Would provide a streaming iterator over a json array.)
 struct Parser {
     pub input_so_far: String,
+    /// The items that were parsed but not read yet.
+    pending: VecDeque<ParserItem>,
 }
 
 impl Parser {
     fn new() -> Self {
-        Parser { input_so_far: String::new() }
+        Parser { input_so_far: String::new(), pending: VecDeque::new() }
     }
 
     /// Triggers an action based on the input:
@@ -402,11 +533,9 @@ fn new() -> Self {
     ///    that we'll always get an item when we attempt to process an item.
     ///
     /// This has be updated if the output format changes at some point.
-    fn triggers_action(&self, input: String) -> Option<Action> {
-        if input.starts_with('[') || input.starts_with(']') {
-            // We don't expect any other characters (except '\n') to appear
-            // after '[' or ']'. The assert below ensures we won't ignore them.
-            assert!(input.len() == 2);
+    fn triggers_action(&self, input: &str) -> Option<Action> {
+        // We don't expect any other characters (except '\n') to appear after '[' or ']'.
+        if matches!(input.trim_end(), "[" | "]") {
             return Some(Action::ClearInput);
         }
         if input.starts_with("  }") {
@@ -421,69 +550,77 @@ fn clear_input(&mut self) {
     }
 
     /// Performs an action. In both cases, the input is cleared.
-    fn do_action(&mut self, action: Action) -> Option<ParserItem> {
+    fn do_action(&mut self, action: Action) {
         match action {
             Action::ClearInput => {
+                if !self.input_so_far.trim_start_matches(['[', ']']).trim().is_empty() {
+                    let record = quote_record(self.input_so_far.trim());
+                    self.warn(format!("ignoring incomplete record in CBMC's output: {record}"));
+                }
                 self.clear_input();
-                None
             }
             Action::ProcessItem => {
-                let item = self.parse_item();
+                self.parse_item();
                 self.clear_input();
-                Some(item)
             }
         }
     }
 
     // Adds a string to the input accumulated so far
-    fn add_to_input(&mut self, input: String) {
-        self.input_so_far.push_str(&input);
-    }
-
-    // Returns a `ParserItem` from the input we have accumulated so far. Since
-    // all items except the last one are delimited (with a comma), we first try
-    // to parse the item without the delimiter (i.e., the last character). If
-    // that fails, then we parse the item using the whole input.
-    fn parse_item(&self) -> ParserItem {
-        let string_without_delimiter = &self.input_so_far[0..self.input_so_far.len() - 2];
-        let result_item: Result<ParserItem, _> = serde_json::from_str(string_without_delimiter);
-        if let Ok(item) = result_item {
-            return item;
+    fn add_to_input(&mut self, input: &str) {
+        self.input_so_far.push_str(input);
+    }
+
+    /// Queue a warning about the output.
+    fn warn(&mut self, msg: String) {
+        self.pending.push_back(ParserItem::Message {
+            message_text: format!("warning: {msg}"),
+            message_type: String::from("WARNING"),
+        });
+    }
+
+    // Parses the input we have accumulated so far, without the delimiter (a comma) that follows
+    // all items except the last one, and queues the resulting item along with the warnings about
+    // its shape.
+    fn parse_item(&mut self) {
+        let input = self.input_so_far.trim_end();
+        let input = input.strip_suffix(',').unwrap_or(input);
+        let record: Value = match serde_json::from_str(input) {
+            Ok(record) => record,
+            Err(error) => {
+                let record = quote_record(input);
+                let msg = format!("ignoring malformed record in CBMC's output ({error}): {record}");
+                self.warn(msg);
+                return;
+            }
+        };
+        let mut warnings = vec![];
+        let item = parse_item(record, &mut warnings);
+        if let Some(ParserItem::Program { program }) = &item {
+            warnings.extend(check_cbmc_version(program));
         }
-        // If we failed to parse a `ParserItem::Result` earlier, we will get
-        // this error message when we attempt to parse it using the complete
-        // string:
-        // ```
-        // thread '<unnamed>' panicked at 'called `Result::unwrap()` on an `Err` value:
-        // Error("data did not match any variant of untagged enum ParserItem", line: 0, column: 0)'
-        // ```
-        // This error message doesn't provide information about what went wrong
-        // while parsing due to `ParserItem` being an untagged enum. A more
-        // informative error message will be produced if we attempt to
-        // deserialize it into a struct. The attempt will still fail, but it
-        // shouldn't be hard to debug with that information. The same strategy
-        // can be used for other `ParserItem` variants, but they're normally
-        // easier to debug.
-        if string_without_delimiter.starts_with(RESULT_ITEM_PREFIX) {
-            let result_item: Result<ResultStruct, _> =
-                serde_json::from_str(string_without_delimiter);
-            result_item.unwrap();
+        self.pending.extend(item);
+        for warning in warnings {
+            self.warn(warning);
         }
-        let complete_string = &self.input_so_far[0..self.input_so_far.len()];
-        let result_item: Result<ParserItem, _> = serde_json::from_str(complete_string);
-        result_item.unwrap()
     }
 
     /// Processes a line to determine if an action must be triggered.
-    /// The action may result in a `ParserItem`, which is then returned.
-    fn process_line(&mut self, input: String) -> Option<ParserItem> {
-        self.add_to_input(input.clone());
-        let action_required = self.triggers_action(input);
-        if let Some(action) = action_required {
-            let possible_item = self.do_action(action);
-            return possible_item;
+    /// The action may queue parsed items.
+    fn process_line(&mut self, input: &str) {
+        self.add_to_input(input);
+        if let Some(action) = self.triggers_action(input) {
+            self.do_action(action);
+        }
+    }
+
+    /// Processes the end of the output, which shouldn't be in the middle of an item.
+    fn finish(&mut self) {
+        if !self.input_so_far.trim().is_empty() {
+            let record = quote_record(self.input_so_far.trim());
+            self.warn(format!("CBMC's output ended in the middle of a record: {record}"));
+            self.clear_input();
         }
-        None
     }
 
     /// Read the process output and return when an item is found in the output
@@ -491,25 +628,19 @@ fn process_line(&mut self, input: String) -> Option<ParserItem> {
     async fn read_output(
         &mut self,
         buffer: &mut BufReader<&mut ChildStdout>,
-    ) -> Option<ParserItem> {
+    ) -> Result<Option<ParserItem>> {
         loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Ok(Some(item));
+            }
             let mut input = String::new();
-            match buffer.read_line(&mut input).await {
-                Ok(len) => {
-                    if len == 0 {
-                        return None;
-                    }
-                    let item = self.process_line(input);
-                    if item.is_some() {
-                        return item;
-                    } else {
-                        continue;
-                    }
-                }
-                Err(error) => {
-                    panic!("Error: Got error {error} while parsing the output.");
-                }
+            let len =
+                buffer.read_line(&mut input).await.context("Failed to read the output of CBMC")?;
+            if len == 0 {
+                self.finish();
+                return Ok(self.pending.pop_front());
             }
+            self.process_line(&input);
         }
     }
 }
@@ -538,7 +669,7 @@ pub async fn process_cbmc_output(
     // This should run until stdout is closed (which should mean the process
     // exited) or the specified timeout is reached
     let mut processed_items = Vec::new();
-    while let Some(item) = parser.read_output(&mut stdout_reader).await {
+    while let Some(item) = parser.read_output(&mut stdout_reader).await? {
         if let Some(item) = eager_filter(item) {
             processed_items.push(item);
         }
@@ -730,12 +861,11 @@ fn check_property_id_deserialization_special_name() {
     }
 
     #[test]
-    #[should_panic]
-    fn check_property_id_deserialization_panics() {
+    fn check_property_id_deserialization_fails() {
         let prop_id_string = "\"not_a_property_ID\"";
         let prop_id_result: Result<PropertyId, serde_json::Error> =
             serde_json::from_str(prop_id_string);
-        let _prop_id = prop_id_result.unwrap();
+        assert!(prop_id_result.is_err());
     }
 
     #[test]
@@ -755,7 +885,7 @@ fn check_trace_value_deserialization_works() {
     }
 
     /// Checks that a valid CBMC "result" item can be deserialized into a
-    /// `ParserItem` or `ResultStruct`.
+    /// `ParserItem`, directly or through `parse_item`.
     #[test]
     fn check_result_deserialization_works() {
         let data = r#"{
@@ -774,8 +904,236 @@ fn check_result_deserialization_works() {
             ]
         }"#;
         let parser_item: Result<ParserItem, _> = serde_json::from_str(data);
-        let result_struct: Result<ResultStruct, _> = serde_json::from_str(data);
         assert!(parser_item.is_ok());
-        assert!(result_struct.is_ok());
+        let mut warnings = vec![];
+        let item = parse_item(serde_json::from_str(data).unwrap(), &mut warnings);
+        assert!(matches!(item, Some(ParserItem::Result { result }) if result.len() == 1));
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn check_property_id_deserialization_errors() {
+        let error = serde_json::from_str::<PropertyId>("\"not_a_property_ID\"").unwrap_err();
+        assert!(error.to_string().contains("doesn't have 2 or 3 attributes"));
+        let error = serde_json::from_str::<PropertyId>("\"foo.assertion.x1\"").unwrap_err();
+        assert!(error.to_string().contains("doesn't match number format"));
+    }
+
+    /// The output of CBMC for a harness with a failed assertion and a satisfied cover.
+    const OUTPUT: &str = r#"[
+  {
+    "program": "CBMC 6.4.1 (cbmc-6.4.1)"
+  },
+  {
+    "messageText": "CBMC version 6.4.1 (cbmc-6.4.1) 64-bit x86_64 linux",
+    "messageType": "STATUS-MESSAGE"
+  },
+  {
+    "result": [
+      {
+        "description": "assertion failed: x < 10",
+        "property": "harness.assertion.1",
+        "sourceLocation": {
+          "column": "5",
+          "file": "main.rs",
+          "function": "harness",
+          "line": "7"
+        },
+        "status": "FAILURE",
+        "trace": [
+          {
+            "hidden": false,
+            "lhs": "x",
+            "stepType": "assignment",
+            "value": {
+              "binary": "00001010",
+              "data": "10",
+              "width": 8
+            }
+          }
+        ]
+      },
+      {
+        "description": "cover condition: x == 1",
+        "property": "harness.cover.1",
+        "sourceLocation": {
+          "column": "5",
+          "file": "main.rs",
+          "function": "harness",
+          "line": "8"
+        },
+        "status": "SATISFIED"
+      }
+    ]
+  },
+  {
+    "cProverStatus": "failure"
+  }
+]
+"#;
+
+    /// Parse the output of CBMC line by line, as `read_output` does.
+    fn parse_output(output: &str) -> Vec<ParserItem> {
+        let mut parser = Parser::new();
+        for line in output.split_inclusive('\n') {
+            parser.process_line(line);
+        }
+        parser.finish();
+        parser.pending.into_iter().collect()
+    }
+
+    fn warnings(items: &[ParserItem]) -> Vec<&str> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                ParserItem::Message { message_text, message_type } if message_type == "WARNING" => {
+                    Some(message_text.as_str())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn results(items: &[ParserItem]) -> Vec<&Property> {
+        items
+            .iter()
+            .flat_map(|item| match item {
+                ParserItem::Result { result } => result.iter().collect(),
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn check_parse_output() {
+        let items = parse_output(OUTPUT);
+        assert_eq!(items.len(), 4);
+        assert!(warnings(&items).is_empty());
+        assert!(
+            matches!(&items[0], ParserItem::Program { program } if program.starts_with("CBMC"))
+        );
+        assert!(matches!(items[3], ParserItem::ProverStatus { .. }));
+        let statuses: Vec<_> = results(&items).iter().map(|property| property.status).collect();
+        assert_eq!(statuses, [CheckStatus::Failure, CheckStatus::Satisfied]);
+    }
+
+    #[test]
+    fn check_cbmc_version() {
+        let version = CbmcVersion::from_program("CBMC 6.4.1 (cbmc-6.4.1)").unwrap();
+        assert_eq!(version, CbmcVersion { major: 6, minor: 4, patch: 1 });
+        assert_eq!(version.to_string(), "6.4.1");
+        assert_eq!(
+            CbmcVersion::from_program("CBMC 5.95"),
+            Some(CbmcVersion { major: 5, minor: 95, patch: 0 })
+        );
+        assert_eq!(CbmcVersion::from_program("CBMC six"), None);
+        assert_eq!(CbmcVersion::from_program("ESBMC 7.0.0"), None);
+
+        assert_eq!(check_cbmc_version("CBMC 5.95.1 (cbmc-5.95.1)"), None);
+        assert_eq!(check_cbmc_version("CBMC 6.4.1 (cbmc-6.4.1)"), None);
+        let warning = check_cbmc_version("CBMC 7.0.0 (cbmc-7.0.0)");
+        assert!(warning.unwrap().contains("CBMC 7.0.0 is newer"));
+        assert!(check_cbmc_version("unknown").is_some());
+    }
+
+    /// The output of a newer version of CBMC is still parsed, with a warning.
+    #[test]
+    fn check_newer_version() {
+        let items = parse_output(&OUTPUT.replace("6.4.1", "7.0.0"));
+        let statuses: Vec<_> = results(&items).iter().map(|property| property.status).collect();
+        assert_eq!(statuses, [CheckStatus::Failure, CheckStatus::Satisfied]);
+        let messages = warnings(&items);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("CBMC 7.0.0 is newer"));
+    }
+
+    /// A malformed check is kept as an `UNDETERMINED` check with the fields that could be
+    /// parsed.
+    #[test]
+    fn check_malformed_check() {
+        let output = OUTPUT
+            .replace("\"FAILURE\"", "\"TIMEOUT\"")
+            .replace("\"width\": 8", "\"width\": \"8\"");
+        let items = parse_output(&output);
+        let properties = results(&items);
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0].status, CheckStatus::Undetermined);
+        assert_eq!(properties[0].description, "assertion failed: x < 10");
+        assert_eq!(properties[0].property_name(), "harness.assertion.1");
+        assert_eq!(properties[0].source_location.line.as_deref(), Some("7"));
+        assert_eq!(properties[1].status, CheckStatus::Satisfied);
+        let messages = warnings(&items);
+        assert_eq!(messages.len(), 1);
+        assert!(
+            messages[0].contains("the check `harness.assertion.1` in CBMC's output is malformed")
+        );
+    }
+
+    #[test]
+    fn check_unexpected_shapes() {
+        let records = [
+            r#"{"result": 5}"#,
+            r#"{"result": [1, "x", null, {"property": 3}]}"#,
+            r#"{"result": [{"property": "not_a_property_ID", "status": "SUCCESS"}]}"#,
+            r#"{"program": 3}"#,
+            r#"{"messageText": "text"}"#,
+            r#"{"unknown": "record"}"#,
+            r#""not an object""#,
+            r#"[]"#,
+        ];
+        for record in records {
+            let items = parse_output(&format!("[\n  {record}\n  }}\n]\n"));
+            assert!(!warnings(&items).is_empty(), "no warning for `{record}`");
+            assert!(results(&items).iter().all(|p| p.status == CheckStatus::Undetermined));
+        }
+    }
+
+    /// Every truncation of the output is parsed without panicking, and the truncation is
+    /// reported if it happens in the middle of the results.
+    #[test]
+    fn check_truncated_output() {
+        let results_start = OUTPUT.find("{\n    \"result\"").unwrap();
+        let results_end = results_start + OUTPUT[results_start..].find("\n  },").unwrap();
+        for len in 0..OUTPUT.len() {
+            let items = parse_output(&OUTPUT[..len]);
+            if len > results_start && len <= results_end {
+                assert!(!warnings(&items).is_empty(), "no warning for truncation at {len}");
+                assert!(results(&items).is_empty());
+            }
+        }
+    }
+
+    /// Random mutations of the output are parsed without panicking, and they are reported unless
+    /// they don't change the checks.
+    #[test]
+    fn check_mutated_output() {
+        const REPLACEMENTS: &[&str] =
+            &["{", "}", "[", "]", "\"", ",", ":", "0", "x", "\n", "  }", ""];
+        // A xorshift generator, so the mutations are reproducible.
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = |bound: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % bound as u64) as usize
+        };
+        for _ in 0..2000 {
+            let mut output = OUTPUT.to_string();
+            for _ in 0..1 + next(3) {
+                let start = next(output.len());
+                let end = (start + next(4)).min(output.len());
+                let replacement = REPLACEMENTS[next(REPLACEMENTS.len())];
+                output.replace_range(start..end, replacement);
+            }
+            let items = parse_output(&output);
+            let statuses: Vec<_> = results(&items).iter().map(|property| property.status).collect();
+            if warnings(&items).is_empty() {
+                assert_eq!(statuses, [CheckStatus::Failure, CheckStatus::Satisfied], "{output}");
+            } else {
+                let expected =
+                    [CheckStatus::Failure, CheckStatus::Satisfied, CheckStatus::Undetermined];
+                assert!(statuses.iter().all(|status| expected.contains(status)), "{output}");
+            }
+        }
     }
 }
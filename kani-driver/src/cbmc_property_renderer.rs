@@ -1,8 +1,8 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::args::OutputFormat;
-use crate::call_cbmc::{FailedProperties, VerificationStatus};
+use crate::args::{OutputFormat, TraceFilter};
+use crate::call_cbmc::{ExpectFailOutcome, FailedProperties, VerificationStatus};
 use crate::cbmc_output_parser::{CheckStatus, ParserItem, Property, TraceItem};
 use crate::coverage::cov_results::CoverageResults;
 use console::style;
@@ -153,6 +153,10 @@
 const UNWINDING_ASSERT_DESC: &str = "unwinding assertion loop";
 const UNWINDING_ASSERT_REC_DESC: &str = "recursion unwinding assertion";
 const UNDEFINED_FUNCTION_DESC: &str = "undefined function should be unreachable";
+/// Prefix of the cover properties generated for each assumption of a `kani::assumption_bundle!`.
+const ASSUMPTION_BUNDLE_DESC: &str = "assumption bundle ";
+/// Prefix of the cover property generated after each call to `kani::concretize`.
+const CONCRETIZE_DESC: &str = "kani::concretize: ";
 
 impl ParserItem {
     /// Determines if an item must be skipped or not.
@@ -169,6 +173,7 @@ fn must_be_skipped(&self) -> bool {
 pub fn kani_cbmc_output_filter(
     item: ParserItem,
     extra_ptr_checks: bool,
+    property_classes: &[String],
     quiet: bool,
     output_format: &OutputFormat,
 ) -> Option<ParserItem> {
@@ -177,7 +182,7 @@ pub fn kani_cbmc_output_filter(
     if item.must_be_skipped() {
         return None;
     }
-    let processed_item = process_item(item, extra_ptr_checks);
+    let processed_item = process_item(item, extra_ptr_checks, property_classes);
     // Both formatting and printing could be handled by objects which
     // implement a trait `Printer`.
     if !quiet {
@@ -195,10 +200,15 @@ pub fn kani_cbmc_output_filter(
 /// except for:
 ///  * Error messages, which may be edited.
 ///  * Verification results, which must be postprocessed.
-fn process_item(item: ParserItem, extra_ptr_checks: bool) -> ParserItem {
+fn process_item(
+    item: ParserItem,
+    extra_ptr_checks: bool,
+    property_classes: &[String],
+) -> ParserItem {
     match item {
         ParserItem::Result { result } => {
-            let postprocessed_result = postprocess_result(result, extra_ptr_checks);
+            let postprocessed_result =
+                postprocess_result(result, extra_ptr_checks, property_classes);
             ParserItem::Result { result: postprocessed_result }
         }
         ParserItem::Message { ref message_type, .. } if message_type == "ERROR" => {
@@ -246,8 +256,11 @@ pub fn format_result(
     properties: &Vec<Property>,
     status: VerificationStatus,
     should_panic: bool,
+    expect_fail: Option<&ExpectFailOutcome>,
     failed_properties: FailedProperties,
     show_checks: bool,
+    trace_filter: Option<&TraceFilter>,
+    trace_depth_limit: Option<u32>,
 ) -> String {
     let mut result_str = String::new();
     let mut number_checks_failed = 0;
@@ -263,6 +276,10 @@ pub fn format_result(
 
     let mut index = 1;
 
+    // `--trace-depth-limit` also prints the traces on its own, with the default trace filter.
+    let default_filter = TraceFilter::UserCode;
+    let trace_filter = trace_filter.or(trace_depth_limit.map(|_| &default_filter));
+
     if show_checks {
         result_str.push_str("\nRESULTS:\n");
     }
@@ -381,14 +398,23 @@ pub fn format_result(
     for prop in failed_tests {
         let failure_message = build_failure_message(prop.description.clone(), &prop.trace.clone());
         result_str.push_str(&failure_message);
+        if prop.is_precondition_property()
+            && let Some(call_site) = prop.trace.as_deref().and_then(find_call_site)
+        {
+            result_str.push_str(&format!(" Call site: {call_site}\n"));
+        }
+        if let (Some(filter), Some(trace)) = (trace_filter, &prop.trace) {
+            result_str.push_str(&format_trace(trace, filter, trace_depth_limit));
+        }
     }
 
-    let verification_result = if status == VerificationStatus::Success {
-        style("SUCCESSFUL").green()
-    } else {
-        style("FAILED").red()
+    let verification_result = match expect_fail {
+        Some(ExpectFailOutcome::ExpectedFailure) => style("EXPECTED FAILURE").green(),
+        Some(ExpectFailOutcome::UnexpectedPass) => style("UNEXPECTED PASS").red(),
+        _ if status == VerificationStatus::Success => style("SUCCESSFUL").green(),
+        _ => style("FAILED").red(),
     };
-    let should_panic_info = if should_panic {
+    let result_info = if should_panic {
         match failed_properties {
             FailedProperties::None => " (encountered no panics, but at least one was expected)",
             FailedProperties::PanicsOnly => " (encountered one or more panics as expected)",
@@ -396,10 +422,19 @@ pub fn format_result(
                 " (encountered failures other than panics, which were unexpected)"
             }
         }
+        .to_string()
     } else {
-        ""
+        match expect_fail {
+            Some(ExpectFailOutcome::UnexpectedPass) => {
+                " (the harness was expected to fail)".to_string()
+            }
+            Some(ExpectFailOutcome::UnmatchedFailure(property)) => {
+                format!(" (no failed check matches the expected failure `{property}`)")
+            }
+            _ => String::new(),
+        }
     };
-    let overall_result = format!("\nVERIFICATION:- {verification_result}{should_panic_info}\n");
+    let overall_result = format!("\nVERIFICATION:- {verification_result}{result_info}\n");
     result_str.push_str(&overall_result);
 
     // Ideally, we should generate two `ParserItem::Message` and push them
@@ -417,6 +452,18 @@ pub fn format_result(
         result_str.push_str("[Kani] info: Verification output shows one or more unwinding failures.\n\
         [Kani] tip: Consider increasing the unwinding value or disabling `--unwinding-assertions`.\n");
     }
+    for prop in unsatisfiable_assumptions(properties) {
+        let location = if prop.source_location.is_missing() {
+            String::new()
+        } else {
+            format!(" at {}", prop.source_location)
+        };
+        result_str.push_str(&format!(
+            "** WARNING: The harness is vacuous because this assumption cannot hold together \
+            with the ones before it: {}{location}\n",
+            prop.description
+        ));
+    }
 
     result_str
 }
@@ -432,14 +479,25 @@ pub fn format_coverage(
     cov_results: &CoverageResults,
     status: VerificationStatus,
     should_panic: bool,
+    expect_fail: Option<&ExpectFailOutcome>,
     failed_properties: FailedProperties,
     show_checks: bool,
+    trace_filter: Option<&TraceFilter>,
+    trace_depth_limit: Option<u32>,
 ) -> String {
     let (_coverage_checks, non_coverage_checks): (Vec<Property>, Vec<Property>) =
         properties.iter().cloned().partition(|x| x.property_class() == "code_coverage");
 
-    let verification_output =
-        format_result(&non_coverage_checks, status, should_panic, failed_properties, show_checks);
+    let verification_output = format_result(
+        &non_coverage_checks,
+        status,
+        should_panic,
+        expect_fail,
+        failed_properties,
+        show_checks,
+        trace_filter,
+        trace_depth_limit,
+    );
     let cov_results_intro = "Source-based code coverage results:";
     let result = format!("{verification_output}\n{cov_results_intro}\n\n{cov_results}");
 
@@ -475,6 +533,158 @@ fn build_failure_message(description: String, trace: &Option<Vec<TraceItem>>) ->
     backup_failure_message
 }
 
+/// Find the location of the call that violates the precondition of a function replaced by its
+/// contract, given the trace that leads to the failed precondition.
+///
+/// The precondition is checked by the replacement of the function, so we walk the trace back to
+/// the call, made outside of the function, that is still active when the check fails.
+fn find_call_site(trace: &[TraceItem]) -> Option<String> {
+    let function_of =
+        |step: &TraceItem| step.source_location.as_ref().and_then(|loc| loc.function.clone());
+    let (failure, steps) = trace.split_last()?;
+    let failure_function = function_of(failure)?;
+    // The replacement is generated as a closure of the function with the contract.
+    let contract_function = match failure_function.find("::{closure") {
+        Some(idx) => &failure_function[..idx],
+        None => &failure_function,
+    };
+    let in_contract = |function: &str| {
+        function == contract_function
+            || function.strip_prefix(contract_function).is_some_and(|rest| rest.starts_with("::"))
+    };
+
+    let mut depth = 0usize;
+    for step in steps.iter().rev() {
+        match step.step_type.as_str() {
+            "function-return" => depth += 1,
+            "function-call" if depth > 0 => depth -= 1,
+            "function-call" => {
+                let location = step.source_location.as_ref()?;
+                let function = location.function.as_deref()?;
+                if !in_contract(function) {
+                    return Some(format!(
+                        "\"{}\", line {}, in {function}",
+                        location.file.as_deref()?,
+                        location.line.as_deref()?
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Formats the steps of a counterexample trace that are selected by the given filter.
+///
+/// Only assignments are considered steps, and consecutive hidden steps are collapsed into a
+/// single line that lists their crates. The last step, i.e., the failure, is always shown.
+/// The `all` filter also shows the steps that CBMC marks as hidden, e.g., the internals of
+/// CBMC's own models.
+/// With a depth limit, only the last `depth_limit` shown steps are kept, and the steps before them
+/// are replaced by a single line.
+fn format_trace(trace: &[TraceItem], filter: &TraceFilter, depth_limit: Option<u32>) -> String {
+    let last_idx = trace.len().saturating_sub(1);
+    let krate_of = |step: &TraceItem| {
+        step.source_location
+            .as_ref()
+            .and_then(|location| location.function.as_deref())
+            .map(crate_of_function)
+    };
+    // The steps of the trace with whether they are shown.
+    let steps: Vec<(usize, &TraceItem, bool)> = trace
+        .iter()
+        .enumerate()
+        .filter(|(idx, step)| *idx == last_idx || step.step_type == "assignment")
+        .map(|(idx, step)| {
+            let visible = idx == last_idx
+                || *filter == TraceFilter::All
+                || (!step.hidden && krate_of(step).is_some_and(|krate| filter.shows(krate)));
+            (idx, step, visible)
+        })
+        .collect();
+
+    let mut result = String::from(" Trace:\n");
+    let mut first_kept = 0;
+    if let Some(limit) = depth_limit.map(|limit| limit as usize) {
+        let shown = steps.iter().filter(|(_, _, visible)| *visible).count();
+        if shown > limit {
+            first_kept = steps
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, _, visible))| *visible)
+                .nth(shown - limit)
+                .map_or(0, |(pos, _)| pos);
+            let plural = if first_kept == 1 { "" } else { "s" };
+            result.push_str(&format!(
+                "   ... (trace truncated, {first_kept} step{plural} omitted; raise \
+                `--trace-depth-limit` to see more)\n"
+            ));
+        }
+    }
+
+    let mut hidden_steps = 0;
+    let mut hidden_crates: Vec<&str> = vec![];
+    for &(idx, step, visible) in &steps[first_kept..] {
+        if !visible {
+            hidden_steps += 1;
+            if let Some(krate) = krate_of(step)
+                && !hidden_crates.contains(&krate)
+            {
+                hidden_crates.push(krate);
+            }
+            continue;
+        }
+        if hidden_steps > 0 {
+            result.push_str(&format_hidden_steps(hidden_steps, &hidden_crates));
+            hidden_steps = 0;
+            hidden_crates.clear();
+        }
+        result.push_str(&format_trace_step(step, idx == last_idx));
+    }
+    result
+}
+
+/// The line that replaces consecutive hidden steps in a counterexample trace.
+fn format_hidden_steps(hidden_steps: usize, hidden_crates: &[&str]) -> String {
+    let plural = if hidden_steps == 1 { "" } else { "s" };
+    if hidden_crates.is_empty() {
+        format!("   ... {hidden_steps} hidden step{plural}\n")
+    } else {
+        format!("   ... {hidden_steps} hidden step{plural} in {}\n", hidden_crates.join(", "))
+    }
+}
+
+/// Formats a single step of a counterexample trace.
+fn format_trace_step(step: &TraceItem, is_failure: bool) -> String {
+    let location = step
+        .source_location
+        .as_ref()
+        .filter(|location| !location.is_missing())
+        .map_or_else(|| "Unknown location".to_string(), |location| location.to_string());
+    if is_failure {
+        return format!("   {location}: failure\n");
+    }
+    let value = step.value.as_ref().and_then(|value| value.data.as_ref());
+    match (&step.lhs, value) {
+        (Some(lhs), Some(value)) => format!("   {location}: {lhs} = {value}\n"),
+        (Some(lhs), None) => format!("   {location}: {lhs} = ...\n"),
+        _ => format!("   {location}: {}\n", step.step_type),
+    }
+}
+
+/// Returns the crate of a function, given its readable name.
+///
+/// Methods of trait implementations for types without a path, e.g., `<u8 as core::ops::Add>::add`,
+/// are attributed to the crate of the trait.
+fn crate_of_function(function: &str) -> &str {
+    let path = match function.strip_prefix('<').and_then(|f| f.split_once(" as ")) {
+        Some((self_ty, trait_path)) if !self_ty.contains("::") => trait_path,
+        _ => function.trim_start_matches(['<', '&', '*']),
+    };
+    path.split("::").next().unwrap_or(path)
+}
+
 /// Edits an error message.
 ///
 /// At present, we only know one case where CBMC emits an error message, related
@@ -510,7 +720,14 @@ fn postprocess_error_message(message: ParserItem) -> ParserItem {
 ///
 ///     Additionally, print a message at the end of the output that indicates if any
 ///     of the special cases above was hit.
-pub fn postprocess_result(properties: Vec<Property>, extra_ptr_checks: bool) -> Vec<Property> {
+///
+///     If the user selected property classes with `--property-class`, the properties
+///     of other classes are removed after the special cases above are handled.
+pub fn postprocess_result(
+    properties: Vec<Property>,
+    extra_ptr_checks: bool,
+    property_classes: &[String],
+) -> Vec<Property> {
     // First, determine if there are reachable unsupported constructs or unwinding assertions
     let has_reachable_unsupported_constructs =
         has_check_failure(&properties, UNSUPPORTED_CONSTRUCT_DESC);
@@ -542,7 +759,19 @@ pub fn postprocess_result(properties: Vec<Property>, extra_ptr_checks: bool) ->
     let updated_properties =
         update_properties_with_reach_status(properties_filtered, has_fundamental_failures);
     let results_after_code_coverage = update_results_of_code_covererage_checks(updated_properties);
-    update_results_of_cover_checks(results_after_code_coverage)
+    let results_after_cover = update_results_of_cover_checks(results_after_code_coverage);
+    let results_after_concretize = update_results_of_concretize_checks(results_after_cover);
+    filter_property_classes(results_after_concretize, property_classes)
+}
+
+/// Return the assumption bundle covers that are unsatisfiable. Any assumption after these is
+/// unreachable, so they point to the exact assumption that makes a harness vacuous.
+fn unsatisfiable_assumptions(properties: &[Property]) -> impl Iterator<Item = &Property> {
+    properties.iter().filter(|prop| {
+        prop.is_cover_property()
+            && prop.status == CheckStatus::Unsatisfiable
+            && prop.description.starts_with(ASSUMPTION_BUNDLE_DESC)
+    })
 }
 
 /// Determines if there is property with status `FAILURE` and the given description
@@ -688,6 +917,26 @@ fn update_results_of_cover_checks(mut properties: Vec<Property>) -> Vec<Property
     }
     properties
 }
+/// Update the results of the cover properties that check that the representative picked by
+/// `kani::concretize` is one of the values that the concretized input can take:
+/// - UNSATISFIABLE -> FAILURE, since the rest of the harness is vacuous
+/// - SATISFIED -> removed, like successful sanity checks
+fn update_results_of_concretize_checks(properties: Vec<Property>) -> Vec<Property> {
+    properties
+        .into_iter()
+        .filter_map(|mut prop| {
+            if prop.is_cover_property() && prop.description.starts_with(CONCRETIZE_DESC) {
+                match prop.status {
+                    CheckStatus::Satisfied => return None,
+                    CheckStatus::Unsatisfiable => prop.status = CheckStatus::Failure,
+                    _ => (),
+                }
+            }
+            Some(prop)
+        })
+        .collect()
+}
+
 /// Some Kani-generated asserts have a unique ID in their description of the form:
 /// ```text
 /// [KANI_CHECK_ID_<crate-fn-name>_<index>]
@@ -739,6 +988,21 @@ fn filter_ptr_checks(properties: Vec<Property>) -> Vec<Property> {
         .collect()
 }
 
+/// Keeps only the properties of the classes selected with `--property-class`.
+/// All properties are kept if no class was selected.
+fn filter_property_classes(
+    properties: Vec<Property>,
+    property_classes: &[String],
+) -> Vec<Property> {
+    if property_classes.is_empty() {
+        return properties;
+    }
+    properties
+        .into_iter()
+        .filter(|prop| property_classes.contains(&prop.property_class()))
+        .collect()
+}
+
 /// When assertion reachability checks are turned on, Kani prefixes each
 /// assert's description with an ID of the following form:
 /// ```text
@@ -803,3 +1067,51 @@ fn annotate_properties_with_reach_results(
     }
     properties
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cbmc_output_parser::SourceLocation;
+
+    fn step(step_type: &str, function: &str, line: u32) -> TraceItem {
+        TraceItem {
+            step_type: step_type.to_string(),
+            hidden: false,
+            lhs: None,
+            source_location: Some(SourceLocation {
+                column: None,
+                file: Some("lib.rs".to_string()),
+                function: Some(function.to_string()),
+                line: Some(line.to_string()),
+            }),
+            value: None,
+        }
+    }
+
+    #[test]
+    fn check_find_call_site() {
+        let trace = vec![
+            step("function-call", "main", 3),
+            step("assignment", "helper", 10),
+            step("function-return", "helper", 11),
+            step("function-call", "main", 4),
+            step("function-call", "div", 20),
+            step("failure", "div::{closure#2}", 18),
+        ];
+        // The completed call to `helper` and the calls within `div` are skipped.
+        assert_eq!(find_call_site(&trace).as_deref(), Some("\"lib.rs\", line 4, in main"));
+        assert_eq!(find_call_site(&trace[5..]), None);
+    }
+
+    #[test]
+    fn check_trace_filter_all_shows_hidden_steps() {
+        let mut hidden = step("assignment", "core::ptr::read", 7);
+        hidden.hidden = true;
+        let trace = vec![step("assignment", "main", 3), hidden, step("failure", "main", 4)];
+        let all = format_trace(&trace, &TraceFilter::All, None);
+        assert!(all.contains("core::ptr::read"), "{all}");
+        assert!(!all.contains("hidden step"), "{all}");
+        let user_code = format_trace(&trace, &TraceFilter::UserCode, None);
+        assert!(user_code.contains("1 hidden step in core"), "{user_code}");
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--emit-chrome-trace`, which records how long each phase, harness and command
+//! of a run takes in the [Chrome Trace Event Format].
+//! The resulting file can be opened with Perfetto or `chrome://tracing`.
+//!
+//! Each event is recorded as a "complete" event with its start timestamp and duration.
+//! Events that run on the same thread and are contained in one another, e.g. running CBMC
+//! while checking a harness, are displayed as nested.
+//!
+//! [Chrome Trace Event Format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+/// The trace of the current run. This is only set if the user passed `--emit-chrome-trace`.
+static TRACE: OnceLock<ChromeTrace> = OnceLock::new();
+
+struct ChromeTrace {
+    /// Where to write the trace.
+    path: PathBuf,
+    /// The time origin of all events.
+    start: Instant,
+    events: Mutex<Vec<TraceEvent>>,
+}
+
+/// A complete event, as described in the Chrome Trace Event Format.
+#[derive(Debug, Serialize)]
+struct TraceEvent {
+    name: String,
+    /// The category of the event, which can be used to filter events in the viewer.
+    cat: &'static str,
+    /// The event type. We only emit complete events (`X`).
+    ph: &'static str,
+    /// Start timestamp in microseconds.
+    ts: u64,
+    /// Duration in microseconds.
+    dur: u64,
+    pid: u32,
+    tid: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceFile<'a> {
+    trace_events: &'a [TraceEvent],
+    display_time_unit: &'static str,
+}
+
+/// Start recording events that will be written to `path` by [write].
+pub fn init(path: &Path) {
+    let _ = TRACE.set(ChromeTrace {
+        path: path.to_path_buf(),
+        start: Instant::now(),
+        events: Mutex::new(vec![]),
+    });
+}
+
+/// Execute `func` and record an event with the given name and category that spans its execution.
+/// This is a no-op if the trace hasn't been initialized.
+pub fn record<T, F>(name: &str, cat: &'static str, func: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let Some(trace) = TRACE.get() else { return func() };
+    let start = Instant::now();
+    let ret = func();
+    let end = Instant::now();
+    let event = TraceEvent {
+        name: name.to_string(),
+        cat,
+        ph: "X",
+        ts: start.duration_since(trace.start).as_micros() as u64,
+        dur: end.duration_since(start).as_micros() as u64,
+        pid: std::process::id(),
+        // Use 0 for the main thread, and shift the worker threads used to verify harnesses.
+        tid: rayon::current_thread_index().map_or(0, |idx| idx + 1),
+    };
+    trace.events.lock().unwrap().push(event);
+    ret
+}
+
+/// Write the events recorded so far, if the trace has been initialized.
+pub fn write() -> Result<()> {
+    let Some(trace) = TRACE.get() else { return Ok(()) };
+    let events = trace.events.lock().unwrap();
+    let file = File::create(&trace.path)
+        .with_context(|| format!("Failed to create `{}`", trace.path.display()))?;
+    let contents = TraceFile { trace_events: &events, display_time_unit: "ms" };
+    serde_json::to_writer(BufWriter::new(file), &contents)
+        .with_context(|| format!("Failed to write `{}`", trace.path.display()))
+}
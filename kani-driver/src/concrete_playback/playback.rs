@@ -3,29 +3,46 @@
 //! Implements the logic related to the playback subcommand
 //! This can be achieved with <kani|cargo kani> playback --test <test_name>
 
-use crate::args::common::Verbosity;
-use crate::args::playback_args::{CargoPlaybackArgs, KaniPlaybackArgs, MessageFormat};
+use crate::args::common::{Verbosity, VerbosityLevel};
+use crate::args::playback_args::{
+    CargoPlaybackArgs, KaniPlaybackArgs, MessageFormat, PlaybackArgs, PlaybackRunner,
+};
 use crate::call_cargo::cargo_config_args;
 use crate::call_single_file::{LibConfig, base_rustc_flags};
-use crate::session::{InstallType, lib_playback_folder, setup_cargo_command};
+use crate::session::{InstallType, get_cargo_path, lib_playback_folder, setup_cargo_command};
+use crate::session_lock::{LockMode, SessionLock, lock_target_dir};
 use crate::util::args::{CargoArg, CommandWrapper, PassTo, RustcArg};
 use crate::{session, util};
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
+use cargo_metadata::{MetadataCommand, Package};
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::fs;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::OnceLock;
 use tracing::debug;
 
 pub fn playback_cargo(args: CargoPlaybackArgs) -> Result<()> {
+    set_message_format(&args.playback);
+    // Playback only reads Kani's artifacts, so it can run alongside other readers.
+    let _lock = SessionLock::acquire(
+        &lock_target_dir(None, args.cargo.common.manifest_path.as_deref()),
+        LockMode::Shared,
+        args.playback.common_opts.wait_for_lock.map(Into::into),
+    )?;
     cargo_test(args)
 }
 
 pub fn playback_standalone(args: KaniPlaybackArgs) -> Result<()> {
+    set_message_format(&args.playback);
     let install = InstallType::new()?;
     let artifact = build_test(&install, &args)?;
     debug!(?artifact, "playback_standalone");
 
-    if !args.playback.common_opts.quiet() {
+    // The artifact is the only JSON message, so it's printed even if the output is quiet.
+    if !args.playback.common_opts.quiet() || args.playback.message_format == MessageFormat::Json {
         print_artifact(&artifact, args.playback.message_format)
     }
 
@@ -36,6 +53,23 @@ pub fn playback_standalone(args: KaniPlaybackArgs) -> Result<()> {
     Ok(())
 }
 
+/// With `--message-format json`, the standard output only contains JSON messages, so that it can be
+/// consumed by other tools. Kani's messages are printed to the standard error instead.
+fn set_message_format(args: &PlaybackArgs) {
+    if args.message_format == MessageFormat::Json {
+        util::print_messages_to_stderr();
+    }
+}
+
+/// Run a command of the playback, whose standard output is filtered to only contain JSON messages
+/// with `--message-format json`.
+fn run_command(args: &PlaybackArgs, cmd: Command) -> Result<()> {
+    match args.message_format {
+        MessageFormat::Human => session::run_terminal(&args.common_opts, cmd),
+        MessageFormat::Json => session::run_json_only(&args.common_opts, cmd),
+    }
+}
+
 fn print_artifact(artifact: &Path, format: MessageFormat) {
     match format {
         MessageFormat::Json => {
@@ -59,8 +93,7 @@ fn run_test(exe: &Path, args: &KaniPlaybackArgs) -> Result<()> {
 
     cmd.args(&args.playback.test_args);
 
-    session::run_terminal(&args.playback.common_opts, cmd)?;
-    Ok(())
+    run_command(&args.playback, cmd)
 }
 
 fn build_test(install: &InstallType, args: &KaniPlaybackArgs) -> Result<PathBuf> {
@@ -86,27 +119,36 @@ fn build_test(install: &InstallType, args: &KaniPlaybackArgs) -> Result<PathBuf>
     let mut cmd = Command::new(install.kani_compiler()?);
     cmd.pass_rustc_args(&rustc_args, PassTo::OnlyLocalCrate);
 
-    session::run_terminal(&args.playback.common_opts, cmd)?;
+    run_command(&args.playback, cmd)?;
 
     Ok(PathBuf::from(TEST_BIN_NAME).canonicalize()?)
 }
 
-/// Invokes cargo test using Kani compiler and the provided arguments.
+/// Invokes cargo test, or cargo nextest, using Kani compiler and the provided arguments.
 fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
     let install = InstallType::new()?;
     let mut cmd = setup_cargo_command()?;
 
     let rustc_args = base_rustc_flags(LibConfig::new(lib_playback_folder()?));
-    let mut cargo_args: Vec<CargoArg> = vec!["test".into()];
+    let mut cargo_args: Vec<CargoArg> = match args.playback.runner {
+        PlaybackRunner::Libtest => vec!["test".into()],
+        PlaybackRunner::Nextest => {
+            check_nextest_installed()?;
+            vec!["nextest".into(), "run".into()]
+        }
+    };
+    let nextest = args.playback.runner == PlaybackRunner::Nextest;
 
     if args.playback.common_opts.verbose() {
-        cargo_args.push("-vv".into());
-    } else if args.playback.common_opts.quiet {
-        cargo_args.push("--quiet".into())
+        cargo_args.push(if nextest { "--cargo-verbose" } else { "-vv" }.into());
+    } else if args.playback.common_opts.level() < VerbosityLevel::Harness {
+        cargo_args.push(if nextest { "--cargo-quiet" } else { "--quiet" }.into())
     }
 
     if args.playback.message_format == MessageFormat::Json {
-        cargo_args.push("--message-format=json".into());
+        let format_arg =
+            if nextest { "--cargo-message-format=json" } else { "--message-format=json" };
+        cargo_args.push(format_arg.into());
     }
 
     if args.playback.only_codegen {
@@ -114,10 +156,16 @@ fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
     }
 
     cargo_args.append(&mut args.cargo.to_cargo_args());
+    if let Some(package) = detect_test_package(&args)? {
+        debug!(?package, "cargo_test: detected package");
+        cargo_args.push(format!("-p={package}").into());
+    }
     cargo_args.append(&mut cargo_config_args());
 
-    // These have to be the last arguments to cargo test.
-    if !args.playback.test_args.is_empty() {
+    if nextest {
+        cargo_args.append(&mut nextest_test_args(&args.playback.test_args)?);
+    } else if !args.playback.test_args.is_empty() {
+        // These have to be the last arguments to cargo test.
         cargo_args.push("--".into());
         cargo_args.extend(args.playback.test_args.iter().map(|arg| arg.into()));
     }
@@ -128,6 +176,137 @@ fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
         .pass_rustc_args(&rustc_args, PassTo::AllCrates)
         .env("CARGO_TERM_PROGRESS_WHEN", "never");
 
-    session::run_terminal(&args.playback.common_opts, cmd)?;
+    run_command(&args.playback, cmd)
+}
+
+/// Find the workspace package that defines the tests selected by the test arguments, so the tests
+/// can be played back from the root of a workspace.
+///
+/// Return `None` if the user selected the packages with `--package` or `--workspace`, if no test
+/// was selected, or if no package defines a playback test that matches the selection.
+fn detect_test_package(args: &CargoPlaybackArgs) -> Result<Option<String>> {
+    let cargo = &args.cargo.common;
+    if !cargo.package.is_empty() || cargo.workspace {
+        return Ok(None);
+    }
+    let test_args = &args.playback.test_args;
+    let exact = test_args.iter().any(|arg| arg == "--exact");
+    // Playback tests are generated at the harness location, so only match the function name.
+    let filters: Vec<&str> = test_args
+        .iter()
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|filter| filter.rsplit("::").next().unwrap())
+        .collect();
+    if filters.is_empty() {
+        return Ok(None);
+    }
+
+    let mut cmd = MetadataCommand::new();
+    cmd.cargo_path(get_cargo_path()?).no_deps();
+    if let Some(path) = &cargo.manifest_path {
+        cmd.manifest_path(path);
+    }
+    let metadata = cmd.exec().context("Failed to get cargo metadata.")?;
+    let mut candidates = vec![];
+    for package in metadata.workspace_packages() {
+        let tests = playback_tests(package)?;
+        let selected = tests.iter().any(|test| {
+            filters.iter().any(|filter| if exact { test == filter } else { test.contains(filter) })
+        });
+        if selected {
+            candidates.push(package.name.to_string());
+        }
+    }
+    debug!(?filters, ?candidates, "detect_test_package");
+    match candidates.as_slice() {
+        [] => Ok(None),
+        [package] => Ok(Some(package.clone())),
+        _ => bail!(
+            "The selected tests are defined in multiple packages: `{}`. Use `--package` to select \
+            the packages to play back.",
+            candidates.join("`, `")
+        ),
+    }
+}
+
+/// Collect the names of the playback tests that Kani generated in the sources of `package`.
+fn playback_tests(package: &Package) -> Result<BTreeSet<String>> {
+    let test_re = {
+        static TEST_RE: OnceLock<Regex> = OnceLock::new();
+        TEST_RE.get_or_init(|| Regex::new(r"fn (kani_concrete_playback_\w+)").unwrap())
+    };
+    let source_dirs: BTreeSet<&Path> = package
+        .targets
+        .iter()
+        // Build scripts are at the root of the package, which may contain other packages.
+        .filter(|target| !target.is_custom_build())
+        .filter_map(|target| target.src_path.parent())
+        .map(|dir| dir.as_std_path())
+        .collect();
+    let mut tests = BTreeSet::new();
+    for dir in source_dirs {
+        for file in rust_files(dir)? {
+            let content = fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read `{}`", file.display()))?;
+            tests.extend(test_re.captures_iter(&content).map(|caps| caps[1].to_string()));
+        }
+    }
+    Ok(tests)
+}
+
+/// List the Rust source files in `dir` and its subdirectories.
+fn rust_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.append(&mut rust_files(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Fail early with installation instructions if the `cargo nextest` subcommand isn't available.
+fn check_nextest_installed() -> Result<()> {
+    let mut cmd = setup_cargo_command()?;
+    cmd.args(["nextest", "--version"]);
+    let installed = cmd.output().is_ok_and(|output| output.status.success());
+    if !installed {
+        bail!(
+            "The `--runner nextest` option requires `cargo-nextest`, which doesn't seem to be \
+            installed. Install it with `cargo install cargo-nextest --locked`, or see \
+            https://nexte.st/docs/installation/ for other options."
+        );
+    }
     Ok(())
 }
+
+/// Translate the libtest arguments given by the user into `cargo nextest run` arguments.
+///
+/// Test names become a filter expression, since nextest doesn't take positional names after
+/// `--`. Only the libtest flags that have a nextest equivalent are supported.
+fn nextest_test_args(test_args: &[String]) -> Result<Vec<CargoArg>> {
+    let exact = test_args.iter().any(|arg| arg == "--exact");
+    let mut nextest_args: Vec<CargoArg> = vec![];
+    let mut filters = vec![];
+    for arg in test_args {
+        match arg.as_str() {
+            "--exact" => {}
+            "--nocapture" => nextest_args.push("--no-capture".into()),
+            "--ignored" => nextest_args.extend(["--run-ignored".into(), "only".into()]),
+            "--include-ignored" => nextest_args.extend(["--run-ignored".into(), "all".into()]),
+            flag if flag.starts_with('-') => {
+                bail!("The test argument `{flag}` is not supported with `--runner nextest`.")
+            }
+            name if exact => filters.push(format!("test(={name})")),
+            name => filters.push(format!("test({name})")),
+        }
+    }
+    if !filters.is_empty() {
+        nextest_args.push("-E".into());
+        nextest_args.push(filters.join(" | ").into());
+    }
+    Ok(nextest_args)
+}
@@ -5,13 +5,14 @@
 //! generating concrete playback unit tests, and adding them to the user's source code.
 
 use crate::args::ConcretePlaybackMode;
+use crate::args::common::{Verbosity, VerbosityLevel};
 use crate::call_cbmc::VerificationResult;
 use crate::cbmc_output_parser::Property;
 use crate::session::KaniSession;
+use crate::util::StableHasher;
 use anyhow::{Context, Result};
 use concrete_vals_extractor::{ConcreteItem, PrimitiveConcreteVal, extract_harness_values};
 use kani_metadata::{HarnessKind, HarnessMetadata};
-use std::collections::hash_map::DefaultHasher;
 use std::ffi::OsString;
 use std::fs::{File, read_to_string};
 use std::hash::{Hash, Hasher};
@@ -68,7 +69,9 @@ pub fn gen_and_add_concrete_playback(
                         }
                     }
                     ConcretePlaybackMode::InPlace => {
-                        if !self.args.common_args.quiet && !unit_tests.is_empty() {
+                        if self.args.common_args.level() >= VerbosityLevel::Harness
+                            && !unit_tests.is_empty()
+                        {
                             println!(
                                 "INFO: Now modifying the source code to include the concrete playback unit test:{}.",
                                 unit_tests
@@ -146,7 +149,7 @@ fn add_tests_inplace(
         // filter out existing harnesses.
         unit_tests.retain(|unit_test| {
             if source_string.contains(&unit_test.name) {
-                if !self.args.common_args.quiet {
+                if self.args.common_args.level() >= VerbosityLevel::Harness {
                     println!(
                         "Concrete playback unit test `{}/{}` already found in source code, so skipping modification.",
                         source_path, unit_test.name,
@@ -228,7 +231,7 @@ fn run_rustfmt(
             cmd.current_dir(current_dir);
         }
 
-        if self.args.common_args.quiet {
+        if self.args.common_args.level() < VerbosityLevel::Harness {
             self.run_suppress(cmd).context("Failed to rustfmt modified source code.")?;
         } else {
             self.run_terminal(cmd).context("Failed to rustfmt modified source code")?;
@@ -284,7 +287,7 @@ fn format_unit_test(
     doc_str: String,
 ) -> UnitTest {
     // Hash the concrete values along with the proof harness name.
-    let mut hasher = DefaultHasher::new();
+    let mut hasher = StableHasher::new();
     harness_name.hash(&mut hasher);
     concrete_items.hash(&mut hasher);
     let hash = hasher.finish();
@@ -748,6 +751,7 @@ fn check_concrete_vals_extractor_primitive() {
             },
             trace: Some(vec![TraceItem {
                 step_type: "assignment".to_string(),
+                hidden: false,
                 lhs: Some("goto_symex$$return_value".to_string()),
                 source_location: Some(SourceLocation {
                     column: None,
@@ -794,6 +798,7 @@ fn check_concrete_vals_extractor_array() {
             trace: Some(vec![
                 TraceItem {
                     step_type: "assignment".to_string(),
+                    hidden: false,
                     lhs: Some("goto_symex$$return_value".to_string()),
                     source_location: Some(SourceLocation {
                         column: None,
@@ -844,6 +849,7 @@ fn check_concrete_vals_extractor_array() {
                 // Since the array is of size 4, there are also TraceItems for each element of the array, which extract_harness_value should ignore.
                 TraceItem {
                     step_type: "assignment".to_string(),
+                    hidden: false,
                     lhs: Some("goto_symex$$return_value".to_string()),
                     source_location: Some(SourceLocation {
                         column: None,
@@ -860,6 +866,7 @@ fn check_concrete_vals_extractor_array() {
                 },
                 TraceItem {
                     step_type: "assignment".to_string(),
+                    hidden: false,
                     lhs: Some("goto_symex$$return_value".to_string()),
                     source_location: Some(SourceLocation {
                         column: None,
@@ -876,6 +883,7 @@ fn check_concrete_vals_extractor_array() {
                 },
                 TraceItem {
                     step_type: "assignment".to_string(),
+                    hidden: false,
                     lhs: Some("goto_symex$$return_value".to_string()),
                     source_location: Some(SourceLocation {
                         column: None,
@@ -892,6 +900,7 @@ fn check_concrete_vals_extractor_array() {
                 },
                 TraceItem {
                     step_type: "assignment".to_string(),
+                    hidden: false,
                     lhs: Some("goto_symex$$return_value".to_string()),
                     source_location: Some(SourceLocation {
                         column: None,
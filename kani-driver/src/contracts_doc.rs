@@ -0,0 +1,261 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the `contracts-doc` subcommand, which documents the contracts of a crate.
+//!
+//! For each function under contract, we document its `requires`, `ensures` and `modifies`
+//! clauses as written by the user, as well as the harnesses that verify the contract with their
+//! status in the last verification run (or in the run summary given to `--report`). The output is
+//! either one Markdown fragment per function, which can be included in its rustdoc
+//! documentation, or a JSON map keyed by the path of the function for a rustdoc post-processor.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::InvocationType;
+use crate::args::VerificationArgs;
+use crate::args::common::{Verbosity, VerbosityLevel};
+use crate::args::contracts_doc_args::{
+    CargoContractsDocArgs, CommonContractsDocArgs, DocFormat, StandaloneContractsDocArgs,
+};
+use crate::metadata::from_json;
+use crate::project::standalone_project;
+use crate::run_summary::{HarnessStatus, harness_statuses, last_run_file};
+use crate::session::KaniSession;
+use crate::session_lock::LockMode;
+use crate::version::print_kani_version;
+use anyhow::{Context, Result};
+use kani_metadata::{ContractClause, ContractClauseKind, KaniMetadata};
+use serde::Serialize;
+
+const OUTPUT_NAME: &str = "kani-contracts-doc";
+
+/// The documentation of a function under contract.
+#[derive(Debug, PartialEq, Serialize)]
+struct ContractDoc {
+    crate_name: String,
+    file: String,
+    clauses: Vec<ContractClause>,
+    harnesses: Vec<HarnessDoc>,
+}
+
+/// A harness that verifies a contract.
+#[derive(Debug, PartialEq, Serialize)]
+struct HarnessDoc {
+    name: String,
+    /// The status of the harness in the report, if the harness was verified in that run.
+    status: Option<HarnessStatus>,
+}
+
+pub fn contracts_doc_cargo(
+    args: CargoContractsDocArgs,
+    mut verify_opts: VerificationArgs,
+) -> Result<()> {
+    let level = args.common_args.level();
+    let quiet = level < VerbosityLevel::Summary;
+    verify_opts.common_args = args.common_args;
+    let mut session = KaniSession::with_lock_mode(verify_opts, LockMode::Shared)?;
+    if level >= VerbosityLevel::Harness {
+        print_kani_version(InvocationType::CargoKani(vec![]));
+    }
+
+    // The contracts are collected from the attributes, so there is no need to generate code.
+    session.metadata_only = true;
+    let outputs = session.cargo_build(false)?;
+    let metadata =
+        outputs.metadata.iter().map(|md_file| from_json(md_file)).collect::<Result<Vec<_>>>()?;
+    write_contracts_doc(&session, metadata, &args.doc_args, quiet)
+}
+
+pub fn contracts_doc_standalone(
+    args: StandaloneContractsDocArgs,
+    mut verify_opts: VerificationArgs,
+) -> Result<()> {
+    let level = args.common_args.level();
+    let quiet = level < VerbosityLevel::Summary;
+    verify_opts.common_args = args.common_args;
+    let session = KaniSession::with_lock_mode(verify_opts, LockMode::Shared)?;
+    if level >= VerbosityLevel::Harness {
+        print_kani_version(InvocationType::Standalone);
+    }
+
+    let project = standalone_project(&args.input, args.crate_name, &session)?;
+    write_contracts_doc(&session, project.metadata, &args.doc_args, quiet)
+}
+
+fn write_contracts_doc(
+    session: &KaniSession,
+    metadata: Vec<KaniMetadata>,
+    args: &CommonContractsDocArgs,
+    quiet: bool,
+) -> Result<()> {
+    let last_run = last_run_file(
+        session.args.target_dir.as_deref(),
+        session.args.cargo.manifest_path.as_deref(),
+    );
+    let statuses = match &args.report {
+        Some(report) => harness_statuses(report)?,
+        // Without a previous run, the contracts are documented as not verified.
+        None if last_run.exists() => harness_statuses(&last_run)?,
+        None => BTreeMap::new(),
+    };
+    let docs = collect_docs(metadata, &statuses);
+
+    let out_path = match (&args.output, args.format) {
+        (Some(path), _) => path.clone(),
+        (None, DocFormat::Markdown) => PathBuf::from(OUTPUT_NAME),
+        (None, DocFormat::Json) => Path::new(OUTPUT_NAME).with_extension("json"),
+    };
+    match args.format {
+        DocFormat::Markdown => {
+            fs::create_dir_all(&out_path)
+                .with_context(|| format!("Failed to create directory `{}`", out_path.display()))?;
+            for (function, doc) in &docs {
+                let path = out_path.join(fragment_file_name(function));
+                fs::write(&path, markdown_fragment(doc))
+                    .with_context(|| format!("Failed to write `{}`", path.display()))?;
+            }
+        }
+        DocFormat::Json => {
+            let json = serde_json::to_string_pretty(&docs)?;
+            fs::write(&out_path, json)
+                .with_context(|| format!("Failed to write `{}`", out_path.display()))?;
+        }
+    }
+
+    if !quiet {
+        println!(
+            "Wrote the documentation of {} contracts to {}",
+            docs.len(),
+            fs::canonicalize(&out_path)?.display()
+        );
+    }
+    Ok(())
+}
+
+/// Collect the documentation of every function under contract, indexed by its path.
+fn collect_docs(
+    metadata: Vec<KaniMetadata>,
+    statuses: &BTreeMap<String, HarnessStatus>,
+) -> BTreeMap<String, ContractDoc> {
+    let mut docs = BTreeMap::new();
+    for crate_md in metadata {
+        // Functions without clauses are targets of a contract harness without a contract.
+        for cf in crate_md.contracted_functions.into_iter().filter(|cf| !cf.clauses.is_empty()) {
+            let mut harnesses: Vec<HarnessDoc> = cf
+                .harnesses
+                .into_iter()
+                .map(|name| HarnessDoc { status: statuses.get(&name).copied(), name })
+                .collect();
+            harnesses.sort_by(|a, b| a.name.cmp(&b.name));
+            let doc = ContractDoc {
+                crate_name: crate_md.crate_name.clone(),
+                file: cf.file,
+                clauses: cf.clauses,
+                harnesses,
+            };
+            docs.insert(cf.function, doc);
+        }
+    }
+    docs
+}
+
+/// The name of the Markdown fragment of a function, e.g., `my_mod.div.md` for `my_mod::div`.
+///
+/// Characters that may not be valid in a file name, e.g., the ones of generic arguments in the
+/// path of a trait method implementation, are replaced by `_`.
+fn fragment_file_name(function: &str) -> String {
+    let name: String = function
+        .replace("::", ".")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-') { c } else { '_' })
+        .collect();
+    format!("{name}.md")
+}
+
+/// Render the documentation of a function as a Markdown fragment.
+fn markdown_fragment(doc: &ContractDoc) -> String {
+    let mut fragment = String::from("#### Kani contract\n\n");
+    for clause in &doc.clauses {
+        let attribute = match clause.kind {
+            ContractClauseKind::EnsuresNoPanic => format!("#[kani::{}]", clause.kind),
+            kind => format!("#[kani::{kind}({})]", clause.expr),
+        };
+        fragment.push_str(&format!("- `{attribute}`\n"));
+    }
+    fragment.push('\n');
+    if doc.harnesses.is_empty() {
+        fragment.push_str("No harness verifies this contract.\n");
+    } else {
+        fragment.push_str("Verified by:\n");
+        for harness in &doc.harnesses {
+            let status =
+                harness.status.map_or_else(|| "not verified".to_string(), |s| s.to_string());
+            fragment.push_str(&format!("- `{}`: {status}\n", harness.name));
+        }
+    }
+    fragment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kani_metadata::ContractedFunction;
+
+    fn clause(kind: ContractClauseKind, expr: &str) -> ContractClause {
+        ContractClause { kind, expr: expr.to_string() }
+    }
+
+    #[test]
+    fn check_markdown_fragment() {
+        let metadata = KaniMetadata {
+            crate_name: "my_crate".to_string(),
+            proof_harnesses: vec![],
+            unsupported_features: vec![],
+            test_harnesses: vec![],
+            contracted_functions: vec![
+                ContractedFunction {
+                    function: "ops::div".to_string(),
+                    file: "src/lib.rs".to_string(),
+                    harnesses: vec!["check_div_u8".to_string(), "check_div".to_string()],
+                    clauses: vec![
+                        clause(ContractClauseKind::Requires, "divisor != 0"),
+                        clause(ContractClauseKind::Ensures, "|result: &u32| *result <= dividend"),
+                        clause(ContractClauseKind::EnsuresNoPanic, ""),
+                    ],
+                },
+                ContractedFunction {
+                    function: "verify::check_target".to_string(),
+                    file: "src/lib.rs".to_string(),
+                    harnesses: vec!["check_target".to_string()],
+                    clauses: vec![],
+                },
+            ],
+            autoharness_md: None,
+            mutants: vec![],
+        };
+        let statuses = BTreeMap::from([("check_div".to_string(), HarnessStatus::Success)]);
+
+        let docs = collect_docs(vec![metadata], &statuses);
+        assert_eq!(docs.keys().collect::<Vec<_>>(), vec!["ops::div"]);
+        assert_eq!(
+            markdown_fragment(&docs["ops::div"]),
+            "#### Kani contract\n\n\
+            - `#[kani::requires(divisor != 0)]`\n\
+            - `#[kani::ensures(|result: &u32| *result <= dividend)]`\n\
+            - `#[kani::ensures_no_panic]`\n\n\
+            Verified by:\n\
+            - `check_div`: success\n\
+            - `check_div_u8`: not verified\n"
+        );
+    }
+
+    #[test]
+    fn check_fragment_file_name() {
+        assert_eq!(fragment_file_name("ops::div"), "ops.div.md");
+        assert_eq!(
+            fragment_file_name("<shapes::Square as shapes::Area<u32>>::area"),
+            "_shapes.Square_as_shapes.Area_u32__.area.md"
+        );
+    }
+}
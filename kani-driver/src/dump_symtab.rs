@@ -0,0 +1,71 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--dump-symtab`, which writes the symbol table generated for a harness in CBMC's
+//! JSON symtab format, instead of verifying it.
+//!
+//! The symbol table is the one produced by the compiler, before it is linked with the C library
+//! models and instrumented by `goto-instrument`.
+
+use crate::args::common::{Verbosity, VerbosityLevel};
+use crate::project::Project;
+use crate::session::KaniSession;
+use anyhow::{Context, Result, bail};
+use kani_metadata::{ArtifactType, HarnessMetadata};
+use std::fs;
+use std::path::Path;
+
+impl KaniSession {
+    /// Copy the JSON symbol table of the only selected harness to `path`.
+    pub fn dump_symtab(
+        &self,
+        project: &Project,
+        harnesses: &[&HarnessMetadata],
+        path: &Path,
+    ) -> Result<()> {
+        let harness = single_harness(harnesses)?;
+        let Some(symtab) = project.get_harness_artifact(harness, ArtifactType::SymTab) else {
+            bail!("Failed to find the symbol table of harness `{}`", harness.pretty_name);
+        };
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(symtab, path)
+            .with_context(|| format!("Failed to write the symbol table to `{}`", path.display()))?;
+        if self.args.common_args.level() >= VerbosityLevel::Harness {
+            println!(
+                "Wrote the symbol table of harness `{}` to `{}`",
+                harness.pretty_name,
+                path.display()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The symbol table is dumped for a single harness, since each one has its own.
+fn single_harness<'a>(harnesses: &[&'a HarnessMetadata]) -> Result<&'a HarnessMetadata> {
+    match harnesses {
+        [harness] => Ok(harness),
+        _ => bail!(
+            "`--dump-symtab` requires a single harness, but {} were selected. Use `--harness` \
+            with `--exact` to select one.",
+            harnesses.len()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+
+    #[test]
+    fn check_single_harness() {
+        let first = mock_proof_harness("first", None, None, None);
+        let second = mock_proof_harness("second", None, None, None);
+        assert_eq!(single_harness(&[&first]).unwrap().pretty_name, "first");
+        assert!(single_harness(&[]).is_err());
+        assert!(single_harness(&[&first, &second]).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--estimate`, which predicts the wall-clock time of a verification run from the
+//! runtime of each harness recorded in the [timing cache](crate::timing_cache), without verifying
+//! anything. The harnesses are only collected from the metadata of the crate, so no code is
+//! generated for them.
+//!
+//! The harnesses are assigned in order to the first thread that becomes available, like the
+//! harness runner does, so the estimate accounts for the number of threads given to `--jobs`.
+
+use crate::args::NumThreads;
+use crate::metadata::sort_harnesses_by_loc;
+use crate::session::KaniSession;
+use crate::timing_cache::{read_cache, timing_cache_file};
+use anyhow::Result;
+use kani_metadata::{HarnessMetadata, KaniMetadata};
+use std::time::Duration;
+
+impl KaniSession {
+    /// Print the estimated runtime of the harnesses selected from the given crates.
+    pub fn estimate(&self, metadata: &[KaniMetadata]) -> Result<()> {
+        let harnesses = metadata
+            .iter()
+            .flat_map(|crate_metadata| {
+                crate_metadata.proof_harnesses.iter().chain(crate_metadata.test_harnesses.iter())
+            })
+            .collect();
+        let harnesses = self.determine_targets(harnesses)?;
+        self.print_estimate(&harnesses)
+    }
+
+    /// Print the estimated runtime of each harness and of the whole run.
+    fn print_estimate(&self, harnesses: &[&HarnessMetadata]) -> Result<()> {
+        let path = timing_cache_file(
+            self.args.target_dir.as_deref(),
+            self.args.cargo.manifest_path.as_deref(),
+        );
+        let cache = read_cache(&path)?;
+        let threads = match self.args.jobs() {
+            NumThreads::UserSpecified(threads) if threads > 0 => threads,
+            NumThreads::NoMultithreading => 1,
+            // Like rayon, use the number of available CPUs by default.
+            _ => rayon::current_num_threads(),
+        };
+
+        let sorted_harnesses = sort_harnesses_by_loc(harnesses);
+        let mut known = vec![];
+        println!("Estimated verification time based on `{}`:", path.display());
+        for harness in &sorted_harnesses {
+            match cache.runtime(&harness.crate_name, &harness.pretty_name) {
+                Some(runtime) => {
+                    println!(" - {}: {}", harness.pretty_name, format_duration(runtime));
+                    known.push(runtime);
+                }
+                None => println!(" - {}: unknown", harness.pretty_name),
+            }
+        }
+
+        let unknown = sorted_harnesses.len() - known.len();
+        let total = format_duration(wall_clock_time(&known, threads));
+        let thread_msg =
+            if threads == 1 { "1 thread".to_string() } else { format!("{threads} threads") };
+        if unknown == 0 {
+            println!("Estimated total wall-clock time with {thread_msg}: {total}");
+        } else {
+            println!(
+                "Estimated total wall-clock time with {thread_msg}: at least {total} \
+                ({unknown} harness{} without timing data)",
+                if unknown == 1 { "" } else { "es" }
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The time it takes to run tasks with the given durations on `threads` threads, where each task
+/// is started, in order, on the first thread that becomes available.
+fn wall_clock_time(durations: &[Duration], threads: usize) -> Duration {
+    let mut available_at = vec![Duration::ZERO; threads.max(1)];
+    for duration in durations {
+        let next = available_at.iter_mut().min().unwrap();
+        *next += *duration;
+    }
+    available_at.into_iter().max().unwrap()
+}
+
+/// Format a duration for humans, e.g., `1h 02m 05s`, `3m 20s` or `4.2s`.
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}h {:02}m {:02}s", secs / 3600, secs % 3600 / 60, secs % 60)
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{:.1}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_wall_clock_time() {
+        let secs = |secs: &[u64]| secs.iter().copied().map(Duration::from_secs).collect::<Vec<_>>();
+        assert_eq!(wall_clock_time(&[], 4), Duration::ZERO);
+        assert_eq!(wall_clock_time(&secs(&[10, 20, 30]), 1), Duration::from_secs(60));
+        // The third task starts on the first thread, once the first task is done.
+        assert_eq!(wall_clock_time(&secs(&[10, 20, 30]), 2), Duration::from_secs(40));
+        assert_eq!(wall_clock_time(&secs(&[10, 20, 30]), 8), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn check_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(4200)), "4.2s");
+        assert_eq!(format_duration(Duration::from_secs(200)), "3m 20s");
+        assert_eq!(format_duration(Duration::from_secs(3725)), "1h 02m 05s");
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--event-log`, which records a timeline of the run.
+//!
+//! Every [KaniSession] owns an [EventBus], which the different stages of the pipeline use to
+//! report what they are doing: stages that are timed with [KaniSession::with_timer], the commands
+//! that are executed, the temporary files that are created and removed, and the state of each
+//! harness. The bus forwards these events to its sinks. With `--event-log <FILE>` or `--debug`,
+//! the events are written to a file as JSON lines, one object per event, e.g.:
+//!
+//! ```json
+//! {"time_ms":1234.5,"thread":1,"event":"process_exit","program":"cbmc","exit_code":10}
+//! ```
+//!
+//! Each line is flushed as soon as it is written, so the log is complete even if Kani crashes.
+//!
+//! [KaniSession]: crate::session::KaniSession
+//! [KaniSession::with_timer]: crate::session::KaniSession::with_timer
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The file where the events are written with `--debug`, if `--event-log` is not given, relative
+/// to the target directory.
+pub const DEBUG_EVENT_LOG_FILE: &str = ".kani/events.jsonl";
+
+/// Something that happened during the run.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    StageStart {
+        stage: String,
+    },
+    StageFinish {
+        stage: String,
+        duration_ms: f64,
+    },
+    ProcessSpawn {
+        argv: Vec<String>,
+    },
+    /// The exit code is missing if the process couldn't be spawned, was killed by a signal or
+    /// timed out. CBMC being killed by a signal is reported as the exit code `128 + signal`,
+    /// like shells do.
+    ProcessExit {
+        program: String,
+        exit_code: Option<i32>,
+        timed_out: bool,
+    },
+    TempFileCreated {
+        path: PathBuf,
+    },
+    TempFileRemoved {
+        path: PathBuf,
+    },
+    HarnessState {
+        harness: String,
+        state: HarnessState,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HarnessState {
+    /// The harness is trivially vacuous, so it won't be verified.
+    Skipped,
+    Started,
+    Succeeded,
+    Failed,
+    TimedOut,
+}
+
+impl Event {
+    /// A [Event::ProcessSpawn] event for the given command.
+    pub fn process_spawn(cmd: &Command) -> Event {
+        let argv = std::iter::once(cmd.get_program())
+            .chain(cmd.get_args())
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect();
+        Event::ProcessSpawn { argv }
+    }
+}
+
+/// An event with the time at which it happened, as sent to the sinks.
+#[derive(Debug, Serialize)]
+pub struct EventRecord<'a> {
+    /// The time since the start of the session in milliseconds.
+    pub time_ms: f64,
+    /// The thread that emitted the event: 0 for the main thread, and the worker threads used to
+    /// verify harnesses are shifted by one.
+    pub thread: usize,
+    #[serde(flatten)]
+    pub event: &'a Event,
+}
+
+/// A consumer of the events emitted during the run.
+pub trait EventSink: Send {
+    fn handle(&mut self, record: &EventRecord);
+}
+
+/// Dispatch the events emitted during the run to the registered sinks.
+pub struct EventBus {
+    /// The time origin of all events.
+    start: Instant,
+    sinks: Mutex<Vec<Box<dyn EventSink>>>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        EventBus { start: Instant::now(), sinks: Mutex::new(vec![]) }
+    }
+}
+
+impl EventBus {
+    /// Send all the events emitted from now on to `sink`.
+    pub fn subscribe(&self, sink: Box<dyn EventSink>) {
+        self.sinks.lock().unwrap().push(sink);
+    }
+
+    pub fn emit(&self, event: Event) {
+        let mut sinks = self.sinks.lock().unwrap();
+        if sinks.is_empty() {
+            return;
+        }
+        let record = EventRecord {
+            time_ms: duration_ms(self.start.elapsed()),
+            thread: rayon::current_thread_index().map_or(0, |idx| idx + 1),
+            event: &event,
+        };
+        for sink in sinks.iter_mut() {
+            sink.handle(&record);
+        }
+    }
+
+    /// Execute `func` surrounded by the start and finish events of the given stage.
+    pub fn stage<T, F>(&self, stage: &str, func: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        self.emit(Event::StageStart { stage: stage.to_string() });
+        let start = Instant::now();
+        let ret = func();
+        let duration_ms = duration_ms(start.elapsed());
+        self.emit(Event::StageFinish { stage: stage.to_string(), duration_ms });
+        ret
+    }
+}
+
+fn duration_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Write every event as a line of JSON.
+pub struct JsonLinesSink {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    /// Whether writing failed, in which case we already warned the user and stop writing.
+    failed: bool,
+}
+
+impl JsonLinesSink {
+    pub fn create(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory `{}`", parent.display()))?;
+        }
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create event log `{}`", path.display()))?;
+        Ok(JsonLinesSink { path: path.to_path_buf(), writer: BufWriter::new(file), failed: false })
+    }
+
+    fn write(&mut self, record: &EventRecord) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        writeln!(self.writer)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+impl EventSink for JsonLinesSink {
+    fn handle(&mut self, record: &EventRecord) {
+        if self.failed {
+            return;
+        }
+        if let Err(err) = self.write(record) {
+            self.failed = true;
+            crate::util::warning(&format!(
+                "Failed to write event log `{}`: {err:#}",
+                self.path.display()
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log/events.jsonl");
+        let bus = EventBus::default();
+        bus.emit(Event::TempFileCreated { path: "ignored.goto".into() });
+        bus.subscribe(Box::new(JsonLinesSink::create(&path).unwrap()));
+        bus.stage("build", || {
+            bus.emit(Event::process_spawn(Command::new("cbmc").arg("--json-ui")));
+            bus.emit(Event::ProcessExit {
+                program: "cbmc".to_string(),
+                exit_code: Some(10),
+                timed_out: false,
+            });
+        });
+        bus.emit(Event::HarnessState {
+            harness: "check".to_string(),
+            state: HarnessState::TimedOut,
+        });
+
+        let events: Vec<serde_json::Value> = fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let kinds: Vec<_> = events.iter().map(|event| event["event"].as_str().unwrap()).collect();
+        assert_eq!(
+            kinds,
+            ["stage_start", "process_spawn", "process_exit", "stage_finish", "harness_state"]
+        );
+        assert_eq!(events[1]["argv"], serde_json::json!(["cbmc", "--json-ui"]));
+        assert_eq!(events[2]["exit_code"], 10);
+        assert_eq!(events[4]["state"], "timed_out");
+        assert_eq!(events[0]["thread"], 0);
+        assert!(events[3]["duration_ms"].is_f64());
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--check-feature-parity`.
+//!
+//! Cargo unifies the features of a package across all the dependents that are built together, so
+//! the features enabled for verification may differ from the ones enabled by `cargo test`, e.g.,
+//! when a dev-dependency enables an additional feature of a dependency. With
+//! `--check-feature-parity`, we ask `cargo tree` for the features resolved in both contexts, with
+//! the same package selection and feature options, and warn about every package whose features
+//! differ.
+
+use crate::args::common::{Verbosity, VerbosityLevel};
+use crate::session::{KaniSession, setup_cargo_command};
+use crate::util;
+use crate::util::args::CommandWrapper as _;
+use anyhow::{Result, bail};
+use cargo_metadata::Metadata;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The features enabled for each package, indexed by `<name> v<version>`.
+pub type FeatureSet = BTreeMap<String, BTreeSet<String>>;
+
+/// The features that are only enabled in one of the two contexts that are compared.
+#[derive(Debug, PartialEq, Eq)]
+struct FeatureDifference {
+    package: String,
+    only_verification: Vec<String>,
+    only_test: Vec<String>,
+}
+
+impl KaniSession {
+    /// Warn about the packages whose features differ between verification and `cargo test`.
+    pub fn check_feature_parity(&self) -> Result<()> {
+        // `cargo rustc` only pulls the dev-dependencies when verifying tests.
+        let verification_edges = if self.args.tests { "normal,build,dev" } else { "normal,build" };
+        let verification = self.cargo_tree_features(verification_edges)?;
+        let test = self.cargo_tree_features("normal,build,dev")?;
+        let differences = feature_differences(&verification, &test);
+        for difference in &differences {
+            let mut msg =
+                format!("The features of `{}` differ from `cargo test`:", difference.package);
+            if !difference.only_verification.is_empty() {
+                msg.push_str(&format!(
+                    "\n  only enabled for verification: {}",
+                    difference.only_verification.join(", ")
+                ));
+            }
+            if !difference.only_test.is_empty() {
+                msg.push_str(&format!(
+                    "\n  only enabled by `cargo test`: {}",
+                    difference.only_test.join(", ")
+                ));
+            }
+            util::warning(&msg);
+        }
+        if differences.is_empty() && self.args.common_args.level() >= VerbosityLevel::Harness {
+            println!("The features resolved for verification match the ones of `cargo test`.");
+        }
+        Ok(())
+    }
+
+    /// The features resolved by `cargo tree` when the given dependency edges are built.
+    fn cargo_tree_features(&self, edges: &str) -> Result<FeatureSet> {
+        let mut cmd = setup_cargo_command()?;
+        cmd.args(["tree", "--prefix", "none", "--format", "{p}|{f}", "--edges", edges]);
+        cmd.pass_cargo_args(&self.args.cargo.to_cargo_args());
+        for package in &self.args.cargo.exclude {
+            cmd.args(["--exclude", package]);
+        }
+        let output = cmd.output()?;
+        if !output.status.success() {
+            bail!(
+                "Failed to resolve the features with `cargo tree` ({}):\n{}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+        Ok(parse_cargo_tree(&String::from_utf8_lossy(&output.stdout)))
+    }
+}
+
+/// Add the features that each package was actually built with to `features`, according to the
+/// artifacts reported by the verification build.
+///
+/// Note that `cargo metadata` resolves the features of the whole dependency graph, including
+/// the dev-dependencies, so it doesn't reflect the verification build.
+pub fn add_built_features(
+    features: &mut FeatureSet,
+    metadata: &Metadata,
+    artifacts: &[cargo_metadata::Artifact],
+) {
+    for artifact in artifacts {
+        let Some(package) = metadata.packages.iter().find(|pkg| pkg.id == artifact.package_id)
+        else {
+            continue;
+        };
+        features
+            .entry(format!("{} v{}", package.name, package.version))
+            .or_default()
+            .extend(artifact.features.iter().cloned());
+    }
+}
+
+/// Parse the output of `cargo tree --prefix none --format "{p}|{f}"`.
+///
+/// Each line has the form `<name> v<version> [(<path>)]|<features>`, and the lines of packages
+/// that were already printed end with ` (*)`.
+fn parse_cargo_tree(output: &str) -> FeatureSet {
+    let mut features = FeatureSet::new();
+    for line in output.lines() {
+        let Some((package, package_features)) = line.rsplit_once('|') else { continue };
+        let package = package.split(" (").next().unwrap_or(package).trim();
+        let package_features = package_features.trim_end_matches(" (*)").trim();
+        features.entry(package.to_string()).or_default().extend(
+            package_features.split(',').filter(|feature| !feature.is_empty()).map(String::from),
+        );
+    }
+    features
+}
+
+/// The packages whose features differ between the two contexts.
+fn feature_differences(verification: &FeatureSet, test: &FeatureSet) -> Vec<FeatureDifference> {
+    let empty = BTreeSet::new();
+    let packages: BTreeSet<&String> = verification.keys().chain(test.keys()).collect();
+    packages
+        .into_iter()
+        .filter_map(|package| {
+            let verification = verification.get(package).unwrap_or(&empty);
+            let test = test.get(package).unwrap_or(&empty);
+            let only_verification: Vec<_> = verification.difference(test).cloned().collect();
+            let only_test: Vec<_> = test.difference(verification).cloned().collect();
+            (!only_verification.is_empty() || !only_test.is_empty()).then(|| FeatureDifference {
+                package: package.clone(),
+                only_verification,
+                only_test,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_feature_differences() {
+        let verification = parse_cargo_tree(
+            "my_crate v0.1.0 (/home/user/my_crate)|default,fast\n\
+            serde v1.0.200|std\n\
+            log v0.4.21|\n\
+            serde v1.0.200|std (*)\n",
+        );
+        assert_eq!(
+            verification["my_crate v0.1.0"],
+            BTreeSet::from(["default".into(), "fast".into()])
+        );
+        assert!(verification["log v0.4.21"].is_empty());
+
+        let test = parse_cargo_tree(
+            "my_crate v0.1.0 (/home/user/my_crate)|default,fast\n\
+            serde v1.0.200|derive,std\n\
+            log v0.4.21|\n\
+            proptest v1.4.0|default,std\n",
+        );
+        assert_eq!(
+            feature_differences(&verification, &test),
+            vec![
+                FeatureDifference {
+                    package: "proptest v1.4.0".to_string(),
+                    only_verification: vec![],
+                    only_test: vec!["default".to_string(), "std".to_string()],
+                },
+                FeatureDifference {
+                    package: "serde v1.0.200".to_string(),
+                    only_verification: vec![],
+                    only_test: vec!["derive".to_string()],
+                },
+            ]
+        );
+        assert!(feature_differences(&test, &test).is_empty());
+    }
+}
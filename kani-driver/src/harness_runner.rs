@@ -4,12 +4,17 @@
 use anyhow::{Error, Result, bail};
 use kani_metadata::{ArtifactType, HarnessKind, HarnessMetadata};
 use rayon::prelude::*;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
+use std::time::Duration;
 
+use crate::args::common::{Verbosity, VerbosityLevel};
 use crate::args::{NumThreads, OutputFormat};
-use crate::call_cbmc::{VerificationResult, VerificationStatus};
+use crate::call_cbmc::{ExitStatus, ExpectFailOutcome, VerificationResult, VerificationStatus};
+use crate::chrome_trace;
+use crate::event_log::{Event, HarnessState};
 use crate::project::Project;
 use crate::session::{BUG_REPORT_URL, KaniSession};
 
@@ -70,48 +75,114 @@ pub(crate) fn check_all_harnesses(
             }
             builder.build()?
         };
+        if pool.current_num_threads() > 1 {
+            let goto_files = sorted_harnesses.iter().filter_map(|harness| {
+                let goto_file: &Path =
+                    self.project.get_harness_artifact(harness, ArtifactType::Goto)?;
+                Some((harness.pretty_name.as_str(), goto_file))
+            });
+            if let Some((first, second)) = find_shared_goto_file(goto_files) {
+                bail!(
+                    "Cannot verify harnesses `{first}` and `{second}` in parallel since they \
+                    share the same goto binary. Use `--jobs 1` to verify them sequentially."
+                );
+            }
+        }
 
         let results = pool.install(|| -> Result<Vec<HarnessResult<'pr>>> {
             sorted_harnesses
                 .par_iter()
                 .enumerate()
-                .map(|(idx, harness)| -> Result<HarnessResult<'pr>> {
-                    let goto_file =
-                        self.project.get_harness_artifact(harness, ArtifactType::Goto).unwrap();
-
-                    self.sess.instrument_model(goto_file, goto_file, self.project, harness)?;
-
-                    if self.sess.args.synthesize_loop_contracts {
-                        self.sess.synthesize_loop_contracts(goto_file, goto_file, harness)?;
-                    }
-
-                    let result = self.sess.check_harness(goto_file, harness)?;
-                    if self.sess.args.fail_fast && result.status == VerificationStatus::Failure {
-                        Err(Error::new(FailFastHarnessInfo {
-                            index_to_failing_harness: idx,
-                            result,
-                        }))
-                    } else {
-                        Ok(HarnessResult { harness, result })
-                    }
-                })
-                .collect::<Result<Vec<_>>>()
+                .map(|(idx, harness)| self.check_one_harness(idx, harness))
+                .collect()
         });
-        match results {
-            Ok(results) => Ok(results),
+        let mut results = match results {
+            Ok(results) => results,
             Err(err) => {
                 if err.is::<FailFastHarnessInfo>() {
                     let failed = err.downcast::<FailFastHarnessInfo>().unwrap();
-                    Ok(vec![HarnessResult {
+                    vec![HarnessResult {
                         harness: sorted_harnesses[failed.index_to_failing_harness],
                         result: failed.result,
-                    }])
+                    }]
                 } else {
-                    Err(err)
+                    return Err(err);
                 }
             }
+        };
+        // See `KaniSession::check_harness`: with more than one thread, the concrete playback tests
+        // are generated once all harnesses are verified, in the order of `sorted_harnesses`.
+        if pool.current_num_threads() > 1 {
+            for HarnessResult { harness, result } in &mut results {
+                self.sess.gen_and_add_concrete_playback(harness, result)?;
+            }
         }
+        Ok(results)
     }
+
+    /// Verify the harness at position `idx` of the sorted harnesses.
+    fn check_one_harness(
+        &self,
+        idx: usize,
+        harness: &'pr HarnessMetadata,
+    ) -> Result<HarnessResult<'pr>> {
+        // The compiler skips the code generation of harnesses that start with a contradictory
+        // assumption, so there is nothing to verify.
+        if harness.is_vacuous {
+            if self.sess.args.common_args.level() >= VerbosityLevel::Harness {
+                crate::util::warning(&format!(
+                    "Harness `{}` is vacuous because it starts with \
+                    `kani::assume(false)`. Skipping verification.",
+                    harness.pretty_name
+                ));
+            }
+            self.sess.events.emit(Event::HarnessState {
+                harness: harness.pretty_name.clone(),
+                state: HarnessState::Skipped,
+            });
+            return Ok(HarnessResult { harness, result: VerificationResult::mock_success() });
+        }
+
+        let goto_file = self.project.get_harness_artifact(harness, ArtifactType::Goto).unwrap();
+
+        self.sess.events.emit(Event::HarnessState {
+            harness: harness.pretty_name.clone(),
+            state: HarnessState::Started,
+        });
+        let result = chrome_trace::record(&harness.pretty_name, "harness", || {
+            self.sess.instrument_model(goto_file, goto_file, self.project, harness)?;
+
+            if self.sess.args.synthesize_loop_contracts {
+                self.sess.synthesize_loop_contracts(goto_file, goto_file, harness)?;
+            }
+
+            self.sess.check_harness(goto_file, harness)
+        })?;
+        let state = match (&result.status, &result.results) {
+            (_, Err(ExitStatus::Timeout)) => HarnessState::TimedOut,
+            (VerificationStatus::Success, _) => HarnessState::Succeeded,
+            (VerificationStatus::Failure, _) => HarnessState::Failed,
+        };
+        self.sess.events.emit(Event::HarnessState { harness: harness.pretty_name.clone(), state });
+        if self.sess.args.fail_fast && result.status == VerificationStatus::Failure {
+            Err(Error::new(FailFastHarnessInfo { index_to_failing_harness: idx, result }))
+        } else {
+            Ok(HarnessResult { harness, result })
+        }
+    }
+}
+
+/// Find two harnesses that are verified from the same goto binary, if any.
+///
+/// The goto binary of a harness is instrumented in place, so harnesses that share one cannot be
+/// verified in parallel.
+fn find_shared_goto_file<'a>(
+    goto_files: impl IntoIterator<Item = (&'a str, &'a Path)>,
+) -> Option<(&'a str, &'a str)> {
+    let mut owners = HashMap::new();
+    goto_files.into_iter().find_map(|(harness, goto_file)| {
+        owners.insert(goto_file, harness).map(|previous| (previous, harness))
+    })
 }
 
 impl KaniSession {
@@ -126,7 +197,12 @@ fn process_output(
                 self.write_output_to_file(result, harness, thread_index);
             }
 
-            let output = result.render(&self.args.output_format, harness.attributes.should_panic);
+            let output = result.render(
+                &self.args.output_format,
+                harness.attributes.should_panic,
+                self.args.trace_filter.as_ref(),
+                self.args.trace_depth_limit,
+            );
             if rayon::current_num_threads() > 1 {
                 println!("Thread {thread_index}: {output}");
             } else {
@@ -136,7 +212,8 @@ fn process_output(
     }
 
     fn should_print_output(&self) -> bool {
-        !self.args.common_args.quiet && self.args.output_format != OutputFormat::Old
+        self.args.common_args.level() >= VerbosityLevel::Harness
+            && self.args.output_format != OutputFormat::Old
     }
 
     fn write_output_to_file(
@@ -152,8 +229,12 @@ fn write_output_to_file(
 
         std::fs::create_dir_all(prefix).unwrap();
         let mut file = File::create(&file_name).unwrap();
-        let mut file_output =
-            result.render(&OutputFormat::Regular, harness.attributes.should_panic);
+        let mut file_output = result.render(
+            &OutputFormat::Regular,
+            harness.attributes.should_panic,
+            self.args.trace_filter.as_ref(),
+            self.args.trace_depth_limit,
+        );
         if rayon::current_num_threads() > 1 {
             file_output = format!("Thread {thread_index}:\n{file_output}");
         }
@@ -179,9 +260,11 @@ pub(crate) fn check_harness(
         harness: &HarnessMetadata,
     ) -> Result<VerificationResult> {
         let thread_index = rayon::current_thread_index().unwrap_or_default();
-        if !self.args.common_args.quiet {
+        if self.args.common_args.level() >= VerbosityLevel::Harness {
             // If the harness is automatically generated, pretty_name refers to the function under verification.
-            let mut msg = if harness.is_automatically_generated {
+            let mut msg = if harness.is_automatically_generated
+                && self.autoharness_compiler_flags.is_some()
+            {
                 if matches!(harness.attributes.kind, HarnessKind::Proof) {
                     format!(
                         "Autoharness: Checking function {} against all possible inputs...",
@@ -202,34 +285,87 @@ pub(crate) fn check_harness(
             }
 
             println!("{msg}");
+            if harness.debug_assertions > 0 {
+                println!(
+                    "{} debug assertions {} (--assertion-semantics={})",
+                    harness.debug_assertions,
+                    self.args.assertion_semantics.description(),
+                    self.args.assertion_semantics
+                );
+            }
+        }
+        if self.args.common_args.verbose() {
+            println!(
+                "Harness {} introduces {} nondeterministic bytes",
+                harness.pretty_name, harness.nondet_bytes
+            );
         }
 
         let mut result = self.with_timer(|| self.run_cbmc(binary, harness), "run_cbmc")?;
 
         self.process_output(&result, harness, thread_index);
-        self.gen_and_add_concrete_playback(harness, &mut result)?;
+        self.check_expected_time(harness, result.runtime);
+        // In-place playback inserts the tests after each harness, at the line recorded during
+        // compilation. Harnesses are sorted so that the tests of one harness don't shift the lines
+        // of the next ones, so with more than one thread the tests are generated by
+        // `HarnessRunner::check_all_harnesses` once all harnesses are verified.
+        if rayon::current_num_threads() == 1 {
+            self.gen_and_add_concrete_playback(harness, &mut result)?;
+        }
         Ok(result)
     }
 
+    /// Warn if the verification time of a harness annotated with `#[kani::expected_time]`
+    /// deviates from the annotation by more than `--time-deviation-pct` percent.
+    fn check_expected_time(&self, harness: &HarnessMetadata, runtime: Duration) {
+        let Some(expected_secs) = harness.attributes.expected_time_secs else { return };
+        let expected = Duration::from_secs(expected_secs);
+        if let Some(deviation) = time_deviation(runtime, expected, self.args.time_deviation_pct) {
+            crate::util::warning(&format!(
+                "Verification of harness `{}` took {:.1}s, which is {deviation} than the expected \
+                {expected_secs}s.",
+                harness.pretty_name,
+                runtime.as_secs_f64()
+            ));
+        }
+    }
+
     /// Concludes a session by printing a summary report and exiting the process with an
     /// error code (if applicable).
     ///
     /// Note: Takes `self` "by ownership". This function wants to be able to drop before
     /// exiting with an error code, if needed.
     pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result<()> {
-        if self.args.common_args.quiet {
-            return Ok(());
+        let unchecked = unchecked_harnesses(results);
+        if self.args.common_args.level() < VerbosityLevel::Summary {
+            return self.check_unchecked_harnesses(&unchecked);
         }
 
-        let (automatic, manual): (Vec<_>, Vec<_>) =
-            results.iter().partition(|r| r.harness.is_automatically_generated);
+        // Outside of the autoharness subcommand, automatic harnesses (e.g., the ones generated by
+        // `-Z contracts-batch`) are reported together with the manual ones.
+        let (automatic, manual): (Vec<_>, Vec<_>) = results.iter().partition(|r| {
+            r.harness.is_automatically_generated && self.autoharness_compiler_flags.is_some()
+        });
 
+        // Harnesses that start with `kani::assume(false)` were skipped, not verified.
+        let (skipped, manual): (Vec<_>, Vec<_>) =
+            manual.into_iter().partition(|r| r.harness.is_vacuous);
         let (successes, failures): (Vec<_>, Vec<_>) =
             manual.into_iter().partition(|r| r.result.status == VerificationStatus::Success);
+        // Harnesses annotated with `#[kani::proof(expect_fail)]` that failed as expected.
+        let (expected_failures, successes): (Vec<_>, Vec<_>) = successes
+            .into_iter()
+            .partition(|r| r.result.expect_fail == Some(ExpectFailOutcome::ExpectedFailure));
 
         let succeeding = successes.len();
+        let expected_failing = expected_failures.len();
         let failing = failures.len();
-        let total = succeeding + failing;
+        let total = succeeding + expected_failing + failing + skipped.len();
+        let skipped_count = if skipped.is_empty() {
+            String::new()
+        } else {
+            format!(", {} vacuous harnesses skipped", skipped.len())
+        };
 
         if self.args.concrete_playback.is_some() {
             if failures.is_empty() {
@@ -246,12 +382,35 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
         println!("Manual Harness Summary:");
 
         for failure in failures.iter() {
-            println!("Verification failed for - {}", failure.harness.pretty_name);
+            let verdict = if failure.result.expect_fail == Some(ExpectFailOutcome::UnexpectedPass) {
+                "Unexpected pass for"
+            } else {
+                "Verification failed for"
+            };
+            println!(
+                "{verdict} - {}{}",
+                failure.harness.pretty_name,
+                self.harness_meta_suffix(failure.harness)
+            );
         }
 
-        if total > 0 {
+        for skip in skipped.iter() {
+            println!("Skipped vacuous harness - {}", skip.harness.pretty_name);
+        }
+
+        if total > 0 && expected_failing > 0 {
+            println!(
+                "Complete - {succeeding} successfully verified harnesses, {expected_failing} expected failures, {failing} failures{skipped_count}, {total} total."
+            );
+        } else if total > 0 {
+            println!(
+                "Complete - {succeeding} successfully verified harnesses, {failing} failures{skipped_count}, {total} total."
+            );
+        } else if let Some(partition) = self.args.partition {
+            // Shards can be empty when there are few harnesses, which isn't an error.
             println!(
-                "Complete - {succeeding} successfully verified harnesses, {failing} failures, {total} total."
+                "No proof harnesses were assigned to partition {}/{}.",
+                partition.index, partition.count
             );
         } else {
             match self.args.harnesses.as_slice() {
@@ -272,6 +431,16 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
             };
         }
 
+        let concretized = results
+            .iter()
+            .filter(|r| r.harness.uses_concretize)
+            .map(|r| r.harness.pretty_name.as_str())
+            .collect::<Vec<_>>();
+        if let Some(msg) = concretize_warning(&concretized) {
+            crate::util::warning(&msg);
+        }
+        self.check_unchecked_harnesses(&unchecked)?;
+
         if self.args.coverage {
             self.show_coverage_summary()?;
         }
@@ -291,6 +460,42 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
         Ok(())
     }
 
+    /// Report the harnesses that have no properties to check, since they succeed trivially.
+    /// This is an error if the user passed `--deny-unchecked-harnesses`.
+    fn check_unchecked_harnesses(&self, harnesses: &[&str]) -> Result<()> {
+        if self.args.deny_unchecked_harnesses && !harnesses.is_empty() {
+            bail!(
+                "The following harness(es) have no properties to check: `{}`.\n\
+                Add assertions or `kani::cover!` statements to check the code they call.",
+                harnesses.join("`, `")
+            );
+        }
+        if self.args.common_args.level() >= VerbosityLevel::Summary
+            && let Some(msg) = unchecked_warning(harnesses)
+        {
+            crate::util::warning(&msg);
+        }
+        Ok(())
+    }
+
+    /// The metadata selected with `--show-meta` for the given harness, e.g. ` (owner: team-a)`.
+    /// Keys that the harness doesn't define are shown as `<none>`.
+    fn harness_meta_suffix(&self, harness: &HarnessMetadata) -> String {
+        if self.args.show_meta.is_empty() {
+            return String::new();
+        }
+        let values = self
+            .args
+            .show_meta
+            .iter()
+            .map(|key| {
+                let value = harness.attributes.metadata.get(key).map_or("<none>", String::as_str);
+                format!("{key}: {value}")
+            })
+            .collect::<Vec<_>>();
+        format!(" ({})", values.join(", "))
+    }
+
     /// Show a coverage summary.
     ///
     /// This is just a placeholder for now.
@@ -298,3 +503,142 @@ fn show_coverage_summary(&self) -> Result<()> {
         Ok(())
     }
 }
+
+/// Describe how much `runtime` deviates from `expected`, e.g., `75% longer`, if the deviation is
+/// more than `max_pct` percent.
+fn time_deviation(runtime: Duration, expected: Duration, max_pct: u32) -> Option<String> {
+    let expected = expected.as_secs_f64();
+    let runtime = runtime.as_secs_f64();
+    if expected == 0.0 {
+        return None;
+    }
+    let pct = (runtime - expected) / expected * 100.0;
+    if pct.abs() <= f64::from(max_pct) {
+        None
+    } else if pct > 0.0 {
+        Some(format!("{pct:.0}% longer"))
+    } else {
+        Some(format!("{:.0}% shorter", -pct))
+    }
+}
+
+/// The harnesses that were verified without any property to check, i.e., without any reachable
+/// assertion, cover statement, or automatic check. Harnesses that were skipped because they are
+/// trivially vacuous are reported separately.
+fn unchecked_harnesses<'a>(results: &'a [HarnessResult<'_>]) -> Vec<&'a str> {
+    results
+        .iter()
+        .filter(|r| !r.harness.is_vacuous)
+        .filter(|r| matches!(&r.result.results, Ok(properties) if properties.is_empty()))
+        .map(|r| r.harness.pretty_name.as_str())
+        .collect()
+}
+
+/// The warning for the harnesses that have no properties to check, which succeed trivially.
+fn unchecked_warning(harnesses: &[&str]) -> Option<String> {
+    let count = match harnesses.len() {
+        0 => return None,
+        1 => "1 harness has".to_string(),
+        n => format!("{n} harnesses have"),
+    };
+    Some(format!(
+        "{count} no properties to check, so verification trivially succeeds: `{}`",
+        harnesses.join("`, `")
+    ))
+}
+
+/// The warning for the harnesses that use `kani::concretize`, whose results only hold for a
+/// single value of the concretized inputs.
+fn concretize_warning(harnesses: &[&str]) -> Option<String> {
+    let count = match harnesses.len() {
+        0 => return None,
+        1 => "1 harness".to_string(),
+        n => format!("{n} harnesses"),
+    };
+    Some(format!(
+        "{count} used `kani::concretize`, so the results only hold for a single value of the \
+        concretized inputs: `{}`",
+        harnesses.join("`, `")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cbmc_output_parser::{CheckStatus, Property, PropertyId, SourceLocation};
+    use crate::metadata::tests::mock_proof_harness;
+
+    #[test]
+    fn check_concretize_warning() {
+        assert_eq!(concretize_warning(&[]), None);
+        assert_eq!(
+            concretize_warning(&["check_a"]).unwrap(),
+            "1 harness used `kani::concretize`, so the results only hold for a single value of \
+            the concretized inputs: `check_a`"
+        );
+        assert!(
+            concretize_warning(&["check_a", "check_b"])
+                .unwrap()
+                .starts_with("2 harnesses used `kani::concretize`")
+        );
+    }
+
+    #[test]
+    fn check_unchecked_warning() {
+        assert_eq!(unchecked_warning(&[]), None);
+        assert_eq!(
+            unchecked_warning(&["check_a"]).unwrap(),
+            "1 harness has no properties to check, so verification trivially succeeds: `check_a`"
+        );
+        assert!(
+            unchecked_warning(&["check_a", "check_b"])
+                .unwrap()
+                .starts_with("2 harnesses have no properties to check")
+        );
+    }
+
+    #[test]
+    fn check_unchecked_harnesses() {
+        let checked = mock_proof_harness("check_checked", None, None, None);
+        let unchecked = mock_proof_harness("check_unchecked", None, None, None);
+        let mut vacuous = mock_proof_harness("check_vacuous", None, None, None);
+        vacuous.is_vacuous = true;
+        let mut with_property = VerificationResult::mock_success();
+        with_property.results = Ok(vec![Property {
+            description: "assertion failed: x > 0".into(),
+            property_id: PropertyId { fn_name: None, class: "assertion".into(), id: 1 },
+            source_location: SourceLocation::default(),
+            status: CheckStatus::Success,
+            reach: None,
+            trace: None,
+        }]);
+        let results = [
+            HarnessResult { harness: &checked, result: with_property },
+            HarnessResult { harness: &unchecked, result: VerificationResult::mock_success() },
+            HarnessResult { harness: &vacuous, result: VerificationResult::mock_success() },
+        ];
+        assert_eq!(unchecked_harnesses(&results), ["check_unchecked"]);
+    }
+
+    #[test]
+    fn check_time_deviation() {
+        let secs = Duration::from_secs;
+        assert_eq!(time_deviation(secs(30), secs(30), 50), None);
+        assert_eq!(time_deviation(secs(45), secs(30), 50), None);
+        assert_eq!(time_deviation(secs(15), secs(30), 50), None);
+        assert_eq!(time_deviation(secs(60), secs(30), 50).as_deref(), Some("100% longer"));
+        assert_eq!(time_deviation(secs(6), secs(30), 50).as_deref(), Some("80% shorter"));
+        assert_eq!(time_deviation(secs(36), secs(30), 10).as_deref(), Some("20% longer"));
+        assert_eq!(time_deviation(secs(1), secs(0), 50), None);
+    }
+
+    #[test]
+    fn check_find_shared_goto_file() {
+        let (a, b, c) = (Path::new("a.out"), Path::new("b.out"), Path::new("c.out"));
+        assert_eq!(find_shared_goto_file([("check_a", a), ("check_b", b), ("check_c", c)]), None);
+        assert_eq!(
+            find_shared_goto_file([("check_a", a), ("check_b", b), ("check_c", a)]),
+            Some(("check_a", "check_c"))
+        );
+    }
+}
@@ -8,19 +8,27 @@
     InvocationType,
     args::{
         VerificationArgs,
+        common::{Verbosity, VerbosityLevel},
         list_args::{CargoListArgs, StandaloneListArgs},
     },
     list::output::output_list_results,
     list::{FileName, HarnessName, ListMetadata},
+    metadata::from_json,
     project::{Project, cargo_project, standalone_project, std_project},
     session::KaniSession,
+    session_lock::LockMode,
     version::print_kani_version,
 };
 use anyhow::Result;
 use kani_metadata::{ContractedFunction, HarnessKind, HarnessMetadata, KaniMetadata};
 
-/// Process the KaniMetadata output from kani-compiler and output the list subcommand results
-pub fn process_metadata(metadata: Vec<KaniMetadata>) -> BTreeSet<ListMetadata> {
+/// Process the KaniMetadata output from kani-compiler and output the list subcommand results.
+///
+/// Only harnesses whose metadata matches all the `key=value` pairs in `meta_filters` are listed.
+pub fn process_metadata(
+    metadata: Vec<KaniMetadata>,
+    meta_filters: &[(String, String)],
+) -> BTreeSet<ListMetadata> {
     let mut list_metadata: BTreeSet<ListMetadata> = BTreeSet::new();
 
     let insert = |harness_meta: HarnessMetadata,
@@ -40,10 +48,27 @@ pub fn process_metadata(metadata: Vec<KaniMetadata>) -> BTreeSet<ListMetadata> {
         let mut contract_harnesses: BTreeMap<FileName, BTreeSet<HarnessName>> = BTreeMap::new();
         let mut contracted_functions: BTreeSet<ContractedFunction> = BTreeSet::new();
 
+        let mut harness_metadata: BTreeMap<HarnessName, BTreeMap<String, String>> = BTreeMap::new();
+        let mut concrete_harnesses: BTreeSet<HarnessName> = BTreeSet::new();
+        let mut filtered_out: BTreeSet<HarnessName> = BTreeSet::new();
+
         let mut standard_harnesses_count = 0;
         let mut contract_harnesses_count = 0;
 
         for harness_meta in kani_meta.proof_harnesses {
+            if !matches_meta_filters(&harness_meta, meta_filters) {
+                filtered_out.insert(harness_meta.pretty_name);
+                continue;
+            }
+            if !harness_meta.attributes.metadata.is_empty() {
+                harness_metadata.insert(
+                    harness_meta.pretty_name.clone(),
+                    harness_meta.attributes.metadata.clone(),
+                );
+            }
+            if harness_meta.is_concrete {
+                concrete_harnesses.insert(harness_meta.pretty_name.clone());
+            }
             match harness_meta.attributes.kind {
                 HarnessKind::Proof => {
                     insert(harness_meta, &mut standard_harnesses, &mut standard_harnesses_count);
@@ -55,7 +80,10 @@ pub fn process_metadata(metadata: Vec<KaniMetadata>) -> BTreeSet<ListMetadata> {
             }
         }
 
-        contracted_functions.extend(kani_meta.contracted_functions.into_iter());
+        contracted_functions.extend(kani_meta.contracted_functions.into_iter().map(|mut cf| {
+            cf.harnesses.retain(|harness| !filtered_out.contains(harness));
+            cf
+        }));
 
         list_metadata.insert(ListMetadata {
             crate_name: kani_meta.crate_name,
@@ -64,31 +92,48 @@ pub fn process_metadata(metadata: Vec<KaniMetadata>) -> BTreeSet<ListMetadata> {
             contract_harnesses,
             contract_harnesses_count,
             contracted_functions,
+            harness_metadata,
+            concrete_harnesses,
         });
     }
 
     list_metadata
 }
 
+/// Whether the harness metadata contains all the given `key=value` pairs.
+fn matches_meta_filters(harness: &HarnessMetadata, meta_filters: &[(String, String)]) -> bool {
+    meta_filters
+        .iter()
+        .all(|(key, value)| harness.attributes.metadata.get(key).is_some_and(|v| v == value))
+}
+
 pub fn list_cargo(args: CargoListArgs, mut verify_opts: VerificationArgs) -> Result<()> {
-    let quiet = args.common_args.quiet;
+    let level = args.common_args.level();
+    let quiet = level < VerbosityLevel::Summary;
     verify_opts.common_args = args.common_args;
-    let mut session = KaniSession::new(verify_opts)?;
-    if !quiet {
+    let mut session = KaniSession::with_lock_mode(verify_opts, LockMode::Shared)?;
+    if level >= VerbosityLevel::Harness {
         print_kani_version(InvocationType::CargoKani(vec![]));
     }
 
-    let project = cargo_project(&mut session, false)?;
-    let list_metadata = process_metadata(project.metadata);
+    let metadata = if args.fast {
+        session.metadata_only = true;
+        let outputs = session.cargo_build(false)?;
+        outputs.metadata.iter().map(|md_file| from_json(md_file)).collect::<Result<Vec<_>>>()?
+    } else {
+        cargo_project(&mut session, false)?.metadata
+    };
+    let list_metadata = process_metadata(metadata, &args.meta);
 
-    output_list_results(list_metadata, args.format, quiet)
+    output_list_results(list_metadata, args.format, quiet, args.fast)
 }
 
 pub fn list_standalone(args: StandaloneListArgs, mut verify_opts: VerificationArgs) -> Result<()> {
-    let quiet = args.common_args.quiet;
+    let level = args.common_args.level();
+    let quiet = level < VerbosityLevel::Summary;
     verify_opts.common_args = args.common_args;
-    let session = KaniSession::new(verify_opts)?;
-    if !quiet {
+    let session = KaniSession::with_lock_mode(verify_opts, LockMode::Shared)?;
+    if level >= VerbosityLevel::Harness {
         print_kani_version(InvocationType::Standalone);
     }
 
@@ -98,7 +143,52 @@ pub fn list_standalone(args: StandaloneListArgs, mut verify_opts: VerificationAr
         standalone_project(&args.input, args.crate_name, &session)?
     };
 
-    let list_metadata = process_metadata(project.metadata);
+    let list_metadata = process_metadata(project.metadata, &args.meta);
 
-    output_list_results(list_metadata, args.format, quiet)
+    output_list_results(list_metadata, args.format, quiet, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+
+    fn harness_with_owner(name: &str, owner: Option<&str>) -> HarnessMetadata {
+        let mut harness = mock_proof_harness(name, None, Some("my_crate"), None);
+        if let Some(owner) = owner {
+            harness.attributes.metadata.insert("owner".to_string(), owner.to_string());
+        }
+        harness
+    }
+
+    #[test]
+    fn check_meta_filter() {
+        let metadata = KaniMetadata {
+            crate_name: "my_crate".to_string(),
+            proof_harnesses: vec![
+                harness_with_owner("check_storage", Some("team-storage")),
+                harness_with_owner("check_network", Some("team-network")),
+                harness_with_owner("check_unowned", None),
+            ],
+            unsupported_features: vec![],
+            test_harnesses: vec![],
+            contracted_functions: vec![],
+            autoharness_md: None,
+            mutants: vec![],
+        };
+
+        let all = process_metadata(vec![metadata.clone()], &[]);
+        let all = all.first().unwrap();
+        assert_eq!(all.standard_harnesses_count, 3);
+        assert_eq!(all.harness_metadata.len(), 2);
+
+        let filter = [("owner".to_string(), "team-storage".to_string())];
+        let filtered = process_metadata(vec![metadata], &filter);
+        let filtered = filtered.first().unwrap();
+        assert_eq!(filtered.standard_harnesses_count, 1);
+        assert_eq!(
+            filtered.standard_harnesses.values().flatten().collect::<Vec<_>>(),
+            vec!["check_storage"]
+        );
+    }
 }
@@ -26,6 +26,10 @@ pub struct ListMetadata {
     contract_harnesses_count: usize,
     // Set of all functions under contract
     contracted_functions: BTreeSet<ContractedFunction>,
+    // Harnesses mapped to their `#[kani::proof(key = "value")]` metadata, if they have any
+    harness_metadata: BTreeMap<HarnessName, BTreeMap<String, String>>,
+    // Harnesses that don't reach any source of non-determinism
+    concrete_harnesses: BTreeSet<HarnessName>,
 }
 
 /// Given a collection of ListMetadata objects, merge them into a single ListMetadata object.
@@ -42,6 +46,8 @@ pub fn merge_list_metadata<T>(collection: T) -> ListMetadata
             acc.contract_harnesses.extend(item.contract_harnesses);
             acc.contract_harnesses_count += item.contract_harnesses_count;
             acc.contracted_functions.extend(item.contracted_functions);
+            acc.harness_metadata.extend(item.harness_metadata);
+            acc.concrete_harnesses.extend(item.concrete_harnesses);
             acc
         })
         .expect("Cannot merge empty collection of ListMetadata objects")
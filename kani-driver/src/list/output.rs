@@ -13,6 +13,7 @@
 use crate::{
     args::list_args::Format,
     list::{ListMetadata, merge_list_metadata},
+    metadata::concrete_harness_note,
     version::KANI_VERSION,
 };
 use anyhow::Result;
@@ -22,19 +23,26 @@
 
 // Represents the version of our JSON file format.
 // Increment this version (according to semantic versioning rules) whenever the JSON output format changes.
-const FILE_VERSION: &str = "0.1";
+const FILE_VERSION: &str = "0.2";
 const OUTPUT_FILENAME: &str = "kani-list";
 
+/// Note added to the output when the results were collected without codegen (`--fast`).
+const METADATA_ONLY_NOTE: &str = "Note: These results were collected with `--fast`, which skips \
+    code generation. Information that requires reachability analysis is not included.";
+
 /// Output the results of the list subcommand.
+///
+/// `metadata_only` indicates that the results were collected without code generation.
 pub fn output_list_results(
     list_metadata: BTreeSet<ListMetadata>,
     format: Format,
     quiet: bool,
+    metadata_only: bool,
 ) -> Result<()> {
     match format {
-        Format::Pretty => pretty(list_metadata),
-        Format::Markdown => markdown(list_metadata, quiet),
-        Format::Json => json(list_metadata, quiet),
+        Format::Pretty => pretty(list_metadata, metadata_only),
+        Format::Markdown => markdown(list_metadata, quiet, metadata_only),
+        Format::Json => json(list_metadata, quiet, metadata_only),
     }
 }
 
@@ -48,6 +56,15 @@ fn markdown_constructor(header: Vec<String>, rows: Vec<Vec<String>>) -> Result<M
     Ok(MarkdownTable::new(Some(header), rows)?)
 }
 
+/// Construct the notes for the harnesses that have no symbolic inputs.
+fn concrete_harness_notes(list_metadata: &BTreeSet<ListMetadata>) -> String {
+    list_metadata
+        .iter()
+        .flat_map(|md| &md.concrete_harnesses)
+        .map(|harness| format!("\n{}", concrete_harness_note(harness)))
+        .collect()
+}
+
 /// Construct the "Contracts" and "Standard Harnesses" tables.
 /// `table_constructor` is a function that, given the header and rows for the tables, creates a particular kind of table.
 fn construct_output<T: Display>(
@@ -80,22 +97,36 @@ fn construct_output<T: Display>(
 }
 
 /// Print results to the terminal.
-fn pretty(list_metadata: BTreeSet<ListMetadata>) -> Result<()> {
+fn pretty(list_metadata: BTreeSet<ListMetadata>, metadata_only: bool) -> Result<()> {
+    let concrete_notes = concrete_harness_notes(&list_metadata);
     let (contract_output, standard_output) = construct_output(list_metadata, pretty_constructor)?;
     println!("{contract_output}");
     println!("{standard_output}");
+    if !concrete_notes.is_empty() {
+        println!("{concrete_notes}");
+    }
+    if metadata_only {
+        println!("\n{METADATA_ONLY_NOTE}");
+    }
 
     Ok(())
 }
 
 /// Output results to a Markdown file.
-fn markdown(list_metadata: BTreeSet<ListMetadata>, quiet: bool) -> Result<()> {
+fn markdown(list_metadata: BTreeSet<ListMetadata>, quiet: bool, metadata_only: bool) -> Result<()> {
+    let concrete_notes = concrete_harness_notes(&list_metadata);
     let (contract_output, standard_output) = construct_output(list_metadata, markdown_constructor)?;
 
     let out_path = Path::new(OUTPUT_FILENAME).with_extension("md");
     let mut out_file = File::create(&out_path).unwrap();
     out_file.write_all(contract_output.as_bytes()).unwrap();
     out_file.write_all(standard_output.as_bytes()).unwrap();
+    if !concrete_notes.is_empty() {
+        out_file.write_all(format!("\n{concrete_notes}\n").as_bytes()).unwrap();
+    }
+    if metadata_only {
+        out_file.write_all(format!("\n\n{METADATA_ONLY_NOTE}\n").as_bytes()).unwrap();
+    }
     if !quiet {
         println!("Wrote list results to {}", std::fs::canonicalize(&out_path)?.display());
     }
@@ -103,24 +134,33 @@ fn markdown(list_metadata: BTreeSet<ListMetadata>, quiet: bool) -> Result<()> {
 }
 
 /// Output results as a JSON file.
-fn json(list_metadata: BTreeSet<ListMetadata>, quiet: bool) -> Result<()> {
+fn json(list_metadata: BTreeSet<ListMetadata>, quiet: bool, metadata_only: bool) -> Result<()> {
     let out_path = Path::new(OUTPUT_FILENAME).with_extension("json");
     let out_file = File::create(&out_path).unwrap();
     let writer = BufWriter::new(out_file);
 
     let combined_md = merge_list_metadata(list_metadata);
+    // The contract clauses are documented by the `contracts-doc` subcommand.
+    let contracts: Vec<_> = combined_md
+        .contracted_functions
+        .iter()
+        .map(|cf| json!({"function": cf.function, "file": cf.file, "harnesses": cf.harnesses}))
+        .collect();
 
     let json_obj = json!({
         "kani-version": KANI_VERSION,
         "file-version": FILE_VERSION,
         "standard-harnesses": combined_md.standard_harnesses,
         "contract-harnesses": combined_md.contract_harnesses,
-        "contracts": combined_md.contracted_functions,
+        "contracts": contracts,
+        "harness-metadata": combined_md.harness_metadata,
+        "concrete-harnesses": combined_md.concrete_harnesses,
         "totals": {
             "standard-harnesses": combined_md.standard_harnesses_count,
             "contract-harnesses": combined_md.contract_harnesses_count,
             "functions-under-contract": combined_md.contracted_functions.len(),
-        }
+        },
+        "metadata-only": metadata_only,
     });
 
     serde_json::to_writer_pretty(writer, &json_obj)?;
@@ -11,17 +11,21 @@
 use args_toml::join_args;
 
 use crate::args::StandaloneSubcommand;
+use crate::args::common::{Verbosity, VerbosityLevel};
 use crate::concrete_playback::playback::{playback_cargo, playback_standalone};
+use crate::contracts_doc::{contracts_doc_cargo, contracts_doc_standalone};
 use crate::list::collect_metadata::{list_cargo, list_standalone};
+use crate::mutate::{mutate_cargo, mutate_standalone};
 use crate::project::Project;
 use crate::session::KaniSession;
-use crate::version::print_kani_version;
+use crate::version::{print_kani_version, print_version_info};
 use clap::Parser;
 use tracing::debug;
 
 mod args;
 mod args_toml;
 mod autoharness;
+mod badge;
 mod call_cargo;
 mod call_cbmc;
 mod call_goto_cc;
@@ -30,13 +34,26 @@
 mod call_single_file;
 mod cbmc_output_parser;
 mod cbmc_property_renderer;
+mod chrome_trace;
 mod concrete_playback;
+mod contracts_doc;
 mod coverage;
+mod dump_symtab;
+mod estimate;
+mod event_log;
+mod feature_parity;
 mod harness_runner;
 mod list;
 mod metadata;
+mod mutate;
 mod project;
+mod reachable;
+mod results_db;
+mod run_summary;
 mod session;
+mod session_lock;
+mod timing_cache;
+mod toolchain;
 mod util;
 mod version;
 
@@ -66,32 +83,53 @@ fn main() -> ExitCode {
 fn cargokani_main(input_args: Vec<OsString>) -> Result<()> {
     let input_args = join_args(input_args)?;
     let args = args::CargoKaniArgs::parse_from(&input_args);
+    if let Some(format) = args.version_info {
+        return print_version_info(format);
+    }
     check_is_valid(&args);
 
     let mut session = match args.command {
         Some(CargoKaniSubcommand::Autoharness(autoharness_args)) => {
             return autoharness_cargo(*autoharness_args);
         }
+        Some(CargoKaniSubcommand::ContractsDoc(doc_args)) => {
+            return contracts_doc_cargo(*doc_args, args.verify_opts);
+        }
         Some(CargoKaniSubcommand::List(list_args)) => {
             return list_cargo(*list_args, args.verify_opts);
         }
+        Some(CargoKaniSubcommand::Mutate(mutate_args)) => {
+            return mutate_cargo(*mutate_args);
+        }
         Some(CargoKaniSubcommand::Playback(args)) => {
             return playback_cargo(*args);
         }
         None => session::KaniSession::new(args.verify_opts)?,
     };
 
-    if !session.args.common_args.quiet {
+    if session.args.common_args.level() >= VerbosityLevel::Harness {
         print_kani_version(InvocationType::CargoKani(input_args));
     }
 
-    let project = project::cargo_project(&mut session, false)?;
+    if session.args.estimate {
+        let metadata = project::cargo_metadata_only(&mut session)?;
+        return session.estimate(&metadata);
+    }
+
+    let project =
+        chrome_trace::record("build", "phase", || project::cargo_project(&mut session, false))?;
+    if session.args.check_feature_parity {
+        session.check_feature_parity()?;
+    }
     if session.args.only_codegen { Ok(()) } else { verify_project(project, session) }
 }
 
 /// The main function for the `kani` command.
 fn standalone_main() -> Result<()> {
     let args = args::StandaloneArgs::parse();
+    if let Some(format) = args.version_info {
+        return print_version_info(format);
+    }
     check_is_valid(&args);
 
     let (session, project) = match args.command {
@@ -99,26 +137,49 @@ fn standalone_main() -> Result<()> {
             return autoharness_standalone(*args);
         }
         Some(StandaloneSubcommand::Playback(args)) => return playback_standalone(*args),
+        Some(StandaloneSubcommand::ContractsDoc(doc_args)) => {
+            return contracts_doc_standalone(*doc_args, args.verify_opts);
+        }
         Some(StandaloneSubcommand::List(list_args)) => {
             return list_standalone(*list_args, args.verify_opts);
         }
+        Some(StandaloneSubcommand::Mutate(mutate_args)) => {
+            return mutate_standalone(*mutate_args);
+        }
         Some(StandaloneSubcommand::VerifyStd(args)) => {
-            let session = KaniSession::new(args.verify_opts)?;
-            if !session.args.common_args.quiet {
+            let mut session = KaniSession::new(args.verify_opts)?;
+            if session.args.common_args.level() >= VerbosityLevel::Harness {
                 print_kani_version(InvocationType::Standalone);
             }
 
-            let project = project::std_project(&args.std_path, &session)?;
+            if session.args.estimate {
+                let metadata = project::std_metadata(&args.std_path, &mut session)?;
+                return session.estimate(&metadata);
+            }
+
+            let project = chrome_trace::record("build", "phase", || {
+                project::std_project(&args.std_path, &session)
+            })?;
             (session, project)
         }
         None => {
-            let session = KaniSession::new(args.verify_opts)?;
-            if !session.args.common_args.quiet {
+            let mut session = KaniSession::new(args.verify_opts)?;
+            if session.args.common_args.level() >= VerbosityLevel::Harness {
                 print_kani_version(InvocationType::Standalone);
             }
 
-            let project =
-                project::standalone_project(&args.input.unwrap(), args.crate_name, &session)?;
+            if session.args.estimate {
+                let metadata = project::standalone_metadata(
+                    &args.input.unwrap(),
+                    args.crate_name,
+                    &mut session,
+                )?;
+                return session.estimate(&[metadata]);
+            }
+
+            let project = chrome_trace::record("build", "phase", || {
+                project::standalone_project(&args.input.unwrap(), args.crate_name, &session)
+            })?;
             (session, project)
         }
     };
@@ -130,10 +191,18 @@ fn verify_project(project: Project, session: KaniSession) -> Result<()> {
     debug!(?project, "verify_project");
     let harnesses = session.determine_targets(project.get_all_harnesses())?;
     debug!(n = harnesses.len(), ?harnesses, "verify_project");
+    session.check_concrete_harnesses(&harnesses)?;
+    if let Some(path) = &session.args.emit_reachable {
+        session.save_reachable_functions(path, &harnesses)?;
+    }
+    if let Some(path) = &session.args.dump_symtab {
+        return session.dump_symtab(&project, &harnesses, path);
+    }
 
     // Verification
     let runner = harness_runner::HarnessRunner { sess: &session, project: &project };
-    let results = runner.check_all_harnesses(&harnesses)?;
+    let results =
+        chrome_trace::record("verify", "phase", || runner.check_all_harnesses(&harnesses))?;
 
     if session.args.coverage {
         // We generate a timestamp to save the coverage data in a folder named
@@ -152,6 +221,15 @@ fn verify_project(project: Project, session: KaniSession) -> Result<()> {
         session.save_coverage_results(&project, &results, &timestamp)?;
     }
 
+    if let Some(path) = &session.args.results_db {
+        session.save_results_db(path, &results)?;
+    }
+    session.save_run_summary(&results, project.features.clone())?;
+    session.update_timing_cache(&results);
+    if let Some(path) = &session.args.emit_badge {
+        session.save_badge(path, &results)?;
+    }
+
     session.print_final_summary(&results)
 }
 
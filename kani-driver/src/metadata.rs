@@ -11,7 +11,10 @@
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
 
+use crate::args::Partition;
+use crate::args::common::{Verbosity, VerbosityLevel};
 use crate::session::KaniSession;
+use crate::util::stable_hash;
 use serde::Deserialize;
 
 /// From either a file or a path with multiple files, output the CBMC restrictions file we should use.
@@ -127,8 +130,70 @@ pub fn determine_targets<'a>(
             );
         }
 
-        Ok(compiler_filtered_harnesses)
+        let Some(partition) = self.args.partition else {
+            return Ok(compiler_filtered_harnesses);
+        };
+        let total = compiler_filtered_harnesses.len();
+        let harnesses = partition_harnesses(compiler_filtered_harnesses, partition);
+        if self.args.common_args.level() >= VerbosityLevel::Harness {
+            println!(
+                "Partition {}/{}: verifying {} out of {total} harness(es)",
+                partition.index,
+                partition.count,
+                harnesses.len()
+            );
+        }
+        Ok(harnesses)
     }
+
+    /// Report the harnesses that don't reach any source of non-determinism, since they only check
+    /// a single execution. This is an error if the user passed `--deny-concrete-harnesses`.
+    pub fn check_concrete_harnesses(&self, harnesses: &[&HarnessMetadata]) -> Result<()> {
+        let concrete: Vec<&str> = harnesses
+            .iter()
+            .filter(|harness| harness.is_concrete)
+            .map(|harness| harness.pretty_name.as_str())
+            .collect();
+        if self.args.deny_concrete_harnesses && !concrete.is_empty() {
+            bail!(
+                "The following harness(es) have no symbolic inputs: `{}`.\n\
+                Use `kani::any()` to make their inputs symbolic, or check them with `cargo test`.",
+                concrete.join("`, `")
+            );
+        }
+        if self.args.common_args.level() >= VerbosityLevel::Harness {
+            for harness in concrete {
+                println!("{}", concrete_harness_note(harness));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The note reported for a harness that doesn't reach any source of non-determinism.
+pub fn concrete_harness_note(harness: &str) -> String {
+    format!(
+        "Note: harness `{harness}` has no symbolic inputs; consider cargo test / playback instead"
+    )
+}
+
+/// Keep the harnesses that belong to the selected shard of `partition`.
+pub fn partition_harnesses<'a>(
+    harnesses: Vec<&'a HarnessMetadata>,
+    partition: Partition,
+) -> Vec<&'a HarnessMetadata> {
+    harnesses
+        .into_iter()
+        .filter(|harness| harness_shard(harness, partition.count) == partition.index)
+        .collect()
+}
+
+/// The shard, between 1 and `count`, that a harness is assigned to.
+/// Like the names of concrete playback tests, this is based on the stable hash of the harness
+/// name, which doesn't depend on the other harnesses, on the order in which they were found, or
+/// on the machine that runs the shard.
+fn harness_shard(harness: &HarnessMetadata, count: u32) -> u32 {
+    (stable_hash(&harness.pretty_name) % u64::from(count)) as u32 + 1
 }
 
 /// Sort harnesses such that for two harnesses in the same file, it is guaranteed that later
@@ -172,7 +237,36 @@ pub fn mock_proof_harness(
             contract: Default::default(),
             has_loop_contracts: false,
             is_automatically_generated: false,
+            is_vacuous: false,
+            is_concrete: false,
+            uses_concretize: false,
+            loop_unwind_bounds: vec![],
+            reachable_functions: vec![],
+            nondet_bytes: 0,
+            debug_assertions: 0,
+        }
+    }
+
+    #[test]
+    fn check_partition_harnesses() {
+        let harnesses: Vec<_> = (0..20)
+            .map(|i| mock_proof_harness(&format!("module::check_{i}"), None, None, None))
+            .collect();
+        let all = harnesses.iter().collect::<Vec<_>>();
+        let shard = |index| partition_harnesses(all.clone(), Partition { index, count: 3 });
+
+        // The shards are disjoint, cover all harnesses, and don't change between runs.
+        let shards: Vec<_> = (1..=3).map(shard).collect();
+        assert_eq!(shards.iter().map(Vec::len).sum::<usize>(), harnesses.len());
+        for harness in &all {
+            assert_eq!(shards.iter().filter(|shard| shard.contains(harness)).count(), 1);
         }
+        assert_eq!(shard(2), shards[1]);
+
+        // The shard of a harness doesn't depend on the other harnesses.
+        let subset = partition_harnesses(all[..5].to_vec(), Partition { index: 2, count: 3 });
+        assert!(subset.iter().all(|harness| shards[1].contains(harness)));
+        assert_eq!(partition_harnesses(all.clone(), Partition { index: 1, count: 1 }), all);
     }
 
     #[test]
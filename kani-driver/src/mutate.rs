@@ -0,0 +1,206 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the `mutate` subcommand, which measures how well the harnesses detect bugs.
+//!
+//! We first verify the harnesses against the original code, and collect the mutations that can
+//! be applied to the functions they reach. We then rebuild the crate once per mutation, and
+//! verify the harnesses that reach the mutated function. A mutant is killed if any of these
+//! harnesses fails, and it survives if all of them succeed.
+//!
+//! The mutations are applied by the compiler in memory, so the source files are never modified.
+
+use crate::args::common::{Verbosity, VerbosityLevel};
+use crate::args::mutate_args::{CargoMutateArgs, CommonMutateArgs, StandaloneMutateArgs};
+use crate::call_cbmc::{ExitStatus, VerificationStatus};
+use crate::harness_runner::HarnessRunner;
+use crate::project::{Project, standalone_project};
+use crate::session::KaniSession;
+use crate::{InvocationType, print_kani_version, project};
+use anyhow::{Result, bail};
+use kani_metadata::MutantMetadata;
+use strum_macros::Display;
+
+pub fn mutate_cargo(args: CargoMutateArgs) -> Result<()> {
+    let session = KaniSession::new(args.verify_opts)?;
+    if session.args.common_args.level() >= VerbosityLevel::Harness {
+        print_kani_version(InvocationType::CargoKani(vec![]));
+    }
+    run_mutants(session, &args.common_mutate_args, |session| project::cargo_project(session, false))
+}
+
+pub fn mutate_standalone(args: StandaloneMutateArgs) -> Result<()> {
+    let session = KaniSession::new(args.verify_opts)?;
+    if session.args.common_args.level() >= VerbosityLevel::Harness {
+        print_kani_version(InvocationType::Standalone);
+    }
+    run_mutants(session, &args.common_mutate_args, |session| {
+        standalone_project(&args.input, args.crate_name.clone(), session)
+    })
+}
+
+/// The outcome of verifying the harnesses against a mutant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Display)]
+enum MutantStatus {
+    /// At least one harness failed, i.e., the harnesses detected the bug.
+    #[strum(serialize = "KILLED")]
+    Killed,
+    /// All harnesses succeeded, i.e., the harnesses missed the bug.
+    #[strum(serialize = "SURVIVED")]
+    Survived,
+    /// The verification of a harness didn't finish within the mutant timeout.
+    #[strum(serialize = "TIMEOUT")]
+    Timeout,
+    /// The mutant could not be built, e.g., because the mutation triggers a compilation error.
+    #[strum(serialize = "UNVIABLE")]
+    Unviable,
+    /// The harnesses could not be verified against the mutant.
+    #[strum(serialize = "ERROR")]
+    Error,
+}
+
+/// Verify the harnesses against the original code, then against each mutant.
+fn run_mutants(
+    mut session: KaniSession,
+    args: &CommonMutateArgs,
+    build: impl Fn(&mut KaniSession) -> Result<Project>,
+) -> Result<()> {
+    let level = session.args.common_args.level();
+
+    session.list_mutants = true;
+    let project = build(&mut session)?;
+    session.list_mutants = false;
+    check_baseline(&session, &project)?;
+
+    let mut mutants: Vec<MutantMetadata> =
+        project.metadata.iter().flat_map(|md| md.mutants.iter().cloned()).collect();
+    let total = mutants.len();
+    if let Some(max) = args.max_mutants {
+        mutants.truncate(max);
+    }
+    if level >= VerbosityLevel::Harness {
+        if mutants.len() < total {
+            println!(
+                "Checking {} of {total} mutant(s). Use `--max-mutants` to check more.",
+                mutants.len()
+            );
+        } else {
+            println!("Checking {total} mutant(s).");
+        }
+    }
+
+    // Only report the progress of each mutant, unless the user asked for more output.
+    if session.args.common_args.level() == VerbosityLevel::Harness {
+        session.args.common_args.verbosity = Some(VerbosityLevel::Summary as u8);
+    }
+    session.args.harness_timeout = Some(args.mutant_timeout);
+    session.args.exact = true;
+
+    let mut outcomes = Vec::with_capacity(mutants.len());
+    for (idx, mutant) in mutants.iter().enumerate() {
+        let status = check_mutant(&mut session, mutant, &build);
+        if level >= VerbosityLevel::Harness {
+            println!(
+                "[{}/{}] {}:{}:{}: {} ... {status}",
+                idx + 1,
+                mutants.len(),
+                mutant.file,
+                mutant.line,
+                mutant.column,
+                mutant.description
+            );
+        }
+        outcomes.push((mutant, status));
+    }
+
+    if level >= VerbosityLevel::Summary {
+        print_mutation_summary(&outcomes);
+    }
+    Ok(())
+}
+
+/// Mutation testing is only meaningful if the harnesses succeed on the original code.
+fn check_baseline(session: &KaniSession, project: &Project) -> Result<()> {
+    let harnesses = session.determine_targets(project.get_all_harnesses())?;
+    if harnesses.is_empty() {
+        bail!("No proof harnesses (functions with #[kani::proof]) were found to verify.");
+    }
+    let runner = HarnessRunner { sess: session, project };
+    let results = runner.check_all_harnesses(&harnesses)?;
+    let failures: Vec<&str> = results
+        .iter()
+        .filter(|r| r.result.status == VerificationStatus::Failure)
+        .map(|r| r.harness.pretty_name.as_str())
+        .collect();
+    if !failures.is_empty() {
+        bail!(
+            "The following harness(es) fail on the original code: `{}`.\n\
+            Fix them before running the `mutate` subcommand.",
+            failures.join("`, `")
+        );
+    }
+    Ok(())
+}
+
+/// Build the given mutant and verify the harnesses that reach the mutated function.
+fn check_mutant(
+    session: &mut KaniSession,
+    mutant: &MutantMetadata,
+    build: &impl Fn(&mut KaniSession) -> Result<Project>,
+) -> MutantStatus {
+    session.mutant = Some(mutant.id.clone());
+    session.args.harnesses = mutant.harnesses.iter().cloned().collect();
+    let Ok(project) = build(session) else { return MutantStatus::Unviable };
+    let Ok(harnesses) = session.determine_targets(project.get_all_harnesses()) else {
+        return MutantStatus::Error;
+    };
+    let runner = HarnessRunner { sess: session, project: &project };
+    let Ok(results) = runner.check_all_harnesses(&harnesses) else { return MutantStatus::Error };
+
+    let failures: Vec<_> =
+        results.iter().filter(|r| r.result.status == VerificationStatus::Failure).collect();
+    if failures.iter().any(|r| !matches!(r.result.results, Err(ExitStatus::Timeout))) {
+        MutantStatus::Killed
+    } else if !failures.is_empty() {
+        MutantStatus::Timeout
+    } else {
+        MutantStatus::Survived
+    }
+}
+
+/// Print the mutation score, i.e., the percentage of the mutants that were killed, as well as
+/// the location of the mutants that survived.
+///
+/// Mutants that timed out count against the score, since the harnesses did not detect them.
+/// Mutants that could not be built or verified are excluded from the score.
+fn print_mutation_summary(outcomes: &[(&MutantMetadata, MutantStatus)]) {
+    let count = |status| outcomes.iter().filter(|(_, s)| *s == status).count();
+    let killed = count(MutantStatus::Killed);
+    let survived = count(MutantStatus::Survived);
+    let timeout = count(MutantStatus::Timeout);
+    let unviable = count(MutantStatus::Unviable);
+    let error = count(MutantStatus::Error);
+
+    println!("\nMutation Testing Summary:");
+    println!(
+        "Complete - {killed} killed, {survived} survived, {timeout} timed out, {unviable} unviable, \
+        {error} errors."
+    );
+    let checked = killed + survived + timeout;
+    if checked > 0 {
+        let score = 100.0 * killed as f64 / checked as f64;
+        println!("Mutation score: {score:.2}% ({killed}/{checked})");
+        if timeout > 0 {
+            println!("Note: The {timeout} mutant(s) that timed out count as not killed.");
+        }
+    }
+
+    if survived > 0 {
+        println!("\nSurviving mutants:");
+        for (mutant, _) in outcomes.iter().filter(|(_, s)| *s == MutantStatus::Survived) {
+            println!(
+                " - {}:{}:{}: {} in `{}`",
+                mutant.file, mutant.line, mutant.column, mutant.description, mutant.function
+            );
+        }
+    }
+}
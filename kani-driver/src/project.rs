@@ -4,6 +4,7 @@
 //! The goal is to provide one project view independent on the build system (cargo / standalone
 //! rustc) and its configuration (e.g.: linker type).
 
+use crate::feature_parity::FeatureSet;
 use crate::metadata::from_json;
 use crate::session::KaniSession;
 use crate::util::{crate_name, info_operation};
@@ -42,6 +43,8 @@ pub struct Project {
     artifacts: Vec<Artifact>,
     /// Records the cargo metadata from the build, if there was any
     pub cargo_metadata: Option<cargo_metadata::Metadata>,
+    /// The features that each package was built with, if this was built with cargo.
+    pub features: FeatureSet,
 }
 
 impl Project {
@@ -62,11 +65,16 @@ pub fn get_all_harnesses(&self) -> Vec<&HarnessMetadata> {
     /// If the harness has information about the goto_file we can use that to find the exact file.
     /// For cases where there is no goto_file, we just assume that everything has been linked
     /// together. I.e.: There should only be one artifact of the given type.
+    ///
+    /// Vacuous harnesses have no artifacts, since the compiler skips their code generation.
     pub fn get_harness_artifact(
         &self,
         harness: &HarnessMetadata,
         typ: ArtifactType,
     ) -> Option<&Artifact> {
+        if harness.is_vacuous {
+            return None;
+        }
         let expected_path = harness
             .goto_file
             .as_ref()
@@ -88,13 +96,17 @@ fn try_new(
         input: Option<PathBuf>,
         metadata: Vec<KaniMetadata>,
         cargo_metadata: Option<cargo_metadata::Metadata>,
+        features: FeatureSet,
     ) -> Result<Self> {
         // For each harness (test or proof) from each metadata, read the path for the goto
         // SymTabGoto file. Use that path to find all the other artifacts.
         let mut artifacts = vec![];
         for crate_metadata in &metadata {
-            for harness_metadata in
-                crate_metadata.test_harnesses.iter().chain(crate_metadata.proof_harnesses.iter())
+            for harness_metadata in crate_metadata
+                .test_harnesses
+                .iter()
+                .chain(crate_metadata.proof_harnesses.iter())
+                .filter(|harness_metadata| !harness_metadata.is_vacuous)
             {
                 let symtab_out = Artifact::try_new(
                     harness_metadata.goto_file.as_ref().expect("Expected a model file"),
@@ -118,7 +130,7 @@ fn try_new(
             }
         }
 
-        Ok(Project { outdir, input, metadata, artifacts, cargo_metadata })
+        Ok(Project { outdir, input, metadata, artifacts, cargo_metadata, features })
     }
 }
 
@@ -185,7 +197,14 @@ pub fn cargo_project(session: &mut KaniSession, keep_going: bool) -> Result<Proj
     // For the MIR Linker we know there is only one metadata per crate. Use that in our favor.
     let metadata =
         outputs.metadata.iter().map(|md_file| from_json(md_file)).collect::<Result<Vec<_>>>()?;
-    Project::try_new(session, outdir, None, metadata, Some(outputs.cargo_metadata))
+    Project::try_new(
+        session,
+        outdir,
+        None,
+        metadata,
+        Some(outputs.cargo_metadata),
+        outputs.features,
+    )
 }
 
 /// Generate a project directly using `kani-compiler` on a single crate.
@@ -197,6 +216,24 @@ pub fn standalone_project(
     StandaloneProjectBuilder::try_new(input, crate_name, session)?.build()
 }
 
+/// Collect the metadata of a single crate without generating code for its harnesses.
+pub fn standalone_metadata(
+    input: &Path,
+    crate_name: Option<String>,
+    session: &mut KaniSession,
+) -> Result<KaniMetadata> {
+    session.metadata_only = true;
+    StandaloneProjectBuilder::try_new(input, crate_name, session)?.build_metadata()
+}
+
+/// Collect the metadata of the crates of a cargo project without generating code for their
+/// harnesses.
+pub fn cargo_metadata_only(session: &mut KaniSession) -> Result<Vec<KaniMetadata>> {
+    session.metadata_only = true;
+    let outputs = session.cargo_build(false)?;
+    outputs.metadata.iter().map(|md_file| from_json(md_file)).collect()
+}
+
 /// Builder for a standalone project.
 struct StandaloneProjectBuilder<'a> {
     /// The directory where all outputs should be directed to.
@@ -235,26 +272,36 @@ fn try_new(input: &Path, krate_name: Option<String>, session: &'a KaniSession) -
 
     /// Build a project by compiling `self.input` file.
     fn build(self) -> Result<Project> {
-        // Register artifacts that may be generated by the compiler / linker for future deletion.
-        let rlib_path = self.rlib_name();
-        self.session.record_temporary_file(&rlib_path);
-        self.session.record_temporary_file(&self.metadata.path);
-
         // Build and link the artifacts.
-        debug!(krate=?self.crate_name, input=?self.input, ?rlib_path, "build compile");
-        self.session.compile_single_rust_file(&self.input, &self.crate_name, &self.outdir)?;
-
-        let metadata = from_json(&self.metadata)?;
+        let metadata = self.build_metadata()?;
 
         // Create the project with the artifacts built by the compiler.
-        let result =
-            Project::try_new(self.session, self.outdir, Some(self.input), vec![metadata], None);
+        let result = Project::try_new(
+            self.session,
+            self.outdir,
+            Some(self.input),
+            vec![metadata],
+            None,
+            FeatureSet::new(),
+        );
         if let Ok(project) = &result {
             self.session.record_temporary_files(&project.artifacts);
         }
         result
     }
 
+    /// Compile `self.input` file and read the metadata of the crate.
+    fn build_metadata(&self) -> Result<KaniMetadata> {
+        // Register artifacts that may be generated by the compiler / linker for future deletion.
+        let rlib_path = self.rlib_name();
+        self.session.record_temporary_file(&rlib_path);
+        self.session.record_temporary_file(&self.metadata.path);
+
+        debug!(krate=?self.crate_name, input=?self.input, ?rlib_path, "build compile");
+        self.session.compile_single_rust_file(&self.input, &self.crate_name, &self.outdir)?;
+        from_json(&self.metadata)
+    }
+
     /// Build the rlib name from the crate name.
     /// This is only used by 'kani', never 'cargo-kani', so we hopefully don't have too many corner
     /// cases to deal with.
@@ -280,6 +327,21 @@ fn standalone_artifact(out_dir: &Path, crate_name: &String, typ: ArtifactType) -
 /// Note that we assume that `std_path` points to a directory named "library".
 /// This should be checked as part of the argument validation.
 pub(crate) fn std_project(std_path: &Path, session: &KaniSession) -> Result<Project> {
+    let (outdir, metadata) = build_std(std_path, session)?;
+    Project::try_new(session, outdir, None, metadata, None, FeatureSet::new())
+}
+
+/// Collect the metadata of the standard library without generating code for its harnesses.
+pub(crate) fn std_metadata(
+    std_path: &Path,
+    session: &mut KaniSession,
+) -> Result<Vec<KaniMetadata>> {
+    session.metadata_only = true;
+    Ok(build_std(std_path, session)?.1)
+}
+
+/// Build the standard library, and return the output directory and the metadata of its crates.
+fn build_std(std_path: &Path, session: &KaniSession) -> Result<(PathBuf, Vec<KaniMetadata>)> {
     // Create output directory
     let outdir = if let Some(target_dir) = &session.args.target_dir {
         target_dir.clone()
@@ -300,7 +362,7 @@ pub(crate) fn std_project(std_path: &Path, session: &KaniSession) -> Result<Proj
     let std_path = std_path.canonicalize()?;
     let outputs = session.cargo_build_std(std_path.parent().unwrap(), &dummy_crate)?;
 
-    // Get the metadata and return a Kani project.
+    // Get the metadata of each crate.
     let metadata = outputs.iter().map(|md_file| from_json(md_file)).collect::<Result<Vec<_>>>()?;
-    Project::try_new(session, outdir, None, metadata, None)
+    Ok((outdir, metadata))
 }
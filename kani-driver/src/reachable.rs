@@ -0,0 +1,56 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--emit-reachable`, which writes the functions reachable from each harness, as
+//! computed by the reachability analysis of the compiler, to a JSON file.
+//!
+//! The harnesses and their functions are sorted, so the files of two runs can be diffed to see
+//! how a change affected the code that each harness verifies.
+
+use crate::session::KaniSession;
+use anyhow::{Context, Result};
+use kani_metadata::HarnessMetadata;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+impl KaniSession {
+    /// Write the functions reachable from each harness to `path`.
+    pub fn save_reachable_functions(
+        &self,
+        path: &Path,
+        harnesses: &[&HarnessMetadata],
+    ) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&reachable_functions(harnesses))?;
+        fs::write(path, json).with_context(|| {
+            format!("Failed to write the reachable functions to `{}`", path.display())
+        })
+    }
+}
+
+/// The reachable functions indexed by the name of the harness.
+fn reachable_functions<'a>(harnesses: &[&'a HarnessMetadata]) -> BTreeMap<&'a str, &'a [String]> {
+    harnesses
+        .iter()
+        .map(|harness| (harness.pretty_name.as_str(), harness.reachable_functions.as_slice()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+
+    #[test]
+    fn check_reachable_functions_json() {
+        let mut second = mock_proof_harness("mod::second", None, None, None);
+        second.reachable_functions = vec!["mod::helper".to_string(), "mod::second".to_string()];
+        let mut first = mock_proof_harness("first", None, None, None);
+        first.reachable_functions = vec!["first".to_string()];
+        let json = serde_json::to_string(&reachable_functions(&[&second, &first])).unwrap();
+        assert_eq!(json, r#"{"first":["first"],"mod::second":["mod::helper","mod::second"]}"#);
+    }
+}
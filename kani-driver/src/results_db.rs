@@ -0,0 +1,125 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--results-db`, which appends the result of each harness to a JSON Lines file,
+//! so results can be compared across runs.
+//!
+//! Each line of the file is a self-contained JSON object, so every run only appends to the file,
+//! and the results can be queried with tools such as `jq`, or loaded into a database.
+//! Every record stores the version of its format in `version`, and we refuse to append to a file
+//! whose records have a version that we don't know about.
+
+use crate::call_cbmc::{ExitStatus, VerificationStatus};
+use crate::harness_runner::HarnessResult;
+use crate::session::KaniSession;
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::Command;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+/// The version of [HarnessRecord]. Bump it whenever its format changes.
+const RECORD_VERSION: u32 = 1;
+
+/// The result of one harness in one run.
+#[derive(Debug, Serialize)]
+struct HarnessRecord<'a> {
+    version: u32,
+    timestamp: &'a str,
+    git_commit: Option<&'a str>,
+    kani_version: &'a str,
+    crate_name: &'a str,
+    harness: &'a str,
+    status: &'a str,
+    duration_secs: f64,
+    solver: &'a str,
+}
+
+impl KaniSession {
+    /// Append the results of this run to the JSON Lines file at `path`.
+    pub fn save_results_db(&self, path: &Path, results: &[HarnessResult]) -> Result<()> {
+        let context = || format!("failed to update results database `{}`", path.display());
+        check_version(path).with_context(context)?;
+
+        // All the records of a run share the same timestamp, which identifies the run.
+        let timestamp = OffsetDateTime::now_utc().format(&Rfc3339)?;
+        let git_commit = git_commit();
+        let mut lines = String::new();
+        for harness_result in results {
+            let harness = harness_result.harness;
+            let result = &harness_result.result;
+            let status = match (&result.status, &result.results) {
+                (_, Err(ExitStatus::Timeout)) => "timeout",
+                (VerificationStatus::Success, _) => "success",
+                (VerificationStatus::Failure, _) => "failure",
+            };
+            let record = HarnessRecord {
+                version: RECORD_VERSION,
+                timestamp: &timestamp,
+                git_commit: git_commit.as_deref(),
+                kani_version: env!("CARGO_PKG_VERSION"),
+                crate_name: &harness.crate_name,
+                harness: &harness.pretty_name,
+                status,
+                duration_secs: result.runtime.as_secs_f64(),
+                solver: self.solver(&harness.attributes.solver).as_ref(),
+            };
+            lines.push_str(&serde_json::to_string(&record)?);
+            lines.push('\n');
+        }
+
+        // Write the whole run at once, so concurrent runs don't interleave their records.
+        let mut file =
+            OpenOptions::new().create(true).append(true).open(path).with_context(context)?;
+        file.write_all(lines.as_bytes()).with_context(context)?;
+        Ok(())
+    }
+}
+
+/// Check that the records already in the file, if any, have the version that we write.
+/// It is enough to check the first record, since we never append to a file of another version.
+fn check_version(path: &Path) -> Result<()> {
+    let Ok(file) = File::open(path) else { return Ok(()) };
+    let Some(first) = BufReader::new(file).lines().next() else { return Ok(()) };
+    let record: serde_json::Value = serde_json::from_str(&first?)?;
+    match record.get("version").and_then(|version| version.as_u64()) {
+        Some(version) if version == RECORD_VERSION as u64 => Ok(()),
+        Some(version) => bail!(
+            "unsupported record version {version}, this version of Kani only supports version \
+            {RECORD_VERSION}"
+        ),
+        None => bail!("the file doesn't contain Kani results"),
+    }
+}
+
+/// The commit of the git repository in the current directory, if there is one.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "HEAD"]).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_record_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("results.jsonl");
+        // A missing or empty file can be appended to.
+        check_version(&path).unwrap();
+        std::fs::write(&path, "").unwrap();
+        check_version(&path).unwrap();
+
+        std::fs::write(&path, format!("{{\"version\":{RECORD_VERSION}}}\n")).unwrap();
+        check_version(&path).unwrap();
+
+        std::fs::write(&path, format!("{{\"version\":{}}}\n", RECORD_VERSION + 1)).unwrap();
+        assert!(check_version(&path).is_err());
+        std::fs::write(&path, "{\"harness\":\"foo\"}\n").unwrap();
+        assert!(check_version(&path).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for `--summary-json` and `--rerun-failed`.
+//!
+//! At the end of every verification run, we record the status and the runtime of each harness in
+//! [last_run_file], as well as in the file given to `--summary-json`. `--rerun-failed` reads one
+//! of these files and only selects the harnesses that failed or timed out in that run. The
+//! `contracts-doc` subcommand reads them to document the verification status of the contracts.
+
+use crate::call_cbmc::{ExitStatus, ExpectFailOutcome, VerificationResult, VerificationStatus};
+use crate::feature_parity::FeatureSet;
+use crate::harness_runner::HarnessResult;
+use crate::session::KaniSession;
+use crate::session_lock::lock_target_dir;
+use anyhow::{Context, Result, bail};
+use kani_metadata::AssertionSemantics;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use strum_macros::Display;
+
+/// The file where we record the result of the last run, relative to the target directory.
+const LAST_RUN_FILE: &str = ".kani/last-run.json";
+
+/// The file where we record the result of the last run. It is stored in the same target directory
+/// as the session lock, so it doesn't depend on the directory Kani is invoked from.
+pub fn last_run_file(target_dir: Option<&Path>, manifest_path: Option<&Path>) -> PathBuf {
+    lock_target_dir(target_dir, manifest_path).join(LAST_RUN_FILE)
+}
+
+/// The result of a verification run.
+#[derive(Debug, Serialize, Deserialize)]
+struct RunSummary {
+    harnesses: Vec<HarnessSummary>,
+    /// How the debug assertions were treated in this run. Older summaries don't record it.
+    #[serde(default)]
+    assertion_semantics: AssertionSemantics,
+    /// The features that each package was built with for verification, for `cargo kani` runs.
+    #[serde(default, skip_serializing_if = "FeatureSet::is_empty")]
+    features: FeatureSet,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HarnessSummary {
+    /// The fully qualified name of the harness.
+    name: String,
+    status: HarnessStatus,
+    /// The time it took to verify the harness, in seconds. Older summaries don't record it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    runtime_secs: Option<f64>,
+    /// The number of debug assertions reachable from the harness, which were treated according to
+    /// the `assertion_semantics` of the run.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    debug_assertions: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum HarnessStatus {
+    Success,
+    Failure,
+    Timeout,
+    /// A harness annotated with `#[kani::proof(expect_fail)]` failed as expected.
+    ExpectedFailure,
+    /// A harness annotated with `#[kani::proof(expect_fail)]` was verified successfully.
+    UnexpectedPass,
+}
+
+impl KaniSession {
+    /// Record the results of this run in [last_run_file] and in the `--summary-json` file, together
+    /// with the features that each package was built with.
+    ///
+    /// Failing to record the last run doesn't fail the verification, since the user didn't ask for
+    /// it explicitly.
+    pub fn save_run_summary(&self, results: &[HarnessResult], features: FeatureSet) -> Result<()> {
+        let summary = RunSummary {
+            harnesses: results
+                .iter()
+                .map(|harness_result| HarnessSummary {
+                    name: harness_result.harness.pretty_name.clone(),
+                    status: harness_status(&harness_result.result),
+                    runtime_secs: Some(harness_result.result.runtime.as_secs_f64()),
+                    debug_assertions: harness_result.harness.debug_assertions,
+                })
+                .collect(),
+            assertion_semantics: self.args.assertion_semantics,
+            features,
+        };
+        let json = serde_json::to_string_pretty(&summary)?;
+        let last_run = last_run_file(
+            self.args.target_dir.as_deref(),
+            self.args.cargo.manifest_path.as_deref(),
+        );
+        if let Err(err) = write_summary(&last_run, &json) {
+            crate::util::warning(&format!("{err:#}"));
+        }
+        if let Some(path) = &self.args.summary_json {
+            write_summary(path, &json)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_zero(count: &u64) -> bool {
+    *count == 0
+}
+
+fn harness_status(result: &VerificationResult) -> HarnessStatus {
+    match (&result.status, &result.results, &result.expect_fail) {
+        (_, Err(ExitStatus::Timeout), _) => HarnessStatus::Timeout,
+        (_, _, Some(ExpectFailOutcome::ExpectedFailure)) => HarnessStatus::ExpectedFailure,
+        (_, _, Some(ExpectFailOutcome::UnexpectedPass)) => HarnessStatus::UnexpectedPass,
+        (VerificationStatus::Success, _, _) => HarnessStatus::Success,
+        (VerificationStatus::Failure, _, _) => HarnessStatus::Failure,
+    }
+}
+
+fn write_summary(path: &Path, json: &str) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory `{}`", parent.display()))?;
+    }
+    fs::write(path, json)
+        .with_context(|| format!("Failed to write run summary `{}`", path.display()))
+}
+
+fn read_summary(path: &Path) -> Result<RunSummary> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read run summary `{}`", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Invalid run summary `{}`", path.display()))
+}
+
+/// The status of each harness in the run recorded in `path`, indexed by harness name.
+pub fn harness_statuses(path: &Path) -> Result<BTreeMap<String, HarnessStatus>> {
+    let summary = read_summary(path)?;
+    Ok(summary.harnesses.into_iter().map(|harness| (harness.name, harness.status)).collect())
+}
+
+/// The names of the harnesses that failed or timed out in the run recorded in `path`.
+pub fn failed_harnesses(path: &Path) -> Result<Vec<String>> {
+    if !path.exists() {
+        bail!(
+            "Cannot rerun the failed harnesses: no previous run was found at `{}`. Run Kani \
+            without `--rerun-failed` first.",
+            path.display()
+        );
+    }
+    let summary = read_summary(path)?;
+    let failed: Vec<String> = summary
+        .harnesses
+        .into_iter()
+        .filter(|harness| {
+            !matches!(harness.status, HarnessStatus::Success | HarnessStatus::ExpectedFailure)
+        })
+        .map(|harness| harness.name)
+        .collect();
+    if failed.is_empty() {
+        bail!("No harness failed in the previous run recorded in `{}`", path.display());
+    }
+    Ok(failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_failed_harnesses() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("summary.json");
+        assert!(failed_harnesses(&path).is_err());
+
+        let content = r#"{"harnesses": [
+            {"name": "check_ok", "status": "success"},
+            {"name": "mod_a::check_bad", "status": "failure"},
+            {"name": "check_slow", "status": "timeout"},
+            {"name": "check_known_bug", "status": "expected_failure"},
+            {"name": "check_fixed_bug", "status": "unexpected_pass"}
+        ]}"#;
+        fs::write(&path, content).unwrap();
+        assert_eq!(
+            failed_harnesses(&path).unwrap(),
+            vec!["mod_a::check_bad", "check_slow", "check_fixed_bug"]
+        );
+
+        fs::write(&path, r#"{"harnesses": [{"name": "check_ok", "status": "success"}]}"#).unwrap();
+        assert!(failed_harnesses(&path).is_err());
+    }
+
+    #[test]
+    fn check_last_run_file() {
+        assert_eq!(
+            last_run_file(Some(Path::new("out")), None),
+            Path::new("out").join(".kani").join("last-run.json")
+        );
+    }
+}
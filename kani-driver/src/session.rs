@@ -3,13 +3,19 @@
 
 use crate::args::Timeout;
 use crate::args::VerificationArgs;
-use crate::args::common::Verbosity;
-use crate::util::render_command;
+use crate::args::common::{Verbosity, VerbosityLevel};
+use crate::chrome_trace;
+use crate::event_log::{DEBUG_EVENT_LOG_FILE, Event, EventBus, JsonLinesSink};
+use crate::session_lock::{LockMode, SessionLock, lock_target_dir};
+use crate::toolchain::check_toolchain_skew;
+use crate::util::{alter_extension, render_command};
 use anyhow::{Context, Result, bail};
+use std::fmt::{self, Display};
 use std::io::IsTerminal;
 use std::io::Write;
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
-use std::process::{Child, Command, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
 use std::sync::Mutex;
 use std::time::Instant;
 use strum_macros::Display;
@@ -39,6 +45,18 @@ pub struct KaniSession {
     /// Invariant: this field is_some() iff the autoharness subcommand is enabled.
     pub autoharness_compiler_flags: Option<Vec<String>>,
 
+    /// Whether the compiler should stop after collecting the harness metadata, without
+    /// generating any code. This is used by `list --fast`.
+    pub metadata_only: bool,
+
+    /// Whether the compiler should collect the mutations that can be applied to the crate.
+    /// This is used by the `mutate` subcommand.
+    pub list_mutants: bool,
+
+    /// The identifier of the mutation that the compiler should apply, if any.
+    /// This is used by the `mutate` subcommand.
+    pub mutant: Option<String>,
+
     /// The location we found the 'kani_rustc' command
     pub kani_compiler: PathBuf,
     /// The location we found 'kani_lib.c'
@@ -47,8 +65,14 @@ pub struct KaniSession {
     /// The temporary files we littered that need to be cleaned up at the end of execution
     pub temporaries: Mutex<Vec<PathBuf>>,
 
+    /// The events emitted during the run, e.g., for `--event-log`.
+    pub events: EventBus,
+
     /// The tokio runtime
     pub runtime: tokio::runtime::Runtime,
+
+    /// The lock on the target directory, held for the whole session.
+    _lock: SessionLock,
 }
 
 /// Represents where we detected Kani, with helper methods for using that information to find critical paths
@@ -63,16 +87,48 @@ pub enum InstallType {
 
 impl KaniSession {
     pub fn new(args: VerificationArgs) -> Result<Self> {
+        Self::with_lock_mode(args, LockMode::Exclusive)
+    }
+
+    /// Create a session that holds the target directory lock in the given mode.
+    /// Use [LockMode::Shared] for commands that do not verify harnesses.
+    pub fn with_lock_mode(mut args: VerificationArgs, lock_mode: LockMode) -> Result<Self> {
         init_logger(&args);
+        args.load_harness_file()?;
+        args.load_failed_harnesses()?;
         let install = InstallType::new()?;
+        check_toolchain_skew(&install, args.allow_toolchain_skew)?;
+        if let Some(path) = &args.emit_chrome_trace {
+            chrome_trace::init(path);
+        }
+        let target_dir =
+            lock_target_dir(args.target_dir.as_deref(), args.cargo.manifest_path.as_deref());
+        let events = EventBus::default();
+        let event_log = args
+            .event_log
+            .clone()
+            .or_else(|| args.common_args.debug.then(|| target_dir.join(DEBUG_EVENT_LOG_FILE)));
+        if let Some(path) = event_log {
+            events.subscribe(Box::new(JsonLinesSink::create(&path)?));
+        }
+        let lock = SessionLock::acquire(
+            &target_dir,
+            lock_mode,
+            args.common_args.wait_for_lock.map(Into::into),
+        )?;
 
         Ok(KaniSession {
             args,
             autoharness_compiler_flags: None,
+            metadata_only: false,
+            list_mutants: false,
+            mutant: None,
             kani_compiler: install.kani_compiler()?,
             kani_lib_c: install.kani_lib_c()?,
             temporaries: Mutex::new(vec![]),
+            events,
             runtime: tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap(),
+            _lock: lock,
         })
     }
 
@@ -88,7 +144,20 @@ pub fn record_temporary_files<T: AsRef<Path>>(&self, temps: &[T]) {
         // unwrap safety: will panic this thread if another thread panicked *while holding the lock.*
         // This is vanishingly unlikely, and even then probably the right thing to do
         let mut t = self.temporaries.lock().unwrap();
-        t.extend(temps.iter().map(|p| p.as_ref().to_owned()));
+        for temp in temps {
+            let path = temp.as_ref().to_owned();
+            self.events.emit(Event::TempFileCreated { path: path.clone() });
+            t.push(path);
+        }
+    }
+
+    /// The path of a temporary file used while verifying the harness whose goto binary is
+    /// `goto_file`. The file is recorded so we can cleanup after ourselves at the end.
+    /// See [harness_file] for how the path is derived.
+    pub fn harness_temporary_file(&self, goto_file: &Path, extension: &str) -> PathBuf {
+        let path = harness_file(goto_file, extension);
+        self.record_temporary_file(&path);
+        path
     }
 
     /// Determine which symbols Kani should codegen (i.e. by slicing away symbols
@@ -112,12 +181,17 @@ pub enum ReachabilityMode {
 
 impl Drop for KaniSession {
     fn drop(&mut self) {
+        if let Err(err) = chrome_trace::write() {
+            crate::util::warning(&format!("{err:#}"));
+        }
         if !self.args.keep_temps {
             let temporaries = self.temporaries.lock().unwrap();
 
             for file in temporaries.iter() {
                 // If it fails, we don't care, skip it
-                let _result = std::fs::remove_file(file);
+                if std::fs::remove_file(file).is_ok() {
+                    self.events.emit(Event::TempFileRemoved { path: file.clone() });
+                }
             }
         }
     }
@@ -126,52 +200,115 @@ fn drop(&mut self) {
 impl KaniSession {
     /// Call [run_terminal] with the verbosity configured by the user.
     pub fn run_terminal(&self, cmd: Command) -> Result<()> {
-        run_terminal(&self.args.common_args, cmd)
+        let program = self.emit_process_spawn(&cmd);
+        let result = run_terminal(&self.args.common_args, cmd);
+        self.emit_process_exit(program, &result);
+        result
     }
 
     /// Call [run_terminal_timeout] with the verbosity configured by the user.
     /// The `bool` value indicates whether the command timed out
     pub fn run_terminal_timeout(&self, cmd: TokioCommand) -> Result<bool> {
-        self.runtime.block_on(run_terminal_timeout(
+        let program = self.emit_process_spawn(cmd.as_std());
+        let result = self.runtime.block_on(run_terminal_timeout(
             &self.args.common_args,
             cmd,
             self.args.harness_timeout,
-        ))
+        ));
+        if let Ok(true) = result {
+            self.events.emit(Event::ProcessExit { program, exit_code: None, timed_out: true });
+        } else {
+            self.emit_process_exit(program, &result);
+        }
+        result
     }
 
     /// Call [run_suppress] with the verbosity configured by the user.
     pub fn run_suppress(&self, cmd: Command) -> Result<()> {
-        run_suppress(&self.args.common_args, cmd)
+        let program = self.emit_process_spawn(&cmd);
+        let result = run_suppress(&self.args.common_args, cmd);
+        self.emit_process_exit(program, &result);
+        result
     }
 
     /// Call [run_piped] with the verbosity configured by the user.
+    ///
+    /// Only the spawn of the process is recorded in the event log, since the caller is
+    /// responsible for waiting for the process to exit and for recording its exit with
+    /// [Self::emit_piped_exit].
     pub fn run_piped(&self, cmd: Command) -> Result<Child> {
-        run_piped(&self.args.common_args, cmd)
+        let program = self.emit_process_spawn(&cmd);
+        let result = run_piped(&self.args.common_args, cmd);
+        if result.is_err() {
+            self.emit_piped_exit(&program, None);
+        }
+        result
     }
 
     /// Call [with_timer] with the verbosity configured by the user.
+    /// The execution is also recorded as a stage in the event log.
     pub fn with_timer<T, F>(&self, func: F, description: &str) -> T
     where
         F: FnOnce() -> T,
     {
-        with_timer(&self.args.common_args, func, description)
+        self.events.stage(description, || with_timer(&self.args.common_args, func, description))
+    }
+
+    /// Emit the event for spawning this command, and return the name of its program.
+    fn emit_process_spawn(&self, cmd: &Command) -> String {
+        self.events.emit(Event::process_spawn(cmd));
+        cmd.get_program().to_string_lossy().to_string()
+    }
+
+    /// Emit the event for the exit of a process started with [Self::run_piped], given the exit
+    /// status collected by the caller, if any.
+    pub fn emit_piped_exit(&self, program: &str, status: Option<ExitStatus>) {
+        let program = program.to_string();
+        let exit_code = status.and_then(|status| status.code());
+        self.events.emit(Event::ProcessExit { program, exit_code, timed_out: false });
+    }
+
+    fn emit_process_exit<T>(&self, program: String, result: &Result<T>) {
+        let exit_code = match result {
+            Ok(_) => Some(0),
+            Err(err) => err.downcast_ref::<CommandFailed>().and_then(|err| err.status.code()),
+        };
+        self.events.emit(Event::ProcessExit { program, exit_code, timed_out: false });
+    }
+}
+
+/// The error returned when a command exits with a failure status.
+#[derive(Debug)]
+struct CommandFailed {
+    program: String,
+    status: ExitStatus,
+}
+
+impl Display for CommandFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} exited with status {}", self.program, self.status)
     }
 }
 
+impl std::error::Error for CommandFailed {}
+
 // The below suite of helper functions for executing Commands are meant to be a common handler
 // for various cmdline flags like 'verbose' and 'quiet'. These functions are temporary: in the
 // longer run we'll switch to a graph-interpreter style of constructing and executing jobs.
 // (In other words: higher-level data structures, rather than passing around Commands.)
 // (e.g. to support emitting Litani build graphs, or to better parallelize our work)
 
-// We basically have three different output policies:
+// We basically have three different output policies, which depend on the verbosity level:
 //               No error                  Error                     Notes
-//               Default  Quiet  Verbose   Default  Quiet  Verbose
-// run_terminal  Y        N      Y         Y        N      Y         (inherits terminal)
-// run_suppress  N        N      Y         Y        N      Y         (buffered text only)
+//               0    1    2    3    4     0    1    2    3    4
+// run_terminal  N    N    Y    Y    Y     N    Y    Y    Y    Y     (inherits terminal)
+// run_suppress  N    N    N    Y    Y     N    Y    Y    Y    Y     (buffered text only)
 
 /// Run a job, leave it outputting to terminal (unless --quiet), and fail if there's a problem.
 pub fn run_terminal(verbosity: &impl Verbosity, mut cmd: Command) -> Result<()> {
+    if verbosity.level() == VerbosityLevel::Summary {
+        return run_buffered(cmd);
+    }
     if verbosity.quiet() {
         cmd.stdout(std::process::Stdio::null());
         cmd.stderr(std::process::Stdio::null());
@@ -189,7 +326,8 @@ pub fn run_terminal(verbosity: &impl Verbosity, mut cmd: Command) -> Result<()>
         &program,
     )?;
     if !result.success() {
-        bail!("{} exited with status {}", cmd.get_program().to_string_lossy(), result);
+        let program = cmd.get_program().to_string_lossy().to_string();
+        return Err(CommandFailed { program, status: result }.into());
     }
     Ok(())
 }
@@ -200,7 +338,7 @@ async fn run_terminal_timeout(
     mut cmd: TokioCommand,
     timeout: Option<Timeout>,
 ) -> Result<bool> {
-    if verbosity.quiet() {
+    if verbosity.level() <= VerbosityLevel::Summary {
         cmd.stdout(std::process::Stdio::null());
         cmd.stderr(std::process::Stdio::null());
     }
@@ -232,16 +370,23 @@ async fn run_terminal_timeout(
     }
     let result = result.unwrap().context(format!("Failed to invoke {program}"))?;
     if !result.success() {
-        bail!("{} exited with status {}", cmd.as_std().get_program().to_string_lossy(), result);
+        return Err(CommandFailed { program, status: result }.into());
     }
     Ok(false)
 }
 
 /// Run a job, but only output (unless --quiet) if it fails, and fail if there's a problem.
-pub fn run_suppress(verbosity: &impl Verbosity, mut cmd: Command) -> Result<()> {
-    if verbosity.is_set() {
-        return run_terminal(verbosity, cmd);
+pub fn run_suppress(verbosity: &impl Verbosity, cmd: Command) -> Result<()> {
+    match verbosity.level() {
+        VerbosityLevel::Summary | VerbosityLevel::Harness => run_buffered(cmd),
+        VerbosityLevel::Silent | VerbosityLevel::Commands | VerbosityLevel::Debug => {
+            run_terminal(verbosity, cmd)
+        }
     }
+}
+
+/// Run a job with its output buffered, and only print the output if the job fails.
+fn run_buffered(mut cmd: Command) -> Result<()> {
     let result = cmd
         .output()
         .context(format!("Failed to invoke {}", cmd.get_program().to_string_lossy()))?;
@@ -253,7 +398,8 @@ pub fn run_suppress(verbosity: &impl Verbosity, mut cmd: Command) -> Result<()>
         let mut handle = stdout.lock();
         handle.write_all(&result.stdout)?;
         handle.write_all(&result.stderr)?;
-        bail!("{} exited with status {}", cmd.get_program().to_string_lossy(), result.status);
+        let program = cmd.get_program().to_string_lossy().to_string();
+        return Err(CommandFailed { program, status: result.status }.into());
     }
     Ok(())
 }
@@ -276,14 +422,52 @@ pub fn run_piped(verbosity: &impl Verbosity, mut cmd: Command) -> Result<Child>
     Ok(process)
 }
 
+/// Run a job whose standard output mixes JSON messages with human-readable text, e.g.
+/// `cargo test --message-format=json`, and only forward the JSON messages to the standard output.
+/// The rest of the output is printed to the standard error, unless the verbosity is quiet.
+pub fn run_json_only(verbosity: &impl Verbosity, mut cmd: Command) -> Result<()> {
+    if verbosity.verbose() {
+        eprintln!("[Kani] Running: `{}`", render_command(&cmd).to_string_lossy());
+    }
+    if verbosity.quiet() {
+        cmd.stderr(Stdio::null());
+    }
+    let mut process = cmd
+        .stdout(Stdio::piped())
+        .spawn()
+        .context(format!("Failed to invoke {}", cmd.get_program().to_string_lossy()))?;
+    let reader = BufReader::new(process.stdout.take().unwrap());
+    let mut stdout = std::io::stdout().lock();
+    let mut stderr = std::io::stderr().lock();
+    for line in reader.split(b'\n') {
+        let line = line?;
+        let is_json = line.starts_with(b"{")
+            && serde_json::from_slice::<serde::de::IgnoredAny>(&line).is_ok();
+        if is_json {
+            stdout.write_all(&line)?;
+            stdout.write_all(b"\n")?;
+        } else if !verbosity.quiet() {
+            stderr.write_all(&line)?;
+            stderr.write_all(b"\n")?;
+        }
+    }
+    let status = process.wait()?;
+    if !status.success() {
+        let program = cmd.get_program().to_string_lossy().to_string();
+        return Err(CommandFailed { program, status }.into());
+    }
+    Ok(())
+}
+
 /// Execute the provided function and measure the clock time it took for its execution.
 /// Print the time with the given description if we are on verbose or debug mode.
+/// The execution is also recorded in the trace emitted with `--emit-chrome-trace`.
 fn with_timer<T, F>(verbosity: &impl Verbosity, func: F, description: &str) -> T
 where
     F: FnOnce() -> T,
 {
     let start = Instant::now();
-    let ret = func();
+    let ret = chrome_trace::record(description, "command", func);
     if verbosity.verbose() {
         let elapsed = start.elapsed();
         println!("Finished {description} in {}s", elapsed.as_secs_f32())
@@ -291,6 +475,15 @@ fn with_timer<T, F>(verbosity: &impl Verbosity, func: F, description: &str) -> T
     ret
 }
 
+/// The path of a file derived from the harness whose goto binary is `goto_file`, e.g.,
+/// `harness.linked-restrictions.json` for `harness.out`.
+///
+/// Every harness has its own goto binary, so the files of harnesses verified in parallel never
+/// collide. Use this for every per-harness file instead of a fixed name.
+pub fn harness_file(goto_file: &Path, extension: &str) -> PathBuf {
+    alter_extension(goto_file, extension)
+}
+
 /// Return the path for the folder where the current executable is located.
 fn bin_folder() -> Result<PathBuf> {
     let exe = std::env::current_exe().context("Cannot determine current executable location")?;
@@ -481,3 +674,25 @@ pub fn get_cargo_path() -> Result<PathBuf> {
 
     Ok(cargo_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_harness_file() {
+        // The goto binaries of two harnesses of the same crate.
+        let first = Path::new("target/crate__RNvCs1_5crate7check_a.out");
+        let second = Path::new("target/crate__RNvCs1_5crate7check_b.out");
+        for extension in ["linked-restrictions.json", "c", "demangled.c"] {
+            let first_file = harness_file(first, extension);
+            let second_file = harness_file(second, extension);
+            assert_ne!(first_file, second_file);
+            assert_eq!(first_file.parent(), first.parent());
+        }
+        assert_eq!(
+            harness_file(first, "demangled.c"),
+            PathBuf::from("target/crate__RNvCs1_5crate7check_a.demangled.c")
+        );
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Advisory lock that prevents concurrent Kani runs from writing to the same target directory.
+//!
+//! Verification runs take an exclusive lock, since they write metadata and artifacts, while
+//! subcommands that only consume those files (e.g. `list` and `playback`) take a shared lock.
+//!
+//! The lock is an OS advisory lock on `<target-dir>/kani.lock`, which the OS releases when the
+//! holding process exits, so a lock file left behind by a crashed run doesn't block the next one.
+//! The file is kept outside of `<target-dir>/kani`, which `--force-build` deletes while the lock
+//! is held. It also records the PID of the last process that acquired the lock, which is reported
+//! to the processes that wait for it.
+
+use crate::session::get_cargo_path;
+use anyhow::{Context, Result, bail};
+use cargo_metadata::MetadataCommand;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Name of the lock file inside the target directory.
+const LOCK_FILE_NAME: &str = "kani.lock";
+
+/// How often we retry to acquire the lock while waiting for it.
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LockMode {
+    /// Used by commands that write to the target directory.
+    Exclusive,
+    /// Used by commands that only read from the target directory.
+    Shared,
+}
+
+/// A held session lock. The lock is released when this object is dropped.
+#[derive(Debug)]
+pub struct SessionLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl SessionLock {
+    /// Acquire the lock for the Kani artifacts inside `target_dir`.
+    ///
+    /// If the lock is held by another process, retry until `wait` has elapsed. Without `wait`,
+    /// fail right away with an error that names the process holding the lock.
+    pub fn acquire(target_dir: &Path, mode: LockMode, wait: Option<Duration>) -> Result<Self> {
+        fs::create_dir_all(target_dir)
+            .with_context(|| format!("Failed to create directory `{}`", target_dir.display()))?;
+        let path = target_dir.join(LOCK_FILE_NAME);
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .with_context(|| format!("Failed to open lock file `{}`", path.display()))?;
+
+        let deadline = wait.map(|duration| Instant::now() + duration);
+        loop {
+            let result = match mode {
+                LockMode::Exclusive => file.try_lock(),
+                LockMode::Shared => file.try_lock_shared(),
+            };
+            match result {
+                Ok(()) => break,
+                Err(TryLockError::WouldBlock) => {
+                    if deadline.is_some_and(|deadline| Instant::now() < deadline) {
+                        thread::sleep(LOCK_POLL_INTERVAL);
+                        continue;
+                    }
+                    let holder = match read_pid(&mut file) {
+                        Some(pid) => format!("another Kani process (PID {pid})"),
+                        None => "another Kani process".to_string(),
+                    };
+                    let hint = if wait.is_some() {
+                        "Increase the `--wait-for-lock` duration or wait for the other run to finish."
+                    } else {
+                        "Use `--wait-for-lock <duration>` to wait for the other run to finish."
+                    };
+                    bail!(
+                        "The Kani target directory `{}` is locked by {holder}. {hint}",
+                        target_dir.display()
+                    );
+                }
+                Err(TryLockError::Error(err)) => {
+                    return Err(err)
+                        .with_context(|| format!("Failed to lock file `{}`", path.display()));
+                }
+            }
+        }
+
+        // Record our PID so that other processes can report who holds the lock.
+        // For shared locks, this only records one of the readers, which is enough for the message.
+        file.set_len(0)?;
+        file.rewind()?;
+        write!(file, "{}", std::process::id())?;
+        file.flush()?;
+        debug!(?path, ?mode, "Acquired session lock");
+        Ok(SessionLock { file, path })
+    }
+}
+
+impl Drop for SessionLock {
+    fn drop(&mut self) {
+        // Dropping the file would release the lock anyway; unlocking explicitly makes it visible
+        // in the logs in case something goes wrong.
+        let result = self.file.unlock();
+        debug!(path=?self.path, ?result, "Released session lock");
+    }
+}
+
+/// The target directory used to store the lock: `--target-dir`, then `CARGO_TARGET_DIR`, then
+/// the target directory of the cargo workspace, which takes the cargo configuration into account.
+/// Outside of a cargo workspace, this is `target` in the current directory.
+pub fn lock_target_dir(target_dir: Option<&Path>, manifest_path: Option<&Path>) -> PathBuf {
+    if let Some(target_dir) = target_dir {
+        return target_dir.to_path_buf();
+    }
+    if let Some(target_dir) = std::env::var_os("CARGO_TARGET_DIR") {
+        return PathBuf::from(target_dir);
+    }
+    let mut cmd = MetadataCommand::new();
+    cmd.no_deps();
+    if let Ok(cargo_path) = get_cargo_path() {
+        cmd.cargo_path(cargo_path);
+    }
+    if let Some(manifest_path) = manifest_path {
+        cmd.manifest_path(manifest_path);
+    }
+    match cmd.exec() {
+        Ok(metadata) => metadata.target_directory.into(),
+        Err(err) => {
+            debug!(?err, "No cargo workspace, using `target` for the session lock");
+            PathBuf::from("target")
+        }
+    }
+}
+
+fn read_pid(file: &mut File) -> Option<u32> {
+    let mut content = String::new();
+    file.rewind().ok()?;
+    file.read_to_string(&mut content).ok()?;
+    content.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_exclusive_lock_conflicts() {
+        let dir = tempfile::tempdir().unwrap();
+        let _lock = SessionLock::acquire(dir.path(), LockMode::Exclusive, None).unwrap();
+        let err = SessionLock::acquire(dir.path(), LockMode::Exclusive, None).unwrap_err();
+        let msg = format!("{err}");
+        assert!(msg.contains(&format!("PID {}", std::process::id())), "{msg}");
+        assert!(SessionLock::acquire(dir.path(), LockMode::Shared, None).is_err());
+    }
+
+    #[test]
+    fn check_shared_locks_coexist() {
+        let dir = tempfile::tempdir().unwrap();
+        let _first = SessionLock::acquire(dir.path(), LockMode::Shared, None).unwrap();
+        let _second = SessionLock::acquire(dir.path(), LockMode::Shared, None).unwrap();
+        assert!(SessionLock::acquire(dir.path(), LockMode::Exclusive, None).is_err());
+    }
+
+    #[test]
+    fn check_wait_for_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let lock = SessionLock::acquire(dir.path(), LockMode::Exclusive, None).unwrap();
+        let path = dir.path().to_path_buf();
+        let waiter = thread::spawn(move || {
+            SessionLock::acquire(&path, LockMode::Exclusive, Some(Duration::from_secs(10)))
+                .map(|_| ())
+        });
+        thread::sleep(Duration::from_millis(300));
+        drop(lock);
+        assert!(waiter.join().unwrap().is_ok());
+    }
+
+    #[test]
+    fn check_leftover_lock_file_does_not_block() {
+        let dir = tempfile::tempdir().unwrap();
+        // A lock file left behind by a process that crashed, which the OS no longer locks.
+        fs::write(dir.path().join(LOCK_FILE_NAME), u32::MAX.to_string()).unwrap();
+        let _lock = SessionLock::acquire(dir.path(), LockMode::Exclusive, None).unwrap();
+        let content = fs::read_to_string(dir.path().join(LOCK_FILE_NAME)).unwrap();
+        assert_eq!(content, std::process::id().to_string());
+    }
+
+    #[test]
+    fn check_lock_survives_kani_dir_removal() {
+        let dir = tempfile::tempdir().unwrap();
+        let kani_dir = dir.path().join("kani");
+        fs::create_dir_all(&kani_dir).unwrap();
+        let _lock = SessionLock::acquire(dir.path(), LockMode::Exclusive, None).unwrap();
+        // What `--force-build` does before rebuilding.
+        fs::remove_dir_all(&kani_dir).unwrap();
+        assert!(dir.path().join(LOCK_FILE_NAME).exists());
+        assert!(SessionLock::acquire(dir.path(), LockMode::Exclusive, None).is_err());
+    }
+
+    #[test]
+    fn check_explicit_lock_target_dir() {
+        let dir = Path::new("/tmp/explicit-target");
+        assert_eq!(lock_target_dir(Some(dir), None), dir);
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The timing cache, which records the runtime of every harness that was verified in the target
+//! directory, and which is read by `--estimate`.
+//!
+//! Unlike the summary of the last run, the cache is merged across runs: a run only updates the
+//! runtime of the harnesses that it verified, so running a subset of the harnesses, e.g., with
+//! `--harness`, keeps the runtime of the other harnesses from the previous runs.
+
+use crate::harness_runner::HarnessResult;
+use crate::session::KaniSession;
+use crate::session_lock::lock_target_dir;
+use crate::util::warning;
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The file of the timing cache, relative to the target directory.
+const TIMING_CACHE_FILE: &str = ".kani/timings.json";
+
+/// The version of [TimingCache]. Bump it whenever its format changes.
+const CACHE_VERSION: u32 = 1;
+
+/// The runtime in seconds of each harness, indexed by crate name and then by harness name.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimingCache {
+    version: u32,
+    crates: BTreeMap<String, BTreeMap<String, f64>>,
+}
+
+impl Default for TimingCache {
+    fn default() -> Self {
+        TimingCache { version: CACHE_VERSION, crates: BTreeMap::new() }
+    }
+}
+
+impl TimingCache {
+    /// The runtime recorded for the given harness, if it was ever verified.
+    pub fn runtime(&self, crate_name: &str, harness: &str) -> Option<Duration> {
+        let secs = *self.crates.get(crate_name)?.get(harness)?;
+        Duration::try_from_secs_f64(secs).ok()
+    }
+
+    /// Record the runtime of the given harnesses, replacing their previous runtime.
+    fn merge<'a>(&mut self, runtimes: impl IntoIterator<Item = (&'a str, &'a str, Duration)>) {
+        for (crate_name, harness, runtime) in runtimes {
+            self.crates
+                .entry(crate_name.to_string())
+                .or_default()
+                .insert(harness.to_string(), runtime.as_secs_f64());
+        }
+    }
+}
+
+/// The file of the timing cache. Like the last run, it is stored in the same target directory as
+/// the session lock.
+pub fn timing_cache_file(target_dir: Option<&Path>, manifest_path: Option<&Path>) -> PathBuf {
+    lock_target_dir(target_dir, manifest_path).join(TIMING_CACHE_FILE)
+}
+
+impl KaniSession {
+    /// Merge the runtime of the harnesses verified in this run into the timing cache.
+    ///
+    /// Failing to update the cache doesn't fail the verification, since the user didn't ask for
+    /// it explicitly.
+    pub fn update_timing_cache(&self, results: &[HarnessResult]) {
+        let path = timing_cache_file(
+            self.args.target_dir.as_deref(),
+            self.args.cargo.manifest_path.as_deref(),
+        );
+        let mut cache = match read_cache(&path) {
+            Ok(cache) => cache,
+            Err(err) => {
+                warning(&format!("{err:#}. The timing cache will be recreated."));
+                TimingCache::default()
+            }
+        };
+        cache.merge(results.iter().map(|harness_result| {
+            let harness = harness_result.harness;
+            (
+                harness.crate_name.as_str(),
+                harness.pretty_name.as_str(),
+                harness_result.result.runtime,
+            )
+        }));
+        if let Err(err) = write_cache(&path, &cache) {
+            warning(&format!("{err:#}"));
+        }
+    }
+}
+
+/// Read the timing cache at `path`. A missing cache is empty.
+pub fn read_cache(path: &Path) -> Result<TimingCache> {
+    if !path.exists() {
+        return Ok(TimingCache::default());
+    }
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read timing cache `{}`", path.display()))?;
+    let cache: TimingCache = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid timing cache `{}`", path.display()))?;
+    if cache.version != CACHE_VERSION {
+        bail!(
+            "Unsupported version {} of timing cache `{}`, this version of Kani only supports \
+            version {CACHE_VERSION}",
+            cache.version,
+            path.display()
+        );
+    }
+    Ok(cache)
+}
+
+fn write_cache(path: &Path, cache: &TimingCache) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory `{}`", parent.display()))?;
+    }
+    fs::write(path, serde_json::to_string_pretty(cache)?)
+        .with_context(|| format!("Failed to write timing cache `{}`", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_merge_across_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".kani").join("timings.json");
+        // A missing cache is empty.
+        let mut cache = read_cache(&path).unwrap();
+        assert_eq!(cache.runtime("my_crate", "check_a"), None);
+
+        cache.merge([
+            ("my_crate", "check_a", Duration::from_secs(10)),
+            ("my_crate", "check_b", Duration::from_millis(500)),
+        ]);
+        write_cache(&path, &cache).unwrap();
+
+        // A later run that only verifies `check_a` keeps the runtime of `check_b`.
+        let mut cache = read_cache(&path).unwrap();
+        cache.merge([
+            ("my_crate", "check_a", Duration::from_secs(4)),
+            ("other_crate", "check_a", Duration::from_secs(1)),
+        ]);
+        write_cache(&path, &cache).unwrap();
+
+        let cache = read_cache(&path).unwrap();
+        assert_eq!(cache.runtime("my_crate", "check_a"), Some(Duration::from_secs(4)));
+        assert_eq!(cache.runtime("my_crate", "check_b"), Some(Duration::from_millis(500)));
+        assert_eq!(cache.runtime("other_crate", "check_a"), Some(Duration::from_secs(1)));
+        assert_eq!(cache.runtime("other_crate", "check_b"), None);
+    }
+
+    #[test]
+    fn check_cache_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("timings.json");
+        fs::write(&path, format!("{{\"version\":{},\"crates\":{{}}}}", CACHE_VERSION + 1)).unwrap();
+        assert!(read_cache(&path).is_err());
+        fs::write(&path, "{\"harnesses\": []}").unwrap();
+        assert!(read_cache(&path).is_err());
+    }
+
+    #[test]
+    fn check_timing_cache_file() {
+        assert_eq!(
+            timing_cache_file(Some(Path::new("out")), None),
+            Path::new("out").join(".kani").join("timings.json")
+        );
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Detect when the Rust toolchain used by cargo is not the one Kani was built with.
+//!
+//! The Kani compiler is linked against the `rustc` libraries of one specific toolchain, so it
+//! cannot load the metadata of dependencies that were compiled by another one, and the errors
+//! that come out of it are hard to relate to the installation. So we check the toolchain at the
+//! start of the session instead.
+
+use crate::session::{InstallType, get_cargo_path};
+use crate::util::warning;
+use anyhow::{Result, bail};
+use std::fmt::{self, Display};
+use std::path::Path;
+use std::process::Command;
+use tracing::debug;
+
+/// The version of a `rustc` binary, as reported by `rustc -vV`.
+#[derive(Debug, PartialEq, Eq)]
+struct RustcVersion {
+    release: String,
+    commit_hash: String,
+}
+
+impl RustcVersion {
+    /// The version of the compiler that built Kani, which was recorded by our build script.
+    fn built_with() -> Self {
+        RustcVersion {
+            release: env!("KANI_RUSTC_RELEASE").to_string(),
+            commit_hash: env!("KANI_RUSTC_COMMIT_HASH").to_string(),
+        }
+    }
+
+    /// Parse the output of `rustc -vV`.
+    fn parse(output: &str) -> Option<Self> {
+        let field = |key: &str| {
+            output.lines().find_map(|line| Some(line.strip_prefix(key)?.strip_prefix(": ")?.trim()))
+        };
+        Some(RustcVersion {
+            release: field("release")?.to_string(),
+            commit_hash: field("commit-hash")?.to_string(),
+        })
+    }
+
+    /// Whether both versions are the same compiler. Compilers built without git information
+    /// report an `unknown` commit hash, so we can only compare their release.
+    fn matches(&self, other: &RustcVersion) -> bool {
+        if self.commit_hash == "unknown" || other.commit_hash == "unknown" {
+            self.release == other.release
+        } else {
+            self.commit_hash == other.commit_hash
+        }
+    }
+}
+
+impl Display for RustcVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hash = self.commit_hash.get(..9).unwrap_or(&self.commit_hash);
+        write!(f, "rustc {} ({hash})", self.release)
+    }
+}
+
+/// Check that the toolchain used by cargo is the one Kani was built with.
+///
+/// On mismatch, fail with a message that explains how to fix the installation, or only warn if
+/// the user passed `--allow-toolchain-skew`. The check is skipped if we cannot query the version
+/// of the toolchain.
+pub fn check_toolchain_skew(install: &InstallType, allow_skew: bool) -> Result<()> {
+    let cargo = get_cargo_path()?;
+    let rustc = cargo.with_file_name(format!("rustc{}", std::env::consts::EXE_SUFFIX));
+    let actual = match Command::new(&rustc).arg("-vV").output() {
+        Ok(output) if output.status.success() => {
+            RustcVersion::parse(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => None,
+    };
+    let Some(actual) = actual else {
+        debug!(?rustc, "Unable to query the version of the toolchain");
+        return Ok(());
+    };
+
+    match skew_message(&cargo, env!("RUSTUP_TOOLCHAIN"), &RustcVersion::built_with(), &actual) {
+        None => Ok(()),
+        Some(msg) if allow_skew => {
+            warning(&format!("{msg}. Proceeding anyway because of `--allow-toolchain-skew`."));
+            Ok(())
+        }
+        Some(msg) => bail!(
+            "{msg}. Run `{}` to fix the installation, or pass `--allow-toolchain-skew` to proceed \
+            anyway.",
+            fix_command(install)
+        ),
+    }
+}
+
+/// Describe the mismatch between the toolchain Kani was built with and the one used by `cargo`,
+/// if any.
+fn skew_message(
+    cargo: &Path,
+    toolchain: &str,
+    expected: &RustcVersion,
+    actual: &RustcVersion,
+) -> Option<String> {
+    (!expected.matches(actual)).then(|| {
+        format!(
+            "Kani was built with the `{toolchain}` toolchain ({expected}), but `{}` uses \
+            {actual}",
+            cargo.display()
+        )
+    })
+}
+
+/// The command that installs the toolchain Kani expects.
+fn fix_command(install: &InstallType) -> &'static str {
+    match install {
+        // Rebuilding picks the toolchain pinned in `rust-toolchain.toml`.
+        InstallType::DevRepo(_) => "cargo build-dev",
+        // The setup links the toolchain that matches the release bundle.
+        InstallType::Release(_) => "cargo kani setup",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const NIGHTLY_OUTPUT: &str = "rustc 1.91.0-nightly (ca7750494 2025-08-15)
+binary: rustc
+commit-hash: ca77504943887037504c7fc0b9bf06dab3910373
+commit-date: 2025-08-15
+host: x86_64-unknown-linux-gnu
+release: 1.91.0-nightly
+LLVM version: 21.1.0
+";
+
+    fn version(release: &str, commit_hash: &str) -> RustcVersion {
+        RustcVersion { release: release.to_string(), commit_hash: commit_hash.to_string() }
+    }
+
+    #[test]
+    fn check_parse_rustc_version() {
+        let parsed = RustcVersion::parse(NIGHTLY_OUTPUT).unwrap();
+        assert_eq!(parsed, version("1.91.0-nightly", "ca77504943887037504c7fc0b9bf06dab3910373"));
+        assert_eq!(parsed.to_string(), "rustc 1.91.0-nightly (ca7750494)");
+        assert_eq!(RustcVersion::parse("rustc 1.91.0-nightly"), None);
+    }
+
+    #[test]
+    fn check_matching_versions() {
+        let built = version("1.91.0-nightly", "ca77504943887037504c7fc0b9bf06dab3910373");
+        assert!(built.matches(&RustcVersion::parse(NIGHTLY_OUTPUT).unwrap()));
+        assert!(
+            !built.matches(&version("1.91.0-nightly", "0f35336396551ec5aa0dc1d8d4e1e7fa5ac1c7e5"))
+        );
+        assert!(built.matches(&version("1.91.0-nightly", "unknown")));
+        assert!(!built.matches(&version("1.92.0-nightly", "unknown")));
+    }
+
+    #[test]
+    fn check_dev_repo_skew() {
+        let install = InstallType::DevRepo(PathBuf::from("/home/user/kani"));
+        let cargo = Path::new("/home/user/.rustup/toolchains/nightly-2025-08-16/bin/cargo");
+        let expected = version("1.91.0-nightly", "ca77504943887037504c7fc0b9bf06dab3910373");
+        let actual = version("1.92.0-nightly", "0f35336396551ec5aa0dc1d8d4e1e7fa5ac1c7e5");
+        assert_eq!(skew_message(cargo, "nightly-2025-08-16", &expected, &expected), None);
+        assert_eq!(
+            skew_message(cargo, "nightly-2025-08-16", &expected, &actual).unwrap(),
+            "Kani was built with the `nightly-2025-08-16` toolchain (rustc 1.91.0-nightly \
+            (ca7750494)), but `/home/user/.rustup/toolchains/nightly-2025-08-16/bin/cargo` uses \
+            rustc 1.92.0-nightly (0f3533639)"
+        );
+        assert_eq!(fix_command(&install), "cargo build-dev");
+    }
+
+    #[test]
+    fn check_release_skew() {
+        let install = InstallType::Release(PathBuf::from("/home/user/.kani/kani-0.65.0"));
+        let cargo = Path::new("/home/user/.kani/kani-0.65.0/toolchain/bin/cargo");
+        let expected = version("1.91.0-nightly", "ca77504943887037504c7fc0b9bf06dab3910373");
+        let actual = version("1.91.0-nightly", "0f35336396551ec5aa0dc1d8d4e1e7fa5ac1c7e5");
+        assert_eq!(skew_message(cargo, "nightly-2025-08-16", &expected, &expected), None);
+        assert_eq!(
+            skew_message(cargo, "nightly-2025-08-16", &expected, &actual).unwrap(),
+            "Kani was built with the `nightly-2025-08-16` toolchain (rustc 1.91.0-nightly \
+            (ca7750494)), but `/home/user/.kani/kani-0.65.0/toolchain/bin/cargo` uses \
+            rustc 1.91.0-nightly (0f3533639)"
+        );
+        assert_eq!(fix_command(&install), "cargo kani setup");
+    }
+}
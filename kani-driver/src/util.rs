@@ -11,8 +11,15 @@
 //! `kani-compiler`.
 
 use std::ffi::OsString;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the messages of `warning`, `error` and `info_operation` are printed to the standard
+/// error instead of the standard output.
+static MESSAGES_TO_STDERR: AtomicBool = AtomicBool::new(false);
 
 /// Replace an extension with another one, in a new PathBuf. (See tests for examples)
 pub fn alter_extension(path: &Path, ext: &str) -> PathBuf {
@@ -71,21 +78,92 @@ pub fn render_command(cmd: &Command) -> OsString {
 pub fn warning(msg: &str) {
     let warning = console::style("warning:").bold().yellow();
     let msg_fmt = console::style(msg).bold();
-    println!("{warning} {msg_fmt}")
+    print_message(format_args!("{warning} {msg_fmt}"))
 }
 
 /// Print an error message. This will add an "error:" tag before the message and style accordingly.
 pub fn error(msg: &str) {
     let error = console::style("error:").bold().red();
     let msg_fmt = console::style(msg).bold();
-    println!("{error} {msg_fmt}")
+    print_message(format_args!("{error} {msg_fmt}"))
 }
 
 /// Print an info message. This will print the stage in bold green and the rest in regular style.
 pub fn info_operation(op: &str, msg: &str) {
     let op_fmt = console::style(op).bold().green();
     let msg_fmt = console::style(msg);
-    println!("{op_fmt} {msg_fmt}")
+    print_message(format_args!("{op_fmt} {msg_fmt}"))
+}
+
+/// Print the messages of `warning`, `error` and `info_operation` to the standard error from now
+/// on, so the standard output only contains machine-readable output.
+pub fn print_messages_to_stderr() {
+    MESSAGES_TO_STDERR.store(true, Ordering::Relaxed);
+}
+
+fn print_message(msg: impl Display) {
+    if MESSAGES_TO_STDERR.load(Ordering::Relaxed) { eprintln!("{msg}") } else { println!("{msg}") }
+}
+
+/// A 64-bit FNV-1a hasher, for the hashes that must not change between runs, Rust releases or
+/// targets, e.g., the names of the concrete playback tests or the shard of a harness.
+/// Use it instead of `DefaultHasher`, whose algorithm is unspecified and may change.
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub fn new() -> Self {
+        StableHasher(Self::OFFSET_BASIS)
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    // Integers are hashed in little endian, and `usize` as a `u64`, so the hash doesn't depend
+    // on the target.
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+}
+
+/// The stable hash of `value`. See [StableHasher].
+pub fn stable_hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = StableHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
 }
 
 /// # Kani Argument Types
@@ -268,6 +346,26 @@ fn check_exe_basename() {
         assert_eq!(executable_basename(&Some(&OsString::from("foo"))), Some("foo".into()));
     }
 
+    #[test]
+    fn check_stable_hasher() {
+        // The reference values of 64-bit FNV-1a.
+        assert_eq!(StableHasher::new().finish(), 0xcbf29ce484222325);
+        let mut hasher = StableHasher::new();
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63dc4c8601ec8c);
+        let mut hasher = StableHasher::new();
+        hasher.write(b"foobar");
+        assert_eq!(hasher.finish(), 0x85944171f73967e8);
+
+        assert_eq!(stable_hash("check_a"), stable_hash("check_a"));
+        assert_ne!(stable_hash("check_a"), stable_hash("check_b"));
+        // Integers are hashed in little endian on every target.
+        assert_eq!(stable_hash(&1usize), stable_hash(&1u64));
+        let mut hasher = StableHasher::new();
+        hasher.write(&[1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(stable_hash(&1u64), hasher.finish());
+    }
+
     #[test]
     fn check_render_command() {
         let mut c1 = Command::new("a");
@@ -2,6 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::InvocationType;
+use crate::args::VersionInfoFormat;
+use crate::call_cbmc::solver_binary;
+use crate::session::{BUG_REPORT_URL, toolchain_shorthand};
+use anyhow::Result;
+use kani_metadata::CbmcSolver;
+use serde::Serialize;
+use std::process::Command;
 
 const KANI_RUST_VERIFIER: &str = "Kani Rust Verifier";
 /// We assume this is the same as the `kani-verifier` version, but we should
@@ -29,3 +36,74 @@ fn kani_version_release(invocation_type: InvocationType) -> String {
     };
     format!("{KANI_RUST_VERIFIER} {KANI_VERSION} ({invocation_str})")
 }
+
+/// The versions of Kani and of the tools it relies on, as reported by `--version-info`.
+#[derive(Debug, Serialize)]
+struct VersionInfo {
+    kani: String,
+    toolchain: String,
+    /// `None` if CBMC could not be found.
+    cbmc: Option<String>,
+    solvers: Vec<SolverVersion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SolverVersion {
+    name: String,
+    /// `built-in` for the solvers included in CBMC, and `None` if the solver could not be found.
+    version: Option<String>,
+}
+
+/// Print the versions of Kani, its toolchain, CBMC and the solvers, e.g., to include them in a
+/// bug report.
+pub(crate) fn print_version_info(format: VersionInfoFormat) -> Result<()> {
+    let solvers = [
+        CbmcSolver::Bitwuzla,
+        CbmcSolver::Cadical,
+        CbmcSolver::Cvc5,
+        CbmcSolver::Kissat,
+        CbmcSolver::Minisat,
+        CbmcSolver::Z3,
+    ];
+    let info = VersionInfo {
+        kani: KANI_VERSION.to_string(),
+        toolchain: toolchain_shorthand().trim_start_matches('+').to_string(),
+        cbmc: tool_version("cbmc"),
+        solvers: solvers
+            .iter()
+            .map(|solver| SolverVersion {
+                name: solver.as_ref().to_string(),
+                version: match solver_binary(solver) {
+                    Some(binary) => tool_version(binary),
+                    None => Some("built-in".to_string()),
+                },
+            })
+            .collect(),
+    };
+    match format {
+        VersionInfoFormat::Pretty => {
+            let not_found = "not found".to_string();
+            println!("{KANI_RUST_VERIFIER} {}", info.kani);
+            println!("Toolchain: {}", info.toolchain);
+            println!("CBMC: {}", info.cbmc.as_ref().unwrap_or(&not_found));
+            println!("Solvers:");
+            for solver in &info.solvers {
+                println!("  {}: {}", solver.name, solver.version.as_ref().unwrap_or(&not_found));
+            }
+            println!("Report bugs at {BUG_REPORT_URL}");
+        }
+        VersionInfoFormat::Json => println!("{}", serde_json::to_string_pretty(&info)?),
+    }
+    Ok(())
+}
+
+/// Run `<binary> --version` and return the first line of its output, if the binary can be
+/// executed.
+fn tool_version(binary: &str) -> Option<String> {
+    let output = Command::new(binary).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout.lines().map(str::trim).find(|line| !line.is_empty()).map(str::to_string)
+}
@@ -0,0 +1,42 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use serde::{Deserialize, Serialize};
+
+/// How the `debug_assert!` family of macros is treated during codegen, which is selected with
+/// `--assertion-semantics`.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    clap::ValueEnum,
+    strum_macros::Display,
+    strum_macros::AsRefStr
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum AssertionSemantics {
+    /// Check debug assertions like any other assertion.
+    #[default]
+    DebugAsAssert,
+    /// Assume that debug assertions hold, i.e., only verify the executions that satisfy them.
+    DebugAsAssume,
+    /// Remove debug assertions, as in a build without `debug_assertions`.
+    DebugIgnore,
+}
+
+impl AssertionSemantics {
+    /// What happens to the debug assertions under these semantics, to report them to the user.
+    pub fn description(&self) -> &'static str {
+        match self {
+            AssertionSemantics::DebugAsAssert => "checked as assertions",
+            AssertionSemantics::DebugAsAssume => "treated as assumptions",
+            AssertionSemantics::DebugIgnore => "ignored",
+        }
+    }
+}
@@ -3,7 +3,11 @@
 
 use crate::CbmcSolver;
 use serde::{Deserialize, Serialize};
-use std::{borrow::Borrow, collections::BTreeSet, path::PathBuf};
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
 use strum_macros::Display;
 use tracing::{debug, trace};
 
@@ -42,6 +46,44 @@ pub struct HarnessMetadata {
     pub has_loop_contracts: bool,
     /// If the harness was automatically generated or manually written.
     pub is_automatically_generated: bool,
+    /// If the harness starts with a contradictory assumption (`kani::assume(false)`),
+    /// which makes its verification vacuous.
+    #[serde(default)]
+    pub is_vacuous: bool,
+    /// If no source of non-determinism (e.g. `kani::any()`) is reachable from the harness,
+    /// which means that it only checks a single concrete execution.
+    #[serde(default)]
+    pub is_concrete: bool,
+    /// If `kani::concretize` is reachable from the harness, which makes its verification only
+    /// cover a single value of the concretized inputs.
+    #[serde(default)]
+    pub uses_concretize: bool,
+    /// The unwind bounds of the loops annotated with `#[kani::loop_unwind]` that are reachable
+    /// from the harness.
+    #[serde(default)]
+    pub loop_unwind_bounds: Vec<LoopUnwindBound>,
+    /// The sorted fully qualified names of the functions reachable from the harness. This is
+    /// only collected for `--emit-reachable`.
+    #[serde(default)]
+    pub reachable_functions: Vec<String>,
+    /// The number of nondeterministic bytes introduced by the harness, counting each call site
+    /// once per call that reaches it, e.g., `kani::any::<[u8; 4]>()` introduces 4 bytes.
+    /// Only counted with `--max-nondet-bytes` or `--verbose`, and 0 otherwise.
+    #[serde(default)]
+    pub nondet_bytes: u64,
+    /// The number of debug assertions reachable from the harness, which are handled according to
+    /// `--assertion-semantics`.
+    #[serde(default)]
+    pub debug_assertions: u64,
+}
+
+/// The unwind bound of a single loop, specified with `#[kani::loop_unwind(<bound>)]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LoopUnwindBound {
+    /// The loop identifier in CBMC's format, i.e., `<function>.<loop head label>`.
+    pub loop_id: String,
+    /// The number of times the loop should be unwound.
+    pub bound: u32,
 }
 
 /// The attributes added by the user to control how a harness is executed.
@@ -59,6 +101,24 @@ pub struct HarnessAttributes {
     pub stubs: Vec<Stub>,
     /// The name of the functions being stubbed by their contract.
     pub verified_stubs: Vec<String>,
+    /// Free-form metadata added with `#[kani::proof(key = "value")]`, e.g. the harness owner.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    /// Whether the harness is expected to fail, added with `#[kani::proof(expect_fail)]`.
+    #[serde(default)]
+    pub expect_fail: Option<ExpectedFailure>,
+    /// The expected verification time in seconds, added with
+    /// `#[kani::expected_time(secs = <seconds>)]`.
+    #[serde(default)]
+    pub expected_time_secs: Option<u64>,
+}
+
+/// The failure expected from a harness annotated with `#[kani::proof(expect_fail)]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExpectedFailure {
+    /// A substring of the description of a property that must fail, added with
+    /// `#[kani::proof(expect_fail = "<property>")]`. Any failure is expected if this is `None`.
+    pub property: Option<String>,
 }
 
 #[derive(Clone, Eq, PartialEq, Debug, Display, Serialize, Deserialize)]
@@ -84,6 +144,9 @@ pub fn new(kind: HarnessKind) -> HarnessAttributes {
             unwind_value: None,
             stubs: vec![],
             verified_stubs: vec![],
+            metadata: BTreeMap::new(),
+            expect_fail: None,
+            expected_time_secs: None,
         }
     }
 
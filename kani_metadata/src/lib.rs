@@ -11,11 +11,13 @@
 use strum_macros::{Display, EnumString};
 
 pub use artifact::ArtifactType;
+pub use assertion_semantics::AssertionSemantics;
 pub use cbmc_solver::CbmcSolver;
 pub use harness::*;
 pub use vtable::*;
 
 pub mod artifact;
+mod assertion_semantics;
 mod cbmc_solver;
 mod harness;
 pub mod unstable;
@@ -39,6 +41,9 @@ pub struct KaniMetadata {
     pub contracted_functions: Vec<ContractedFunction>,
     /// Metadata for the `autoharness` subcommand
     pub autoharness_md: Option<AutoHarnessMetadata>,
+    /// The mutations that can be applied to this crate, collected for the `mutate` subcommand.
+    #[serde(default)]
+    pub mutants: Vec<MutantMetadata>,
 }
 
 /// For the autoharness subcommand, all of the user-defined functions we found,
@@ -80,6 +85,48 @@ pub struct ContractedFunction {
     pub file: String,
     /// The pretty names of the proof harnesses (`#[kani::proof_for_contract]`) for this function
     pub harnesses: Vec<String>,
+    /// The clauses of the contract, as written by the user.
+    #[serde(default)]
+    pub clauses: Vec<ContractClause>,
+}
+
+/// A clause of a function contract, e.g., `#[kani::requires(divisor != 0)]`.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ContractClause {
+    pub kind: ContractClauseKind,
+    /// The pretty-printed argument of the clause, e.g., `divisor != 0`. Empty for clauses
+    /// without arguments.
+    pub expr: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ContractClauseKind {
+    Requires,
+    Ensures,
+    Modifies,
+    EnsuresNoPanic,
+}
+
+/// A mutation that can be applied to a function reachable from the crate harnesses.
+/// Mutants are used by the `mutate` subcommand to evaluate how many bugs the harnesses can catch.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
+pub struct MutantMetadata {
+    /// The identifier that should be given to the compiler (`--mutant`) to apply this mutation.
+    pub id: String,
+    /// The fully qualified name of the mutated function.
+    pub function: String,
+    /// A human readable description of the mutation, e.g.: "replace `<` with `>=`".
+    pub description: String,
+    /// The file where the mutation is applied.
+    pub file: String,
+    /// The line where the mutation is applied.
+    pub line: usize,
+    /// The column where the mutation is applied.
+    pub column: usize,
+    /// The pretty names of the proof harnesses that can reach the mutated function.
+    pub harnesses: BTreeSet<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,6 +75,12 @@ pub enum UnstableFeature {
     ConcretePlayback,
     /// Allow Kani to link against C code.
     CFfi,
+    /// Automatically generate contract harnesses for every function under contract that
+    /// doesn't have one.
+    ContractsBatch,
+    /// Replace the functions of annotated `extern` blocks by the stubs declared with
+    /// `#[kani::ffi_stubs(...)]`.
+    FfiStubs,
     /// Kani APIs related to floating-point operations (e.g. `float_to_int_in_range`)
     FloatLib,
     /// Enable function contracts [RFC 9](https://model-checking.github.io/kani/rfc/rfcs/0009-function-contracts.html)
@@ -89,6 +95,8 @@ pub enum UnstableFeature {
     LoopContracts,
     /// Memory predicate APIs.
     MemPredicates,
+    /// Enable the mutate subcommand.
+    Mutate,
     /// Enable vtable restriction.
     RestrictVtable,
     /// Enable source-based code coverage workflow.
@@ -106,6 +114,9 @@ pub enum UnstableFeature {
     /// Automatically check that no invalid value is produced which is considered UB in Rust.
     /// Note that this does not include checking uninitialized value.
     ValidValueChecks,
+    /// Generate a harness for every `unsafe` function annotated with `#[kani::verify_unsafe]`,
+    /// and enable the undefined behavior checks that use shadow memory.
+    VerifyUnsafe,
 }
 
 impl UnstableFeature {
@@ -0,0 +1,95 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic aligned addresses and pointers, useful to verify OS kernels, device
+//! drivers, memory allocators and alignment-sensitive code, e.g., code that uses SIMD loads.
+
+use crate::{Arbitrary, any, assume};
+
+/// The page size assumed by [`any_page_aligned_addr`].
+pub const PAGE_SIZE: usize = 4096;
+
+/// Generates a symbolic address that is a multiple of `align`.
+///
+/// Every multiple of `align` that fits in a `usize` can be generated, including `0`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let addr = kani::any_aligned_addr(64);
+/// assert_eq!(addr % 64, 0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `align` is not a power of two.
+pub fn any_aligned_addr(align: usize) -> usize {
+    assert!(align.is_power_of_two(), "`any_aligned_addr` expects a power of two alignment");
+    any::<usize>() & !(align - 1)
+}
+
+/// Generates a symbolic address that is a multiple of the page size, i.e. [`PAGE_SIZE`].
+///
+/// # Example:
+///
+/// ```no_run
+/// let addr = kani::any_page_aligned_addr();
+/// assert_eq!(addr % kani::addr::PAGE_SIZE, 0);
+/// ```
+pub fn any_page_aligned_addr() -> usize {
+    any_aligned_addr(PAGE_SIZE)
+}
+
+/// Assumes that `ptr` is aligned to `align` bytes, e.g., to verify a function that requires an
+/// aligned pointer for its SIMD loads.
+///
+/// Like [`kani::assume`](crate::assume), this restricts verification to the executions where the
+/// pointer is aligned.
+///
+/// # Example:
+///
+/// ```no_run
+/// let buf = [0u32; 8];
+/// let ptr = buf.as_ptr().wrapping_add(kani::any_where(|idx| *idx < 4));
+/// kani::assume_aligned(ptr, 16);
+/// assert_eq!(ptr as usize % 16, 0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `align` is not a power of two.
+pub fn assume_aligned<T: ?Sized>(ptr: *const T, align: usize) {
+    assert!(align.is_power_of_two(), "`assume_aligned` expects a power of two alignment");
+    assume((ptr as *const u8).addr() % align == 0);
+}
+
+/// Generates a pointer aligned to at least `align` bytes to a symbolic value of type `T`.
+///
+/// The value is stored in a new allocation that is never freed, so the pointer is valid for
+/// reads and writes for the rest of the harness. The alignment of the pointer is the largest of
+/// `align` and the alignment of `T`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let ptr = kani::any_aligned_ptr::<[u32; 4]>(16);
+/// assert_eq!(ptr as usize % 16, 0);
+/// let lanes = unsafe { *ptr };
+/// ```
+///
+/// # Panics
+///
+/// Panics if `align` is not a power of two.
+pub fn any_aligned_ptr<T: Arbitrary>(align: usize) -> *mut T {
+    assert!(align.is_power_of_two(), "`any_aligned_ptr` expects a power of two alignment");
+    let align = align.max(align_of::<T>());
+    // The allocations don't have any alignment guarantee during verification, so we allocate
+    // enough bytes to find an aligned address in them.
+    let storage = Box::leak(vec![0u8; size_of::<T>() + align - 1].into_boxed_slice());
+    let base = storage.as_mut_ptr();
+    let offset = (align - base.addr() % align) % align;
+    let ptr = base.wrapping_add(offset).cast::<T>();
+    // SAFETY: `ptr` is aligned and points to `size_of::<T>()` bytes of the allocation.
+    unsafe { ptr.write(any()) };
+    ptr
+}
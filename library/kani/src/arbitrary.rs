@@ -23,3 +23,34 @@ fn any() -> Self {
         std::time::Duration::new(u64::any(), nanos)
     }
 }
+
+impl<T> Arbitrary for std::sync::Mutex<T>
+where
+    T: Arbitrary,
+{
+    fn any() -> Self {
+        std::sync::Mutex::new(T::any())
+    }
+}
+
+macro_rules! atomic_arbitrary {
+    ($atomic:ty, $base:ty) => {
+        impl Arbitrary for $atomic {
+            fn any() -> Self {
+                <$atomic>::new(<$base>::any())
+            }
+        }
+    };
+}
+
+atomic_arbitrary!(std::sync::atomic::AtomicBool, bool);
+atomic_arbitrary!(std::sync::atomic::AtomicU8, u8);
+atomic_arbitrary!(std::sync::atomic::AtomicU16, u16);
+atomic_arbitrary!(std::sync::atomic::AtomicU32, u32);
+atomic_arbitrary!(std::sync::atomic::AtomicU64, u64);
+atomic_arbitrary!(std::sync::atomic::AtomicUsize, usize);
+atomic_arbitrary!(std::sync::atomic::AtomicI8, i8);
+atomic_arbitrary!(std::sync::atomic::AtomicI16, i16);
+atomic_arbitrary!(std::sync::atomic::AtomicI32, i32);
+atomic_arbitrary!(std::sync::atomic::AtomicI64, i64);
+atomic_arbitrary!(std::sync::atomic::AtomicIsize, isize);
@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic bitmasks, useful to verify code that manipulates
+//! flags registers, permission sets and protocol headers.
+
+use crate::any_where;
+
+/// Generates a symbolic 64-bit mask with exactly `len_bits` bits set.
+///
+/// # Example:
+///
+/// ```no_run
+/// let mask = kani::any_bitmask(3);
+/// assert_eq!(mask.count_ones(), 3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `len_bits` is greater than 64.
+pub fn any_bitmask(len_bits: usize) -> u64 {
+    assert!(len_bits <= u64::BITS as usize, "`any_bitmask` supports at most 64 bits");
+    any_where(|mask: &u64| mask.count_ones() == len_bits as u32)
+}
+
+/// Generates a symbolic 64-bit mask with at most `max_bits` bits set.
+///
+/// # Example:
+///
+/// ```no_run
+/// let mask = kani::any_bitmask_at_most(2);
+/// assert!(mask.count_ones() <= 2);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_bits` is greater than 64.
+pub fn any_bitmask_at_most(max_bits: usize) -> u64 {
+    assert!(max_bits <= u64::BITS as usize, "`any_bitmask_at_most` supports at most 64 bits");
+    any_where(|mask: &u64| mask.count_ones() <= max_bits as u32)
+}
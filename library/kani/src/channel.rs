@@ -0,0 +1,51 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for the values received from channels, such as `std::sync::mpsc` or
+//! `tokio::sync::mpsc`, whose `recv` methods return `None` once the channel is closed.
+//!
+//! Replacing the receiving end of a channel by these values allows verifying a consumer against
+//! any message, without modeling the producers.
+
+use crate::{Arbitrary, any, any_where};
+
+/// Generates the result of receiving from a channel: either `None`, when the channel is closed,
+/// or `Some(kani::any::<T>())`.
+///
+/// # Example:
+///
+/// ```no_run
+/// match kani::any_channel_message::<u8>() {
+///     Some(byte) => assert!(byte <= u8::MAX),
+///     None => { /* The channel is closed. */ }
+/// }
+/// ```
+pub fn any_channel_message<T: Arbitrary>() -> Option<T> {
+    if any() { Some(any()) } else { None }
+}
+
+/// Generates a symbolic sequence of up to `max_msgs` results of receiving from a channel. Only
+/// the last result can be `None`, which represents the closure of the channel, since a closed
+/// channel doesn't receive any other message.
+///
+/// Loops over the sequence require an unwinding bound of `max_msgs + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let messages = kani::any_channel_sequence::<u8>(3);
+/// assert!(messages.len() <= 3);
+/// assert!(messages.iter().rev().skip(1).all(Option::is_some));
+/// ```
+pub fn any_channel_sequence<T: Arbitrary>(max_msgs: usize) -> Vec<Option<T>> {
+    let len: usize = any_where(|len| *len <= max_msgs);
+    let closed: bool = len > 0 && any();
+    let mut messages = Vec::with_capacity(len);
+    for _ in 0..len - usize::from(closed) {
+        messages.push(Some(any()));
+    }
+    if closed {
+        messages.push(None);
+    }
+    messages
+}
@@ -0,0 +1,79 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic command line arguments, useful to verify argument parsers such as
+//! the ones built with `clap`.
+//!
+//! The arguments are ASCII strings without NUL bytes, since these cannot be passed to a process.
+//! Harnesses using these functions need an unwind bound of at least `MAX_ARG_LEN + 2`.
+
+use crate::any_where;
+
+/// The maximum length of each argument generated by [`any_command_line_args`] and
+/// [`any_arg_starting_with`].
+pub const MAX_ARG_LEN: usize = 32;
+
+/// Generates a symbolic list of 0 to `max_argc` command line arguments, each of 0 to
+/// [`MAX_ARG_LEN`] ASCII characters without NUL bytes.
+///
+/// The list doesn't include the name of the binary, which most parsers expect as the first
+/// argument.
+///
+/// # Example:
+///
+/// ```no_run
+/// let args = kani::any_command_line_args(2);
+/// assert!(args.len() <= 2);
+/// assert!(args.iter().all(|arg| arg.len() <= kani::cli::MAX_ARG_LEN));
+/// ```
+pub fn any_command_line_args(max_argc: usize) -> Vec<String> {
+    let argc: usize = any_where(|argc| *argc <= max_argc);
+    let mut args = Vec::with_capacity(max_argc);
+    for _ in 0..argc {
+        let mut arg = String::with_capacity(MAX_ARG_LEN);
+        push_any_arg_chars(&mut arg, MAX_ARG_LEN);
+        args.push(arg);
+    }
+    args
+}
+
+/// Generates a symbolic command line argument that starts with `prefix`, e.g., `--` for long
+/// options or `--output=` for the value of an option.
+///
+/// The prefix is followed by ASCII characters without NUL bytes, up to a total length of
+/// [`MAX_ARG_LEN`].
+///
+/// # Example:
+///
+/// ```no_run
+/// let arg = kani::any_arg_starting_with("--");
+/// assert!(arg.starts_with("--"));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `prefix` is longer than [`MAX_ARG_LEN`], or if it contains non-ASCII characters or
+/// NUL bytes.
+pub fn any_arg_starting_with(prefix: &str) -> String {
+    assert!(
+        prefix.len() <= MAX_ARG_LEN,
+        "`any_arg_starting_with` requires a prefix of at most {MAX_ARG_LEN} characters"
+    );
+    assert!(
+        prefix.is_ascii() && !prefix.contains('\0'),
+        "`any_arg_starting_with` requires an ASCII prefix without NUL bytes"
+    );
+    let mut arg = String::with_capacity(MAX_ARG_LEN);
+    arg.push_str(prefix);
+    push_any_arg_chars(&mut arg, MAX_ARG_LEN - prefix.len());
+    arg
+}
+
+/// Append 0 to `max_len` symbolic ASCII characters without NUL bytes to `arg`.
+fn push_any_arg_chars(arg: &mut String, max_len: usize) {
+    let len: usize = any_where(|len| *len <= max_len);
+    for _ in 0..len {
+        let byte: u8 = any_where(|byte: &u8| byte.is_ascii() && *byte != 0);
+        arg.push(byte as char);
+    }
+}
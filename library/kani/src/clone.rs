@@ -0,0 +1,37 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks for the `Clone` implementations of user types.
+
+use crate::{Arbitrary, any};
+
+/// Checks that cloning a symbolic value of type `T` produces a value that is equal to it, i.e.,
+/// that `x.clone() == x` holds for every `x`.
+///
+/// The check relies on the `PartialEq` implementation of `T` to compare the values, so it is only
+/// meaningful if `PartialEq` compares all the state that `Clone` should copy. For instance, a
+/// derived `PartialEq` compares every field.
+///
+/// # Example:
+///
+/// ```no_run
+/// #[derive(Clone, PartialEq, kani::Arbitrary)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[kani::proof]
+/// fn check_point_clone() {
+///     kani::check_clone_eq::<Point>();
+/// }
+/// ```
+///
+/// # Panics
+///
+/// The assertion fails if a clone of some value is not equal to it.
+pub fn check_clone_eq<T: Clone + PartialEq + Arbitrary>() {
+    let value: T = any();
+    let clone = value.clone();
+    crate::assert(clone == value, "clone is equal to the original value");
+}
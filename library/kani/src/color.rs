@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic colors, useful to verify image processing code and
+//! color space conversions.
+
+use crate::{any, any_where};
+
+/// Generates a symbolic RGB color `(r, g, b)` where each component is in `0..=255`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (r, g, b) = kani::any_color_rgb();
+/// let gray = (r as u16 + g as u16 + b as u16) / 3;
+/// assert!(gray <= 255);
+/// ```
+pub fn any_color_rgb() -> (u8, u8, u8) {
+    (any(), any(), any())
+}
+
+/// Generates a symbolic HSV color `(h, s, v)` where the hue `h` is in `[0.0, 360.0)`,
+/// and the saturation `s` and value `v` are in `[0.0, 1.0]`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (h, s, v) = kani::any_color_hsv();
+/// assert!(h >= 0.0 && h < 360.0);
+/// ```
+pub fn any_color_hsv() -> (f32, f32, f32) {
+    let h: f32 = any_where(|h: &f32| *h >= 0.0 && *h < 360.0);
+    let s: f32 = any_where(|s: &f32| *s >= 0.0 && *s <= 1.0);
+    let v: f32 = any_where(|v: &f32| *v >= 0.0 && *v <= 1.0);
+    (h, s, v)
+}
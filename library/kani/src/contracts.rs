@@ -161,6 +161,12 @@
 //! effects and it is also currently unsound if the expression can panic. For more
 //! information see the [write sets](#write-sets) section.
 //!
+//! The [`ensures_no_panic`](macro@ensures_no_panic) attribute states that the
+//! function never panics when its preconditions hold. Any panic reachable from
+//! a [`proof_for_contract`][macro@proof_for_contract] harness of such a function
+//! is reported as a violation of this clause (property class
+//! `ensures_no_panic`), separately from the functional postconditions.
+//!
 //! During verified stubbing the return value of a function with a contract is
 //! replaced by a call to `kani::any`. As such the return value must implement
 //! the `kani::Arbitrary` trait.
@@ -275,4 +281,23 @@
 //! Here, the value stored in `a` is precomputed and remembered after the function
 //! is called, even though the contents of `a` changed during the function execution.
 //!
-pub use super::{ensures, modifies, proof_for_contract, requires, stub_verified};
+//! Parameters that are passed by value and whose type is `Copy` do not need
+//! `old`: an ensures clause that mentions them directly, including inside a
+//! macro invocation such as `assert!`, captures their value on entry
+//! automatically. This also holds for parameters declared `mut` that the
+//! function body reassigns.
+//!
+//! ```
+//! #[kani::requires(x < u32::MAX)]
+//! #[kani::ensures(|result| *result == x + 1)]
+//! fn add_one(x: u32) -> u32 {
+//!     x + 1
+//! }
+//! ```
+//!
+//! Parameters behind a reference (`&T`, `&mut T`) still refer to their current
+//! state, so `*a` above is the value after the call. Referring directly to a
+//! non-`Copy` by-value parameter is an error; use `old(..)` to say explicitly
+//! which value you mean.
+//!
+pub use super::{ensures, ensures_no_panic, modifies, proof_for_contract, requires, stub_verified};
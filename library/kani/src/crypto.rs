@@ -0,0 +1,55 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic cryptographic material, useful to verify the code that parses and
+//! checks the format of keys.
+//!
+//! The generated bytes have the length expected for the given algorithm, but they are otherwise
+//! unconstrained. They are not valid keys, so they are not suitable to verify cryptographic
+//! operations.
+
+use crate::vec::exact_vec;
+
+/// The public key algorithms supported by [`any_pub_key_bytes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyAlgorithm {
+    /// An Ed25519 public key: a 32-byte compressed curve point.
+    Ed25519,
+    /// A P-256 public key in the uncompressed SEC1 encoding: a `0x04` tag followed by the two
+    /// 32-byte coordinates.
+    P256,
+    /// The 256-byte modulus of an RSA-2048 public key.
+    RSA2048,
+}
+
+impl KeyAlgorithm {
+    /// The length in bytes of the public keys of this algorithm.
+    pub const fn pub_key_len(self) -> usize {
+        match self {
+            KeyAlgorithm::Ed25519 => 32,
+            KeyAlgorithm::P256 => 65,
+            KeyAlgorithm::RSA2048 => 256,
+        }
+    }
+}
+
+/// Generates the symbolic bytes of a public key of the given algorithm.
+///
+/// The result has exactly [`KeyAlgorithm::pub_key_len`] bytes, and every byte is symbolic,
+/// including the tag of the P-256 encoding.
+///
+/// # Example:
+///
+/// ```no_run
+/// use kani::KeyAlgorithm;
+///
+/// let key = kani::any_pub_key_bytes(KeyAlgorithm::Ed25519);
+/// assert_eq!(key.len(), 32);
+/// ```
+pub fn any_pub_key_bytes(algorithm: KeyAlgorithm) -> Vec<u8> {
+    match algorithm {
+        KeyAlgorithm::Ed25519 => exact_vec::<u8, 32>(),
+        KeyAlgorithm::P256 => exact_vec::<u8, 65>(),
+        KeyAlgorithm::RSA2048 => exact_vec::<u8, 256>(),
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for the verification of lock-ordering disciplines, which prevent deadlocks when
+//! threads acquire several mutexes.
+//!
+//! Kani doesn't verify concurrent code, so instead of running the threads, harnesses model the
+//! order in which each thread acquires the mutexes with [`any_lock_order`], and check with
+//! [`may_deadlock`] that no two orders can form a cycle.
+
+use crate::any_where;
+
+/// Generates a symbolic permutation of `0..mutex_count`, which represents the order in which a
+/// thread acquires `mutex_count` mutexes identified by their index.
+///
+/// Loops over the order require an unwinding bound of `mutex_count + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let order = kani::any_lock_order(3);
+/// assert_eq!(order.len(), 3);
+/// assert!((0..3).all(|mutex| order.contains(&mutex)));
+/// ```
+pub fn any_lock_order(mutex_count: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..mutex_count).collect();
+    // A Fisher-Yates shuffle where each swap is symbolic can produce every permutation.
+    for i in 0..mutex_count {
+        let j: usize = any_where(|j| *j >= i && *j < mutex_count);
+        order.swap(i, j);
+    }
+    order
+}
+
+/// Whether two threads that acquire the same mutexes in the given orders, and hold them until
+/// they acquired all of them, can deadlock.
+///
+/// This is the case if, and only if, the threads acquire two of the mutexes in opposite orders:
+/// each thread can then hold the mutex that the other is waiting for.
+///
+/// # Example:
+///
+/// ```no_run
+/// assert!(kani::deadlock::may_deadlock(&[0, 1], &[1, 0]));
+/// assert!(!kani::deadlock::may_deadlock(&[0, 1], &[0, 1]));
+/// ```
+pub fn may_deadlock(first: &[usize], second: &[usize]) -> bool {
+    first.iter().enumerate().any(|(i, before)| {
+        first[i + 1..].iter().any(|after| acquires_before(second, *after, *before))
+    })
+}
+
+/// Whether `order` acquires mutex `first` before mutex `second`.
+fn acquires_before(order: &[usize], first: usize, second: usize) -> bool {
+    let position = |mutex| order.iter().position(|acquired| *acquired == mutex);
+    matches!((position(first), position(second)), (Some(first), Some(second)) if first < second)
+}
@@ -0,0 +1,81 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic domain names, useful to verify DNS resolvers and hostname validation.
+//!
+//! The generated names follow the preferred name syntax of RFC 1035 (section 2.3.1): each label
+//! is made of letters, digits and hyphens, and it neither starts nor ends with a hyphen.
+
+use crate::{any_charset, any_where};
+
+/// Characters that may appear in a DNS label.
+const LABEL_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-";
+
+/// Characters that may start or end a DNS label.
+const LABEL_ENDPOINT_CHARS: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// The maximum length of a DNS label.
+pub const MAX_LABEL_LEN: usize = 63;
+
+/// The maximum length of each label generated by [`any_fqdn`].
+pub const MAX_FQDN_LABEL_LEN: usize = 8;
+
+/// Generates a symbolic DNS label of 1 to `max_len` characters.
+///
+/// The label matches `[A-Za-z0-9]([A-Za-z0-9-]*[A-Za-z0-9])?`, and `max_len` is capped at
+/// [`MAX_LABEL_LEN`]. Harnesses using this function need an unwind bound of at least
+/// `max_len + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let label = kani::any_dns_label(4);
+/// assert!(!label.is_empty() && label.len() <= 4);
+/// assert!(!label.starts_with('-') && !label.ends_with('-'));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_len` is zero.
+pub fn any_dns_label(max_len: usize) -> String {
+    assert!(max_len > 0, "`any_dns_label` requires `max_len` to be at least 1");
+    let max_len = max_len.min(MAX_LABEL_LEN);
+    let len: usize = any_where(|len| *len >= 1 && *len <= max_len);
+    let mut label = String::with_capacity(max_len);
+    for idx in 0..len {
+        let charset = if idx == 0 || idx == len - 1 { LABEL_ENDPOINT_CHARS } else { LABEL_CHARS };
+        label.push(any_charset(charset));
+    }
+    label
+}
+
+/// Generates a symbolic domain name of 1 to `max_labels` labels separated by dots.
+///
+/// Each label is generated by [`any_dns_label`] with at most [`MAX_FQDN_LABEL_LEN`] characters.
+/// The name doesn't end with the dot of the root label. Harnesses using this function need an
+/// unwind bound of at least `max(max_labels, MAX_FQDN_LABEL_LEN) + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let name = kani::any_fqdn(3);
+/// let labels = name.split('.').count();
+/// assert!(labels >= 1 && labels <= 3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_labels` is zero.
+pub fn any_fqdn(max_labels: usize) -> String {
+    assert!(max_labels > 0, "`any_fqdn` requires `max_labels` to be at least 1");
+    let num_labels: usize = any_where(|num| *num >= 1 && *num <= max_labels);
+    let mut name = String::with_capacity(max_labels * (MAX_FQDN_LABEL_LEN + 1));
+    for idx in 0..num_labels {
+        if idx > 0 {
+            name.push('.');
+        }
+        name.push_str(&any_dns_label(MAX_FQDN_LABEL_LEN));
+    }
+    name
+}
@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic [`Duration`]s within bounds, useful to model timing assumptions, e.g.,
+//! of timeouts, retry delays or refill intervals.
+
+use crate::any_where;
+use std::time::Duration;
+
+/// Generates a symbolic duration in the inclusive range `[min, max]`.
+///
+/// # Example:
+///
+/// ```no_run
+/// use std::time::Duration;
+/// let timeout = kani::any_duration_in_range(Duration::from_millis(100), Duration::from_secs(5));
+/// assert!(timeout >= Duration::from_millis(100));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min` is greater than `max`.
+pub fn any_duration_in_range(min: Duration, max: Duration) -> Duration {
+    assert!(min <= max, "`any_duration_in_range` requires `min` to be at most `max`");
+    any_where(|duration| min <= *duration && *duration <= max)
+}
+
+/// Generates a symbolic duration in the inclusive range `[center - delta, center + delta]`.
+///
+/// The bounds saturate, i.e., the range starts at [`Duration::ZERO`] if `delta` is greater than
+/// `center`, and it ends at [`Duration::MAX`] if `center + delta` overflows.
+///
+/// # Example:
+///
+/// ```no_run
+/// use std::time::Duration;
+/// // A timer that fires after one second, with a jitter of up to 10 milliseconds.
+/// let fired_after = kani::any_duration_near(Duration::from_secs(1), Duration::from_millis(10));
+/// assert!(fired_after <= Duration::from_millis(1010));
+/// ```
+pub fn any_duration_near(center: Duration, delta: Duration) -> Duration {
+    any_duration_in_range(center.saturating_sub(delta), center.saturating_add(delta))
+}
@@ -0,0 +1,100 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators and a model of the environment variables, to verify code that reads them with
+//! `std::env::var`.
+//!
+//! Kani doesn't model the environment of the process. Instead, a harness populates a model of
+//! it with [`setup_env`], and stubs the functions of `std::env` that read it with the ones in
+//! [`crate::stubs`], e.g.:
+//!
+//! ```no_run
+//! #[kani::proof]
+//! #[kani::stub(std::env::var, kani::stubs::env_var)]
+//! fn check_port() {
+//!     kani::setup_env(&[kani::any_env_var()]);
+//!     if let Ok(port) = std::env::var("PORT") {
+//!         assert!(port.is_ascii());
+//!     }
+//! }
+//! ```
+
+use crate::any_where;
+use std::ffi::OsStr;
+
+/// The maximum length of the keys generated by [`any_env_var`].
+pub const MAX_ENV_KEY_LEN: usize = 4;
+
+/// The maximum length of the values generated by [`any_env_var`].
+pub const MAX_ENV_VALUE_LEN: usize = 4;
+
+/// The model of the environment read by the stubs, populated by [`setup_env`].
+static mut ENV: Vec<(String, String)> = Vec::new();
+
+/// Generates a symbolic environment variable, i.e., a `(key, value)` pair of ASCII strings
+/// without NUL bytes.
+///
+/// The key has 1 to [`MAX_ENV_KEY_LEN`] characters and doesn't contain `=`, which separates the
+/// key from the value in the environment. The value has at most [`MAX_ENV_VALUE_LEN`]
+/// characters, and may be empty.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (key, value) = kani::any_env_var();
+/// assert!(!key.is_empty() && !key.contains('='));
+/// assert!(value.is_ascii());
+/// ```
+pub fn any_env_var() -> (String, String) {
+    let key = any_env_string(1, MAX_ENV_KEY_LEN, b'=');
+    let value = any_env_string(0, MAX_ENV_VALUE_LEN, b'\0');
+    (key, value)
+}
+
+/// Generates a symbolic ASCII string of `min_len` to `max_len` characters, without NUL bytes and
+/// without the `excluded` character.
+fn any_env_string(min_len: usize, max_len: usize, excluded: u8) -> String {
+    let len: usize = any_where(|len| *len >= min_len && *len <= max_len);
+    let mut string = String::with_capacity(max_len);
+    for _ in 0..len {
+        let byte: u8 = any_where(|byte: &u8| byte.is_ascii() && *byte != 0 && *byte != excluded);
+        string.push(byte as char);
+    }
+    string
+}
+
+/// Populates the environment with the given variables before running the code under
+/// verification. A variable overrides the previous ones with the same key, like successive calls
+/// to `std::env::set_var`.
+///
+/// During verification, the variables replace the content of the model of the environment that
+/// is read by the stubs of [`crate::stubs`], e.g., [`crate::stubs::env_var`]. During concrete
+/// playback, the stubs aren't applied, so the variables are set in the environment of the
+/// process instead, where they are also inherited by the subprocesses that it spawns.
+///
+/// # Example:
+///
+/// ```no_run
+/// kani::setup_env(&[("PORT".to_string(), kani::any_numeric_string(4))]);
+/// ```
+pub fn setup_env(vars: &[(String, String)]) {
+    #[cfg(not(feature = "concrete_playback"))]
+    {
+        // SAFETY: Kani is single threaded.
+        unsafe { ENV = vars.to_vec() };
+    }
+
+    #[cfg(feature = "concrete_playback")]
+    for (key, value) in vars {
+        // SAFETY: Each concrete playback test runs a single harness, which is expected not to
+        // read the environment from other threads while it is set up.
+        unsafe { std::env::set_var(key, value) };
+    }
+}
+
+/// Returns the value of the variable `key` in the model of the environment.
+pub(crate) fn env_lookup(key: &OsStr) -> Option<String> {
+    // SAFETY: Kani is single threaded, and the model is only modified by `setup_env`.
+    let env = unsafe { &*&raw const ENV };
+    env.iter().rev().find(|(name, _)| OsStr::new(name) == key).map(|(_, value)| value.clone())
+}
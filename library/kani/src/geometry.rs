@@ -0,0 +1,45 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic points, useful to verify computational geometry algorithms such as
+//! convex hulls, polygon intersections or point-in-polygon tests.
+
+use crate::any_where;
+use std::ops::Range;
+
+/// Generates a symbolic point `(x, y)` where `x` is in `x_range` and `y` is in `y_range`.
+///
+/// Both coordinates are finite, even if one of the ranges starts at negative infinity.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (x, y) = kani::any_2d_point(-10.0..10.0, 0.0..1.0);
+/// assert!(x >= -10.0 && x < 10.0);
+/// assert!(y >= 0.0 && y < 1.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if either range is empty, since no such point exists.
+pub fn any_2d_point(x_range: Range<f64>, y_range: Range<f64>) -> (f64, f64) {
+    assert!(!x_range.is_empty() && !y_range.is_empty(), "`any_2d_point` requires non-empty ranges");
+    let x = any_where(|x: &f64| x.is_finite() && x_range.contains(x));
+    let y = any_where(|y: &f64| y.is_finite() && y_range.contains(y));
+    (x, y)
+}
+
+/// Generates a symbolic point `(x, y)` in the unit square, i.e., with both coordinates in
+/// `[0.0, 1.0]`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (x, y) = kani::any_unit_square_point();
+/// assert!(x * y <= 1.0);
+/// ```
+pub fn any_unit_square_point() -> (f64, f64) {
+    let x = any_where(|x: &f64| 0.0 <= *x && *x <= 1.0);
+    let y = any_where(|y: &f64| 0.0 <= *y && *y <= 1.0);
+    (x, y)
+}
@@ -0,0 +1,150 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic JSON values and JSONPath queries, useful to verify JSON processing
+//! code.
+//!
+//! The Kani library cannot depend on `serde_json`, since the `serde_json::Value` type used by a
+//! crate under verification comes from that crate's own dependency graph. Thus, the generator is
+//! provided as the [`any_json_value!`](crate::any_json_value) macro, which is expanded inside the
+//! user crate and requires `serde_json` to be one of its dependencies.
+//!
+//! The hidden items in this module are used by the macro expansion and are not meant to be used
+//! directly.
+
+use crate::{any, any_ascii_identifier, any_charset, any_where};
+
+/// The maximum number of elements of a symbolic array or object, and the maximum number of
+/// characters of a symbolic string or object key.
+pub const MAX_LEN: usize = 2;
+
+/// Characters used in symbolic strings. They include characters that must be escaped.
+const STRING_CHARSET: &[u8] = b"a0 \"\\\n";
+
+/// The kind of JSON value to be generated.
+#[doc(hidden)]
+pub enum JsonKind {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+/// Pick the kind of a symbolic JSON value. Only scalars are allowed once `depth` reaches zero.
+#[doc(hidden)]
+pub fn any_json_kind(depth: usize) -> JsonKind {
+    let num_kinds = if depth == 0 { 4 } else { 6 };
+    match any_where(|kind: &u8| *kind < num_kinds) {
+        0 => JsonKind::Null,
+        1 => JsonKind::Bool,
+        2 => JsonKind::Number,
+        3 => JsonKind::String,
+        4 => JsonKind::Array,
+        _ => JsonKind::Object,
+    }
+}
+
+/// Generate the length of a symbolic array, object or string.
+#[doc(hidden)]
+pub fn any_json_len() -> usize {
+    any_where(|len| *len <= MAX_LEN)
+}
+
+/// Generate a symbolic JSON string, which is also used for object keys.
+#[doc(hidden)]
+pub fn any_json_string() -> String {
+    (0..any_json_len()).map(|_| any_charset(STRING_CHARSET)).collect()
+}
+
+/// Generate a symbolic JSON number. Only integers are generated, since floating point numbers
+/// are not guaranteed to round-trip through their textual representation.
+#[doc(hidden)]
+pub fn any_json_number() -> i32 {
+    any()
+}
+
+/// Generates a symbolic JSONPath query, such as `$.store.book[0].title`, with 1 to `max_depth`
+/// segments.
+///
+/// Each segment is either a key, `.` followed by an identifier as generated by
+/// [`any_ascii_identifier`](crate::any_ascii_identifier), or an index, `[N]`. Keys and indices
+/// are bounded by [`json::MAX_LEN`](crate::json::MAX_LEN), like the values generated by
+/// [`any_json_value!`](crate::any_json_value), so a query may select an existing element or
+/// miss it. Harnesses using this function need an unwind bound of at least
+/// `max(max_depth, json::MAX_LEN) + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let path = kani::any_json_path(2);
+/// assert!(path.starts_with('$'));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_depth` is zero.
+pub fn any_json_path(max_depth: usize) -> String {
+    assert!(max_depth > 0, "`any_json_path` requires `max_depth` to be at least 1");
+    let num_segments: usize = any_where(|num| *num >= 1 && *num <= max_depth);
+    let mut path = String::from("$");
+    for _ in 0..num_segments {
+        if any() {
+            path.push('.');
+            path.push_str(&any_ascii_identifier(MAX_LEN));
+        } else {
+            // Up to one past the last index of the largest symbolic array, which selects nothing.
+            let idx: u32 = any_where(|idx| *idx <= MAX_LEN as u32);
+            path.push('[');
+            path.push(char::from_digit(idx, 10).unwrap());
+            path.push(']');
+        }
+    }
+    path
+}
+
+/// Generates a symbolic `serde_json::Value` with a nesting depth of at most `max_depth`.
+///
+/// A value of depth 0 is a scalar, i.e., `null`, a boolean, an integer or a string.
+/// Values of greater depth may also be arrays or objects whose elements have a smaller depth.
+/// Strings, arrays and objects have at most [`json::MAX_LEN`](crate::json::MAX_LEN) elements,
+/// so harnesses using this macro need an unwind bound of at least `json::MAX_LEN + 1`, plus
+/// whatever the code processing the value requires.
+///
+/// This is a macro rather than a function because the value is built with the `serde_json`
+/// version of the crate under verification, which must list `serde_json` as a dependency.
+///
+/// # Example:
+///
+/// ```ignore
+/// let value: serde_json::Value = kani::any_json_value!(1);
+/// if let serde_json::Value::Array(elems) = &value {
+///     assert!(elems.iter().all(|elem| !elem.is_array() && !elem.is_object()));
+/// }
+/// ```
+#[macro_export]
+macro_rules! any_json_value {
+    ($max_depth:expr) => {{
+        fn any_json_value(depth: usize) -> ::serde_json::Value {
+            use $crate::json::JsonKind;
+            match $crate::json::any_json_kind(depth) {
+                JsonKind::Null => ::serde_json::Value::Null,
+                JsonKind::Bool => ::serde_json::Value::Bool($crate::any()),
+                JsonKind::Number => {
+                    ::serde_json::Value::Number($crate::json::any_json_number().into())
+                }
+                JsonKind::String => ::serde_json::Value::String($crate::json::any_json_string()),
+                JsonKind::Array => ::serde_json::Value::Array(
+                    (0..$crate::json::any_json_len()).map(|_| any_json_value(depth - 1)).collect(),
+                ),
+                JsonKind::Object => ::serde_json::Value::Object(
+                    (0..$crate::json::any_json_len())
+                        .map(|_| ($crate::json::any_json_string(), any_json_value(depth - 1)))
+                        .collect(),
+                ),
+            }
+        }
+        any_json_value($max_depth)
+    }};
+}
@@ -22,24 +22,79 @@
 #![feature(f128)]
 #![feature(convert_float_to_int)]
 #![feature(sized_hierarchy)]
+// Used to identify the locks of the standard library in their models.
+#![feature(mutex_data_ptr, rwlock_data_ptr)]
 
 // Allow us to use `kani::` to access crate features.
 extern crate self as kani;
 
+pub mod addr;
 pub mod arbitrary;
+pub mod bitmask;
 pub mod bounded_arbitrary;
+pub mod channel;
+pub mod cli;
+pub mod clone;
+pub mod color;
 #[cfg(feature = "concrete_playback")]
 mod concrete_playback;
+pub mod crypto;
+pub mod deadlock;
+pub mod dns;
+pub mod duration;
+pub mod env;
 pub mod futures;
+pub mod geometry;
 pub mod invariant;
+pub mod json;
+pub mod net;
+pub mod posix;
+pub mod probability;
+pub mod progress;
+pub mod range;
+pub mod semver;
 pub mod shadow;
+pub mod state_machine;
+pub mod strings;
+pub mod stubs;
+pub mod sync;
+pub mod timestamp;
+pub mod uuid;
 pub mod vec;
 
 mod models;
 
+pub use addr::{any_aligned_addr, any_aligned_ptr, any_page_aligned_addr, assume_aligned};
+pub use bitmask::{any_bitmask, any_bitmask_at_most};
+pub use channel::{any_channel_message, any_channel_sequence};
+pub use cli::{any_arg_starting_with, any_command_line_args};
+pub use clone::check_clone_eq;
+pub use color::{any_color_hsv, any_color_rgb};
 #[cfg(feature = "concrete_playback")]
 pub use concrete_playback::concrete_playback_run;
+pub use crypto::{KeyAlgorithm, any_pub_key_bytes};
+pub use deadlock::any_lock_order;
+pub use dns::{any_dns_label, any_fqdn};
+pub use duration::{any_duration_in_range, any_duration_near};
+pub use env::{any_env_var, setup_env};
+pub use geometry::{any_2d_point, any_unit_square_point};
 pub use invariant::Invariant;
+pub use json::any_json_path;
+pub use net::{
+    Ipv6Scope, any_ipv6_addr, any_ipv6_addr_in_scope, any_socket_addr, any_socket_message,
+};
+pub use posix::{FdState, any_error_code, any_file_descriptor, any_successful_fd};
+pub use probability::{any_probability, any_probability_f32};
+pub use range::{any_sorted_pair, any_strict_sorted_pair};
+pub use semver::{any_semver, any_semver_range};
+pub use state_machine::any_state_machine_trace;
+pub use strings::{
+    any_ascii_identifier, any_base64_string, any_charset, any_cstr, any_cstr_or_null, any_csv_row,
+    any_float_string, any_hex_string, any_numeric_string, any_signed_numeric_string,
+    any_utf8_bytes, any_word_string,
+};
+pub use timestamp::{any_unix_timestamp, any_unix_timestamp_around_boundary};
+pub use uuid::{any_uuid, any_uuid_v4};
 
 #[cfg(not(feature = "concrete_playback"))]
 /// NOP `concrete_playback` for type checking during verification mode.
@@ -75,6 +130,50 @@ macro_rules! cover {
     };
 }
 
+/// Assert that a loop makes progress, i.e., that the given measure strictly decreases from one
+/// iteration to the next.
+///
+/// Place this macro inside the body of a loop that should terminate. The measure can be any
+/// expression of a primitive integer type that is bounded from below, such as the number of
+/// remaining retries. The first iteration only records the measure, and every following
+/// iteration checks that the measure is smaller than the one of the previous iteration.
+///
+/// This turns a loop that doesn't terminate, e.g. a livelock in a state machine, into a
+/// property failure, instead of an unwinding assertion failure that cannot be distinguished
+/// from an insufficient unwinding bound.
+///
+/// The measure of the previous iteration is stored in a hidden variable that is specific to
+/// each use of this macro. Thus, this macro should not be used in a loop that is entered more
+/// than once, such as an inner loop, since the first iteration of a new execution of the loop
+/// would be compared with the last iteration of the previous one.
+///
+/// # Example:
+///
+/// ```no_run
+/// let mut retries: u8 = kani::any();
+/// loop {
+///     kani::assert_progress!(retries);
+///     if retries == 0 {
+///         break;
+///     }
+///     retries -= 1;
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_progress {
+    ($measure:expr $(,)?) => {{
+        static mut PREVIOUS_MEASURE: Option<i128> = None;
+        // SAFETY: Each use of the macro has its own variable, which is only accessed here.
+        unsafe {
+            $crate::progress::check_progress(
+                &raw mut PREVIOUS_MEASURE,
+                ($measure) as i128,
+                concat!("progress measure must strictly decrease: ", stringify!($measure)),
+            )
+        }
+    }};
+}
+
 /// `implies!(premise => conclusion)` means that if the `premise` is true, so
 /// must be the `conclusion`.
 ///
@@ -5,6 +5,9 @@
 //!
 //! Note that these are models that Kani uses by default; thus, we keep them separate from stubs.
 
+#[allow(dead_code)]
+pub(crate) mod sync;
+
 // Definitions in this module are not meant to be visible to the end user, only the compiler.
 #[allow(dead_code)]
 mod intrinsics {
@@ -0,0 +1,226 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Models of the locks of the standard library for single-threaded harnesses.
+//!
+//! Kani replaces the calls to the public API of `Mutex` and `RwLock` by the models below, and
+//! the calls to the system mutex that implements `Mutex` on each platform by the `sys_mutex_*`
+//! models. The models keep their own state:
+//!  - The system mutexes that the harness thread holds. A lock is always acquired immediately,
+//!    unless the harness thread already holds it, which would block forever. This is reported
+//!    as a deadlock.
+//!  - The poisoned locks. The standard library is compiled with `panic=abort` for verification,
+//!    which removes its poisoning logic, and a panic ends the verification path instead of
+//!    unwinding, so locks are only poisoned by [`crate::sync::drop_while_panicking`]. A poisoned
+//!    lock is identified by the address of its data, so it must not be moved. The compiler
+//!    passes the original address of the lock to the models of `into_inner`, which take the lock
+//!    by value.
+
+use std::cell::UnsafeCell;
+use std::ptr;
+use std::sync::{
+    LockResult, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    TryLockError, TryLockResult,
+};
+
+/// The maximum number of system mutexes that the harness thread holds at the same time, and of
+/// poisoned locks.
+const MAX_LOCKS: usize = 8;
+
+/// A set of locks, identified by an address.
+struct LockSet(UnsafeCell<[*const u8; MAX_LOCKS]>);
+
+// SAFETY: Harnesses run a single thread.
+unsafe impl Sync for LockSet {}
+
+impl LockSet {
+    const fn new() -> Self {
+        LockSet(UnsafeCell::new([ptr::null(); MAX_LOCKS]))
+    }
+
+    fn contains(&self, lock: *const u8) -> bool {
+        // SAFETY: Harnesses run a single thread, and no reference to the set outlives a call.
+        let locks = unsafe { &*self.0.get() };
+        locks.contains(&lock)
+    }
+
+    fn insert(&self, lock: *const u8) {
+        if self.contains(lock) {
+            return;
+        }
+        // SAFETY: Harnesses run a single thread, and no reference to the set outlives a call.
+        let locks = unsafe { &mut *self.0.get() };
+        match locks.iter_mut().find(|slot| slot.is_null()) {
+            Some(slot) => *slot = lock,
+            None => crate::unsupported("Kani supports at most 8 locks held or poisoned at once"),
+        }
+    }
+
+    fn remove(&self, lock: *const u8) {
+        // SAFETY: Harnesses run a single thread, and no reference to the set outlives a call.
+        let locks = unsafe { &mut *self.0.get() };
+        if let Some(slot) = locks.iter_mut().find(|slot| **slot == lock) {
+            *slot = ptr::null();
+        }
+    }
+}
+
+/// The system mutexes that the harness thread holds.
+static HELD: LockSet = LockSet::new();
+
+/// The poisoned locks, identified by the address of their data.
+static POISONED: LockSet = LockSet::new();
+
+/// Poison the lock of the given data, see [`crate::sync::drop_while_panicking`].
+pub(crate) fn poison(data: *const u8) {
+    POISONED.insert(data);
+}
+
+/// Report that the harness thread waits for a lock that it already holds, which never returns.
+///
+/// The message must be a string literal.
+#[kanitool::fn_marker = "DeadlockHook"]
+#[inline(never)]
+fn deadlock(_msg: &'static str) -> ! {
+    crate::kani_intrinsic()
+}
+
+/// Wrap `value` in a `PoisonError` if the lock of `data` is poisoned.
+fn check_poison<V>(data: *const u8, value: V) -> LockResult<V> {
+    if POISONED.contains(data) { Err(PoisonError::new(value)) } else { Ok(value) }
+}
+
+/// The address of the data of a lock that was moved from `original` to `lock`.
+fn moved_data<L, T>(lock: &L, data: *mut T, original: *const L) -> *const u8 {
+    // SAFETY: `data` points inside of `lock`.
+    let offset = unsafe { (data as *const u8).offset_from(lock as *const L as *const u8) };
+    original.cast::<u8>().wrapping_offset(offset)
+}
+
+/// Turn the result of a `try_*` function into the one of the blocking function, or `None` if the
+/// blocking function would wait forever.
+fn wait<G>(data: *const u8, result: TryLockResult<G>) -> Option<LockResult<G>> {
+    match result {
+        Ok(guard) => Some(check_poison(data, guard)),
+        Err(TryLockError::Poisoned(error)) => Some(check_poison(data, error.into_inner())),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+/// Add the poisoning of the lock of `data` to the result of a `try_*` function.
+fn try_wait<G>(data: *const u8, result: TryLockResult<G>) -> TryLockResult<G> {
+    let guard = match result {
+        Ok(guard) => guard,
+        Err(TryLockError::Poisoned(error)) => error.into_inner(),
+        Err(TryLockError::WouldBlock) => return Err(TryLockError::WouldBlock),
+    };
+    check_poison(data, guard).map_err(TryLockError::Poisoned)
+}
+
+#[kanitool::fn_marker = "MutexLockModel"]
+pub fn mutex_lock<T: ?Sized>(mutex: &Mutex<T>) -> LockResult<MutexGuard<'_, T>> {
+    wait(mutex.data_ptr() as *const u8, mutex.try_lock()).unwrap_or_else(|| {
+        deadlock("deadlock (relock): the mutex is already locked by the harness thread")
+    })
+}
+
+#[kanitool::fn_marker = "MutexTryLockModel"]
+pub fn mutex_try_lock<T: ?Sized>(mutex: &Mutex<T>) -> TryLockResult<MutexGuard<'_, T>> {
+    try_wait(mutex.data_ptr() as *const u8, mutex.try_lock())
+}
+
+#[kanitool::fn_marker = "MutexIsPoisonedModel"]
+pub fn mutex_is_poisoned<T: ?Sized>(mutex: &Mutex<T>) -> bool {
+    POISONED.contains(mutex.data_ptr() as *const u8)
+}
+
+#[kanitool::fn_marker = "MutexClearPoisonModel"]
+pub fn mutex_clear_poison<T: ?Sized>(mutex: &Mutex<T>) {
+    POISONED.remove(mutex.data_ptr() as *const u8)
+}
+
+#[kanitool::fn_marker = "MutexIntoInnerModel"]
+pub fn mutex_into_inner<T>(mutex: Mutex<T>, original: *const Mutex<T>) -> LockResult<T> {
+    let data = moved_data(&mutex, mutex.data_ptr(), original);
+    let result = check_poison(data, mutex.into_inner().unwrap_or_else(PoisonError::into_inner));
+    // The lock no longer exists, so another lock may later use the same address.
+    POISONED.remove(data);
+    result
+}
+
+#[kanitool::fn_marker = "MutexGetMutModel"]
+pub fn mutex_get_mut<T: ?Sized>(mutex: &mut Mutex<T>) -> LockResult<&mut T> {
+    let data = mutex.data_ptr() as *const u8;
+    check_poison(data, mutex.get_mut().unwrap_or_else(PoisonError::into_inner))
+}
+
+#[kanitool::fn_marker = "RwLockReadModel"]
+pub fn rwlock_read<T: ?Sized>(lock: &RwLock<T>) -> LockResult<RwLockReadGuard<'_, T>> {
+    wait(lock.data_ptr() as *const u8, lock.try_read()).unwrap_or_else(|| {
+        deadlock("deadlock (relock): the rwlock is already write-locked by the harness thread")
+    })
+}
+
+#[kanitool::fn_marker = "RwLockTryReadModel"]
+pub fn rwlock_try_read<T: ?Sized>(lock: &RwLock<T>) -> TryLockResult<RwLockReadGuard<'_, T>> {
+    try_wait(lock.data_ptr() as *const u8, lock.try_read())
+}
+
+#[kanitool::fn_marker = "RwLockWriteModel"]
+pub fn rwlock_write<T: ?Sized>(lock: &RwLock<T>) -> LockResult<RwLockWriteGuard<'_, T>> {
+    wait(lock.data_ptr() as *const u8, lock.try_write()).unwrap_or_else(|| {
+        deadlock("deadlock (relock): the rwlock is already locked by the harness thread")
+    })
+}
+
+#[kanitool::fn_marker = "RwLockTryWriteModel"]
+pub fn rwlock_try_write<T: ?Sized>(lock: &RwLock<T>) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+    try_wait(lock.data_ptr() as *const u8, lock.try_write())
+}
+
+#[kanitool::fn_marker = "RwLockIsPoisonedModel"]
+pub fn rwlock_is_poisoned<T: ?Sized>(lock: &RwLock<T>) -> bool {
+    POISONED.contains(lock.data_ptr() as *const u8)
+}
+
+#[kanitool::fn_marker = "RwLockClearPoisonModel"]
+pub fn rwlock_clear_poison<T: ?Sized>(lock: &RwLock<T>) {
+    POISONED.remove(lock.data_ptr() as *const u8)
+}
+
+#[kanitool::fn_marker = "RwLockIntoInnerModel"]
+pub fn rwlock_into_inner<T>(lock: RwLock<T>, original: *const RwLock<T>) -> LockResult<T> {
+    let data = moved_data(&lock, lock.data_ptr(), original);
+    let result = check_poison(data, lock.into_inner().unwrap_or_else(PoisonError::into_inner));
+    // The lock no longer exists, so another lock may later use the same address.
+    POISONED.remove(data);
+    result
+}
+
+#[kanitool::fn_marker = "RwLockGetMutModel"]
+pub fn rwlock_get_mut<T: ?Sized>(lock: &mut RwLock<T>) -> LockResult<&mut T> {
+    let data = lock.data_ptr() as *const u8;
+    check_poison(data, lock.get_mut().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Model of `lock` of the system mutex `M` of any platform.
+#[kanitool::fn_marker = "SysMutexLockModel"]
+pub fn sys_mutex_lock<M>(mutex: &M) {
+    if !sys_mutex_try_lock(mutex) {
+        deadlock("deadlock (relock): the mutex is already locked by the harness thread")
+    }
+}
+
+/// Model of `try_lock` of the system mutex `M` of any platform.
+#[kanitool::fn_marker = "SysMutexTryLockModel"]
+pub fn sys_mutex_try_lock<M>(mutex: &M) -> bool {
+    let mutex = mutex as *const M as *const u8;
+    let acquired = !HELD.contains(mutex);
+    HELD.insert(mutex);
+    acquired
+}
+
+/// Model of `unlock` of the system mutex `M` of any platform.
+#[kanitool::fn_marker = "SysMutexUnlockModel"]
+pub fn sys_mutex_unlock<M>(mutex: &M) {
+    HELD.remove(mutex as *const M as *const u8)
+}
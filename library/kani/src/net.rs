@@ -0,0 +1,104 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic network values, useful to verify socket handling code.
+
+use crate::{any, any_where};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+/// Generates a symbolic socket address, which may be either an IPv4 or an IPv6 address with
+/// any port.
+///
+/// # Example:
+///
+/// ```no_run
+/// let addr = kani::any_socket_addr();
+/// assert!(addr.is_ipv4() || addr.is_ipv6());
+/// ```
+pub fn any_socket_addr() -> SocketAddr {
+    if any() {
+        SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::from(any::<[u8; 4]>()), any()))
+    } else {
+        SocketAddr::V6(SocketAddrV6::new(any_ipv6_addr(), any(), any(), any()))
+    }
+}
+
+/// Generates a symbolic IPv6 address, which may be any of the 2^128 addresses.
+///
+/// # Example:
+///
+/// ```no_run
+/// let addr = kani::any_ipv6_addr();
+/// kani::cover!(addr.is_loopback());
+/// ```
+pub fn any_ipv6_addr() -> Ipv6Addr {
+    Ipv6Addr::from(any::<u128>())
+}
+
+/// The scope of an IPv6 address, as determined by its prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ipv6Scope {
+    /// The loopback address `::1`.
+    Loopback,
+    /// The unicast link-local addresses `fe80::/10`.
+    LinkLocal,
+    /// The global unicast addresses `2000::/3`.
+    Global,
+    /// The multicast addresses `ff00::/8`.
+    Multicast,
+}
+
+impl Ipv6Scope {
+    /// The prefix of the addresses in this scope, and its length in bits.
+    fn prefix(self) -> (u128, u32) {
+        match self {
+            Ipv6Scope::Loopback => (1, 128),
+            Ipv6Scope::LinkLocal => (0xfe80 << 112, 10),
+            Ipv6Scope::Global => (0x2000 << 112, 3),
+            Ipv6Scope::Multicast => (0xff00 << 112, 8),
+        }
+    }
+
+    /// Whether `addr` belongs to this scope.
+    pub fn contains(self, addr: &Ipv6Addr) -> bool {
+        let (prefix, prefix_len) = self.prefix();
+        let mask = u128::MAX.checked_shl(128 - prefix_len).unwrap_or(0);
+        u128::from(*addr) & mask == prefix
+    }
+}
+
+/// Generates a symbolic IPv6 address in the given scope, i.e., an address that starts with the
+/// prefix of the scope and has symbolic bits after it.
+///
+/// # Example:
+///
+/// ```no_run
+/// let addr = kani::any_ipv6_addr_in_scope(kani::Ipv6Scope::Multicast);
+/// assert!(addr.is_multicast());
+/// ```
+pub fn any_ipv6_addr_in_scope(scope: Ipv6Scope) -> Ipv6Addr {
+    let (prefix, prefix_len) = scope.prefix();
+    let host_mask = u128::MAX.checked_shr(prefix_len).unwrap_or(0);
+    Ipv6Addr::from(prefix | (u128::from(any_ipv6_addr()) & host_mask))
+}
+
+/// Generates a symbolic datagram, i.e., a source address together with a payload of at most
+/// `max_payload` bytes.
+///
+/// The payload length is symbolic, so harnesses using this function need an unwind bound of at
+/// least `max_payload + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (src, payload) = kani::any_socket_message(8);
+/// assert!(payload.len() <= 8);
+/// ```
+pub fn any_socket_message(max_payload: usize) -> (SocketAddr, Vec<u8>) {
+    let len: usize = any_where(|len| *len <= max_payload);
+    let mut payload = Vec::with_capacity(max_payload);
+    for _ in 0..len {
+        payload.push(any());
+    }
+    (any_socket_addr(), payload)
+}
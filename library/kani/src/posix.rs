@@ -0,0 +1,134 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic values returned by POSIX system calls, useful to verify code that
+//! wraps `libc` functions.
+
+use crate::any_where;
+
+/// Error codes that have the same value on all the platforms supported by Kani.
+const COMMON_ERROR_CODES: [i32; 33] = [
+    1,  // EPERM
+    2,  // ENOENT
+    3,  // ESRCH
+    4,  // EINTR
+    5,  // EIO
+    6,  // ENXIO
+    7,  // E2BIG
+    8,  // ENOEXEC
+    9,  // EBADF
+    10, // ECHILD
+    12, // ENOMEM
+    13, // EACCES
+    14, // EFAULT
+    15, // ENOTBLK
+    16, // EBUSY
+    17, // EEXIST
+    18, // EXDEV
+    19, // ENODEV
+    20, // ENOTDIR
+    21, // EISDIR
+    22, // EINVAL
+    23, // ENFILE
+    24, // EMFILE
+    25, // ENOTTY
+    26, // ETXTBSY
+    27, // EFBIG
+    28, // ENOSPC
+    29, // ESPIPE
+    30, // EROFS
+    31, // EMLINK
+    32, // EPIPE
+    33, // EDOM
+    34, // ERANGE
+];
+
+/// `EAGAIN`, which is also used for `EWOULDBLOCK`.
+#[cfg(target_os = "macos")]
+const EAGAIN: i32 = 35;
+#[cfg(not(target_os = "macos"))]
+const EAGAIN: i32 = 11;
+
+/// The lowest file descriptor that isn't a standard stream.
+const MIN_FD: i32 = 3;
+
+/// The highest file descriptor under the default limit of open files (`ulimit -n`).
+const MAX_FD: i32 = 1023;
+
+/// Generates a symbolic `errno` value, which is one of the error codes defined by POSIX, such as
+/// `EPERM`, `ENOENT` or `EINVAL`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let err = std::io::Error::from_raw_os_error(kani::any_error_code());
+/// assert!(err.raw_os_error().unwrap() > 0);
+/// ```
+pub fn any_error_code() -> i32 {
+    let idx: usize = any_where(|idx| *idx <= COMMON_ERROR_CODES.len());
+    if idx == COMMON_ERROR_CODES.len() { EAGAIN } else { COMMON_ERROR_CODES[idx] }
+}
+
+/// The state of a file descriptor generated by [any_file_descriptor].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FdState {
+    /// An open file descriptor, in `3..=1023`, which excludes the standard streams.
+    Valid,
+    /// A file descriptor that cannot be open, since it is above the default limit of open files.
+    /// System calls fail with `EBADF` for these descriptors.
+    Closed,
+    /// A negative number, which is never a file descriptor. System calls also fail with `EBADF`
+    /// for these numbers.
+    BadNumber,
+}
+
+impl FdState {
+    /// The state of `fd`, for stubs of system calls that need to decide whether to fail with
+    /// `EBADF`. The standard streams are considered valid.
+    ///
+    /// Since the descriptors are only symbolic values, this doesn't check whether `fd` is
+    /// actually open.
+    pub fn of(fd: i32) -> FdState {
+        if fd < 0 {
+            FdState::BadNumber
+        } else if fd > MAX_FD {
+            FdState::Closed
+        } else {
+            FdState::Valid
+        }
+    }
+}
+
+/// Generates a symbolic file descriptor in the given state, to verify code that calls stubbed
+/// system calls such as `read`, `write` or `close`.
+///
+/// No file descriptor is actually opened. Stubs of the system calls can use [FdState::of] to find
+/// the state of the descriptors they receive.
+///
+/// # Example:
+///
+/// ```no_run
+/// use kani::FdState;
+/// let fd = kani::any_file_descriptor(FdState::Closed);
+/// assert_eq!(FdState::of(fd), FdState::Closed);
+/// ```
+pub fn any_file_descriptor(state: FdState) -> i32 {
+    match state {
+        FdState::Valid => any_where(|fd| (MIN_FD..=MAX_FD).contains(fd)),
+        FdState::Closed => any_where(|fd| *fd > MAX_FD),
+        FdState::BadNumber => any_where(|fd| *fd < 0),
+    }
+}
+
+/// Generates a symbolic file descriptor that may be returned by a successful call to `open`,
+/// i.e., a value in `3..=1023`, which excludes the standard streams.
+///
+/// # Example:
+///
+/// ```no_run
+/// let fd = kani::any_successful_fd();
+/// assert!(fd > 2);
+/// ```
+pub fn any_successful_fd() -> i32 {
+    any_file_descriptor(FdState::Valid)
+}
@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for probabilities, useful to verify randomized algorithms such as randomized
+//! quicksort, Bloom filters, or Monte Carlo methods.
+
+use crate::any_where;
+
+/// Generates a symbolic probability, i.e., a finite `f64` in `[0.0, 1.0]`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let p = kani::any_probability();
+/// assert!((1.0 - p).is_finite() && 1.0 - p >= 0.0);
+/// ```
+pub fn any_probability() -> f64 {
+    // NaN fails both comparisons, and the infinities are out of range.
+    any_where(|p: &f64| 0.0 <= *p && *p <= 1.0)
+}
+
+/// Generates a symbolic single precision probability, i.e., a finite `f32` in `[0.0, 1.0]`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let p = kani::any_probability_f32();
+/// assert!(p * p <= p);
+/// ```
+pub fn any_probability_f32() -> f32 {
+    any_where(|p: &f32| 0.0 <= *p && *p <= 1.0)
+}
@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Support for [`assert_progress`](crate::assert_progress), which checks that a loop makes
+//! progress towards its termination.
+
+/// Check that `current` is strictly smaller than the measure of the previous iteration, if any,
+/// and record `current` as the measure of this iteration.
+///
+/// This is an implementation detail of [`assert_progress`](crate::assert_progress).
+///
+/// # Safety
+///
+/// `previous` must be valid for reads and writes.
+#[doc(hidden)]
+pub unsafe fn check_progress(previous: *mut Option<i128>, current: i128, msg: &'static str) {
+    let previous_measure = unsafe { (*previous).replace(current) };
+    if let Some(previous_measure) = previous_measure {
+        // The values of both measures are part of the counterexample trace. In concrete playback,
+        // we also include them in the panic message.
+        #[cfg(feature = "concrete_playback")]
+        assert!(
+            current < previous_measure,
+            "{msg}: the measure went from {previous_measure} to {current}"
+        );
+        #[cfg(not(feature = "concrete_playback"))]
+        crate::assert(current < previous_measure, msg);
+    }
+}
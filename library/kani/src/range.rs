@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for the bounds of symbolic ranges, useful to verify range queries, such as
+//! interval trees, range locks or `BETWEEN` clauses.
+
+use crate::{Arbitrary, any, assume};
+
+/// Generates a symbolic pair `(lo, hi)` with `lo <= hi`.
+///
+/// Every such pair can be generated, including the pairs where `lo == hi`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (lo, hi) = kani::any_sorted_pair::<u8>();
+/// assert!(lo <= hi);
+/// ```
+pub fn any_sorted_pair<T: Ord + Arbitrary>() -> (T, T) {
+    let first: T = any();
+    let second: T = any();
+    if first <= second { (first, second) } else { (second, first) }
+}
+
+/// Generates a symbolic pair `(lo, hi)` with `lo < hi`.
+///
+/// The harness is vacuous if `T` has a single value, such as `()`, since no such pair exists.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (lo, hi) = kani::any_strict_sorted_pair::<u8>();
+/// assert!(lo < hi);
+/// ```
+pub fn any_strict_sorted_pair<T: Ord + Arbitrary>() -> (T, T) {
+    let (lo, hi) = any_sorted_pair();
+    assume(lo != hi);
+    (lo, hi)
+}
@@ -0,0 +1,42 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic semantic versions, useful to verify code that parses or compares
+//! package versions.
+
+use crate::{any, assume};
+
+/// A semantic version as a `(major, minor, patch)` triple.
+///
+/// Tuples are compared lexicographically, which matches the semantic versioning precedence of
+/// versions without pre-release or build metadata.
+pub type Semver = (u32, u32, u32);
+
+/// Generates a symbolic `(major, minor, patch)` semantic version.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (major, minor, patch) = kani::any_semver();
+/// let version = format!("{major}.{minor}.{patch}");
+/// assert!(version.split('.').count() == 3);
+/// ```
+pub fn any_semver() -> Semver {
+    any()
+}
+
+/// Generates a symbolic range of semantic versions `(lo, hi)`, where `lo <= hi` under the
+/// semantic versioning precedence.
+///
+/// # Example:
+///
+/// ```no_run
+/// let (lo, hi) = kani::any_semver_range();
+/// assert!(lo.0 <= hi.0);
+/// ```
+pub fn any_semver_range() -> (Semver, Semver) {
+    let lo = any_semver();
+    let hi = any_semver();
+    assume(lo <= hi);
+    (lo, hi)
+}
@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for the verification of state machines, e.g., protocol implementations.
+
+use crate::{Arbitrary, any, any_where};
+
+/// Generates a symbolic sequence of inputs of a state machine with states `S` and inputs `I`.
+///
+/// The sequence has between `0` and `max_steps` elements. Combined with a symbolic initial state
+/// `kani::any::<S>()`, this allows checking that an invariant holds after every step, from any
+/// initial state. The states type is only used to convey the intent of the harness.
+///
+/// Loops over the trace require an unwinding bound of `max_steps + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// # #[derive(kani::Arbitrary)]
+/// # struct Door { open: bool }
+/// # #[derive(kani::Arbitrary)]
+/// # enum Action { Open, Close }
+/// let mut door: Door = kani::any();
+/// for action in kani::any_state_machine_trace::<Door, Action>(3) {
+///     door.open = matches!(action, Action::Open);
+/// }
+/// ```
+pub fn any_state_machine_trace<S, I>(max_steps: usize) -> Vec<I>
+where
+    S: Arbitrary,
+    I: Arbitrary,
+{
+    let len: usize = any_where(|len| *len <= max_steps);
+    let mut trace = Vec::with_capacity(len);
+    for _ in 0..len {
+        trace.push(any());
+    }
+    trace
+}
@@ -0,0 +1,394 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic strings that follow a given structure.
+//!
+//! The length of the generated strings is symbolic but bounded by the `max_len`
+//! argument, so harnesses using these functions need an unwind bound of at least
+//! `max_len + 1`.
+
+use crate::{any, any_where, assume};
+use std::ffi::CString;
+
+/// Characters that may start an ASCII identifier.
+const IDENT_START: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz_";
+
+/// Characters that may appear after the first character of an ASCII identifier.
+const IDENT_CONTINUE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_";
+
+/// Decimal digits.
+const DIGITS: &[u8] = b"0123456789";
+
+/// Characters of a word generated by [`any_word_string`].
+const WORD_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The base64 alphabet, where the index of each character is the 6-bit value it encodes.
+const BASE64_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Hexadecimal digits, in both cases.
+const HEX_CHARS: &[u8] = b"0123456789abcdefABCDEF";
+
+/// Base64 characters whose 4 low bits are zero, i.e., the last character before `==`.
+const BASE64_CHARS_BEFORE_TWO_PAD: &[u8] = b"AQgw";
+
+/// Base64 characters whose 2 low bits are zero, i.e., the last character before `=`.
+const BASE64_CHARS_BEFORE_ONE_PAD: &[u8] = b"AEIMQUYcgkosw048";
+
+/// Characters of a field generated by [`any_csv_row`]: the printable ASCII characters, which
+/// include the separator `,` and the quote `"`, and the line feed.
+const CSV_FIELD_CHARS: &[u8] = b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+    [\\]^_`abcdefghijklmnopqrstuvwxyz{|}~\n";
+
+/// The maximum length of each word generated by [`any_word_string`].
+pub const MAX_WORD_LEN: usize = 16;
+
+/// Generates a symbolic character that is one of the ASCII characters in `charset`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let digit = kani::any_charset(b"0123456789");
+/// assert!(digit.is_ascii_digit());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `charset` is empty or contains non-ASCII bytes.
+pub fn any_charset(charset: &[u8]) -> char {
+    assert!(!charset.is_empty(), "`any_charset` requires a non-empty charset");
+    assert!(charset.is_ascii(), "`any_charset` requires an ASCII charset");
+    let idx: usize = any_where(|idx| *idx < charset.len());
+    charset[idx] as char
+}
+
+/// Generates a symbolic ASCII identifier of length 1 to `max_len`.
+///
+/// The first character is in `[A-Za-z_]` and the following ones are in `[A-Za-z0-9_]`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let ident = kani::any_ascii_identifier(4);
+/// assert!(!ident.is_empty() && ident.len() <= 4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_len` is zero.
+pub fn any_ascii_identifier(max_len: usize) -> String {
+    assert!(max_len > 0, "`any_ascii_identifier` requires `max_len` to be at least 1");
+    let mut ident = String::with_capacity(max_len);
+    push_any_token(&mut ident, max_len, IDENT_START, IDENT_CONTINUE);
+    ident
+}
+
+/// Generates a symbolic string of 1 to `max_words` words separated by a single space.
+///
+/// Each word is 1 to [`MAX_WORD_LEN`] characters in `[A-Za-z]`, so the length of the string is
+/// at most `max_words * (MAX_WORD_LEN + 1) - 1`. Harnesses using this function need an unwind
+/// bound of at least `max(max_words, MAX_WORD_LEN) + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let text = kani::any_word_string(3);
+/// let words = text.split(' ').count();
+/// assert!(words >= 1 && words <= 3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_words` is zero.
+pub fn any_word_string(max_words: usize) -> String {
+    assert!(max_words > 0, "`any_word_string` requires `max_words` to be at least 1");
+    let num_words: usize = any_where(|num| *num >= 1 && *num <= max_words);
+    let mut text = String::with_capacity(max_words * (MAX_WORD_LEN + 1));
+    for idx in 0..num_words {
+        if idx > 0 {
+            text.push(' ');
+        }
+        push_any_token(&mut text, MAX_WORD_LEN, WORD_CHARS, WORD_CHARS);
+    }
+    text
+}
+
+/// Generates a symbolic base64 string (standard alphabet, with padding) that encodes a payload of
+/// at most `max_decoded_len` bytes.
+///
+/// The string is canonical: its length is a multiple of 4, and the unused bits of the last
+/// character before the padding are zero, so decoding and re-encoding it yields the same string.
+/// Harnesses using this function need an unwind bound of at least `max_decoded_len / 3 + 2`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let encoded = kani::any_base64_string(6);
+/// assert!(encoded.len() % 4 == 0 && encoded.len() <= 8);
+/// ```
+pub fn any_base64_string(max_decoded_len: usize) -> String {
+    let decoded_len: usize = any_where(|len| *len <= max_decoded_len);
+    let mut encoded = String::with_capacity(max_decoded_len.div_ceil(3) * 4);
+    for _ in 0..decoded_len / 3 {
+        for _ in 0..4 {
+            encoded.push(any_charset(BASE64_CHARS));
+        }
+    }
+    match decoded_len % 3 {
+        1 => {
+            encoded.push(any_charset(BASE64_CHARS));
+            encoded.push(any_charset(BASE64_CHARS_BEFORE_TWO_PAD));
+            encoded.push_str("==");
+        }
+        2 => {
+            encoded.push(any_charset(BASE64_CHARS));
+            encoded.push(any_charset(BASE64_CHARS));
+            encoded.push(any_charset(BASE64_CHARS_BEFORE_ONE_PAD));
+            encoded.push('=');
+        }
+        _ => {}
+    }
+    encoded
+}
+
+/// Generates a symbolic string of 0 to `max_pairs` pairs of hexadecimal digits, i.e., matching
+/// `([0-9a-fA-F]{2}){0,max_pairs}`.
+///
+/// Each pair encodes one byte, so the string has an even length of at most `2 * max_pairs`.
+/// Harnesses that loop over the digits of the string need an unwind bound of at least
+/// `2 * max_pairs + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let hex = kani::any_hex_string(4);
+/// assert!(hex.len() % 2 == 0 && hex.len() <= 8);
+/// ```
+pub fn any_hex_string(max_pairs: usize) -> String {
+    let pairs: usize = any_where(|pairs| *pairs <= max_pairs);
+    let mut hex = String::with_capacity(2 * max_pairs);
+    for _ in 0..pairs {
+        hex.push(any_charset(HEX_CHARS));
+        hex.push(any_charset(HEX_CHARS));
+    }
+    hex
+}
+
+/// Generates a symbolic string of 1 to `max_digits` decimal digits, i.e., matching
+/// `[0-9]{1,max_digits}`.
+///
+/// Leading zeros are allowed, so a string of up to 18 digits always fits in an `i64`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let number = kani::any_numeric_string(4);
+/// assert!(number.parse::<u16>().unwrap() <= 9999);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_digits` is zero.
+pub fn any_numeric_string(max_digits: usize) -> String {
+    assert!(max_digits > 0, "`any_numeric_string` requires `max_digits` to be at least 1");
+    let mut number = String::with_capacity(max_digits);
+    push_any_token(&mut number, max_digits, DIGITS, DIGITS);
+    number
+}
+
+/// Generates a symbolic string of 1 to `max_digits` decimal digits with an optional leading `-`,
+/// i.e., matching `-?[0-9]{1,max_digits}`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let number = kani::any_signed_numeric_string(4);
+/// assert!(number.parse::<i16>().is_ok());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_digits` is zero.
+pub fn any_signed_numeric_string(max_digits: usize) -> String {
+    assert!(max_digits > 0, "`any_signed_numeric_string` requires `max_digits` to be at least 1");
+    let mut number = String::with_capacity(max_digits + 1);
+    if any() {
+        number.push('-');
+    }
+    push_any_token(&mut number, max_digits, DIGITS, DIGITS);
+    number
+}
+
+/// Generates a symbolic decimal number with a fractional part, i.e., matching
+/// `[0-9]+\.[0-9]+`, with at most `max_digits` digits in total.
+///
+/// # Example:
+///
+/// ```no_run
+/// let number = kani::any_float_string(4);
+/// assert!(number.parse::<f32>().is_ok());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_digits` is less than 2.
+pub fn any_float_string(max_digits: usize) -> String {
+    assert!(max_digits >= 2, "`any_float_string` requires `max_digits` to be at least 2");
+    let int_digits: usize = any_where(|len| *len >= 1 && *len < max_digits);
+    let mut number = String::with_capacity(max_digits + 1);
+    push_any_token(&mut number, int_digits, DIGITS, DIGITS);
+    number.push('.');
+    push_any_token(&mut number, max_digits - int_digits, DIGITS, DIGITS);
+    number
+}
+
+/// Generates a symbolic CSV row (RFC 4180) of 1 to `max_fields` fields, each of at most
+/// `max_field_len` characters.
+///
+/// The characters of a field are printable ASCII characters or line feeds. A field is quoted if
+/// and only if it contains a `,`, a `"` or a line feed, in which case its quotes are doubled.
+/// The row doesn't end with a line break. Harnesses using this function need an unwind bound of
+/// at least `max(max_fields, max_field_len) + 1`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let row = kani::any_csv_row(3, 8);
+/// assert!(row.split(',').count() >= 1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_fields` is zero.
+pub fn any_csv_row(max_fields: usize, max_field_len: usize) -> String {
+    assert!(max_fields > 0, "`any_csv_row` requires `max_fields` to be at least 1");
+    let num_fields: usize = any_where(|num| *num >= 1 && *num <= max_fields);
+    let mut row = String::with_capacity(max_fields * (2 * max_field_len + 3));
+    let mut field = String::with_capacity(2 * max_field_len);
+    for idx in 0..num_fields {
+        if idx > 0 {
+            row.push(',');
+        }
+        let len: usize = any_where(|len| *len <= max_field_len);
+        let mut quoted = false;
+        field.clear();
+        for _ in 0..len {
+            let c = any_charset(CSV_FIELD_CHARS);
+            quoted |= matches!(c, ',' | '"' | '\n');
+            if c == '"' {
+                field.push('"');
+            }
+            field.push(c);
+        }
+        if quoted {
+            row.push('"');
+            row.push_str(&field);
+            row.push('"');
+        } else {
+            row.push_str(&field);
+        }
+    }
+    row
+}
+
+/// Appends 1 to `max_len` symbolic characters to `buf`, the first one from `start` and the
+/// following ones from `continue_`.
+fn push_any_token(buf: &mut String, max_len: usize, start: &[u8], continue_: &[u8]) {
+    let len: usize = any_where(|len| *len >= 1 && *len <= max_len);
+    buf.push(any_charset(start));
+    for _ in 1..len {
+        buf.push(any_charset(continue_));
+    }
+}
+
+/// Generates a symbolic sequence of at most `max_len` bytes that is valid UTF-8.
+///
+/// Unlike a `String`, this gives direct access to the encoded bytes, which is useful to verify
+/// code that processes UTF-8 at the byte level, such as a streaming validator.
+/// The bytes follow the UTF-8 encoding rules: the leading byte of each character determines
+/// how many continuation bytes (`0x80..=0xBF`) follow, and overlong encodings, surrogates and
+/// code points above `U+10FFFF` are excluded.
+///
+/// # Example:
+///
+/// ```no_run
+/// let bytes = kani::any_utf8_bytes(4);
+/// assert!(std::str::from_utf8(&bytes).is_ok());
+/// ```
+pub fn any_utf8_bytes(max_len: usize) -> Vec<u8> {
+    let len: usize = any_where(|len| *len <= max_len);
+    let mut bytes = Vec::with_capacity(max_len);
+    while bytes.len() < len {
+        let remaining = len - bytes.len();
+        let width: usize = any_where(|width| *width >= 1 && *width <= 4 && *width <= remaining);
+        let lead: u8 = any();
+        // The valid range of the second byte depends on the leading byte to exclude
+        // overlong encodings, surrogates and code points above `U+10FFFF`.
+        let (second_min, second_max) = match width {
+            1 => {
+                assume(lead <= 0x7F);
+                bytes.push(lead);
+                continue;
+            }
+            2 => {
+                assume((0xC2..=0xDF).contains(&lead));
+                (0x80, 0xBF)
+            }
+            3 => {
+                assume((0xE0..=0xEF).contains(&lead));
+                match lead {
+                    0xE0 => (0xA0, 0xBF),
+                    0xED => (0x80, 0x9F),
+                    _ => (0x80, 0xBF),
+                }
+            }
+            _ => {
+                assume((0xF0..=0xF4).contains(&lead));
+                match lead {
+                    0xF0 => (0x90, 0xBF),
+                    0xF4 => (0x80, 0x8F),
+                    _ => (0x80, 0xBF),
+                }
+            }
+        };
+        bytes.push(lead);
+        bytes.push(any_where(|byte: &u8| *byte >= second_min && *byte <= second_max));
+        for _ in 2..width {
+            bytes.push(any_where(|byte: &u8| (0x80..=0xBF).contains(byte)));
+        }
+    }
+    bytes
+}
+
+/// Generates a symbolic C string of at most `max_len` bytes, excluding the NUL terminator.
+///
+/// The bytes can have any non-zero value, so the string can be passed to C functions that
+/// expect a NUL-terminated string, e.g., through [`CString::as_ptr`].
+///
+/// # Example:
+///
+/// ```no_run
+/// let cstr = kani::any_cstr(4);
+/// assert!(cstr.as_bytes().len() <= 4);
+/// assert!(!cstr.as_bytes().contains(&0));
+/// ```
+pub fn any_cstr(max_len: usize) -> CString {
+    let len: usize = any_where(|len| *len <= max_len);
+    let mut bytes = Vec::with_capacity(len + 1);
+    for _ in 0..len {
+        bytes.push(any_where(|byte: &u8| *byte != 0));
+    }
+    CString::new(bytes).expect("the bytes of `any_cstr` cannot contain NUL")
+}
+
+/// Generates either `None`, which represents a null pointer, or a symbolic C string of at most
+/// `max_len` bytes as generated by [`any_cstr`].
+///
+/// # Example:
+///
+/// ```no_run
+/// let cstr = kani::any_cstr_or_null(4);
+/// let ptr = cstr.as_ref().map_or(std::ptr::null(), |cstr| cstr.as_ptr());
+/// ```
+pub fn any_cstr_or_null(max_len: usize) -> Option<CString> {
+    if any() { Some(any_cstr(max_len)) } else { None }
+}
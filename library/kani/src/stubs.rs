@@ -0,0 +1,104 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Stubs for standard library functions whose result is non-deterministic, to be used with the
+//! `#[kani::stub(...)]` attribute, e.g.:
+//!
+//! ```no_run
+//! #[kani::proof]
+//! #[kani::stub(std::time::Instant::now, kani::stubs::instant_now)]
+//! fn check_timeout() {
+//!     let start = std::time::Instant::now();
+//!     assert!(start.elapsed() >= std::time::Duration::ZERO);
+//! }
+//! ```
+//!
+//! Each stub documents the assumptions that it makes about the function that it replaces.
+//!
+//! Stubs for the `rand` crate, e.g., `rand::thread_rng`, are not provided since Kani doesn't
+//! depend on it. See the stubbing documentation for an example of how to stub `rand::random`.
+
+use crate::env::env_lookup;
+use crate::{any, any_where};
+use std::collections::hash_map::RandomState;
+use std::env::VarError;
+use std::ffi::{OsStr, OsString};
+use std::mem::transmute;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The time elapsed between an arbitrary starting point and the last call to [`instant_now`].
+static mut LAST_INSTANT: Option<Duration> = None;
+
+/// The largest number of seconds of an instant returned by [`instant_now`]. `Instant` stores the
+/// seconds as an `i64`, so a larger number of seconds would be read as a negative one.
+const MAX_INSTANT_SECS: u64 = i64::MAX as u64;
+
+/// The largest number of seconds since the Unix epoch returned by [`system_time_now`], i.e.,
+/// 2106-02-07T06:28:15Z.
+pub const MAX_SYSTEM_TIME_SECS: u64 = u32::MAX as u64;
+
+/// Stub for [`Instant::now`].
+///
+/// The first call returns an arbitrary instant, and every following call returns an arbitrary
+/// instant that is not earlier than the previous one, which is the monotonicity that the standard
+/// library guarantees. There is no upper bound on the time elapsed between two calls, other than
+/// the largest instant that `Instant` can represent.
+///
+/// `Instant` doesn't have a public constructor, so this stub relies on `Instant` having the same
+/// layout as [`Duration`], which is the case on the platforms supported by Kani. A change to the
+/// size of `Instant` is caught at compile time by `transmute`.
+pub fn instant_now() -> Instant {
+    let in_range = |now: &Duration| now.as_secs() <= MAX_INSTANT_SECS;
+    // SAFETY: Kani is single threaded.
+    let now = match unsafe { LAST_INSTANT } {
+        None => any_where(in_range),
+        Some(last) => {
+            let elapsed: Duration =
+                any_where(|elapsed| last.checked_add(*elapsed).is_some_and(|now| in_range(&now)));
+            last + elapsed
+        }
+    };
+    // SAFETY: Kani is single threaded.
+    unsafe { LAST_INSTANT = Some(now) };
+    // SAFETY: Both types store the seconds and nanoseconds of a time, see above.
+    unsafe { transmute::<Duration, Instant>(now) }
+}
+
+/// Stub for [`SystemTime::now`].
+///
+/// Returns an arbitrary time between [`UNIX_EPOCH`] and [`MAX_SYSTEM_TIME_SECS`] seconds after
+/// it. Unlike [`instant_now`], successive calls are unrelated, since the system clock may be
+/// adjusted backwards at any time.
+pub fn system_time_now() -> SystemTime {
+    let secs = any_where(|secs: &u64| *secs <= MAX_SYSTEM_TIME_SECS);
+    let nanos = any_where(|nanos: &u32| *nanos < 1_000_000_000);
+    UNIX_EPOCH + Duration::new(secs, nanos)
+}
+
+/// Stub for [`RandomState::new`].
+///
+/// Returns a hasher state whose keys are zero. This assumes that the property under verification
+/// doesn't depend on the hash of the keys, e.g., on the iteration order of a `HashMap`, which
+/// avoids the cost of hashing with symbolic keys.
+pub fn random_state_new() -> RandomState {
+    let keys: [u64; 2] = [0, 0];
+    // SAFETY: `RandomState` stores the two keys of the SipHash hasher.
+    unsafe { transmute::<[u64; 2], RandomState>(keys) }
+}
+
+/// Stub for [`std::env::var`].
+///
+/// Returns the value of `key` in the model of the environment populated by
+/// [`setup_env`](crate::setup_env), which is empty unless the harness populates it. The values of
+/// the model are strings, so this never returns [`VarError::NotUnicode`].
+pub fn env_var<K: AsRef<OsStr>>(key: K) -> Result<String, VarError> {
+    env_lookup(key.as_ref()).ok_or(VarError::NotPresent)
+}
+
+/// Stub for [`std::env::var_os`].
+///
+/// Like [`env_var`], returns the value of `key` in the model of the environment populated by
+/// [`setup_env`](crate::setup_env).
+pub fn env_var_os<K: AsRef<OsStr>>(key: K) -> Option<OsString> {
+    env_lookup(key.as_ref()).map(OsString::from)
+}
@@ -0,0 +1,76 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Poisoning of the locks of the standard library.
+//!
+//! A lock is poisoned when a panic unwinds while one of its guards is alive, so that the next
+//! users of the lock know that the data may be inconsistent. Kani ends a verification path at a
+//! panic instead of unwinding the stack, so a panic never poisons a lock during verification.
+//! Harnesses use [`drop_while_panicking`] instead to verify how code handles a poisoned lock.
+//!
+//! Kani identifies a poisoned lock by the address of its data, so a lock must not be moved after
+//! it is poisoned, except by its `into_inner` method.
+
+use std::sync::{MutexGuard, RwLockWriteGuard};
+
+/// A guard that poisons its lock if it is dropped while the thread panics.
+///
+/// Guards of a read lock of an `RwLock` don't poison it, since readers cannot leave the data in
+/// an inconsistent state.
+pub trait PoisonGuard: private::Sealed {
+    /// The address of the data that the guard protects.
+    #[doc(hidden)]
+    fn data(&self) -> *const u8;
+}
+
+impl<T: ?Sized> PoisonGuard for MutexGuard<'_, T> {
+    fn data(&self) -> *const u8 {
+        &**self as *const T as *const u8
+    }
+}
+
+impl<T: ?Sized> PoisonGuard for RwLockWriteGuard<'_, T> {
+    fn data(&self) -> *const u8 {
+        &**self as *const T as *const u8
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+    impl<T: ?Sized> Sealed for std::sync::MutexGuard<'_, T> {}
+    impl<T: ?Sized> Sealed for std::sync::RwLockWriteGuard<'_, T> {}
+}
+
+/// Drops `guard` like a panic that unwinds while the guard is alive, which releases and poisons
+/// its lock.
+///
+/// The following calls that acquire the lock return a `PoisonError`, until the poison is cleared.
+///
+/// # Example:
+///
+/// ```no_run
+/// use std::sync::Mutex;
+///
+/// let mutex = Mutex::new(0);
+/// let mut guard = mutex.lock().unwrap();
+/// *guard = 1;
+/// kani::sync::drop_while_panicking(guard);
+/// assert!(mutex.is_poisoned());
+/// assert_eq!(*mutex.lock().unwrap_err().into_inner(), 1);
+/// ```
+pub fn drop_while_panicking<G: PoisonGuard>(guard: G) {
+    #[cfg(not(feature = "concrete_playback"))]
+    {
+        crate::models::sync::poison(guard.data());
+        drop(guard);
+    }
+
+    #[cfg(feature = "concrete_playback")]
+    {
+        // `resume_unwind` doesn't invoke the panic hook, so nothing is printed.
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            let _guard = guard;
+            std::panic::resume_unwind(Box::new(()))
+        }));
+    }
+}
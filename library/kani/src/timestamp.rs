@@ -0,0 +1,72 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic Unix timestamps, i.e., the number of seconds since
+//! 1970-01-01T00:00:00Z, useful to verify date and time handling code.
+
+use crate::any_where;
+use core::ops::Range;
+
+/// Timestamps around which date and time handling code often goes wrong.
+const BOUNDARY_TIMESTAMPS: [i64; 10] = [
+    // 1900-01-01T00:00:00Z, the start of a century that isn't a leap year.
+    -2_208_988_800,
+    // 1901-12-13T20:45:52Z, the smallest timestamp that fits in an `i32`.
+    i32::MIN as i64,
+    // 1970-01-01T00:00:00Z, the Unix epoch, which separates negative and positive timestamps.
+    0,
+    // 2000-01-01T00:00:00Z, Y2K.
+    946_684_800,
+    // 2000-02-29T00:00:00Z, the leap day of a century that is a leap year.
+    951_782_400,
+    // 2024-03-10T07:00:00Z, the start of daylight saving time in the US Eastern time zone.
+    1_710_054_000,
+    // 2024-03-31T01:00:00Z, the start of summer time in the EU.
+    1_711_846_800,
+    // 2024-10-27T01:00:00Z, the end of summer time in the EU.
+    1_729_990_800,
+    // 2024-11-03T06:00:00Z, the end of daylight saving time in the US Eastern time zone.
+    1_730_613_600,
+    // 2038-01-19T03:14:07Z, Y2K38, the largest timestamp that fits in an `i32`.
+    i32::MAX as i64,
+];
+
+/// The maximum distance in seconds between a timestamp generated by
+/// [`any_unix_timestamp_around_boundary`] and the closest boundary, i.e., one day.
+pub const MAX_BOUNDARY_OFFSET: i64 = 24 * 60 * 60;
+
+/// Generates a symbolic Unix timestamp within `range`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let timestamp = kani::any_unix_timestamp(0..946_684_800);
+/// assert!(timestamp >= 0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `range` is empty.
+pub fn any_unix_timestamp(range: Range<i64>) -> i64 {
+    assert!(!range.is_empty(), "`any_unix_timestamp` requires a non-empty range");
+    any_where(|timestamp| range.contains(timestamp))
+}
+
+/// Generates a symbolic Unix timestamp that is at most [`MAX_BOUNDARY_OFFSET`] seconds away from
+/// a timestamp that is known to be problematic.
+///
+/// The boundaries are the Unix epoch, Y2K, Y2K38 and the smallest `i32` timestamp, the start of
+/// 1900 and the leap day of 2000, as well as the 2024 daylight saving time transitions in the US
+/// and the EU. This includes negative timestamps, and timestamps that don't fit in an `i32`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let timestamp = kani::any_unix_timestamp_around_boundary();
+/// kani::cover!(i32::try_from(timestamp).is_err());
+/// ```
+pub fn any_unix_timestamp_around_boundary() -> i64 {
+    let idx: usize = any_where(|idx| *idx < BOUNDARY_TIMESTAMPS.len());
+    let offset: i64 = any_where(|offset: &i64| offset.abs() <= MAX_BOUNDARY_OFFSET);
+    BOUNDARY_TIMESTAMPS[idx] + offset
+}
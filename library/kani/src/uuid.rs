@@ -0,0 +1,40 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generators for symbolic UUIDs, useful to verify code that uses UUIDs as keys
+//! or identifiers.
+
+use crate::any;
+
+/// Generates a symbolic UUID, where all 16 bytes are symbolic.
+///
+/// # Example:
+///
+/// ```no_run
+/// let key = kani::any_uuid();
+/// let mut map = std::collections::BTreeMap::new();
+/// map.insert(key, 1);
+/// assert_eq!(map.get(&key), Some(&1));
+/// ```
+pub fn any_uuid() -> [u8; 16] {
+    any()
+}
+
+/// Generates a symbolic version 4 UUID as specified by RFC 4122.
+///
+/// All bytes are symbolic, except that the version field (the high nibble of byte 6) is `4`,
+/// and the variant field (the two most significant bits of byte 8) is `10`.
+///
+/// # Example:
+///
+/// ```no_run
+/// let uuid = kani::any_uuid_v4();
+/// assert_eq!(uuid[6] >> 4, 4);
+/// assert_eq!(uuid[8] >> 6, 0b10);
+/// ```
+pub fn any_uuid_v4() -> [u8; 16] {
+    let mut uuid = any_uuid();
+    uuid[6] = (uuid[6] & 0x0f) | 0x40;
+    uuid[8] = (uuid[8] & 0x3f) | 0x80;
+    uuid
+}
@@ -3,6 +3,15 @@
 use crate::{Arbitrary, any, any_where};
 
 /// Generates an arbitrary vector whose length is at most MAX_LENGTH.
+///
+/// The capacity of the vector is equal to its length, like for [`exact_vec`].
+///
+/// # Example:
+///
+/// ```no_run
+/// let v = kani::vec::any_vec::<u8, 4>();
+/// assert!(v.len() <= 4 && v.capacity() == v.len());
+/// ```
 pub fn any_vec<T, const MAX_LENGTH: usize>() -> Vec<T>
 where
     T: Arbitrary,
@@ -22,6 +31,16 @@ pub fn any_vec<T, const MAX_LENGTH: usize>() -> Vec<T>
 }
 
 /// Generates an arbitrary vector that is exactly EXACT_LENGTH long.
+///
+/// The vector is allocated at once with a capacity of EXACT_LENGTH, so unlike a vector built by
+/// pushing symbolic values in a loop, there is no reallocation for Kani to model.
+///
+/// # Example:
+///
+/// ```no_run
+/// let v = kani::vec::exact_vec::<u8, 16>();
+/// assert!(v.len() == 16 && v.capacity() == 16);
+/// ```
 pub fn exact_vec<T, const EXACT_LENGTH: usize>() -> Vec<T>
 where
     T: Arbitrary,
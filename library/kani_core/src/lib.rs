@@ -284,6 +284,30 @@ pub fn bounded_any<T: BoundedArbitrary, const N: usize>() -> T {
             T::bounded_any::<N>()
         }
 
+        /// Collapse the symbolic value `x` into a single concrete representative, to speed up
+        /// harnesses where only some of the inputs need to remain symbolic.
+        ///
+        /// **This weakens the proof**: the harness is only verified for one value of `x`
+        /// instead of all of them. Kani lists the harnesses that use this function in the
+        /// verification summary.
+        ///
+        /// The representative is the value whose bytes are all zero, which is how the backend
+        /// initializes static variables. If `x` cannot be zero, e.g., because it's a reference or
+        /// because of a previous assumption, the rest of the harness would be unreachable, so
+        /// the verification fails instead.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let config: [u8; 64] = kani::concretize(kani::any());
+        /// let input: u8 = kani::any();
+        /// ```
+        #[inline(never)]
+        #[kanitool::fn_marker = "ConcretizeHook"]
+        pub fn concretize<T>(x: T) -> T {
+            x
+        }
+
         /// This function is only used for function contract instrumentation.
         /// It behaves exaclty like `kani::any<T>()`, except it will check for the trait bounds
         /// at compilation time. It allows us to avoid type checking errors while using function
@@ -327,6 +351,48 @@ pub fn any_where<T: Arbitrary, F: FnOnce(&T) -> bool>(f: F) -> T {
             result
         }
 
+        /// This creates a symbolic value of type `T` in the range `[lo, hi)`.
+        ///
+        /// This is a shorthand for `kani::any_where(|x| lo <= *x && *x < hi)` that also checks
+        /// that the range isn't empty, since an empty range would make the rest of the harness
+        /// vacuous.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// # use crate::kani;
+        /// let idx: usize = kani::any_in_range(0, 10);
+        /// assert!(idx < 10);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// Panics if `lo >= hi`.
+        #[inline(always)]
+        pub fn any_in_range<T: Arbitrary + PartialOrd>(lo: T, hi: T) -> T {
+            assert!(lo < hi, "`any_in_range` requires `lo < hi`");
+            any_where(|x| lo <= *x && *x < hi)
+        }
+
+        /// This creates a symbolic value of type `T` in the range `[lo, hi]`.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// # use crate::kani;
+        /// let percent: u8 = kani::any_in_range_inclusive(0, 100);
+        /// assert!(percent <= 100);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// Panics if `lo > hi`.
+        #[inline(always)]
+        pub fn any_in_range_inclusive<T: Arbitrary + PartialOrd>(lo: T, hi: T) -> T {
+            assert!(lo <= hi, "`any_in_range_inclusive` requires `lo <= hi`");
+            any_where(|x| lo <= *x && *x <= hi)
+        }
+
         /// This function creates a symbolic value of type `T`. This may result in an invalid value.
         ///
         /// # Safety
@@ -401,6 +467,46 @@ pub(crate) fn safety_check_no_assume(cond: bool, msg: &'static str) {
             assert!(cond, "Safety check failed: {msg}");
         }
 
+        /// A safety check for pointer arithmetic, e.g., that an offset stays within the bounds of
+        /// its allocation. These checks are reported as a separate property class.
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        #[kanitool::fn_marker = "PointerOffsetCheckHook"]
+        #[inline(never)]
+        pub(crate) fn pointer_offset_check(cond: bool, msg: &'static str) {
+            #[cfg(not(feature = "concrete_playback"))]
+            return kani_intrinsic();
+
+            #[cfg(feature = "concrete_playback")]
+            assert!(cond, "Pointer offset check failed: {msg}");
+        }
+
+        /// A safety check that memory is initialized before it is read. These checks are reported
+        /// as a separate property class.
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        #[kanitool::fn_marker = "UninitCheckHook"]
+        #[inline(never)]
+        pub(crate) fn uninit_check(cond: bool, msg: &'static str) {
+            #[cfg(not(feature = "concrete_playback"))]
+            return kani_intrinsic();
+
+            #[cfg(feature = "concrete_playback")]
+            assert!(cond, "Uninitialized memory check failed: {msg}");
+        }
+
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        #[kanitool::fn_marker = "UninitCheckNoAssumeHook"]
+        #[inline(never)]
+        pub(crate) fn uninit_check_no_assume(cond: bool, msg: &'static str) {
+            #[cfg(not(feature = "concrete_playback"))]
+            return kani_intrinsic();
+
+            #[cfg(feature = "concrete_playback")]
+            assert!(cond, "Uninitialized memory check failed: {msg}");
+        }
+
         /// This should indicate that Kani does not support a certain operation.
         #[doc(hidden)]
         #[allow(dead_code)]
@@ -509,10 +615,73 @@ pub fn init_contracts() {}
             /// This should only be used within contracts. The intent is to
             /// perform type inference on a closure's argument
             #[doc(hidden)]
+            #[kanitool::fn_marker = "ApplyClosureModel"]
             pub fn apply_closure<T, U: Fn(&T) -> bool>(f: U, x: &T) -> bool {
                 f(x)
             }
 
+            /// Evaluate a `requires` clause. The compiler uses this marker to find the
+            /// closure with the condition and check that it is free of side effects.
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "ContractConditionModel"]
+            pub fn contract_condition<F: FnOnce() -> bool>(f: F) -> bool {
+                f()
+            }
+
+            /// Wraps a precondition in a contract harness so that `--contract-negative` can
+            /// check it is neither unsatisfiable nor trivially true.
+            #[inline(never)]
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "CheckPreconditionHook"]
+            pub fn check_precondition(cond: bool, _msg: &'static str) -> bool {
+                cond
+            }
+
+            /// Assert the precondition of a function replaced by its contract at the call site,
+            /// i.e., when the function is stubbed with `stub_verified`.
+            #[inline(never)]
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "AssertPreconditionHook"]
+            pub fn assert_precondition(cond: bool, msg: &'static str) {
+                assert!(cond, "{}", msg);
+            }
+
+            /// Dereference a raw pointer argument inside an `ensures` clause.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for reads of `T` for as long as the returned reference is
+            /// used, and the pointee must not be mutated during that time. The contract macros
+            /// only call this on raw pointer parameters of the annotated function and
+            /// dereference the returned reference immediately.
+            /// Invalid pointers are still reported by Kani's memory safety checks when the
+            /// reference is read.
+            #[doc(hidden)]
+            pub unsafe fn deref_raw<'a, T: ?Sized>(ptr: *const T) -> &'a T {
+                unsafe { &*ptr }
+            }
+
+            /// Marker for parameters that an `ensures` clause can capture by value.
+            ///
+            /// This only exists to give a targeted error message when a postcondition
+            /// refers to a non-`Copy` parameter.
+            #[doc(hidden)]
+            #[diagnostic::on_unimplemented(
+                message = "`ensures` clause refers to parameter of non-`Copy` type `{Self}`",
+                label = "this parameter cannot be captured by value",
+                note = "only `Copy` parameters can be used directly in an `ensures` clause; wrap the expression in `old(..)` to refer to its value on entry"
+            )]
+            pub trait EnsuresParam: Copy {}
+
+            impl<T: Copy> EnsuresParam for T {}
+
+            /// Copy a parameter's value on entry so an `ensures` clause can refer to it
+            /// without `old()`.
+            #[doc(hidden)]
+            pub fn ensures_param<T: EnsuresParam>(x: &T) -> T {
+                *x
+            }
+
             /// Recieves a reference to a pointer-like object and assigns kani::any_modifies to that object.
             /// Only for use within function contracts and will not be replaced if the recursive or function stub
             /// replace contracts are not used.
@@ -80,7 +80,7 @@ pub unsafe fn ptr_offset_from<T>(ptr1: *const T, ptr2: *const T) -> isize {
                 if ptr1 == ptr2 {
                     0
                 } else {
-                    kani::safety_check(
+                    kani::pointer_offset_check(
                         kani::mem::same_allocation_internal(ptr1, ptr2),
                         "Offset result and original pointer should point to the same allocation",
                     );
@@ -89,7 +89,7 @@ pub unsafe fn ptr_offset_from<T>(ptr1: *const T, ptr2: *const T) -> isize {
                         .wrapping_sub(kani::mem::pointer_offset(ptr2))
                         as isize;
                     let t_size = size_of::<T>() as isize;
-                    kani::safety_check(
+                    kani::pointer_offset_check(
                         offset_bytes % t_size == 0,
                         "Expected the distance between the pointers, in bytes, to be a
                         multiple of the size of `T`",
@@ -101,7 +101,10 @@ pub unsafe fn ptr_offset_from<T>(ptr1: *const T, ptr2: *const T) -> isize {
             #[kanitool::fn_marker = "PtrOffsetFromUnsignedModel"]
             pub unsafe fn ptr_offset_from_unsigned<T>(ptr1: *const T, ptr2: *const T) -> usize {
                 let offset = ptr_offset_from(ptr1, ptr2);
-                kani::safety_check(offset >= 0, "Expected non-negative distance between pointers");
+                kani::pointer_offset_check(
+                    offset >= 0,
+                    "Expected non-negative distance between pointers",
+                );
                 offset as usize
             }
 
@@ -122,7 +125,7 @@ pub fn offset<T, P: Ptr<T>, O: ToISize>(ptr: P, offset: O) -> P {
                 }
 
                 let (byte_offset, overflow) = offset.overflowing_mul(t_size);
-                kani::safety_check(!overflow, "Offset in bytes overflows isize");
+                kani::pointer_offset_check(!overflow, "Offset in bytes overflows isize");
                 let orig_ptr = ptr.to_const_ptr();
                 // NOTE: For CBMC, using the pointer addition can have unexpected behavior
                 // when the offset is higher than the object bits since it will wrap around.
@@ -132,7 +135,7 @@ pub fn offset<T, P: Ptr<T>, O: ToISize>(ptr: P, offset: O) -> P {
                 // unexpected failures that still require further debugging.
                 // let new_ptr = orig_ptr.addr().wrapping_add_signed(byte_offset) as *const T;
                 let new_ptr = orig_ptr.wrapping_byte_offset(byte_offset);
-                kani::safety_check(
+                kani::pointer_offset_check(
                     kani::mem::same_allocation_internal(orig_ptr, new_ptr),
                     "Offset result and original pointer must point to the same allocation",
                 );
@@ -177,7 +180,7 @@ fn to_isize(self) -> isize {
                     if let Ok(val) = self.try_into() {
                         val
                     } else {
-                        kani::safety_check(false, "Offset value overflows isize");
+                        kani::pointer_offset_check(false, "Offset value overflows isize");
                         unreachable!();
                     }
                 }
@@ -0,0 +1,145 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! This module provides Kani's `assumption_bundle!` and `use_bundle!` macros.
+//!
+//! ```
+//! kani::assumption_bundle! {
+//!     bundle valid_header(h: &Header) {
+//!         kani::assume(h.len <= MAX_LEN);
+//!         kani::assume(h.version == 1);
+//!     }
+//! }
+//!
+//! let h: Header = kani::any();
+//! kani::use_bundle!(valid_header(&h));
+//! ```
+//!
+//! Each bundle expands to two functions:
+//! - `valid_header`, a predicate that returns the conjunction of all the assumptions, which can be
+//!   used in contracts, e.g. `#[kani::requires(valid_header(&h))]`. The predicate is marked as
+//!   trusted pure, since the assumptions must not have side effects.
+//! - `__kani_bundle_valid_header`, which covers and then assumes each condition in order. The
+//!   cover properties allow the driver to report which assumption made a harness vacuous.
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Block, Expr, ExprCall, ExprPath, Ident, PatType, Stmt, Token, Visibility,
+    parenthesized, parse_macro_input,
+};
+
+use crate::derive::kani_path;
+
+mod kw {
+    syn::custom_keyword!(bundle);
+}
+
+/// A single `bundle name(args) { kani::assume(..); ... }` definition.
+struct Bundle {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    args: Punctuated<PatType, Token![,]>,
+    conditions: Vec<Expr>,
+}
+
+/// The input of `assumption_bundle!`, i.e., one or more bundle definitions.
+struct Bundles(Vec<Bundle>);
+
+impl Parse for Bundle {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<kw::bundle>()?;
+        let name = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+        let args = content.parse_terminated(PatType::parse, Token![,])?;
+        let body: Block = input.parse()?;
+        let conditions =
+            body.stmts.into_iter().map(assumed_condition).collect::<syn::Result<_>>()?;
+        Ok(Bundle { attrs, vis, name, args, conditions })
+    }
+}
+
+impl Parse for Bundles {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut bundles = vec![];
+        while !input.is_empty() {
+            bundles.push(input.parse()?);
+        }
+        Ok(Bundles(bundles))
+    }
+}
+
+/// Extract `cond` from a `kani::assume(cond);` statement.
+fn assumed_condition(stmt: Stmt) -> syn::Result<Expr> {
+    let span = stmt.span();
+    if let Stmt::Expr(Expr::Call(ExprCall { func, mut args, .. }), Some(_)) = stmt
+        && let Expr::Path(ExprPath { path, qself: None, .. }) = func.as_ref()
+        && path.segments.len() == 2
+        && path.segments[0].ident == "kani"
+        && path.segments[1].ident == "assume"
+        && args.len() == 1
+    {
+        Ok(args.pop().unwrap().into_value())
+    } else {
+        Err(syn::Error::new(
+            span,
+            "an assumption bundle may only contain `kani::assume(..);` statements",
+        ))
+    }
+}
+
+/// Expand `assumption_bundle!` into a predicate and an assumption function per bundle.
+pub fn expand_assumption_bundle(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let Bundles(bundles) = parse_macro_input!(item as Bundles);
+    let kani_path = kani_path();
+    let expanded = bundles.iter().map(|Bundle { attrs, vis, name, args, conditions }| {
+        let assume_fn = bundle_fn_name(name);
+        let predicate =
+            if conditions.is_empty() { quote!(true) } else { quote!(#((#conditions))&&*) };
+        let assumptions = conditions.iter().map(|cond| {
+            let line = cond.span().unwrap().start().line();
+            let prefix = format!("assumption bundle `{name}` (line {line}): ");
+            quote_spanned! {cond.span()=>
+                #kani_path::cover(#cond, concat!(#prefix, stringify!(#cond)));
+                #kani_path::assume(#cond);
+            }
+        });
+        quote! {
+            #(#attrs)*
+            #[allow(dead_code)]
+            #[cfg_attr(kani, kanitool::trusted_pure)]
+            #vis fn #name(#args) -> bool {
+                #predicate
+            }
+
+            #[doc(hidden)]
+            #[allow(dead_code, non_snake_case)]
+            #vis fn #assume_fn(#args) {
+                #(#assumptions)*
+            }
+        }
+    });
+    quote!(#(#expanded)*).into()
+}
+
+/// Expand `use_bundle!(name(args))` into a call to the bundle's assumption function.
+pub fn expand_use_bundle(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ExprCall { func, args, .. } = parse_macro_input!(item as ExprCall);
+    let Expr::Path(ExprPath { mut path, .. }) = *func else {
+        return syn::Error::new(func.span(), "expected the name of an assumption bundle")
+            .into_compile_error()
+            .into();
+    };
+    let last = path.segments.last_mut().unwrap();
+    last.ident = bundle_fn_name(&last.ident);
+    quote!(#path(#args)).into()
+}
+
+fn bundle_fn_name(name: &Ident) -> Ident {
+    format_ident!("__kani_bundle_{}", name)
+}
@@ -0,0 +1,67 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! This module provides Kani's `any_fn_ptr!` macro.
+//!
+//! ```
+//! fn double(x: u32) -> u32 { x.wrapping_mul(2) }
+//! fn identity(x: u32) -> u32 { x }
+//!
+//! let callback = kani::any_fn_ptr!(fn(u32) -> u32 from [double, identity]);
+//! ```
+use proc_macro_error2::abort_call_site;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, Token, TypeBareFn, bracketed, parse_macro_input};
+
+use crate::derive::kani_path;
+
+/// The input of `any_fn_ptr!`, i.e., `<fn pointer type> from [<fn>, ...]`.
+struct AnyFnPtr {
+    ty: TypeBareFn,
+    candidates: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for AnyFnPtr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        let from: Ident = input.parse()?;
+        if from != "from" {
+            return Err(syn::Error::new(from.span(), "expected `from`"));
+        }
+        let content;
+        bracketed!(content in input);
+        let candidates = content.parse_terminated(Expr::parse, Token![,])?;
+        Ok(AnyFnPtr { ty, candidates })
+    }
+}
+
+/// Expand `any_fn_ptr!` into a nondeterministic choice among the given functions.
+///
+/// Each function is coerced to the function pointer type individually, so a function with a
+/// different signature is reported as a type mismatch pointing to that function.
+pub fn expand_any_fn_ptr(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let AnyFnPtr { ty, candidates } = parse_macro_input!(item as AnyFnPtr);
+    if candidates.is_empty() {
+        abort_call_site!("`any_fn_ptr!` requires at least one function to choose from");
+    }
+    let kani_path = kani_path();
+    let num_candidates = candidates.len();
+    let arms = candidates.iter().enumerate().map(|(idx, candidate)| {
+        let pattern = if idx + 1 == num_candidates { quote!(_) } else { quote!(#idx) };
+        quote_spanned! {candidate.span()=>
+            #pattern => {
+                let callback: #ty = #candidate;
+                callback
+            }
+        }
+    });
+    let expanded: TokenStream = quote! {
+        match #kani_path::any_where(|idx: &usize| *idx < #num_candidates) {
+            #(#arms)*
+        }
+    };
+    expanded.into()
+}
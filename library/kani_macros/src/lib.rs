@@ -9,8 +9,10 @@
 //   RUSTFLAGS="-Zcrate-attr=feature(register_tool) -Zcrate-attr=register_tool(kanitool)"
 #![feature(proc_macro_diagnostic)]
 #![feature(proc_macro_span)]
+mod bundle;
 mod derive;
 mod derive_bounded;
+mod fn_ptr;
 
 // proc_macro::quote is nightly-only, so we'll cobble things together instead
 use proc_macro::TokenStream;
@@ -30,6 +32,16 @@
 /// e.g. `#[kani::proof(schedule = kani::RoundRobin::default())]`.
 ///
 /// This will wrap the async function in a call to [`block_on_with_spawn`](https://model-checking.github.io/kani/crates/doc/kani/futures/fn.block_on_with_spawn.html) (see its documentation for more information).
+///
+/// A harness that documents a known bug can be marked with `#[kani::proof(expect_fail)]`:
+/// its verification failure counts as a success, while a successful verification is reported as
+/// `UNEXPECTED PASS` and fails the run. The form `#[kani::proof(expect_fail = "<property>")]`
+/// additionally requires the description of one of the failed checks to contain `<property>`.
+///
+/// Any other `key = "value"` option is stored as free-form metadata of the harness,
+/// e.g. `#[kani::proof(owner = "team-storage")]`. Values must be string literals.
+/// The metadata can be displayed in the verification summary with `--show-meta <KEY>`,
+/// and used to filter the output of `kani list` with `--meta <KEY>=<VALUE>`.
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -63,15 +75,61 @@ pub fn recursion(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::recursion(attr, item)
 }
 
+/// Allow this function to be called from contract conditions.
+///
+/// Kani rejects contract conditions (`requires`, `ensures` and loop invariants) that
+/// may have side effects, such as assignments, calls to functions that are not known
+/// to be pure, or interior mutability. This attribute tells Kani to trust that the
+/// annotated function is free of side effects. Kani does not check this claim.
+#[proc_macro_attribute]
+pub fn trusted_pure(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::trusted_pure(attr, item)
+}
+
+/// Mark a function as part of the specification, e.g., a predicate used by contracts.
+///
+/// Specification functions can be called from contract conditions (`requires`, `ensures` and
+/// loop invariants), proof harnesses, and other specification functions. Other code may only
+/// call them if the result is only used by `kani::assert` or `kani::cover`. Kani reports an
+/// error for any other call. Outside of Kani, i.e., without `--cfg kani`, the function is removed,
+/// so it isn't compiled into the program, and any remaining call to it fails to compile.
+///
+/// Unlike `#[kani::trusted_pure]`, Kani checks the calls made by a specification function
+/// when it is used in a contract condition.
+#[proc_macro_attribute]
+pub fn spec_fn(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::spec_fn(attr, item)
+}
+
 /// Set Loop unwind limit for proof harnesses
 /// The attribute `#[kani::unwind(arg)]` can only be called alongside `#[kani::proof]`.
 /// arg - Takes in a integer value (u32) that represents the unwind value for the harness.
+///       It can also be a constant expression, e.g., `BUF_SIZE + 1` or `Buffer::CAPACITY`.
 #[allow(clippy::too_long_first_doc_paragraph)]
 #[proc_macro_attribute]
 pub fn unwind(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::unwind(attr, item)
 }
 
+/// Automatically verify that an `unsafe` function has no undefined behavior.
+///
+/// Kani generates a harness that calls the annotated function with arbitrary inputs, and checks
+/// it with the undefined behavior checks enabled, including the ones that use shadow memory.
+/// If the function has a contract, the harness checks the contract instead, i.e., the
+/// preconditions restrict the inputs and the postconditions are asserted.
+///
+/// This attribute requires the unstable `verify-unsafe` feature, and every argument of the
+/// function must implement `kani::Arbitrary`.
+///
+/// # Arguments
+/// * `unwind` - The unwind value of the generated harness, e.g., `unwind = 3`.
+/// * `solver` - The solver used by the generated harness, e.g., `solver = "kissat"`.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn verify_unsafe(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::verify_unsafe(attr, item)
+}
+
 /// Specify a function/method stub pair to use for proof harness
 ///
 /// The attribute `#[kani::stub(original, replacement)]` can only be used alongside `#[kani::proof]`.
@@ -84,11 +142,48 @@ pub fn stub(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::stub(attr, item)
 }
 
+/// Annotate a proof harness with the time that its verification is expected to take.
+///
+/// The attribute `#[kani::expected_time(secs = <seconds>)]` can only be used alongside
+/// `#[kani::proof]` or `#[kani::proof_for_contract]`. After verifying the harness, Kani prints a
+/// warning if the measured verification time deviates from the annotation by more than
+/// `--time-deviation-pct` percent (50% by default), in either direction.
+#[proc_macro_attribute]
+pub fn expected_time(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::expected_time(attr, item)
+}
+
+/// Acknowledge Kani lints for a proof harness.
+///
+/// The attribute `#[kani::allow(lint, ...)]` can only be used alongside `#[kani::proof]` or
+/// `#[kani::proof_for_contract]`. The supported lints are:
+/// * `unwind_smaller_than_bound` - The harness unwind bound is smaller than the number of
+///   elements generated by a bounded generator, such as `kani::bounded_any::<Vec<u8>, 16>()`.
+#[proc_macro_attribute]
+pub fn allow(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::allow(attr, item)
+}
+
+/// Specify the stubs of the functions declared in an `extern` block.
+///
+/// The attribute `#[kani::ffi_stubs(original = replacement, ...)]` can only be used on an
+/// `extern` block, and it requires the unstable `ffi-stubs` feature. The stubs are applied to
+/// every harness. Calls to a function of the block that has no stub fail verification.
+///
+/// # Arguments
+/// * `original` - The name of a function declared in the `extern` block.
+/// * `replacement` - The function to use as a replacement, specified as a path.
+#[proc_macro_attribute]
+pub fn ffi_stubs(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::ffi_stubs(attr, item)
+}
+
 /// Select the SAT solver to use with CBMC for this harness
 ///
 /// The attribute `#[kani::solver(arg)]` can only be used alongside `#[kani::proof]`.
 ///
-/// arg - name of solver, e.g. kissat
+/// arg - name of solver, e.g. kissat, or a SAT solver binary, e.g. `bin = "kissat"`. The binary
+///       can also be given by a string constant, e.g. `bin = SOLVER_BINARY`.
 #[proc_macro_attribute]
 pub fn solver(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::solver(attr, item)
@@ -337,6 +432,67 @@ pub fn derive_invariant(item: TokenStream) -> TokenStream {
     derive::expand_derive_invariant(item)
 }
 
+/// Generate a function pointer that is a nondeterministic choice among a fixed set of functions.
+///
+/// The first argument is the function pointer type, followed by `from` and the list of
+/// candidate functions. Every candidate must coerce to the given type, otherwise compilation
+/// fails.
+///
+/// This is implemented as a nondeterministic switch over the candidates, which allows harnesses
+/// to verify code that dispatches through callbacks for every registered callback.
+///
+/// ```rust
+/// fn double(x: u32) -> u32 { x.wrapping_mul(2) }
+/// fn negate(x: u32) -> u32 { x.wrapping_neg() }
+/// fn identity(x: u32) -> u32 { x }
+///
+/// let callback: fn(u32) -> u32 = kani::any_fn_ptr!(fn(u32) -> u32 from [double, negate, identity]);
+/// ```
+#[proc_macro_error]
+#[proc_macro]
+pub fn any_fn_ptr(item: TokenStream) -> TokenStream {
+    fn_ptr::expand_any_fn_ptr(item)
+}
+
+/// Define one or more named, parameterized sets of assumptions.
+///
+/// Each bundle is written as a function whose body only contains `kani::assume` calls. It can
+/// then be assumed in a harness with [`use_bundle!`], or used as a boolean predicate by name,
+/// e.g., inside a [`requires`][macro@requires] clause.
+///
+/// When a bundle is used in a harness, Kani checks that each of its assumptions can be
+/// satisfied given the previous ones, and reports the assumption that makes the harness
+/// vacuous otherwise.
+///
+/// ```rust
+/// kani::assumption_bundle! {
+///     bundle valid_header(h: &Header) {
+///         kani::assume(h.len <= MAX_LEN);
+///         kani::assume(h.version == 1);
+///     }
+/// }
+///
+/// #[kani::requires(valid_header(&h))]
+/// fn parse(h: Header) -> Packet { ... }
+///
+/// #[kani::proof]
+/// fn check_parse() {
+///     let h: Header = kani::any();
+///     kani::use_bundle!(valid_header(&h));
+///     parse(h);
+/// }
+/// ```
+#[proc_macro]
+pub fn assumption_bundle(item: TokenStream) -> TokenStream {
+    bundle::expand_assumption_bundle(item)
+}
+
+/// Assume all the conditions of a bundle defined with [`assumption_bundle!`].
+#[proc_macro]
+pub fn use_bundle(item: TokenStream) -> TokenStream {
+    bundle::expand_use_bundle(item)
+}
+
 /// Add a precondition to this function.
 ///
 /// This is part of the function contract API, for more general information see
@@ -376,6 +532,25 @@ pub fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::ensures(attr, item)
 }
 
+/// State that this function never panics when its preconditions hold.
+///
+/// This is part of the function contract API, for more general information see
+/// the [module-level documentation](../kani/contracts/index.html).
+///
+/// The attribute takes no arguments. When the contract is checked with a
+/// [`proof_for_contract`][macro@proof_for_contract] harness, any panic
+/// reachable from the function is reported as a violation of this clause,
+/// separately from the functional postconditions given with
+/// [`ensures`][macro@ensures]. Panics in the harness itself remain ordinary
+/// assertions.
+///
+/// A function annotated with this attribute is considered to have a contract,
+/// even if it has no other contract clause.
+#[proc_macro_attribute]
+pub fn ensures_no_panic(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::ensures_no_panic(attr, item)
+}
+
 /// Designates this function as a harness to check a function contract.
 ///
 /// The argument to this macro is the relative path (e.g. `foo` or
@@ -389,6 +564,31 @@ pub fn proof_for_contract(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::proof_for_contract(attr, item)
 }
 
+/// Designates this generic function as a harness to check the contract of a
+/// trait method for every implementation of the trait in the crate.
+///
+/// The argument to this macro is the path to the method in the trait
+/// definition (e.g. `Codec::decode`). The harness must have a single type
+/// parameter, and Kani generates one harness per implementation of the trait,
+/// named `harness::<ImplType>`, which checks the contract of the method of
+/// that implementation. Implementations whose type doesn't implement
+/// `kani::Arbitrary` are skipped with a warning.
+///
+/// ```ignore
+/// #[kani::proof_for_contract_all_impls(Codec::decode)]
+/// fn check_decode<T: Codec + kani::Arbitrary>() {
+///     let value: T = kani::any();
+///     T::decode(&value.encode());
+/// }
+/// ```
+///
+/// This is part of the function contract API, for more general information see
+/// the [module-level documentation](../kani/contracts/index.html).
+#[proc_macro_attribute]
+pub fn proof_for_contract_all_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::proof_for_contract_all_impls(attr, item)
+}
+
 /// `stub_verified(TARGET)` is a harness attribute (to be used on
 /// [`proof`][macro@proof] or [`proof_for_contract`][macro@proof_for_contract]
 /// function) that replaces all occurrences of `TARGET` reachable from this
@@ -420,6 +620,10 @@ pub fn stub_verified(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// All Rust syntax is supported, even calling other functions, but the computations must be side
 /// effect free, e.g. it cannot perform I/O or use mutable memory.
 ///
+/// A static that the function writes, either a `static mut` or a static with interior mutability
+/// such as an atomic, can be named by its path, e.g. `#[kani::modifies(crate::COUNTER)]`. Any path
+/// that doesn't name a parameter of the function must resolve to a static.
+///
 /// Kani requires each function that uses a contract to have at least one designated
 /// [`proof_for_contract`][macro@proof_for_contract] harness for checking the
 /// contract.
@@ -444,6 +648,16 @@ pub fn loop_invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn loop_modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::loop_modifies(attr, item)
 }
+
+/// Set the unwind bound of this loop.
+///
+/// The attribute `#[kani::loop_unwind(arg)]` can be added to `while`, `while let`, `loop` and
+/// `for` loops. It overrides the unwind value of the harness for this loop only.
+/// arg - Takes in a integer value (u32) that represents the unwind value for the loop.
+#[proc_macro_attribute]
+pub fn loop_unwind(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::loop_unwind(attr, item)
+}
 /// This module implements Kani attributes in a way that only Kani's compiler can understand.
 /// This code should only be activated when pre-building Kani's sysroot.
 #[cfg(kani_sysroot)]
@@ -452,16 +666,21 @@ mod sysroot {
 
     mod contracts;
     mod loop_contracts;
+    mod loop_unwind;
 
-    pub use contracts::{ensures, modifies, proof_for_contract, requires, stub_verified};
+    pub use contracts::{
+        ensures, ensures_no_panic, modifies, proof_for_contract, proof_for_contract_all_impls,
+        requires, stub_verified,
+    };
     pub use loop_contracts::{loop_invariant, loop_modifies};
+    pub use loop_unwind::loop_unwind;
 
     use super::*;
 
     use {
         quote::{format_ident, quote},
         syn::parse::{Parse, ParseStream},
-        syn::{ItemFn, parse_macro_input},
+        syn::{ItemFn, ItemForeignMod, parse_macro_input},
     };
 
     /// Annotate the harness with a #[kanitool::<name>] with optional arguments.
@@ -492,27 +711,126 @@ pub fn $name(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     struct ProofOptions {
         schedule: Option<syn::Expr>,
+        /// Set by `expect_fail`, with the expected property if given as `expect_fail = "..."`.
+        expect_fail: Option<Option<syn::LitStr>>,
+        /// Free-form `key = "value"` metadata attached to the harness.
+        metadata: Vec<(syn::Ident, syn::LitStr)>,
     }
 
     impl Parse for ProofOptions {
         fn parse(input: ParseStream) -> syn::Result<Self> {
-            if input.is_empty() {
-                Ok(ProofOptions { schedule: None })
-            } else {
+            let mut options = ProofOptions { schedule: None, expect_fail: None, metadata: vec![] };
+            while !input.is_empty() {
                 let ident = input.parse::<syn::Ident>()?;
-                if ident != "schedule" {
+                if ident == "expect_fail" {
+                    if options.expect_fail.is_some() {
+                        abort!(ident, "`expect_fail` was specified more than once.");
+                    }
+                    let property = if input.peek(syn::Token![=]) {
+                        let _ = input.parse::<syn::Token![=]>()?;
+                        let Ok(property) = input.parse::<syn::LitStr>() else {
+                            abort!(ident, "the expected property of `expect_fail` must be a string literal.";
+                                help = "try `expect_fail = \"<property description>\"`";
+                            );
+                        };
+                        Some(property)
+                    } else {
+                        None
+                    };
+                    options.expect_fail = Some(property);
+                    if !input.is_empty() {
+                        let _ = input.parse::<syn::Token![,]>()?;
+                    }
+                    continue;
+                }
+                if !input.peek(syn::Token![=]) {
                     abort_call_site!("`{}` is not a valid option for `#[kani::proof]`.", ident;
-                        help = "did you mean `schedule`?";
-                        note = "for now, `schedule` is the only option for `#[kani::proof]`.";
+                        help = "did you mean `schedule = ...` or `{} = \"...\"`?", ident;
+                        note = "`#[kani::proof]` accepts `schedule`, `expect_fail` and free-form `key = \"value\"` metadata.";
                     );
                 }
                 let _ = input.parse::<syn::Token![=]>()?;
-                let schedule = Some(input.parse::<syn::Expr>()?);
-                Ok(ProofOptions { schedule })
+                if ident == "schedule" {
+                    if options.schedule.is_some() {
+                        abort!(ident, "`schedule` was specified more than once.");
+                    }
+                    options.schedule = Some(input.parse::<syn::Expr>()?);
+                } else {
+                    if options.metadata.iter().any(|(key, _)| *key == ident) {
+                        abort!(ident, "metadata key `{}` was specified more than once.", ident);
+                    }
+                    let expr = input.parse::<syn::Expr>()?;
+                    let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(value), .. }) = &expr
+                    else {
+                        abort!(expr, "the value of the harness metadata `{}` must be a string literal.", ident;
+                            help = "try `{} = \"...\"`", ident;
+                        );
+                    };
+                    options.metadata.push((ident, value.clone()));
+                }
+                if !input.is_empty() {
+                    let _ = input.parse::<syn::Token![,]>()?;
+                }
+            }
+            Ok(options)
+        }
+    }
+
+    struct VerifyUnsafeOptions {
+        unwind: Option<syn::LitInt>,
+        solver: Option<syn::LitStr>,
+    }
+
+    impl Parse for VerifyUnsafeOptions {
+        fn parse(input: ParseStream) -> syn::Result<Self> {
+            let mut options = VerifyUnsafeOptions { unwind: None, solver: None };
+            while !input.is_empty() {
+                let ident = input.parse::<syn::Ident>()?;
+                let _ = input.parse::<syn::Token![=]>()?;
+                if ident == "unwind" && options.unwind.is_none() {
+                    options.unwind = Some(input.parse::<syn::LitInt>()?);
+                } else if ident == "solver" && options.solver.is_none() {
+                    // Accept both `solver = kissat` and `solver = "kissat"`.
+                    options.solver = Some(if input.peek(syn::LitStr) {
+                        input.parse::<syn::LitStr>()?
+                    } else {
+                        let solver = input.parse::<syn::Ident>()?;
+                        syn::LitStr::new(&solver.to_string(), solver.span())
+                    });
+                } else if ident == "unwind" || ident == "solver" {
+                    abort!(ident, "`{}` was specified more than once.", ident);
+                } else {
+                    abort!(ident, "`{}` is not a valid option for `#[kani::verify_unsafe]`.", ident;
+                        note = "`#[kani::verify_unsafe]` accepts `unwind = <N>` and `solver = \"<SOLVER>\"`.";
+                    );
+                }
+                if !input.is_empty() {
+                    let _ = input.parse::<syn::Token![,]>()?;
+                }
             }
+            Ok(options)
         }
     }
 
+    /// Annotate an `unsafe` function with `#[kanitool::verify_unsafe(...)]`, so the compiler
+    /// generates a harness for it.
+    pub fn verify_unsafe(attr: TokenStream, item: TokenStream) -> TokenStream {
+        let options = parse_macro_input!(attr as VerifyUnsafeOptions);
+        let fn_item = parse_macro_input!(item as ItemFn);
+        if fn_item.sig.unsafety.is_none() {
+            abort!(fn_item.sig.fn_token, "`#[kani::verify_unsafe]` can only be applied to `unsafe` functions";
+                help = "use `#[kani::proof]` to write a harness for this function instead";
+            );
+        }
+        let unwind = options.unwind.iter();
+        let solver = options.solver.iter();
+        quote!(
+            #[kanitool::verify_unsafe(#(unwind = #unwind,)* #(solver = #solver)*)]
+            #fn_item
+        )
+        .into()
+    }
+
     pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
         let proof_options = parse_macro_input!(attr as ProofOptions);
         let fn_item = parse_macro_input!(item as ItemFn);
@@ -521,9 +839,17 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
         let sig = fn_item.sig;
         let body = fn_item.block;
 
+        let metadata = proof_options.metadata.iter().map(|(key, value)| quote!(#key = #value));
+        let expect_fail = match &proof_options.expect_fail {
+            None => quote!(),
+            Some(None) => quote!(#[kanitool::expect_fail]),
+            Some(Some(property)) => quote!(#[kanitool::expect_fail(property = #property)]),
+        };
         let kani_attributes = quote!(
             #[allow(dead_code)]
             #[kanitool::proof]
+            #expect_fail
+            #(#[kanitool::meta(#metadata)])*
         );
 
         if sig.asyncness.is_none() {
@@ -588,12 +914,27 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     }
 
+    /// Annotate an `extern` block with its `#[kanitool::ffi_stubs(...)]`.
+    pub fn ffi_stubs(attr: TokenStream, item: TokenStream) -> TokenStream {
+        let args = proc_macro2::TokenStream::from(attr);
+        let foreign_mod = parse_macro_input!(item as ItemForeignMod);
+        quote!(
+            #[kanitool::ffi_stubs(#args)]
+            #foreign_mod
+        )
+        .into()
+    }
+
     kani_attribute!(should_panic, no_args);
     kani_attribute!(recursion, no_args);
+    kani_attribute!(trusted_pure, no_args);
+    kani_attribute!(spec_fn, no_args);
     kani_attribute!(solver);
     kani_attribute!(stub);
     kani_attribute!(unstable);
     kani_attribute!(unwind);
+    kani_attribute!(expected_time);
+    kani_attribute!(allow);
 }
 
 /// This module provides dummy implementations of Kani attributes which cannot be interpreted by
@@ -621,17 +962,37 @@ pub fn proof(_attr: TokenStream, item: TokenStream) -> TokenStream {
         result
     }
 
+    /// Only keep a specification function with `--cfg kani`, e.g., for concrete playback, so
+    /// that calling it from code that is compiled without Kani fails to compile.
+    pub fn spec_fn(_attr: TokenStream, item: TokenStream) -> TokenStream {
+        let fn_item = syn::parse_macro_input!(item as syn::ItemFn);
+        quote::quote!(
+            #[allow(dead_code, unexpected_cfgs)]
+            #[cfg(kani)]
+            #fn_item
+        )
+        .into()
+    }
+
     no_op!(should_panic);
     no_op!(recursion);
+    no_op!(trusted_pure);
     no_op!(solver);
     no_op!(stub);
+    no_op!(ffi_stubs);
+    no_op!(verify_unsafe);
     no_op!(unstable);
     no_op!(unwind);
+    no_op!(expected_time);
+    no_op!(allow);
     no_op!(requires);
     no_op!(ensures);
+    no_op!(ensures_no_panic);
     no_op!(modifies);
     no_op!(proof_for_contract);
+    no_op!(proof_for_contract_all_impls);
     no_op!(stub_verified);
     no_op!(loop_invariant);
     no_op!(loop_modifies);
+    no_op!(loop_unwind);
 }
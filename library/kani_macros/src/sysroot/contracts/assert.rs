@@ -65,7 +65,10 @@ fn make_assert_body(&self, mut body_stmts: Vec<Stmt>) -> TokenStream2 {
         match &self.condition_type {
             ContractConditionsData::Requires { attr } => {
                 quote!({
-                    kani::assert(#attr, stringify!(#attr_copy));
+                    kani::assert(
+                        kani::internal::contract_condition(|| #attr),
+                        stringify!(#attr_copy),
+                    );
                     #(#body_stmts)*
                 })
             }
@@ -6,9 +6,9 @@
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{Expr, ItemFn, Stmt};
+use syn::{Attribute, Expr, ItemFn, Stmt, parse_quote};
 
-use super::{ContractConditionsHandler, INTERNAL_RESULT_IDENT, helpers::*};
+use super::{ContractConditionsData, ContractConditionsHandler, INTERNAL_RESULT_IDENT, helpers::*};
 
 impl<'a> ContractConditionsHandler<'a> {
     /// Generate initial contract.
@@ -26,6 +26,7 @@ pub fn handle_untouched(&mut self) {
         let check_closure = self.check_closure();
         let recursion_closure = self.new_recursion_closure(&replace_closure, &check_closure);
         let assert_closure = self.assert_closure();
+        let clause_attribute = self.clause_attribute();
 
         let span = Span::call_site();
         let replace_ident = Ident::new(&self.replace_name, span);
@@ -46,6 +47,7 @@ pub fn handle_untouched(&mut self) {
             #[kanitool::replaced_with = #replace_name]
             #[kanitool::asserted_with = #assert_name]
             #[kanitool::modifies_wrapper = #modifies_name]
+            #clause_attribute
             #vis #sig {
                 // Dummy functions used to force the compiler to annotate Kani's
                 // closures as `FnOnce`. Without this, Rust infers the generated closures as `FnMut`,
@@ -106,6 +108,7 @@ const fn kani_contract_mode() -> kani::internal::Mode {
     /// to the attribute being handled.
     pub fn handle_expanded(&mut self) {
         let mut annotated_fn = self.annotated_fn.clone();
+        annotated_fn.attrs.push(self.clause_attribute());
         let ItemFn { block, .. } = &mut annotated_fn;
         let recursion_closure = expect_closure_in_match(&mut block.stmts, "recursion_check");
         self.expand_recursion(recursion_closure);
@@ -122,6 +125,26 @@ pub fn handle_expanded(&mut self) {
         self.output.extend(quote!(#annotated_fn));
     }
 
+    /// The attribute that records the clause being handled, e.g.,
+    /// `#[kanitool::requires_clause = "divisor != 0"]`, which is used to document the contract.
+    ///
+    /// The attribute is appended after the ones of the previous clauses, so the clauses are
+    /// recorded in the order they were written.
+    fn clause_attribute(&self) -> Attribute {
+        let attr_copy = &self.attr_copy;
+        match self.condition_type {
+            ContractConditionsData::Requires { .. } => {
+                parse_quote!(#[kanitool::requires_clause = stringify!(#attr_copy)])
+            }
+            ContractConditionsData::Ensures { .. } => {
+                parse_quote!(#[kanitool::ensures_clause = stringify!(#attr_copy)])
+            }
+            ContractConditionsData::Modifies { .. } => {
+                parse_quote!(#[kanitool::modifies_clause = stringify!(#attr_copy)])
+            }
+        }
+    }
+
     /// Generate the tokens for the recursion closure.
     fn new_recursion_closure(
         &self,
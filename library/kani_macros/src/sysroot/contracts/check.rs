@@ -25,7 +25,10 @@ pub fn make_check_body(&self, mut body_stmts: Vec<Stmt>) -> TokenStream2 {
         match &self.condition_type {
             ContractConditionsData::Requires { attr } => {
                 quote!({
-                    kani::assume(#attr);
+                    kani::assume(kani::internal::check_precondition(
+                        kani::internal::contract_condition(|| #attr),
+                        stringify!(#attr_copy),
+                    ));
                     #(#body_stmts)*
                 })
             }
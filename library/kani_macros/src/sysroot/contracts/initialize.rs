@@ -5,12 +5,13 @@
 
 use proc_macro::{Diagnostic, TokenStream};
 use proc_macro2::TokenStream as TokenStream2;
-use syn::ItemFn;
+use syn::{Expr, ExprPath, FnArg, Ident, ItemFn, Signature, parse_quote};
 
 use super::{
     ContractConditionsData, ContractConditionsHandler, ContractConditionsType,
     ContractFunctionState,
-    helpers::{chunks_by, is_token_stream_2_comma, matches_path},
+    helpers::{chunks_by, is_token_stream_2_comma, matches_path, pat_to_bindings},
+    shared::capture_param_values,
 };
 
 impl<'a> TryFrom<&'a syn::Attribute> for ContractFunctionState {
@@ -62,10 +63,12 @@ pub fn new(
                 ContractConditionsData::Requires { attr: syn::parse(attr)? }
             }
             ContractConditionsType::Ensures => {
-                ContractConditionsData::Ensures { attr: syn::parse(attr)? }
+                let mut attr = syn::parse(attr)?;
+                capture_param_values(&mut attr, &annotated_fn.sig);
+                ContractConditionsData::Ensures { attr }
             }
             ContractConditionsType::Modifies => {
-                ContractConditionsData::new_modifies(attr, &mut output)
+                ContractConditionsData::new_modifies(attr, &annotated_fn.sig, &mut output)
             }
         };
 
@@ -94,7 +97,16 @@ impl ContractConditionsData {
     /// Constructs a [`Self::Modifies`] from the contents of the decorating attribute.
     ///
     /// Responsible for parsing the attribute.
-    fn new_modifies(attr: TokenStream, output: &mut TokenStream2) -> Self {
+    fn new_modifies(attr: TokenStream, sig: &Signature, output: &mut TokenStream2) -> Self {
+        let params: Vec<&Ident> = sig
+            .inputs
+            .iter()
+            .flat_map(|arg| match arg {
+                FnArg::Receiver(_) => vec![],
+                FnArg::Typed(typed) => pat_to_bindings(&typed.pat),
+            })
+            .map(|(_, ident)| ident)
+            .collect();
         let attr = chunks_by(TokenStream2::from(attr), is_token_stream_2_comma)
             .map(syn::parse2)
             .filter_map(|expr| match expr {
@@ -102,10 +114,24 @@ fn new_modifies(attr: TokenStream, output: &mut TokenStream2) -> Self {
                     output.extend(e.into_compile_error());
                     None
                 }
-                Ok(expr) => Some(expr),
+                Ok(expr) => Some(modifies_target(expr, &params)),
             })
             .collect();
 
         ContractConditionsData::Modifies { attr }
     }
 }
+
+/// Turn a path to a static in a `modifies` clause into a pointer to that static.
+///
+/// The other targets are expressions that already return a pointer. The parameters of the
+/// function are the only local variables in scope of a contract, so any other path names an item,
+/// and we leave its resolution to the compiler: `&raw const` only type checks if the path resolves
+/// to a place, i.e., a `static` or a `static mut`, and it is rejected for anything else, e.g., a
+/// constant.
+fn modifies_target(expr: Expr, params: &[&Ident]) -> Expr {
+    let Expr::Path(ExprPath { qself: None, path, .. }) = &expr else { return expr };
+    let is_param =
+        path.is_ident("self") || path.get_ident().is_some_and(|ident| params.contains(&ident));
+    if is_param { expr } else { parse_quote!((&raw const #path)) }
+}
@@ -204,7 +204,10 @@
 //!                     #[kanitool::is_contract_generated(check)]
 //!                     #[allow(dead_code, unused_variables, unused_mut)]
 //!                     let mut __kani_check_div = kani_force_fn_once(|| -> u32 {
-//!                         kani::assume(divisor != 0);
+//!                         kani::assume(kani::internal::check_precondition(
+//!                             divisor != 0,
+//!                             stringify!(divisor != 0),
+//!                         ));
 //!                         let _wrapper_arg = ();
 //!                         #[kanitool::is_contract_generated(wrapper)]
 //!                         #[allow(dead_code, unused_variables, unused_mut)]
@@ -249,7 +252,10 @@
 //!             #[kanitool::is_contract_generated(check)]
 //!             #[allow(dead_code, unused_variables, unused_mut)]
 //!             let mut __kani_check_div = kani_force_fn_once(|| -> u32 {
-//!                 kani::assume(divisor != 0);
+//!                 kani::assume(kani::internal::check_precondition(
+//!                     divisor != 0,
+//!                     stringify!(divisor != 0),
+//!                 ));
 //!                 let _wrapper_arg = ();
 //!                 #[kanitool::is_contract_generated(wrapper)]
 //!                 #[allow(dead_code, unused_variables, unused_mut)]
@@ -384,7 +390,10 @@
 //!                     #[kanitool::is_contract_generated(check)]
 //!                     #[allow(dead_code, unused_variables, unused_mut)]
 //!                     let mut __kani_check_modify = kani_force_fn_once(|| {
-//!                         kani::assume(*ptr < 100);
+//!                         kani::assume(kani::internal::check_precondition(
+//!                             *ptr < 100,
+//!                             stringify!(*ptr < 100),
+//!                         ));
 //!                         let remember_kani_internal_2e780b148d45b5c8 = *ptr + 1;
 //!                         let remember_kani_internal_2e780b148d45b5c8 = *ptr + 1;
 //!                         let _wrapper_arg = (ptr as *const _,);
@@ -448,7 +457,10 @@
 //!             #[kanitool::is_contract_generated(check)]
 //!             #[allow(dead_code, unused_variables, unused_mut)]
 //!             let mut __kani_check_modify = kani_force_fn_once(|| {
-//!                 kani::assume(*ptr < 100);
+//!                 kani::assume(kani::internal::check_precondition(
+//!                     *ptr < 100,
+//!                     stringify!(*ptr < 100),
+//!                 ));
 //!                 let remember_kani_internal_2e780b148d45b5c8 = *ptr + 1;
 //!                 let remember_kani_internal_2e780b148d45b5c8 = *ptr + 1;
 //!                 let _wrapper_arg = (ptr as *const _,);
@@ -541,6 +553,21 @@ pub fn modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
     contract_main(attr, item, ContractConditionsType::Modifies)
 }
 
+/// Mark the function with `#[kanitool::ensures_no_panic]`, which the compiler uses to report
+/// reachable panics as contract violations when checking the contract.
+///
+/// The function also gets a trivial precondition, so that it is handled like any other function
+/// with a contract even if this is its only contract attribute.
+pub fn ensures_no_panic(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let error_msg = "`#[kani::ensures_no_panic]` does not take any arguments";
+        return Error::new(Span::call_site(), error_msg).into_compile_error().into();
+    }
+    let mut item_fn = parse_macro_input!(item as ItemFn);
+    item_fn.attrs.push(parse_quote!(#[kanitool::ensures_no_panic]));
+    contract_main(quote!(true).into(), quote!(#item_fn).into(), ContractConditionsType::Requires)
+}
+
 /// This is very similar to the kani_attribute macro, but it instead creates
 /// key-value style attributes which I find a little easier to parse.
 macro_rules! passthrough {
@@ -578,6 +605,18 @@ pub fn proof_for_contract(attr: TokenStream, item: TokenStream) -> TokenStream {
     .into()
 }
 
+pub fn proof_for_contract_all_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = proc_macro2::TokenStream::from(attr);
+    let mut fn_item = parse_macro_input!(item as ItemFn);
+    fn_item.block.stmts.insert(0, parse_quote!(kani::internal::init_contracts();));
+    quote!(
+        #[allow(dead_code)]
+        #[kanitool::proof_for_contract_all_impls = stringify!(#args)]
+        #fn_item
+    )
+    .into()
+}
+
 /// Classifies the state a function is in the contract handling pipeline.
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum ContractFunctionState {
@@ -86,7 +86,10 @@ fn expand_replace_body(&self, before: &[Stmt], after: &[Stmt]) -> TokenStream {
                 let Self { attr_copy, .. } = self;
                 let result = Ident::new(INTERNAL_RESULT_IDENT, Span::call_site());
                 quote!({
-                    kani::assert(#attr, stringify!(#attr_copy));
+                    kani::internal::assert_precondition(
+                        kani::internal::contract_condition(|| #attr),
+                        stringify!(#attr_copy),
+                    );
                     #(#before)*
                     #(#after)*
                     #result
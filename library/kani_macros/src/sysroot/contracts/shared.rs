@@ -7,13 +7,19 @@
 //! This is so we can keep [`super`] distraction-free as the definitions of data
 //! structures and the entry point for contract handling.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::quote;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use syn::{
-    Expr, ExprCall, ExprClosure, ExprPath, Path, Stmt, spanned::Spanned, visit_mut::VisitMut,
+    Arm, Block, Expr, ExprCall, ExprClosure, ExprPath, ExprUnary, FnArg, Macro, Pat, PatIdent,
+    Path, Signature, Stmt, Token, Type, UnOp,
+    parse::{ParseStream, discouraged::Speculative},
+    parse_quote_spanned,
+    spanned::Spanned,
+    visit::Visit,
+    visit_mut::VisitMut,
 };
 
 use super::{ContractMode, INTERNAL_RESULT_IDENT};
@@ -130,6 +136,202 @@ pub fn build_ensures(data: &ExprClosure) -> (TokenStream2, Expr) {
     (remembers_stmts, Expr::Verbatim(quote!(kani::internal::apply_closure(#expr, &#result))))
 }
 
+/// Rewrite every direct use of a by-value parameter in an ensures closure into
+/// `old(kani::internal::ensures_param(&x))`, so the clause sees the value the
+/// parameter had on entry without the user having to write `old` themselves.
+///
+/// Parameters of reference type are left untouched, since the clause usually
+/// wants to observe the state behind them after the call. Uses inside `old(..)`
+/// and uses shadowed by a local binding are also left alone. Parameters declared
+/// `mut` are captured the same way, so the clause sees their value on entry even
+/// if the function body reassigns them. Non-`Copy` parameters are rejected by the
+/// bound on `ensures_param`. Uses inside macro invocations such as `assert!(x > 0)`
+/// are rewritten as long as the macro arguments parse as expressions.
+///
+/// Dereferences of raw pointer parameters, both inside and outside `old(..)`, are
+/// rewritten into `*unsafe { kani::internal::deref_raw(ptr) }` unless they are already
+/// inside an `unsafe` block. This way `old(*ptr)` snapshots the target on entry and `*ptr`
+/// reads it on exit without the user writing an `unsafe` block. Each dereference goes through its own pointer,
+/// so arguments that alias observe each other's writes.
+pub fn capture_param_values(closure: &mut ExprClosure, sig: &Signature) {
+    let params = sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(typed) if !matches!(typed.ty.as_ref(), Type::Reference(_)) => {
+                match typed.pat.as_ref() {
+                    Pat::Ident(PatIdent { by_ref: None, subpat: None, ident, .. }) => {
+                        Some(ident.clone())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    let raw_ptrs = sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(typed) if matches!(typed.ty.as_ref(), Type::Ptr(_)) => {
+                match typed.pat.as_ref() {
+                    Pat::Ident(PatIdent { by_ref: None, subpat: None, ident, .. }) => {
+                        Some(ident.clone())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
+    let mut vis = ParamVisitor {
+        params,
+        raw_ptrs,
+        shadowed: HashSet::new(),
+        in_old: false,
+        in_unsafe: false,
+    };
+    vis.visit_expr_closure_mut(closure);
+}
+
+struct ParamVisitor {
+    /// Parameters passed by value.
+    params: HashSet<Ident>,
+    /// Parameters of raw pointer type.
+    raw_ptrs: HashSet<Ident>,
+    /// Parameters that are currently shadowed by a local binding.
+    shadowed: HashSet<Ident>,
+    /// Whether we are visiting the argument of `old`.
+    in_old: bool,
+    /// Whether we are inside an `unsafe` block, where raw pointers can already be dereferenced.
+    in_unsafe: bool,
+}
+
+impl ParamVisitor {
+    /// Shadow every parameter bound by `pat` and return the set that was
+    /// shadowed before, so the caller can restore it once the scope ends.
+    fn shadow(&mut self, pat: &Pat) -> HashSet<Ident> {
+        struct Bindings<'a>(&'a mut HashSet<Ident>);
+        impl<'ast> Visit<'ast> for Bindings<'_> {
+            fn visit_pat_ident(&mut self, pat: &'ast PatIdent) {
+                self.0.insert(pat.ident.clone());
+                syn::visit::visit_pat_ident(self, pat)
+            }
+        }
+        let saved = self.shadowed.clone();
+        Bindings(&mut self.shadowed).visit_pat(pat);
+        saved
+    }
+}
+
+impl VisitMut for ParamVisitor {
+    fn visit_expr_mut(&mut self, ex: &mut Expr) {
+        match ex {
+            Expr::Call(ExprCall { func, .. })
+                if matches!(func.as_ref(), Expr::Path(ExprPath { qself: None, path, .. })
+                    if path.is_ident("old")) =>
+            {
+                // The argument of `old` is already evaluated on entry, so only raw pointer
+                // dereferences need to be rewritten there.
+                let in_old = std::mem::replace(&mut self.in_old, true);
+                syn::visit_mut::visit_expr_mut(self, ex);
+                self.in_old = in_old;
+            }
+            Expr::Unsafe(_) => {
+                let in_unsafe = std::mem::replace(&mut self.in_unsafe, true);
+                syn::visit_mut::visit_expr_mut(self, ex);
+                self.in_unsafe = in_unsafe;
+            }
+            Expr::Unary(ExprUnary { op: UnOp::Deref(_), expr, attrs })
+                if attrs.is_empty()
+                    && !self.in_unsafe
+                    && matches!(expr.as_ref(), Expr::Path(ExprPath { qself: None, path, .. })
+                        if path.get_ident().is_some_and(|ident| self.raw_ptrs.contains(ident)
+                            && !self.shadowed.contains(ident))) =>
+            {
+                let span = expr.span();
+                *ex = parse_quote_spanned!(span=> (*unsafe { kani::internal::deref_raw(#expr) }));
+            }
+            Expr::Path(ExprPath { qself: None, path, attrs })
+                if attrs.is_empty() && !self.in_old =>
+            {
+                let Some(ident) = path.get_ident() else { return };
+                if !self.params.contains(ident) || self.shadowed.contains(ident) {
+                    return;
+                }
+                let span = ident.span();
+                *ex = parse_quote_spanned!(span=> old(kani::internal::ensures_param(&#ident)));
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, ex),
+        }
+    }
+
+    fn visit_expr_closure_mut(&mut self, closure: &mut ExprClosure) {
+        let saved = self.shadowed.clone();
+        closure.inputs.iter().for_each(|input| {
+            self.shadow(input);
+        });
+        self.visit_expr_mut(&mut closure.body);
+        self.shadowed = saved;
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        let saved = self.shadowed.clone();
+        for stmt in &mut block.stmts {
+            match stmt {
+                Stmt::Local(local) => {
+                    if let Some(init) = &mut local.init {
+                        self.visit_local_init_mut(init);
+                    }
+                    self.shadow(&local.pat);
+                }
+                _ => self.visit_stmt_mut(stmt),
+            }
+        }
+        self.shadowed = saved;
+    }
+
+    fn visit_arm_mut(&mut self, arm: &mut Arm) {
+        let saved = self.shadow(&arm.pat);
+        if let Some((_, guard)) = &mut arm.guard {
+            self.visit_expr_mut(guard);
+        }
+        self.visit_expr_mut(&mut arm.body);
+        self.shadowed = saved;
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        visit_macro_args(mac, |expr| self.visit_expr_mut(expr));
+    }
+}
+
+/// Macro arguments are opaque tokens to `syn`, so parse the leading arguments
+/// that are expressions (e.g. both arguments of `assert_eq!(x, 1)` or the
+/// scrutinee of `matches!(x, Some(_))`), call `visit` on each of them and keep
+/// the remaining tokens as they are. Macros whose arguments are not expressions
+/// are left alone.
+fn visit_macro_args(mac: &mut Macro, visit: impl FnMut(&mut Expr)) {
+    type MacroArgs = (Vec<(Expr, Option<Token![,]>)>, TokenStream2);
+    fn parse_args(input: ParseStream) -> syn::Result<MacroArgs> {
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            let fork = input.fork();
+            let Ok(expr) = fork.parse::<Expr>() else { break };
+            if !fork.is_empty() && !fork.peek(Token![,]) {
+                break;
+            }
+            input.advance_to(&fork);
+            let comma: Option<Token![,]> = input.parse()?;
+            args.push((expr, comma));
+        }
+        Ok((args, input.parse()?))
+    }
+    let Ok((mut args, rest)) = mac.parse_body_with(parse_args) else { return };
+    args.iter_mut().map(|(expr, _)| expr).for_each(visit);
+    let args = args.iter().map(|(expr, comma)| quote!(#expr #comma));
+    mac.tokens = quote!(#(#args)* #rest);
+}
+
 trait OldTrigger {
     /// You are provided with the expression that is the first argument of the
     /// `old()` call. You may modify it as you see fit. The return value
@@ -203,6 +405,10 @@ fn visit_expr_mut(&mut self, ex: &mut Expr) {
             syn::visit_mut::visit_expr_mut(self, ex)
         }
     }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        visit_macro_args(mac, |expr| self.visit_expr_mut(expr));
+    }
 }
 
 impl OldTrigger for OldLifter {
@@ -232,7 +232,7 @@ fn transform_break_continue(block: &mut Block) {
     block.stmts.push(return_stmt);
 }
 
-fn while_let_rewrite(loopexpr: Stmt) -> Stmt {
+pub(super) fn while_let_rewrite(loopexpr: Stmt) -> Stmt {
     if let Stmt::Expr(ref expr, _) = loopexpr
         && let Expr::While(ExprWhile { cond, body, .. }) = expr
         && let Expr::Let(ref let_expr) = **cond
@@ -406,7 +406,7 @@ const fn #register_ident<F: Fn() -> bool>(_f: &F, _transformed: usize) -> bool {
     }
 }
 
-fn generate_unique_id_from_span(stmt: &Stmt) -> String {
+pub(super) fn generate_unique_id_from_span(stmt: &Stmt) -> String {
     // Extract the span of the expression
     let span = stmt.span().unwrap();
 
@@ -0,0 +1,99 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementation of the per-loop unwind bound code generation.
+//!
+//! A loop annotated with `#[kani::loop_unwind(N)]` gets a call to a marker function at the
+//! beginning of its head, which the compiler uses to identify the loop and its bound.
+//!
+//! A while loop of the form
+//! ``` rust
+//!  while guard {
+//!      body
+//!  }
+//! ```
+//! will be annotated as
+//! ``` rust
+//! #[inline(never)]
+//! #[kanitool::fn_marker = "kani_register_loop_unwind"]
+//! const fn kani_register_loop_unwind_id(_bound: u32) -> bool {
+//!     true
+//! }
+//!  while kani_register_loop_unwind_id(N) && guard {
+//!      body
+//!  }
+//! ```
+//! `while let` and `for` loops are first rewritten into a `loop` with a `match`, and the call is
+//! added as the first statement of the `loop` body.
+
+use super::loop_contracts::{generate_unique_id_from_span, while_let_rewrite};
+use proc_macro::TokenStream;
+use proc_macro_error2::{abort, abort_call_site};
+use quote::{format_ident, quote};
+use syn::{Expr, ExprForLoop, LitInt, Stmt, parse_quote};
+
+pub fn loop_unwind(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let bound: LitInt = syn::parse(attr).unwrap_or_else(|_| {
+        abort_call_site!("`#[kani::loop_unwind]` expects a single integer argument";
+            note = "for example, `#[kani::loop_unwind(10)]`";
+        )
+    });
+    let bound: u32 = bound.base10_parse().unwrap_or_else(|_| {
+        abort!(bound, "the loop unwind bound must be an integer of type `u32`")
+    });
+
+    let loop_stmt: Stmt = syn::parse(item).unwrap();
+    let loop_id = generate_unique_id_from_span(&loop_stmt);
+    let (iter_decl, mut loop_stmt) = for_loop_rewrite(while_let_rewrite(loop_stmt));
+
+    // ident of the register function
+    let mut register_name: String = "kani_register_loop_unwind".to_owned();
+    register_name.push_str(&loop_id);
+    let register_ident = format_ident!("{}", register_name);
+
+    match &mut loop_stmt {
+        Stmt::Expr(Expr::While(ew), _) => {
+            let guard = &ew.cond;
+            *ew.cond = parse_quote!(#register_ident(#bound) && #guard);
+        }
+        Stmt::Expr(Expr::Loop(el), _) => {
+            el.body.stmts.insert(0, parse_quote!(#register_ident(#bound);));
+        }
+        _ => abort_call_site!("`#[kani::loop_unwind]` can only be applied to loops"),
+    }
+
+    quote!(
+    {
+        #[inline(never)]
+        #[kanitool::fn_marker = "kani_register_loop_unwind"]
+        const fn #register_ident(_bound: u32) -> bool {
+            true
+        }
+        #iter_decl
+        #loop_stmt
+    })
+    .into()
+}
+
+/// Rewrite a `for` loop into a `loop` with a `match`, so that the register function is called
+/// before the iterator is advanced, i.e., in the loop head.
+///
+/// Returns the declaration of the iterator, which must be placed before the new loop.
+fn for_loop_rewrite(loop_stmt: Stmt) -> (Option<Stmt>, Stmt) {
+    if let Stmt::Expr(Expr::ForLoop(ExprForLoop { label, pat, expr, body, .. }), _) = &loop_stmt {
+        let iter = format_ident!("__kani_loop_iter");
+        let iter_decl = parse_quote! {
+            let mut #iter = ::core::iter::IntoIterator::into_iter(#expr);
+        };
+        let new_loop = parse_quote! {
+            #label loop {
+                match ::core::iter::Iterator::next(&mut #iter) {
+                    ::core::option::Option::Some(#pat) => #body,
+                    ::core::option::Option::None => break,
+                }
+            }
+        };
+        return (Some(iter_decl), new_loop);
+    }
+    (None, loop_stmt)
+}
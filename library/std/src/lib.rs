@@ -12,9 +12,7 @@
 // re-export all std symbols
 pub use std::*;
 
-#[cfg(not(feature = "concrete_playback"))]
-// Override process calls with stubs.
-pub mod process;
+// Note that `std::process::{abort, exit}` are not overridden here, since the compiler models them.
 
 /// This assert macro calls kani's assert function passing it down the condition
 /// as well as a message that will be used when reporting the assertion result.
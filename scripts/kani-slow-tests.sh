@@ -24,6 +24,27 @@ cargo build
 # Run slow compiletests
 cargo run -p compiletest --quiet -- --suite slow --mode cargo-kani
 
+# Check that `cargo kani list --fast` is significantly faster than a full `cargo kani list`.
+# We list the harnesses once beforehand so that both measurements only rebuild the local crate.
+TOKIO_DIR=$KANI_DIR/tests/slow/tokio-proofs
+LIST_TARGET_DIR=$(mktemp -d)
+list_time_ms() {
+  touch "$TOKIO_DIR/src/lib.rs"
+  local start
+  start=$(date +%s%N)
+  (cd "$TOKIO_DIR" && CARGO_TARGET_DIR="$LIST_TARGET_DIR" cargo kani list "$@" > /dev/null)
+  echo $(( ($(date +%s%N) - start) / 1000000 ))
+}
+list_time_ms > /dev/null
+FULL_MS=$(list_time_ms)
+FAST_MS=$(list_time_ms --fast)
+rm -rf "$LIST_TARGET_DIR"
+echo "cargo kani list: ${FULL_MS}ms, cargo kani list --fast: ${FAST_MS}ms"
+if (( FAST_MS * 2 > FULL_MS )); then
+  echo "Expected \`cargo kani list --fast\` to take less than half the time of \`cargo kani list\`"
+  exit 1
+fi
+
 echo
 echo "Kani slow tests completed successfully."
 echo
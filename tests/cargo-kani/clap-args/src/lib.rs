@@ -0,0 +1,72 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a `clap` parser either parses the symbolic arguments generated by
+//! `kani::any_command_line_args` or returns a well-formed error, and that `--help` and
+//! `--version` exit successfully.
+
+use clap::Parser;
+use std::path::PathBuf;
+
+/// The command line interface under verification.
+#[derive(Debug, Parser)]
+#[command(name = "app", version = "1.0")]
+pub struct Cli {
+    /// Print more information.
+    #[arg(short, long)]
+    pub verbose: bool,
+    /// Where to write the output.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+    /// The input file.
+    pub input: Option<String>,
+}
+
+/// Parse the arguments given after the name of the binary.
+pub fn parse(args: Vec<String>) -> Result<Cli, clap::Error> {
+    Cli::try_parse_from(std::iter::once("app".to_string()).chain(args))
+}
+
+#[cfg(kani)]
+mod verify {
+    use super::*;
+    use clap::error::ErrorKind;
+
+    #[kani::proof]
+    #[kani::unwind(34)]
+    fn check_parse_any_args() {
+        let args = kani::any_command_line_args(1);
+        // Short arguments are enough to reach every option and error of the parser.
+        kani::assume(args.iter().all(|arg| arg.len() <= 10));
+        match parse(args) {
+            Ok(cli) => assert!(cli.input.as_ref().is_none_or(|input| !input.contains('\0'))),
+            // Help and version are the only errors that exit successfully.
+            Err(err) => match err.kind() {
+                ErrorKind::DisplayHelp | ErrorKind::DisplayVersion => {
+                    assert_eq!(err.exit_code(), 0)
+                }
+                _ => assert_eq!(err.exit_code(), 2),
+            },
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(34)]
+    fn check_help_and_version() {
+        let flag = if kani::any() { "--help" } else { "--version" };
+        let err = parse(vec![flag.to_string()]).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayVersion));
+        assert_eq!(err.exit_code(), 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(34)]
+    fn check_output_option() {
+        let arg = kani::any_arg_starting_with("--output=");
+        let value = arg["--output=".len()..].to_string();
+        kani::assume(!value.is_empty());
+        let cli = parse(vec![arg]).unwrap();
+        assert_eq!(cli.output, Some(PathBuf::from(value)));
+        assert!(!cli.verbose);
+    }
+}
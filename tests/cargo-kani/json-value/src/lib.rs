@@ -0,0 +1,65 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_json_value!` generates values that respect the depth bound, and that
+//! serializing and parsing them back yields the same value. Also check that the queries generated
+//! by `kani::any_json_path` can be applied to these values.
+
+#[cfg(kani)]
+mod verify {
+    use serde_json::Value;
+
+    fn depth(value: &Value) -> usize {
+        match value {
+            Value::Array(elems) => 1 + elems.iter().map(depth).max().unwrap_or(0),
+            Value::Object(map) => 1 + map.values().map(depth).max().unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_depth() {
+        let value: Value = kani::any_json_value!(2);
+        assert!(depth(&value) <= 2);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(12)]
+    fn check_round_trip() {
+        let value: Value = kani::any_json_value!(1);
+        let text = serde_json::to_string(&value).unwrap();
+        let parsed: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed, value);
+    }
+
+    /// Apply a JSONPath query made of `.key` and `[index]` segments.
+    fn query<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+        let mut segments = path.strip_prefix('$')?;
+        let mut current = value;
+        while !segments.is_empty() {
+            let end = segments[1..].find(['.', '[']).map_or(segments.len(), |end| end + 1);
+            let (segment, rest) = segments.split_at(end);
+            current = if let Some(key) = segment.strip_prefix('.') {
+                current.get(key)?
+            } else {
+                let idx = segment.strip_prefix('[')?.strip_suffix(']')?;
+                current.get(idx.parse::<usize>().ok()?)?
+            };
+            segments = rest;
+        }
+        Some(current)
+    }
+
+    #[kani::proof]
+    #[kani::unwind(11)]
+    fn check_json_path() {
+        let value: Value = kani::any_json_value!(3);
+        let path = kani::any_json_path(3);
+        let segments = path.matches(['.', '[']).count();
+        assert!(segments >= 1 && segments <= 3);
+        let result = query(&value, &path);
+        assert_eq!(query(&value, &path), result);
+        kani::cover!(result.is_some_and(|result| !result.is_null()));
+    }
+}
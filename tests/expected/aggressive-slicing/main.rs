@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --aggressive-slicing --default-unwind 3
+
+//! Check that `--aggressive-slicing` keeps the property checks that CBMC would otherwise add
+//! while solving: arithmetic and pointer checks, unwinding assertions and cover properties.
+
+fn store(dst: *mut u8, value: u8) {
+    unsafe { *dst = value };
+}
+
+#[kani::proof]
+fn check_overflow() {
+    let x: u8 = kani::any();
+    let _ = x + 1;
+}
+
+#[kani::proof]
+fn check_dangling_write() {
+    let ptr = {
+        let mut local = 0u8;
+        &mut local as *mut u8
+    };
+    store(ptr, kani::any());
+}
+
+#[kani::proof]
+fn check_unwinding() {
+    let bound: u8 = kani::any();
+    for _ in 0..bound {}
+}
+
+#[kani::proof]
+fn check_data_through_pointer() {
+    let mut value = 0u8;
+    store(&mut value, 42);
+    kani::cover!(value == 42);
+    kani::cover!(value != 42);
+}
@@ -0,0 +1,40 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the pointers generated by `kani::any_aligned_ptr` and the pointers constrained by
+//! `kani::assume_aligned` can be used by an aligned SIMD-like load, and that the misaligned
+//! accesses are still detected.
+
+/// The 4 lanes of a 128-bit vector register.
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+struct Lanes([u32; 4]);
+
+/// Load 4 lanes from `ptr`, which must be aligned to 16 bytes, like an aligned SIMD load.
+unsafe fn load_aligned(ptr: *const u32) -> [u32; 4] {
+    unsafe { (*ptr.cast::<Lanes>()).0 }
+}
+
+#[kani::proof]
+fn check_any_aligned_ptr() {
+    let ptr = kani::any_aligned_ptr::<[u32; 4]>(16);
+    assert_eq!(ptr as usize % 16, 0);
+    let lanes = unsafe { load_aligned(ptr.cast()) };
+    assert_eq!(lanes, unsafe { *ptr });
+}
+
+#[kani::proof]
+fn check_assume_aligned() {
+    let ptr = kani::any_aligned_ptr::<[u32; 8]>(16).cast::<u32>();
+    let ptr = ptr.wrapping_add(kani::any_where(|idx| *idx < 5));
+    kani::assume_aligned(ptr, 16);
+    kani::cover!(true, "the pointer is aligned");
+    let lanes = unsafe { load_aligned(ptr) };
+    assert_eq!(lanes[0], unsafe { *ptr });
+}
+
+#[kani::proof]
+fn check_misaligned_load() {
+    let ptr = kani::any_aligned_ptr::<[u32; 8]>(16).cast::<u32>();
+    let _ = unsafe { load_aligned(ptr.add(1)) };
+}
@@ -0,0 +1,51 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_fn_ptr!` lets a harness verify an event-dispatch table over every
+//! registered callback, and that a callback that breaks the table invariant is caught.
+
+/// Handlers must keep the value within `0..=LIMIT` if it starts in that range.
+const LIMIT: u32 = 1000;
+
+fn halve(x: u32) -> u32 {
+    x / 2
+}
+
+fn saturate(x: u32) -> u32 {
+    x.min(LIMIT)
+}
+
+fn increment(x: u32) -> u32 {
+    x + 1
+}
+
+struct Dispatcher {
+    handlers: [fn(u32) -> u32; 2],
+}
+
+impl Dispatcher {
+    fn dispatch(&self, event: usize, value: u32) -> u32 {
+        self.handlers[event % self.handlers.len()](value)
+    }
+}
+
+#[kani::proof]
+fn check_valid_handlers() {
+    let dispatcher = Dispatcher {
+        handlers: [
+            kani::any_fn_ptr!(fn(u32) -> u32 from [halve, saturate]),
+            kani::any_fn_ptr!(fn(u32) -> u32 from [halve, saturate]),
+        ],
+    };
+    let value = kani::any_where(|value: &u32| *value <= LIMIT);
+    assert!(dispatcher.dispatch(kani::any(), value) <= LIMIT);
+}
+
+#[kani::proof]
+fn check_invalid_handler() {
+    let dispatcher = Dispatcher {
+        handlers: [kani::any_fn_ptr!(fn(u32) -> u32 from [halve, saturate, increment]), halve],
+    };
+    let value = kani::any_where(|value: &u32| *value <= LIMIT);
+    assert!(dispatcher.dispatch(kani::any(), value) <= LIMIT, "handler broke the invariant");
+}
@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that an empty range is reported as a failure instead of making the harness vacuous.
+
+#[kani::proof]
+fn check_empty_range() {
+    let len: usize = kani::any();
+    let idx: usize = kani::any_in_range(0, len);
+    assert!(idx < len);
+}
+
+#[kani::proof]
+fn check_reversed_inclusive_range() {
+    let value: u8 = kani::any_in_range_inclusive(10, 5);
+    assert!(value == 0);
+}
@@ -0,0 +1,83 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that `kani::assert_progress!` detects a livelock in a connection state machine.
+//! The buggy version goes back to `Connecting` after a failed handshake without consuming a
+//! retry, so the connection can bounce between these two states forever.
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum State {
+    Idle,
+    Connecting,
+    Handshake,
+    Established,
+    Closed,
+}
+
+struct Connection {
+    state: State,
+    retries: u8,
+    buggy: bool,
+}
+
+impl Connection {
+    fn new(retries: u8, buggy: bool) -> Self {
+        Connection { state: State::Idle, retries, buggy }
+    }
+
+    fn is_done(&self) -> bool {
+        matches!(self.state, State::Established | State::Closed)
+    }
+
+    /// Decreases with every transition of a correct state machine.
+    fn measure(&self) -> u32 {
+        let phase = match self.state {
+            State::Idle => 3,
+            State::Connecting => 2,
+            State::Handshake => 1,
+            State::Established | State::Closed => 0,
+        };
+        self.retries as u32 * 4 + phase
+    }
+
+    fn step(&mut self, ok: bool) {
+        self.state = match self.state {
+            State::Idle => State::Connecting,
+            State::Connecting if ok => State::Handshake,
+            State::Handshake if ok => State::Established,
+            State::Handshake if self.buggy => State::Connecting,
+            State::Connecting | State::Handshake => {
+                if self.retries == 0 {
+                    State::Closed
+                } else {
+                    self.retries -= 1;
+                    State::Connecting
+                }
+            }
+            State::Established | State::Closed => unreachable!(),
+        }
+    }
+}
+
+fn run(buggy: bool) {
+    let mut conn = Connection::new(kani::any_where(|retries| *retries <= 3), buggy);
+    loop {
+        kani::assert_progress!(conn.measure());
+        if conn.is_done() {
+            break;
+        }
+        conn.step(kani::any());
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(12)]
+fn check_protocol() {
+    run(false);
+}
+
+#[kani::proof]
+#[kani::unwind(12)]
+fn check_buggy_protocol() {
+    run(true);
+}
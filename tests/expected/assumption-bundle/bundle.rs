@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that an assumption bundle can be used both in a harness and inside a
+//! `requires` clause.
+
+const MAX_LEN: u16 = 1500;
+
+#[derive(kani::Arbitrary)]
+struct Header {
+    version: u8,
+    len: u16,
+    offset: u16,
+}
+
+kani::assumption_bundle! {
+    bundle valid_header(h: &Header) {
+        kani::assume(h.version == 4 || h.version == 6);
+        kani::assume(h.len <= MAX_LEN);
+        kani::assume(h.offset <= h.len);
+    }
+}
+
+#[kani::requires(valid_header(&h))]
+#[kani::ensures(|result: &u16| *result <= MAX_LEN)]
+fn payload_len(h: Header) -> u16 {
+    h.len - h.offset
+}
+
+#[kani::proof]
+fn check_payload_len() {
+    let h: Header = kani::any();
+    kani::use_bundle!(valid_header(&h));
+    assert!(h.len - h.offset <= MAX_LEN);
+}
+
+#[kani::proof_for_contract(payload_len)]
+fn check_payload_len_contract() {
+    payload_len(kani::any());
+}
@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani reports which assumption of a bundle makes a harness vacuous.
+
+kani::assumption_bundle! {
+    bundle small_range(lo: u8, hi: u8) {
+        kani::assume(lo < 10);
+        kani::assume(hi < 5);
+        kani::assume(lo < hi);
+        kani::assume(hi > 20);
+    }
+}
+
+#[kani::proof]
+fn check_range() {
+    let lo: u8 = kani::any();
+    let hi: u8 = kani::any();
+    kani::use_bundle!(small_range(lo, hi));
+    assert!(lo < 5);
+}
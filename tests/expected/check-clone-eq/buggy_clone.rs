@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::check_clone_eq` finds a `Clone` implementation that doesn't copy all the
+//! state compared by `PartialEq`.
+
+#[derive(PartialEq, kani::Arbitrary)]
+struct Counter {
+    count: u32,
+    limit: u32,
+}
+
+impl Clone for Counter {
+    fn clone(&self) -> Self {
+        // Bug: the clone is reset instead of copying the count.
+        Counter { count: 0, limit: self.limit }
+    }
+}
+
+#[kani::proof]
+fn check_counter_clone() {
+    kani::check_clone_eq::<Counter>();
+}
@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::concretize` collapses a symbolic value into a single representative, that
+//! it fails if the representative isn't a possible value of the input, and that the summary warns
+//! about the harnesses that use it.
+
+#[kani::proof]
+fn check_concretized_config() {
+    let config: [u8; 8] = kani::concretize(kani::any());
+    let idx: usize = kani::any_where(|idx| *idx < 8);
+    assert_eq!(config[idx], config[0]);
+}
+
+#[kani::proof]
+fn check_single_value() {
+    let x: u32 = kani::concretize(kani::any());
+    // This would fail for most values of `x`, but only one of them is verified.
+    assert!(x < 10);
+}
+
+#[kani::proof]
+fn check_nonzero_input() {
+    let x: u32 = kani::concretize(kani::any_where(|x| *x > 0));
+    // Unreachable, since the representative is zero.
+    assert!(x > 0);
+}
+
+#[kani::proof]
+fn check_without_concretize() {
+    let x: u32 = kani::any();
+    assert!(x.checked_add(0).is_some());
+}
@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that acquiring a lock that the harness thread already holds is reported as a deadlock.
+
+use std::sync::{Mutex, RwLock};
+
+struct Counter {
+    value: Mutex<u32>,
+}
+
+impl Counter {
+    fn get(&self) -> u32 {
+        *self.value.lock().unwrap()
+    }
+
+    /// Buggy: `get` locks the mutex while the guard of `increment` is still alive.
+    fn increment(&self) {
+        let mut value = self.value.lock().unwrap();
+        if self.get() < u32::MAX {
+            *value += 1;
+        }
+    }
+}
+
+#[kani::proof]
+fn check_mutex_relock() {
+    let counter = Counter { value: Mutex::new(kani::any()) };
+    counter.increment();
+}
+
+#[kani::proof]
+fn check_rwlock_write_while_reading() {
+    let lock = RwLock::new(kani::any::<u8>());
+    let value = lock.read().unwrap();
+    if *value == 0 {
+        *lock.write().unwrap() = 1;
+    }
+}
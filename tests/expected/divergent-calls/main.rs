@@ -0,0 +1,64 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check the modeling of standard library functions that never return: `process::exit` and
+//! `process::abort` end the path without a failure, while reaching `unreachable_unchecked` is
+//! reported.
+
+use std::hint::unreachable_unchecked;
+use std::process;
+
+fn parse_flag(arg: u8) -> bool {
+    match arg {
+        b'y' => true,
+        b'n' => false,
+        _ => {
+            // Invalid input: terminate the program.
+            process::exit(2)
+        }
+    }
+}
+
+#[kani::proof]
+fn check_exit() {
+    let arg: u8 = kani::any();
+    let flag = parse_flag(arg);
+    // No code runs after `exit`, so the argument was valid.
+    assert!(arg == b'y' || arg == b'n');
+    assert_eq!(flag, arg == b'y');
+}
+
+fn checked_len(len: usize) -> usize {
+    if len > 16 {
+        // Corrupted length: terminate the program.
+        process::abort()
+    }
+    len
+}
+
+#[kani::proof]
+fn check_abort() {
+    let len = checked_len(kani::any());
+    // No code runs after `abort`, so the length is in bounds.
+    assert!(len <= 16);
+}
+
+fn digit_value(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        // SAFETY: The caller must only pass ASCII digits.
+        _ => unsafe { unreachable_unchecked() },
+    }
+}
+
+#[kani::proof]
+fn check_unreachable_unchecked_safe() {
+    let digit = kani::any_where(|digit: &u8| digit.is_ascii_digit());
+    assert!(digit_value(digit) < 10);
+}
+
+#[kani::proof]
+fn check_unreachable_unchecked_reached() {
+    let digit: u8 = kani::any();
+    let _ = digit_value(digit);
+}
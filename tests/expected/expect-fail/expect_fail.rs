@@ -0,0 +1,35 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that harnesses annotated with `#[kani::proof(expect_fail)]` succeed when verification
+//! fails, and fail when verification succeeds or when no failure matches the expected property.
+
+/// A buggy absolute value that overflows for `i8::MIN`.
+fn abs(x: i8) -> i8 {
+    if x < 0 { -x } else { x }
+}
+
+#[kani::proof(expect_fail)]
+fn check_known_bug() {
+    let x: i8 = kani::any();
+    assert!(abs(x) >= 0);
+}
+
+#[kani::proof(expect_fail = "attempt to negate with overflow")]
+fn check_known_bug_property() {
+    let x: i8 = kani::any();
+    let _ = abs(x);
+}
+
+#[kani::proof(expect_fail = "index out of bounds")]
+fn check_unmatched_property() {
+    let x: i8 = kani::any();
+    let _ = abs(x);
+}
+
+#[kani::proof(expect_fail)]
+fn check_fixed_bug() {
+    let x: i8 = kani::any();
+    kani::assume(x > i8::MIN);
+    assert!(abs(x) >= 0);
+}
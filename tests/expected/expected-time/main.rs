@@ -0,0 +1,12 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani warns when the verification time of a harness deviates from the time
+//! annotated with `#[kani::expected_time]`.
+
+#[kani::proof]
+#[kani::expected_time(secs = 1000)]
+fn check_faster_than_expected() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(1).is_some() || x == u8::MAX);
+}
@@ -0,0 +1,28 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z ffi-stubs -Z c-ffi
+//
+//! Check that calling a function of an `extern` block annotated with `#[kani::ffi_stubs(...)]`
+//! that has no stub fails verification, even if C-FFI is enabled.
+
+extern "C" fn open_model(_flags: i32) -> i32 {
+    3
+}
+
+#[kani::ffi_stubs(open_device = open_model)]
+extern "C" {
+    fn open_device(flags: i32) -> i32;
+    fn reset_device(fd: i32) -> i32;
+}
+
+#[kani::proof]
+fn check_stubbed() {
+    assert_eq!(unsafe { open_device(0) }, 3);
+}
+
+#[kani::proof]
+fn check_not_stubbed() {
+    let fd = unsafe { open_device(0) };
+    let _ = unsafe { reset_device(fd) };
+}
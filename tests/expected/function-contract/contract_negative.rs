@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts --contract-negative
+
+//! Check that `--contract-negative` reports whether each precondition of a
+//! contract harness can both hold and be violated.
+
+#[kani::requires(divisor != 0)]
+#[kani::ensures(|result: &u32| *result <= dividend)]
+fn div(dividend: u32, divisor: u32) -> u32 {
+    dividend / divisor
+}
+
+/// The precondition is always true, so it can never be violated.
+#[kani::requires(x <= u8::MAX)]
+#[kani::ensures(|result: &u8| *result == x / 2)]
+fn halve(x: u8) -> u8 {
+    x / 2
+}
+
+/// The precondition can never hold, so the contract is vacuous.
+#[kani::requires(x > 10 && x < 5)]
+#[kani::ensures(|result: &u8| *result == 0)]
+fn impossible(x: u8) -> u8 {
+    x
+}
+
+#[kani::proof_for_contract(div)]
+fn check_div() {
+    div(kani::any(), kani::any());
+}
+
+#[kani::proof_for_contract(halve)]
+fn check_halve() {
+    halve(kani::any());
+}
+
+#[kani::proof_for_contract(impossible)]
+fn check_impossible() {
+    impossible(kani::any());
+}
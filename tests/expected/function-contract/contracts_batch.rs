@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts -Zcontracts-batch
+
+//! Check that `-Z contracts-batch` verifies the contract of every function that doesn't have a
+//! contract harness, and that it skips functions whose arguments don't implement `Arbitrary`.
+
+mod arith {
+    #[kani::requires(x < 100)]
+    #[kani::ensures(|result: &u32| *result > x)]
+    pub fn increment(x: u32) -> u32 {
+        x + 1
+    }
+
+    #[kani::ensures(|result: &u32| *result <= x && *result <= y)]
+    pub fn max(x: u32, y: u32) -> u32 {
+        if x > y { x } else { y }
+    }
+
+    #[kani::ensures(|result: &u32| *result <= x)]
+    pub fn halve(x: u32) -> u32 {
+        x / 2
+    }
+
+    #[kani::ensures(|result: &bool| *result == ptr.is_null())]
+    pub fn is_null(ptr: *const u32) -> bool {
+        ptr.is_null()
+    }
+}
+
+#[kani::proof_for_contract(arith::halve)]
+fn check_halve() {
+    arith::halve(kani::any());
+}
@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that a panic reachable under the precondition is reported as a
+//! violation of `ensures_no_panic`, separately from the postcondition.
+
+#[kani::requires(divisor != 0)]
+#[kani::ensures(|result: &u32| *result <= dividend)]
+#[kani::ensures_no_panic]
+fn checked_div(dividend: u32, divisor: u32) -> u32 {
+    if divisor == 1 {
+        panic!("unexpected divisor");
+    }
+    dividend / divisor
+}
+
+#[kani::proof_for_contract(checked_div)]
+fn checked_div_harness() {
+    checked_div(kani::any(), kani::any());
+}
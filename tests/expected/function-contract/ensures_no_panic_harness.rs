@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that only panics reachable from the function under contract violate
+//! `ensures_no_panic`. A panic in the harness itself is an ordinary assertion.
+
+#[kani::ensures_no_panic]
+fn halve(value: u32) -> u32 {
+    value / 2
+}
+
+#[kani::proof_for_contract(halve)]
+fn halve_harness() {
+    let value: u32 = kani::any();
+    if halve(value) == 7 {
+        panic!("harness panic");
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that `ensures_no_panic` can be used on its own and that it holds when
+//! the precondition rules out all panics.
+
+#[kani::ensures_no_panic]
+fn first_or_zero(values: &[u8]) -> u8 {
+    if values.is_empty() { 0 } else { values[0] }
+}
+
+#[kani::requires(idx < values.len())]
+#[kani::ensures_no_panic]
+fn get(values: &[u8; 4], idx: usize) -> u8 {
+    values[idx]
+}
+
+#[kani::proof_for_contract(first_or_zero)]
+fn first_or_zero_harness() {
+    let values: [u8; 4] = kani::any();
+    let len: usize = kani::any_where(|len| *len <= values.len());
+    first_or_zero(&values[..len]);
+}
+
+#[kani::proof_for_contract(get)]
+fn get_harness() {
+    get(&kani::any(), kani::any());
+}
@@ -0,0 +1,49 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that `ensures` clauses can refer to `Copy` parameters without `old`,
+//! while parameters behind references keep referring to their current state.
+
+#[derive(Copy, Clone, PartialEq, kani::Arbitrary)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+#[kani::requires(*acc as u32 + step as u32 <= u8::MAX as u32)]
+#[kani::requires(*acc as u32 + bound.x as u32 <= u8::MAX as u32)]
+#[kani::ensures(|result| *result == step + bound.x)]
+#[kani::ensures(|_| *acc == old(*acc) + step)]
+#[kani::ensures(|_| *base == origin)]
+#[kani::modifies(acc)]
+fn accumulate(acc: &mut u8, step: u8, base: &Point, origin: Point, bound: Point) -> u8 {
+    *acc += step;
+    step + bound.x
+}
+
+#[kani::proof_for_contract(accumulate)]
+fn check_accumulate() {
+    let mut acc = kani::any();
+    let base: Point = kani::any();
+    let bound: Point = kani::any();
+    accumulate(&mut acc, kani::any(), &base, base, bound);
+}
+
+#[kani::requires(x < u32::MAX)]
+#[kani::ensures(|r| *r == x + 1)]
+fn add_one(x: u32) -> u32 {
+    x + 1
+}
+
+#[kani::proof_for_contract(add_one)]
+fn check_add_one() {
+    add_one(kani::any());
+}
+
+#[kani::proof]
+#[kani::stub_verified(add_one)]
+fn check_add_one_stub() {
+    let x: u32 = kani::any_where(|x| *x < 100);
+    assert_eq!(add_one(x), x + 1);
+}
@@ -0,0 +1,28 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that `ensures` clauses that refer to a `mut` parameter directly see
+//! its value on entry, also when the parameter is used inside a macro invocation.
+
+#[kani::ensures(|result| *result <= n && (n < 2 || *result < n))]
+fn halve(mut n: u32) -> u32 {
+    n /= 2;
+    n
+}
+
+#[kani::proof_for_contract(halve)]
+fn check_halve() {
+    halve(kani::any());
+}
+
+#[kani::ensures(|result| { assert_eq!(*result, n / 2); matches!(n, 0..=u32::MAX) })]
+fn halve_macro(mut n: u32) -> u32 {
+    n /= 2;
+    n
+}
+
+#[kani::proof_for_contract(halve_macro)]
+fn check_halve_macro() {
+    halve_macro(kani::any());
+}
@@ -0,0 +1,54 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that `old` can snapshot the target of raw pointer arguments, including
+//! when the pointers alias.
+
+#[kani::requires(kani::mem::can_dereference(ptr))]
+#[kani::requires(unsafe { *ptr } < 100)]
+#[kani::modifies(ptr)]
+#[kani::ensures(|_| old(*ptr) + 1 == *ptr)]
+unsafe fn increment(ptr: *mut u32) {
+    unsafe { *ptr += 1 };
+}
+
+/// `dst` and `src` may point to the same location, in which case `dst` ends up
+/// with twice its original value.
+#[kani::requires(kani::mem::can_dereference(dst) && kani::mem::can_dereference(src))]
+#[kani::requires(unsafe { *dst } < 100 && unsafe { *src } < 100)]
+#[kani::modifies(dst)]
+#[kani::ensures(|_| *dst == old(*dst) + old(*src))]
+#[kani::ensures(|_| dst != src as *mut u32 || *src == *dst)]
+unsafe fn add_assign(dst: *mut u32, src: *const u32) {
+    unsafe { *dst += *src };
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    let mut x: u32 = kani::any();
+    unsafe { increment(&mut x) };
+}
+
+#[kani::proof_for_contract(add_assign)]
+fn check_add_assign() {
+    let mut x: u32 = kani::any();
+    let y: u32 = kani::any();
+    unsafe { add_assign(&mut x, &y) };
+}
+
+#[kani::proof_for_contract(add_assign)]
+fn check_add_assign_alias() {
+    let mut x: u32 = kani::any();
+    let ptr: *mut u32 = &mut x;
+    unsafe { add_assign(ptr, ptr) };
+}
+
+#[kani::proof]
+#[kani::stub_verified(increment)]
+fn check_increment_stub() {
+    let mut x: u32 = kani::any_where(|x| *x < 100);
+    let old = x;
+    unsafe { increment(&mut x) };
+    assert_eq!(x, old + 1);
+}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that referring to a non-`Copy` parameter directly in an `ensures`
+//! clause produces an error that points the user to `old`.
+
+#[kani::ensures(|result| *result == v.len())]
+fn consume(v: Vec<u8>) -> usize {
+    v.len()
+}
+
+#[kani::proof_for_contract(consume)]
+fn main() {
+    consume(vec![kani::any()]);
+}
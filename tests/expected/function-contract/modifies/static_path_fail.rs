@@ -0,0 +1,25 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that writing a static that is not named by the `modifies` clause is reported, even if
+//! the function writes another static that is.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static HITS: AtomicU32 = AtomicU32::new(0);
+static MISSES: AtomicU32 = AtomicU32::new(0);
+
+#[kani::modifies(HITS)]
+fn lookup(found: bool) {
+    if found {
+        HITS.fetch_add(1, Ordering::Relaxed);
+    } else {
+        MISSES.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[kani::proof_for_contract(lookup)]
+fn check_lookup() {
+    lookup(kani::any());
+}
@@ -0,0 +1,66 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that a `modifies` clause can name the statics that a function writes by their path,
+//! both for a `static mut` and for statics with interior mutability. The paths are resolved by
+//! the compiler, so they don't depend on the naming conventions.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static mut COUNTER: u32 = 0;
+static HITS: AtomicU32 = AtomicU32::new(0);
+static LAST: Mutex<u32> = Mutex::new(0);
+#[allow(non_upper_case_globals)]
+static mut total: u32 = 0;
+
+mod counter {
+    #[kani::requires(unsafe { crate::COUNTER } < 100)]
+    #[kani::modifies(crate::COUNTER)]
+    #[kani::ensures(|_| unsafe { crate::COUNTER } > 0)]
+    pub fn increment() {
+        unsafe { crate::COUNTER += 1 }
+    }
+}
+
+#[kani::requires(HITS.load(Ordering::Relaxed) < 100)]
+#[kani::modifies(HITS)]
+#[kani::ensures(|_| HITS.load(Ordering::Relaxed) > 0)]
+fn hit() {
+    HITS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[kani::modifies(LAST)]
+#[kani::ensures(|_| *LAST.lock().unwrap() == value)]
+fn record(value: u32) {
+    *LAST.lock().unwrap() = value;
+}
+
+#[kani::modifies(total, dst)]
+#[kani::ensures(|_| unsafe { total } == value)]
+fn store(dst: &mut u32, value: u32) {
+    *dst = value;
+    unsafe { total = value }
+}
+
+#[kani::proof_for_contract(counter::increment)]
+fn check_increment() {
+    counter::increment();
+}
+
+#[kani::proof_for_contract(hit)]
+fn check_hit() {
+    hit();
+}
+
+#[kani::proof_for_contract(record)]
+fn check_record() {
+    record(kani::any());
+}
+
+#[kani::proof_for_contract(store)]
+fn check_store() {
+    let mut dst = 0;
+    store(&mut dst, kani::any());
+}
@@ -0,0 +1,45 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts -Zstubbing
+
+//! Check that the statics named by the `modifies` clause of a contract are havocked when the
+//! contract replaces the function, both for a `static mut` and for an atomic static.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static mut COUNTER: u32 = 0;
+static HITS: AtomicU32 = AtomicU32::new(0);
+
+#[kani::modifies(COUNTER)]
+#[kani::ensures(|_| unsafe { COUNTER } > 0)]
+fn increment() {
+    unsafe { COUNTER = COUNTER.saturating_add(1) }
+}
+
+#[kani::modifies(HITS)]
+#[kani::ensures(|_| HITS.load(Ordering::Relaxed) > 0)]
+fn hit() {
+    HITS.store(HITS.load(Ordering::Relaxed).saturating_add(1), Ordering::Relaxed);
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    increment();
+}
+
+#[kani::proof_for_contract(hit)]
+fn check_hit() {
+    hit();
+}
+
+#[kani::proof]
+#[kani::stub_verified(increment)]
+#[kani::stub_verified(hit)]
+fn replace_statics() {
+    increment();
+    hit();
+    let counter = unsafe { COUNTER };
+    let hits = HITS.load(Ordering::Relaxed);
+    assert!(counter > 0 && hits > 0, "replaced");
+    kani::cover!(counter > 1 && hits > 1, "havocked");
+}
@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts -Zstubbing -Zunstable-options --precondition-call-sites
+
+//! Check that the precondition of a function replaced by its contract is checked at each call
+//! site, and that a violation is reported with the location of the call.
+
+#[kani::requires(divisor != 0)]
+#[kani::ensures(|result : &u32| *result <= dividend)]
+fn div(dividend: u32, divisor: u32) -> u32 {
+    dividend / divisor
+}
+
+/// The divisor of the second call is never zero.
+fn ratio_pass(x: u32) -> u32 {
+    let half = div(x, 2);
+    div(x, half.saturating_add(1))
+}
+
+/// The divisor of the second call can be zero.
+fn ratio_fail(x: u32) -> u32 {
+    let half = div(x, 2);
+    div(x, half)
+}
+
+#[kani::proof_for_contract(div)]
+fn check_div() {
+    div(kani::any(), kani::any());
+}
+
+#[kani::proof]
+#[kani::stub_verified(div)]
+fn check_ratio_pass() {
+    ratio_pass(kani::any());
+}
+
+#[kani::proof]
+#[kani::stub_verified(div)]
+fn check_ratio_fail() {
+    ratio_fail(kani::any());
+}
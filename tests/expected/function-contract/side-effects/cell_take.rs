@@ -0,0 +1,18 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that Kani rejects `Cell::take` in a contract condition, even though the pure
+//! `Iterator::take` has the same name.
+
+use std::cell::Cell;
+
+#[kani::requires(counter.take() < 100)]
+fn increment(x: u32, counter: &Cell<u32>) -> u32 {
+    x + 1
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    increment(kani::any(), &Cell::new(0));
+}
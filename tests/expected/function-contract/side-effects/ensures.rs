@@ -0,0 +1,31 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that Kani rejects `ensures` clauses that may have side effects.
+
+fn is_positive(x: &u32) -> bool {
+    println!("checking {x}");
+    *x > 0
+}
+
+#[kani::ensures(|result| is_positive(result))]
+fn increment(x: u32) -> u32 {
+    x.saturating_add(1)
+}
+
+#[kani::ensures(|result| { unsafe { *log = *result }; *result < x })]
+fn decrement(x: u32, log: *mut u32) -> u32 {
+    x - 1
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    increment(kani::any());
+}
+
+#[kani::proof_for_contract(decrement)]
+fn check_decrement() {
+    let mut log = 0;
+    decrement(kani::any_where(|x| *x > 0), &mut log);
+}
@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that Kani accepts the pure standard library functions in contract conditions.
+
+#[kani::requires(values.iter().take(2).all(|v| *v < 100) && !values.is_empty())]
+#[kani::ensures(|result| Some(result) == values.first())]
+fn first(values: &[u32]) -> &u32 {
+    &values[0]
+}
+
+#[kani::proof_for_contract(first)]
+fn check_first() {
+    let values: [u32; 3] = kani::any();
+    first(&values);
+}
@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that Kani rejects `requires` clauses that may have side effects.
+
+use std::cell::Cell;
+
+static mut CALLS: u32 = 0;
+
+fn count_call(x: u32) -> bool {
+    unsafe { CALLS += 1 };
+    x > 0
+}
+
+#[kani::requires(count_call(x))]
+fn decrement(x: u32) -> u32 {
+    x - 1
+}
+
+#[kani::requires({ counter.set(counter.get() + 1); x < 100 })]
+fn increment(x: u32, counter: &Cell<u32>) -> u32 {
+    x + 1
+}
+
+#[kani::proof_for_contract(decrement)]
+fn check_decrement() {
+    decrement(kani::any());
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    increment(kani::any(), &Cell::new(0));
+}
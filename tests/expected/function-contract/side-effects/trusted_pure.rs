@@ -0,0 +1,26 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that helpers marked with `#[kani::trusted_pure]` can be called from contract
+//! conditions, as well as the pure standard library functions, and reading a `Cell`.
+
+use std::cell::Cell;
+
+#[kani::trusted_pure]
+fn is_small(x: u32) -> bool {
+    x < 100
+}
+
+#[kani::requires(is_small(x) && counter.get() < 10)]
+#[kani::ensures(|result| is_small(*result - 1) && values.iter().all(|v| *v <= *result))]
+fn increment(x: u32, counter: &Cell<u32>, values: &[u32]) -> u32 {
+    let _ = counter.get();
+    values.iter().fold(x, |max, v| max.max(*v)) + 1
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    let values: [u32; 2] = [kani::any_where(|v| *v < 50), kani::any_where(|v| *v < 50)];
+    increment(kani::any(), &Cell::new(kani::any()), &values);
+}
@@ -0,0 +1,55 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that Kani rejects calls to `PartialEq::ne` and `Clone::clone` in a contract condition
+//! when the implementation that is called has side effects, even though the implementations of
+//! the standard library are known to be pure. The provided `ne` method calls the `eq` method
+//! implemented below.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static COMPARISONS: AtomicUsize = AtomicUsize::new(0);
+static mut CLONES: usize = 0;
+
+struct Tracked(u32);
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Self) -> bool {
+        COMPARISONS.fetch_add(1, Ordering::Relaxed);
+        self.0 == other.0
+    }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        unsafe { CLONES += 1 };
+        Tracked(self.0)
+    }
+}
+
+/// The derived implementations don't have side effects, so they are accepted.
+#[derive(Clone, PartialEq)]
+struct Plain(u32);
+
+#[kani::requires(*value != Tracked(0))]
+#[kani::ensures(|result| *result == value.clone().0)]
+fn unwrap_tracked(value: &Tracked) -> u32 {
+    value.0
+}
+
+#[kani::requires(*value != Plain(0))]
+#[kani::ensures(|result| *result == value.clone().0)]
+fn unwrap_plain(value: &Plain) -> u32 {
+    value.0
+}
+
+#[kani::proof_for_contract(unwrap_tracked)]
+fn check_unwrap_tracked() {
+    unwrap_tracked(&Tracked(kani::any()));
+}
+
+#[kani::proof_for_contract(unwrap_plain)]
+fn check_unwrap_plain() {
+    unwrap_plain(&Plain(kani::any()));
+}
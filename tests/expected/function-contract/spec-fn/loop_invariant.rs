@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z loop-contracts
+
+//! Check that specification functions can be called from loop invariants, and from regular code
+//! when their result is only used by `kani::assert` or `kani::cover`.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+#[kani::spec_fn]
+fn in_bounds(i: u8, max: u8) -> bool {
+    i <= max
+}
+
+fn count_up(max: u8) -> u8 {
+    let mut i: u8 = 0;
+    #[kani::loop_invariant(in_bounds(i, max))]
+    while i < max {
+        i += 1;
+    }
+    let done = in_bounds(i, max);
+    kani::assert(done, "the counter stays in bounds");
+    kani::cover(!in_bounds(i, 10), "the counter goes past 10");
+    i
+}
+
+#[kani::proof]
+fn check_count_up() {
+    let max: u8 = kani::any();
+    assert_eq!(count_up(max), max);
+}
@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that Kani rejects calls to specification functions from regular code, and checks the
+//! calls made by specification functions that are used in contract conditions.
+
+#[kani::spec_fn]
+fn is_even(x: u32) -> bool {
+    x % 2 == 0
+}
+
+#[kani::spec_fn]
+fn is_logged(x: u32) -> bool {
+    println!("checking {x}");
+    x > 0
+}
+
+fn halve(x: u32) -> u32 {
+    if is_even(x) { x / 2 } else { x / 2 + 1 }
+}
+
+#[kani::requires(is_logged(x))]
+fn decrement(x: u32) -> u32 {
+    x - 1
+}
+
+#[kani::proof]
+fn check_halve() {
+    assert!(halve(kani::any()) <= u32::MAX / 2 + 1);
+}
+
+#[kani::proof_for_contract(decrement)]
+fn check_decrement() {
+    decrement(kani::any());
+}
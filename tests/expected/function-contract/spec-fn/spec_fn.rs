@@ -0,0 +1,46 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that specification functions can be called from contract conditions, harnesses and
+//! other specification functions.
+
+#[kani::spec_fn]
+fn is_sorted(values: &[u8]) -> bool {
+    (1..values.len()).all(|i| values[i - 1] <= values[i])
+}
+
+#[kani::spec_fn]
+fn is_sorted_pair(pair: &(u8, u8)) -> bool {
+    is_sorted(&[pair.0, pair.1])
+}
+
+#[kani::ensures(|result| is_sorted_pair(result))]
+fn sort_pair(a: u8, b: u8) -> (u8, u8) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+#[kani::requires(is_sorted(values))]
+#[kani::ensures(|result| result.is_some() == !values.is_empty())]
+fn min(values: &[u8]) -> Option<u8> {
+    values.iter().copied().min()
+}
+
+#[kani::proof_for_contract(sort_pair)]
+fn check_sort_pair() {
+    sort_pair(kani::any(), kani::any());
+}
+
+#[kani::proof_for_contract(min)]
+#[kani::unwind(4)]
+fn check_min() {
+    let values: [u8; 3] = kani::any();
+    min(&values);
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_harness() {
+    let (a, b) = sort_pair(kani::any(), kani::any());
+    kani::assert(is_sorted(&[a, b]), "sort_pair sorts");
+}
@@ -0,0 +1,68 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that contract harnesses can target inherent and trait methods with the different kinds
+//! of paths that name them.
+
+trait Scale {
+    fn scale(&self, factor: u32) -> u32;
+}
+
+trait Shift {
+    fn shift(&self, offset: u32) -> u32;
+}
+
+struct Counter {
+    value: u32,
+}
+
+type Alias = Counter;
+
+impl Counter {
+    #[kani::requires(self.value < u32::MAX)]
+    #[kani::ensures(|result| *result == self.value + 1)]
+    fn next(&self) -> u32 {
+        self.value + 1
+    }
+}
+
+impl Scale for Counter {
+    #[kani::requires(self.value.checked_mul(factor).is_some())]
+    #[kani::ensures(|result| factor == 0 || *result >= self.value)]
+    fn scale(&self, factor: u32) -> u32 {
+        self.value * factor
+    }
+}
+
+impl Shift for Counter {
+    #[kani::requires(self.value.checked_add(offset).is_some())]
+    #[kani::ensures(|result| *result - offset == self.value)]
+    fn shift(&self, offset: u32) -> u32 {
+        self.value + offset
+    }
+}
+
+#[kani::proof_for_contract(Counter::next)]
+fn check_inherent() {
+    let counter = Counter { value: kani::any() };
+    let _ = counter.next();
+}
+
+#[kani::proof_for_contract(Alias::next)]
+fn check_inherent_alias() {
+    let counter = Counter { value: kani::any() };
+    let _ = counter.next();
+}
+
+#[kani::proof_for_contract(Counter::scale)]
+fn check_trait_method() {
+    let counter = Counter { value: kani::any() };
+    let _ = counter.scale(kani::any());
+}
+
+#[kani::proof_for_contract(<Counter as Shift>::shift)]
+fn check_qualified_trait_method() {
+    let counter = Counter { value: kani::any() };
+    let _ = counter.shift(kani::any());
+}
@@ -0,0 +1,25 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani flags the UB of `to_int_unchecked` on values that a
+//! saturating `as` cast would silently clamp. `i32::MAX as f32` rounds up to
+//! 2147483648.0, which is out of range of `i32`.
+
+#[kani::proof]
+fn check_i32_max_rounding() {
+    let f = i32::MAX as f32;
+    let _i: i32 = unsafe { f.to_int_unchecked() };
+}
+
+#[kani::proof]
+fn check_nan_f64() {
+    let f: f64 = kani::any_where(|v: &f64| v.is_nan());
+    let _i: i32 = unsafe { f.to_int_unchecked() };
+}
+
+#[kani::proof]
+fn check_in_range() {
+    let f: f64 = kani::any_where(|v: &f64| *v > -2147483649.0 && *v < 2147483648.0);
+    let i: i32 = unsafe { f.to_int_unchecked() };
+    assert_eq!(i, f as i32);
+}
@@ -0,0 +1,37 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: -Z loop-contracts
+
+//! Check that Kani rejects loop invariants that may have side effects.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+use std::cell::Cell;
+
+fn in_bounds(i: u8) -> bool {
+    println!("checking {i}");
+    i <= 100
+}
+
+#[kani::proof]
+fn non_pure_call() {
+    let mut i: u8 = 0;
+    #[kani::loop_invariant(in_bounds(i))]
+    while i < 100 {
+        i += 1;
+    }
+    assert!(i == 100);
+}
+
+#[kani::proof]
+fn interior_mutability() {
+    let iterations = Cell::new(0u8);
+    let mut i: u8 = 0;
+    #[kani::loop_invariant(iterations.replace(i) <= i && i <= 100)]
+    while i < 100 {
+        i += 1;
+    }
+    assert!(i == 100);
+}
@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `#[kani::loop_unwind]` bounds each loop independently of the harness unwind value.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+fn sum_and_count(data: &[u8; 10], limit: u8) -> (u32, u8) {
+    let mut sum = 0;
+    #[kani::loop_unwind(11)]
+    for elem in data {
+        sum += *elem as u32;
+    }
+
+    let mut count = 0;
+    #[kani::loop_unwind(4)]
+    while count < limit {
+        count += 1;
+    }
+    (sum, count)
+}
+
+#[kani::proof]
+#[kani::unwind(2)]
+fn check_per_loop_bound() {
+    let data = [1; 10];
+    let limit: u8 = kani::any_where(|l| *l <= 3);
+    let (sum, count) = sum_and_count(&data, limit);
+    assert_eq!(sum, 10);
+    assert_eq!(count, limit);
+}
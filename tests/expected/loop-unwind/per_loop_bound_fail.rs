@@ -0,0 +1,26 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that an insufficient `#[kani::loop_unwind]` bound only affects the annotated loop.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_insufficient_loop_bound() {
+    let mut first = 0;
+    while first < 5 {
+        first += 1;
+    }
+
+    let mut second = 0;
+    #[kani::loop_unwind(3)]
+    loop {
+        second += 1;
+        if second == 5 {
+            break;
+        }
+    }
+    assert_eq!(first, second);
+}
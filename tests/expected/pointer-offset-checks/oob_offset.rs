@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: -Z unstable-options --property-class pointer_offset
+
+//! Check that computing an out-of-bounds pointer is reported as a pointer offset violation,
+//! even if the pointer is never dereferenced, when the `pointer_offset` class is selected.
+
+#[kani::proof]
+fn check_oob_offset() {
+    let array = [0u32; 4];
+    let idx: usize = kani::any_where(|i| *i <= 5);
+    let base_ptr = array.as_ptr();
+    // SAFETY: This is UB if `idx > 4`.
+    let ptr = unsafe { base_ptr.add(idx) };
+    assert!(ptr.addr() >= base_ptr.addr());
+}
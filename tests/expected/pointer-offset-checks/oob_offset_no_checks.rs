@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: --no-pointer-offset-checks
+
+//! Check that pointer offset checks can be turned off.
+
+#[kani::proof]
+fn check_oob_offset_unchecked() {
+    let array = [0u32; 4];
+    let idx: usize = kani::any_where(|i| *i <= 5);
+    let base_ptr = array.as_ptr();
+    // SAFETY: This is UB if `idx > 4`, but we don't check for it.
+    let ptr = unsafe { base_ptr.add(idx) };
+    kani::cover!(idx == 5);
+    let _ = ptr;
+}
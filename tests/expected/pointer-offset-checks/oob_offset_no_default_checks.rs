@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: --no-default-checks
+
+//! Check that pointer offset checks are still performed with `--no-default-checks`, and that
+//! they are reported as safety checks when the `pointer_offset` class isn't selected.
+
+#[kani::proof]
+fn check_oob_offset() {
+    let array = [0u32; 4];
+    let idx: usize = kani::any_where(|i| *i <= 5);
+    let base_ptr = array.as_ptr();
+    // SAFETY: This is UB if `idx > 4`.
+    let ptr = unsafe { base_ptr.add(idx) };
+    assert!(ptr.addr() >= base_ptr.addr());
+}
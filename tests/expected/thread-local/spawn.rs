@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani reports the harnesses whose spawned threads access thread-local variables,
+//! since each thread would need its own copy of them.
+
+use std::cell::Cell;
+use std::thread;
+
+thread_local! {
+    static DEPTH: Cell<u8> = const { Cell::new(0) };
+}
+
+fn enter() -> u8 {
+    DEPTH.with(|depth| {
+        depth.set(depth.get() + 1);
+        depth.get()
+    })
+}
+
+#[kani::proof]
+fn check_single_thread() {
+    assert_eq!(enter(), 1);
+    assert_eq!(enter(), 2);
+}
+
+#[kani::proof]
+fn check_spawned_thread() {
+    assert_eq!(enter(), 1);
+    let depth = thread::spawn(enter).join().unwrap();
+    // Each thread starts with its own copy of `DEPTH`.
+    assert_eq!(depth, 1);
+}
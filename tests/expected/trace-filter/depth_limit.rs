@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --trace-filter --trace-depth-limit 3
+
+//! Check that `--trace-depth-limit` only prints the last steps of the trace, and that it says how
+//! many steps were omitted.
+
+#[kani::proof]
+#[kani::unwind(11)]
+fn check_sum() {
+    let mut sum: u32 = 0;
+    for step in 0..10 {
+        sum += step;
+    }
+    let value: u32 = kani::any();
+    assert!(value != sum);
+}
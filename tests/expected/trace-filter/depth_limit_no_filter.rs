@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --trace-depth-limit 3
+
+//! Check that `--trace-depth-limit` prints the truncated traces without `--trace-filter`.
+
+#[kani::proof]
+#[kani::unwind(11)]
+fn check_sum() {
+    let mut sum: u32 = 0;
+    for step in 0..10 {
+        sum += step;
+    }
+    let value: u32 = kani::any();
+    assert!(value != sum);
+}
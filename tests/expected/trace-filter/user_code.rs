@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --trace-filter
+
+//! Check that the trace of a failed check collapses the steps in the standard library, and
+//! that it always ends with the failing step.
+
+#[kani::proof]
+fn check_vec_value() {
+    let value: u8 = kani::any();
+    let mut values = Vec::new();
+    values.push(value);
+    let first = values[0];
+    assert!(first != 10);
+}
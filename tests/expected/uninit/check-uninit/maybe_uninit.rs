@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --check-uninit --property-class uninitialized_memory
+
+//! Check that `--check-uninit` reports reads of uninitialized memory under their own property
+//! class when it is selected with `--property-class`.
+
+use std::mem::MaybeUninit;
+
+/// Only the first byte of the buffer is written before the second one is read.
+#[kani::proof]
+fn check_partial_init() {
+    let mut buf = MaybeUninit::<[u8; 4]>::uninit();
+    let ptr = buf.as_mut_ptr() as *mut u8;
+    unsafe {
+        ptr.write(1);
+        let _second = *ptr.add(1);
+    }
+}
+
+#[kani::proof]
+fn check_full_init() {
+    let mut buf = MaybeUninit::<[u8; 4]>::uninit();
+    let ptr = buf.as_mut_ptr() as *mut u8;
+    for i in 0..4 {
+        unsafe { ptr.add(i).write(i as u8) };
+    }
+    let buf = unsafe { buf.assume_init() };
+    assert_eq!(buf[3], 3);
+}
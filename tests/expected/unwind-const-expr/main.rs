@@ -0,0 +1,40 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that the unwind value can be a constant expression, so that the bound follows the
+//! constants it depends on.
+
+const BUF_SIZE: u32 = 4;
+
+struct Buffer;
+
+impl Buffer {
+    const CAPACITY: u32 = BUF_SIZE * 2;
+}
+
+/// A loop with `len` iterations, which needs an unwind value of `len + 1`.
+fn fill(len: u32) -> u32 {
+    let mut filled = 0;
+    while filled < len {
+        filled += 1;
+    }
+    filled
+}
+
+#[kani::proof]
+#[kani::unwind(BUF_SIZE + 1)]
+fn check_fill() {
+    assert_eq!(fill(BUF_SIZE), BUF_SIZE);
+}
+
+#[kani::proof]
+#[kani::unwind(Buffer::CAPACITY + 1)]
+fn check_fill_capacity() {
+    assert_eq!(fill(Buffer::CAPACITY), Buffer::CAPACITY);
+}
+
+#[kani::proof]
+#[kani::unwind(BUF_SIZE)]
+fn check_fill_too_small() {
+    assert_eq!(fill(BUF_SIZE), BUF_SIZE);
+}
@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z verify-unsafe
+//
+//! Check that `#[kani::verify_unsafe]` reports the arguments that don't implement `Arbitrary`.
+
+#[kani::verify_unsafe]
+unsafe fn deref(ptr: *const u8) -> u8 {
+    unsafe { *ptr }
+}
@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z verify-unsafe
+//
+//! Check that `#[kani::verify_unsafe]` is rejected on safe functions.
+
+#[kani::verify_unsafe]
+fn safe_fn(x: u8) -> u8 {
+    x
+}
@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z verify-unsafe
+//
+//! Check that the extra undefined behavior checks of `#[kani::verify_unsafe]` only apply to the
+//! generated harnesses, and not to the other harnesses of the crate.
+
+use std::mem::MaybeUninit;
+
+/// Reads uninitialized memory when `value` is zero.
+#[kani::verify_unsafe]
+unsafe fn read_maybe_init(value: u8) -> u8 {
+    let mut buf = MaybeUninit::<u8>::uninit();
+    if value != 0 {
+        buf.write(value);
+    }
+    unsafe { buf.assume_init() }
+}
+
+/// The same code in a regular harness isn't checked for uninitialized memory.
+#[kani::proof]
+fn regular_harness() {
+    let value: u8 = kani::any();
+    let mut buf = MaybeUninit::<u8>::uninit();
+    if value != 0 {
+        buf.write(value);
+    }
+    let _ = unsafe { buf.assume_init() };
+}
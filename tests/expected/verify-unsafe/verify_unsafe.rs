@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z verify-unsafe -Z function-contracts
+//
+//! Check that Kani generates a harness for every function annotated with
+//! `#[kani::verify_unsafe]`, and that the harnesses detect undefined behavior.
+
+use std::mem::MaybeUninit;
+
+/// Has an off-by-one error that reads past the end of the array.
+#[kani::verify_unsafe]
+unsafe fn read_unchecked(arr: [u8; 4], idx: usize) -> u8 {
+    if idx <= arr.len() { unsafe { *arr.as_ptr().add(idx) } } else { 0 }
+}
+
+/// Reads uninitialized memory when `value` is zero, which is detected using shadow memory.
+#[kani::verify_unsafe]
+unsafe fn read_maybe_init(value: u8) -> u8 {
+    let mut buf = MaybeUninit::<u8>::uninit();
+    if value != 0 {
+        buf.write(value);
+    }
+    unsafe { buf.assume_init() }
+}
+
+/// The contract rules out the inputs that would cause undefined behavior.
+#[kani::verify_unsafe]
+#[kani::requires(idx < arr.len())]
+#[kani::ensures(|result: &u8| *result == arr[idx])]
+unsafe fn get_with_contract(arr: [u8; 4], idx: usize) -> u8 {
+    unsafe { *arr.get_unchecked(idx) }
+}
+
+#[kani::verify_unsafe(unwind = 5, solver = "minisat")]
+unsafe fn sum_bytes(arr: [u8; 4]) -> u32 {
+    let mut sum = 0u32;
+    let ptr = arr.as_ptr();
+    for idx in 0..arr.len() {
+        sum += unsafe { *ptr.add(idx) } as u32;
+    }
+    sum
+}
@@ -0,0 +1,59 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the blocks handed out by a buddy allocator satisfy the requested
+//! alignment for all symbolic allocation sizes and alignments, given an arena
+//! generated by `kani::any_aligned_addr`.
+
+/// The arena spans `2^ARENA_ORDER` bytes.
+const ARENA_ORDER: u32 = 20;
+const ARENA_SIZE: usize = 1 << ARENA_ORDER;
+const MIN_BLOCK_ORDER: u32 = 4;
+
+/// The order of the smallest block that can hold `size` bytes aligned to `align`.
+fn block_order(size: usize, align: usize) -> u32 {
+    size.max(align).next_power_of_two().trailing_zeros().max(MIN_BLOCK_ORDER)
+}
+
+/// The address of the `index`-th block of the given order.
+fn block_addr(base: usize, order: u32, index: usize) -> usize {
+    base + (index << order)
+}
+
+/// The address of the buddy of the block at `addr`.
+fn buddy_addr(base: usize, order: u32, addr: usize) -> usize {
+    base + ((addr - base) ^ (1 << order))
+}
+
+#[kani::proof]
+fn check_block_alignment() {
+    let base = kani::any_aligned_addr(ARENA_SIZE);
+    kani::assume(base <= usize::MAX - ARENA_SIZE);
+    let size: usize = kani::any_where(|size| *size > 0 && *size <= ARENA_SIZE);
+    let align_shift: u32 = kani::any_where(|shift| *shift <= 12);
+    let align = 1usize << align_shift;
+
+    let order = block_order(size, align);
+    kani::assume(order <= ARENA_ORDER);
+    let index: usize = kani::any_where(|index| *index < 1 << (ARENA_ORDER - order));
+    let addr = block_addr(base, order, index);
+
+    assert_eq!(addr % align, 0);
+    assert!(1 << order >= size);
+    assert!(addr - base + (1 << order) <= ARENA_SIZE);
+
+    let buddy = buddy_addr(base, order, addr);
+    assert_eq!(buddy % (1 << order), 0);
+    assert_eq!(buddy_addr(base, order, buddy), addr);
+}
+
+#[kani::proof]
+fn check_page_aligned_arena() {
+    let base = kani::any_page_aligned_addr();
+    assert_eq!(base % kani::addr::PAGE_SIZE, 0);
+    // Blocks of at least one page are page aligned when the arena is.
+    let order: u32 = kani::any_where(|order| *order >= 12 && *order <= ARENA_ORDER);
+    let index: usize = kani::any_where(|index| *index < 1 << (ARENA_ORDER - order));
+    kani::assume(base <= usize::MAX - ARENA_SIZE);
+    assert_eq!(block_addr(base, order, index) % kani::addr::PAGE_SIZE, 0);
+}
@@ -0,0 +1,81 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a page table mapping function never maps an address that is not on a
+//! page boundary, and that translated addresses stay within the mapped frame.
+
+use kani::addr::PAGE_SIZE;
+
+const PRESENT: usize = 1;
+const FLAGS_MASK: usize = PAGE_SIZE - 1;
+const NUM_ENTRIES: usize = 8;
+
+#[derive(Debug, PartialEq)]
+enum MapError {
+    Unaligned,
+    OutOfRange,
+}
+
+struct PageTable {
+    entries: [usize; NUM_ENTRIES],
+}
+
+impl PageTable {
+    fn new() -> Self {
+        PageTable { entries: [0; NUM_ENTRIES] }
+    }
+
+    fn map(&mut self, vaddr: usize, paddr: usize) -> Result<(), MapError> {
+        if vaddr % PAGE_SIZE != 0 || paddr % PAGE_SIZE != 0 {
+            return Err(MapError::Unaligned);
+        }
+        let index = vaddr / PAGE_SIZE;
+        if index >= NUM_ENTRIES {
+            return Err(MapError::OutOfRange);
+        }
+        self.entries[index] = paddr | PRESENT;
+        Ok(())
+    }
+
+    fn translate(&self, vaddr: usize) -> Option<usize> {
+        let entry = *self.entries.get(vaddr / PAGE_SIZE)?;
+        (entry & PRESENT != 0).then_some((entry & !FLAGS_MASK) + vaddr % PAGE_SIZE)
+    }
+}
+
+#[kani::proof]
+fn check_map_aligned() {
+    let mut table = PageTable::new();
+    let vaddr = kani::any_page_aligned_addr();
+    let paddr = kani::any_page_aligned_addr();
+    kani::assume(vaddr < NUM_ENTRIES * PAGE_SIZE);
+    assert_eq!(table.map(vaddr, paddr), Ok(()));
+    assert_eq!(table.translate(vaddr), Some(paddr));
+}
+
+#[kani::proof]
+fn check_map_never_unaligned() {
+    let mut table = PageTable::new();
+    let vaddr: usize = kani::any();
+    let paddr: usize = kani::any();
+    if table.map(vaddr, paddr).is_ok() {
+        assert_eq!(vaddr % PAGE_SIZE, 0);
+        assert_eq!(paddr % PAGE_SIZE, 0);
+    }
+    for entry in table.entries {
+        assert_eq!(entry & FLAGS_MASK & !PRESENT, 0);
+    }
+}
+
+#[kani::proof]
+fn check_translate_within_frame() {
+    let mut table = PageTable::new();
+    let page = kani::any_page_aligned_addr();
+    let frame = kani::any_page_aligned_addr();
+    kani::assume(page < NUM_ENTRIES * PAGE_SIZE);
+    table.map(page, frame).unwrap();
+    let offset: usize = kani::any_where(|offset| *offset < PAGE_SIZE);
+    let paddr = table.translate(page + offset).unwrap();
+    assert_eq!(paddr & !FLAGS_MASK, frame);
+    assert_eq!(paddr % PAGE_SIZE, offset);
+}
@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_in_range` and `kani::any_in_range_inclusive` generate all the values
+//! in the range, and only those.
+
+#[kani::proof]
+fn check_exclusive() {
+    let value: i8 = kani::any_in_range(-3, 5);
+    assert!(value >= -3 && value < 5);
+    kani::cover!(value == -3);
+    kani::cover!(value == 4);
+}
+
+#[kani::proof]
+fn check_inclusive() {
+    let value: u64 = kani::any_in_range_inclusive(10, u64::MAX);
+    assert!(value >= 10);
+    kani::cover!(value == u64::MAX);
+}
+
+#[kani::proof]
+fn check_singleton() {
+    let lo: u32 = kani::any();
+    assert_eq!(kani::any_in_range_inclusive(lo, lo), lo);
+    if lo < u32::MAX {
+        assert_eq!(kani::any_in_range(lo, lo + 1), lo);
+    }
+}
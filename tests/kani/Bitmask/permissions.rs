@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a permission check based on bitmasks grants access exactly to the
+//! symbolic masks generated by `kani::any_bitmask` and `kani::any_bitmask_at_most`
+//! that contain all the required permissions.
+
+const READ: u64 = 1 << 0;
+const WRITE: u64 = 1 << 1;
+const EXECUTE: u64 = 1 << 2;
+const ADMIN: u64 = 1 << 63;
+
+fn has_permissions(permissions: u64, required: u64) -> bool {
+    permissions & required == required
+}
+
+#[kani::proof]
+fn check_exact_bitmask() {
+    let permissions = kani::any_bitmask(2);
+    assert_eq!(permissions.count_ones(), 2);
+    // With only two bits set, read and write access is granted by a single mask.
+    assert_eq!(has_permissions(permissions, READ | WRITE), permissions == READ | WRITE);
+    kani::cover!(has_permissions(permissions, READ | ADMIN));
+    kani::cover!(!has_permissions(permissions, READ));
+    assert!(!has_permissions(permissions, READ | WRITE | EXECUTE));
+}
+
+#[kani::proof]
+fn check_bitmask_at_most() {
+    let permissions = kani::any_bitmask_at_most(1);
+    assert!(permissions.count_ones() <= 1);
+    assert!(!has_permissions(permissions, READ | WRITE));
+    assert_eq!(has_permissions(permissions, EXECUTE), permissions == EXECUTE);
+    kani::cover!(permissions == 0);
+}
+
+#[kani::proof]
+fn check_full_bitmask() {
+    let permissions = kani::any_bitmask(64);
+    assert_eq!(permissions, u64::MAX);
+    assert!(has_permissions(permissions, READ | WRITE | EXECUTE | ADMIN));
+    assert!(has_permissions(permissions, kani::any()));
+}
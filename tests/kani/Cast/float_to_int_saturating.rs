@@ -0,0 +1,65 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that float to integer `as` casts are modeled with Rust's saturating
+//! semantics, including the boundary values that are rounded when converted
+//! to a float.
+
+#[kani::proof]
+fn check_nan_is_zero() {
+    let f: f32 = kani::any_where(|v: &f32| v.is_nan());
+    assert_eq!(f as i32, 0);
+    assert_eq!(f as u8, 0);
+    assert_eq!(f64::NAN as i128, 0);
+}
+
+#[kani::proof]
+fn check_infinities_saturate() {
+    assert_eq!(f32::INFINITY as i32, i32::MAX);
+    assert_eq!(f32::NEG_INFINITY as i32, i32::MIN);
+    assert_eq!(f64::INFINITY as u64, u64::MAX);
+    assert_eq!(f64::NEG_INFINITY as u64, 0);
+}
+
+#[kani::proof]
+fn check_i32_max_rounding() {
+    // `i32::MAX` is not representable as `f32` and rounds up to 2^31.
+    let f = i32::MAX as f32;
+    assert_eq!(f, 2147483648.0);
+    assert_eq!(f as i32, i32::MAX);
+    assert_eq!(f as i64, 2147483648);
+    // The largest `f32` that fits in an `i32`.
+    assert_eq!(2147483520.0f32 as i32, 2147483520);
+    // `i32::MIN` is exactly representable.
+    assert_eq!(i32::MIN as f32 as i32, i32::MIN);
+}
+
+#[kani::proof]
+fn check_f32_to_i32_symbolic() {
+    let f: f32 = kani::any();
+    let i = f as i32;
+    if f.is_nan() {
+        assert_eq!(i, 0);
+    } else if f >= 2147483648.0 {
+        assert_eq!(i, i32::MAX);
+    } else if f < -2147483648.0 {
+        assert_eq!(i, i32::MIN);
+    } else {
+        assert_eq!(i, unsafe { f.to_int_unchecked::<i32>() });
+    }
+}
+
+#[kani::proof]
+fn check_f64_to_u8_symbolic() {
+    let f: f64 = kani::any();
+    let u = f as u8;
+    if f <= -1.0 {
+        assert_eq!(u, 0);
+    } else if f >= 256.0 {
+        assert_eq!(u, u8::MAX);
+    } else if f.is_finite() {
+        // Values in (-1.0, 0.0) truncate to zero.
+        assert_eq!(u, f.trunc() as u8);
+        assert!(u as f64 <= f.max(0.0));
+    }
+}
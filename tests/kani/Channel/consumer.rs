@@ -0,0 +1,57 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a consumer that processes the messages of a channel until it is closed processes
+//! every message generated by `kani::any_channel_sequence` exactly once.
+
+/// A receiver that replays a sequence of results of `recv`.
+struct Receiver {
+    results: std::vec::IntoIter<Option<u8>>,
+}
+
+impl Receiver {
+    /// Like `tokio::sync::mpsc::Receiver::recv`, return `None` once the channel is closed. We
+    /// also consider the channel closed when the sequence ends.
+    fn recv(&mut self) -> Option<u8> {
+        self.results.next().flatten()
+    }
+}
+
+/// The consumer under verification.
+fn consume(receiver: &mut Receiver, processed: &mut Vec<u8>) {
+    while let Some(message) = receiver.recv() {
+        processed.push(message);
+    }
+}
+
+#[kani::proof]
+fn check_channel_message() {
+    let message = kani::any_channel_message::<u8>();
+    kani::cover!(message.is_none());
+    kani::cover!(message == Some(42));
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_channel_sequence() {
+    let results = kani::any_channel_sequence::<u8>(3);
+    assert!(results.len() <= 3);
+    // The channel can only be closed by the last result.
+    assert!(results.iter().rev().skip(1).all(Option::is_some));
+    kani::cover!(results.len() == 3 && results[2].is_none());
+    kani::cover!(results.len() == 3 && results[2].is_some());
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_consumer_processes_every_message_once() {
+    let results = kani::any_channel_sequence::<u8>(3);
+    let sent: Vec<u8> = results.iter().flatten().copied().collect();
+    let mut receiver = Receiver { results: results.into_iter() };
+    let mut processed = Vec::new();
+    consume(&mut receiver, &mut processed);
+    // No message was dropped or processed twice.
+    assert_eq!(processed, sent);
+    // Nothing is received after the channel is closed.
+    assert!(receiver.recv().is_none());
+}
@@ -0,0 +1,62 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check derived and hand-written `Clone` implementations with `kani::check_clone_eq`.
+
+#[derive(Clone, PartialEq, kani::Arbitrary)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Clone, PartialEq, kani::Arbitrary)]
+enum Shape {
+    Dot(Point),
+    Segment(Point, Point),
+    Empty,
+}
+
+/// A fixed-capacity buffer that only compares, and only clones, its used elements.
+#[derive(kani::Arbitrary)]
+struct Buffer {
+    len: u8,
+    data: [u8; 4],
+}
+
+impl Clone for Buffer {
+    fn clone(&self) -> Self {
+        let mut data = [0; 4];
+        let len = usize::from(self.len).min(data.len());
+        data[..len].copy_from_slice(&self.data[..len]);
+        Buffer { len: self.len, data }
+    }
+}
+
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Self) -> bool {
+        let len = usize::from(self.len).min(self.data.len());
+        self.len == other.len && self.data[..len] == other.data[..len]
+    }
+}
+
+#[kani::proof]
+fn check_point_clone() {
+    kani::check_clone_eq::<Point>();
+}
+
+#[kani::proof]
+fn check_shape_clone() {
+    kani::check_clone_eq::<Shape>();
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_buffer_clone() {
+    kani::check_clone_eq::<Buffer>();
+}
+
+#[kani::proof]
+fn check_primitive_clone() {
+    kani::check_clone_eq::<(u8, bool, char)>();
+    kani::check_clone_eq::<Option<u64>>();
+}
@@ -0,0 +1,88 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that RGB to HSV and HSV to RGB conversions are approximate inverses
+//! for all symbolic colors generated by `kani::any_color_rgb` and
+//! `kani::any_color_hsv`.
+
+/// Tolerance of one 8-bit channel step.
+const TOLERANCE: f32 = 1.0 / 255.0;
+
+fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let r = r as f32 / 255.0;
+    let g = g as f32 / 255.0;
+    let b = b as f32 / 255.0;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        let h = 60.0 * ((g - b) / delta);
+        if h < 0.0 { h + 360.0 } else { h }
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+    (if h >= 360.0 { 0.0 } else { h }, s, max)
+}
+
+fn hsv_to_rgb_f32(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
+    let c = v * s;
+    let sector = h / 60.0;
+    let x = c * (1.0 - ((sector % 2.0) - 1.0).abs());
+    let m = v - c;
+    let (r, g, b) = match sector as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    (r + m, g + m, b + m)
+}
+
+fn to_channel(value: f32) -> u8 {
+    (value * 255.0 + 0.5) as u8
+}
+
+fn close(lhs: f32, rhs: f32) -> bool {
+    (lhs - rhs).abs() <= TOLERANCE
+}
+
+#[kani::proof]
+fn check_hsv_range() {
+    let (h, s, v) = kani::any_color_hsv();
+    assert!(h >= 0.0 && h < 360.0);
+    assert!(s >= 0.0 && s <= 1.0);
+    assert!(v >= 0.0 && v <= 1.0);
+}
+
+#[kani::proof]
+fn check_rgb_roundtrip() {
+    let (r, g, b) = kani::any_color_rgb();
+    let (h, s, v) = rgb_to_hsv(r, g, b);
+    assert!(h >= 0.0 && h < 360.0);
+    let (r2, g2, b2) = hsv_to_rgb_f32(h, s, v);
+    assert!(close(r2, r as f32 / 255.0));
+    assert!(close(g2, g as f32 / 255.0));
+    assert!(close(b2, b as f32 / 255.0));
+    assert_eq!((to_channel(r2), to_channel(g2), to_channel(b2)), (r, g, b));
+}
+
+#[kani::proof]
+fn check_hsv_roundtrip() {
+    let (h, s, v) = kani::any_color_hsv();
+    let (r, g, b) = hsv_to_rgb_f32(h, s, v);
+    let (r2, g2, b2) = hsv_to_rgb_f32_roundtrip(to_channel(r), to_channel(g), to_channel(b));
+    assert!(close(r2, r) && close(g2, g) && close(b2, b));
+}
+
+/// Convert the quantized color back to HSV and then to floating-point RGB again.
+fn hsv_to_rgb_f32_roundtrip(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (h, s, v) = rgb_to_hsv(r, g, b);
+    hsv_to_rgb_f32(h, s, v)
+}
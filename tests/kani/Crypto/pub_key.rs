@@ -0,0 +1,92 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a public key parser accepts the keys of the expected length generated by
+//! `kani::any_pub_key_bytes`, and rejects the keys of any other length.
+
+use kani::KeyAlgorithm;
+
+#[derive(Debug, PartialEq, Eq)]
+enum KeyError {
+    InvalidLength { expected: usize, found: usize },
+    UnsupportedEncoding(u8),
+}
+
+#[derive(Debug)]
+struct PublicKey<'a> {
+    algorithm: KeyAlgorithm,
+    bytes: &'a [u8],
+}
+
+/// The parser under verification.
+fn parse_pub_key(algorithm: KeyAlgorithm, bytes: &[u8]) -> Result<PublicKey<'_>, KeyError> {
+    let expected = match algorithm {
+        KeyAlgorithm::Ed25519 => 32,
+        KeyAlgorithm::P256 => 65,
+        KeyAlgorithm::RSA2048 => 256,
+    };
+    if bytes.len() != expected {
+        return Err(KeyError::InvalidLength { expected, found: bytes.len() });
+    }
+    if algorithm == KeyAlgorithm::P256 && bytes[0] != 0x04 {
+        return Err(KeyError::UnsupportedEncoding(bytes[0]));
+    }
+    Ok(PublicKey { algorithm, bytes })
+}
+
+fn any_algorithm() -> KeyAlgorithm {
+    match kani::any::<u8>() % 3 {
+        0 => KeyAlgorithm::Ed25519,
+        1 => KeyAlgorithm::P256,
+        _ => KeyAlgorithm::RSA2048,
+    }
+}
+
+#[kani::proof]
+fn check_accepts_expected_length() {
+    let algorithm = any_algorithm();
+    let bytes = kani::any_pub_key_bytes(algorithm);
+    assert_eq!(bytes.len(), algorithm.pub_key_len());
+    match parse_pub_key(algorithm, &bytes) {
+        Ok(key) => {
+            assert_eq!(key.algorithm, algorithm);
+            assert_eq!(key.bytes, &bytes[..]);
+        }
+        Err(error) => {
+            // Only the encoding of P-256 keys can be rejected.
+            assert_eq!(algorithm, KeyAlgorithm::P256);
+            assert_eq!(error, KeyError::UnsupportedEncoding(bytes[0]));
+        }
+    }
+    kani::cover!(algorithm == KeyAlgorithm::P256 && bytes[0] == 0x04);
+}
+
+#[kani::proof]
+fn check_rejects_truncated_key() {
+    let algorithm = any_algorithm();
+    let mut bytes = kani::any_pub_key_bytes(algorithm);
+    let len: usize = kani::any_where(|len| *len < algorithm.pub_key_len());
+    bytes.truncate(len);
+    let result = parse_pub_key(algorithm, &bytes);
+    assert_eq!(
+        result.unwrap_err(),
+        KeyError::InvalidLength { expected: algorithm.pub_key_len(), found: len }
+    );
+}
+
+#[kani::proof]
+fn check_rejects_key_of_other_algorithm() {
+    let algorithm = any_algorithm();
+    let other = any_algorithm();
+    kani::assume(algorithm != other);
+    let mut bytes = kani::any_pub_key_bytes(other);
+    // A trailing byte must be rejected as well.
+    if kani::any() {
+        bytes.push(kani::any());
+    }
+    assert!(matches!(
+        parse_pub_key(algorithm, &bytes),
+        Err(KeyError::InvalidLength { expected, found })
+            if expected == algorithm.pub_key_len() && found != expected
+    ));
+}
@@ -0,0 +1,48 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_lock_order` generates every acquisition order of the mutexes, and that
+//! always acquiring the mutexes in ascending index order prevents deadlocks.
+
+use kani::deadlock::may_deadlock;
+
+/// The lock-ordering discipline under verification.
+fn ascending(mut order: Vec<usize>) -> Vec<usize> {
+    order.sort_unstable();
+    order
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_lock_order_is_permutation() {
+    let order = kani::any_lock_order(3);
+    assert_eq!(order.len(), 3);
+    assert!((0..3).all(|mutex| order.contains(&mutex)));
+    kani::cover!(order == [2, 0, 1]);
+    kani::cover!(order == [1, 2, 0]);
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_unordered_locks_may_deadlock() {
+    let first = kani::any_lock_order(2);
+    let second = kani::any_lock_order(2);
+    kani::cover!(may_deadlock(&first, &second));
+    assert_eq!(may_deadlock(&first, &second), first != second);
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_ascending_order_prevents_deadlock_2() {
+    let first = ascending(kani::any_lock_order(2));
+    let second = ascending(kani::any_lock_order(2));
+    assert!(!may_deadlock(&first, &second));
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_ascending_order_prevents_deadlock_3() {
+    let first = ascending(kani::any_lock_order(3));
+    let second = ascending(kani::any_lock_order(3));
+    assert!(!may_deadlock(&first, &second));
+}
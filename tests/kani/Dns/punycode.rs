@@ -0,0 +1,97 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a Punycode encoder (RFC 3492) handles the symbolic ASCII labels generated by
+//! `kani::any_dns_label`.
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (BASE - TMIN + 1) * delta / (delta + SKEW)
+}
+
+fn encode_digit(digit: u32) -> char {
+    if digit < 26 { (b'a' + digit as u8) as char } else { (b'0' + (digit - 26) as u8) as char }
+}
+
+/// Encode `input` with Punycode, or return `None` on overflow.
+fn encode(input: &str) -> Option<String> {
+    let code_points: Vec<u32> = input.chars().map(u32::from).collect();
+    let mut output: String = input.chars().filter(char::is_ascii).collect();
+    let basic = output.len() as u32;
+    if basic > 0 {
+        output.push('-');
+    }
+    let (mut n, mut delta, mut bias, mut handled) = (INITIAL_N, 0u32, INITIAL_BIAS, basic);
+    while (handled as usize) < code_points.len() {
+        let next = code_points.iter().copied().filter(|c| *c >= n).min()?;
+        delta = delta.checked_add((next - n).checked_mul(handled + 1)?)?;
+        n = next;
+        for c in &code_points {
+            if *c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if *c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = (k.saturating_sub(bias)).clamp(TMIN, TMAX);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Some(output)
+}
+
+/// Convert a label to its ASCII form, as in IDNA: only labels with non-ASCII characters are
+/// encoded.
+fn label_to_ascii(label: &str) -> Option<String> {
+    if label.is_ascii() {
+        Some(label.to_string())
+    } else {
+        encode(label).map(|e| format!("xn--{e}"))
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(8)]
+fn check_ascii_label() {
+    let label = kani::any_dns_label(6);
+    let encoded = encode(&label).unwrap();
+    // Basic code points are copied, followed by the delimiter.
+    assert_eq!(encoded.len(), label.len() + 1);
+    assert!(encoded.starts_with(label.as_str()) && encoded.ends_with('-'));
+    assert_eq!(label_to_ascii(&label).as_deref(), Some(label.as_str()));
+}
+
+#[kani::proof]
+#[kani::unwind(8)]
+fn check_non_ascii_label() {
+    assert_eq!(encode("bücher").as_deref(), Some("bcher-kva"));
+    assert_eq!(label_to_ascii("bücher").as_deref(), Some("xn--bcher-kva"));
+}
@@ -0,0 +1,56 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a resolver accepts every domain name generated by `kani::any_fqdn`, and that
+//! `kani::any_dns_label` only generates valid labels.
+
+use std::net::Ipv4Addr;
+
+#[derive(Debug, PartialEq)]
+enum ResolveError {
+    InvalidName,
+    NotFound,
+}
+
+/// Whether `label` follows the preferred name syntax of RFC 1035.
+fn is_valid_label(label: &str) -> bool {
+    let bytes = label.as_bytes();
+    !bytes.is_empty()
+        && bytes.len() <= 63
+        && bytes.iter().all(|byte| byte.is_ascii_alphanumeric() || *byte == b'-')
+        && bytes[0] != b'-'
+        && bytes[bytes.len() - 1] != b'-'
+}
+
+/// A resolver with a static table, where names are case insensitive.
+fn resolve(name: &str) -> Result<Ipv4Addr, ResolveError> {
+    if name.len() > 253 || !name.split('.').all(is_valid_label) {
+        return Err(ResolveError::InvalidName);
+    }
+    if name.eq_ignore_ascii_case("localhost") {
+        Ok(Ipv4Addr::LOCALHOST)
+    } else if name.eq_ignore_ascii_case("example.com") {
+        Ok(Ipv4Addr::new(93, 184, 215, 14))
+    } else {
+        Err(ResolveError::NotFound)
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(7)]
+fn check_dns_label() {
+    let label = kani::any_dns_label(5);
+    assert!(is_valid_label(&label));
+    assert!(label.len() <= 5);
+    kani::cover!(label.len() == 3 && label.as_bytes()[1] == b'-');
+}
+
+#[kani::proof]
+#[kani::unwind(19)]
+fn check_resolver_accepts_fqdn() {
+    let name = kani::any_fqdn(2);
+    let result = resolve(&name);
+    assert_ne!(result, Err(ResolveError::InvalidName));
+    kani::cover!(result == Ok(Ipv4Addr::LOCALHOST));
+    kani::cover!(name == "Example.COM");
+}
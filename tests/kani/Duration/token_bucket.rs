@@ -0,0 +1,76 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check a token-bucket rate limiter for symbolic refill intervals and capacities, using
+//! `kani::any_duration_in_range` and `kani::any_duration_near`.
+
+use std::time::Duration;
+
+const MIN_INTERVAL: Duration = Duration::from_millis(10);
+const MAX_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A rate limiter that earns one token per refill interval, up to its capacity.
+struct TokenBucket {
+    capacity: u32,
+    tokens: u32,
+    refill_interval: Duration,
+    since_refill: Duration,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32, refill_interval: Duration) -> Self {
+        TokenBucket { capacity, tokens: capacity, refill_interval, since_refill: Duration::ZERO }
+    }
+
+    /// Refill the bucket with the tokens earned during `elapsed`, then try to take one token.
+    fn try_acquire(&mut self, elapsed: Duration) -> bool {
+        self.since_refill += elapsed;
+        while self.since_refill >= self.refill_interval {
+            self.since_refill -= self.refill_interval;
+            self.tokens = (self.tokens + 1).min(self.capacity);
+        }
+        if self.tokens > 0 {
+            self.tokens -= 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A full bucket allows a burst of `capacity` requests, and blocks the next one.
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_burst() {
+    let capacity = kani::any_where(|capacity: &u32| (1..=4).contains(capacity));
+    let interval = kani::any_duration_in_range(MIN_INTERVAL, MAX_INTERVAL);
+    let mut bucket = TokenBucket::new(capacity, interval);
+    for _ in 0..capacity {
+        assert!(bucket.try_acquire(Duration::ZERO));
+    }
+    assert!(!bucket.try_acquire(Duration::ZERO));
+}
+
+/// An empty bucket allows a new request if and only if a refill interval has elapsed.
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_refill() {
+    let interval = kani::any_duration_in_range(MIN_INTERVAL, MAX_INTERVAL);
+    let mut bucket = TokenBucket::new(1, interval);
+    assert!(bucket.try_acquire(Duration::ZERO));
+
+    let jitter = Duration::from_millis(5);
+    let elapsed = kani::any_duration_near(interval, jitter);
+    assert!(interval - jitter <= elapsed && elapsed <= interval + jitter);
+    assert_eq!(bucket.try_acquire(elapsed), elapsed >= interval);
+    kani::cover!(elapsed < interval);
+    kani::cover!(elapsed >= interval);
+}
+
+/// The range of `any_duration_near` saturates at zero.
+#[kani::proof]
+fn check_near_saturates() {
+    let duration = kani::any_duration_near(Duration::from_secs(1), Duration::from_secs(2));
+    assert!(duration <= Duration::from_secs(3));
+    kani::cover!(duration == Duration::ZERO);
+}
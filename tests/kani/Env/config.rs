@@ -0,0 +1,83 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z stubbing
+//
+//! Check that code that reads the environment can be verified with the symbolic environment
+//! variables generated by `kani::any_env_var` and the `kani::stubs::env_var` stub.
+
+use std::collections::BTreeMap;
+
+/// The configuration of a server, read from the environment.
+#[derive(Debug, PartialEq)]
+struct Config {
+    port: u16,
+    debug: bool,
+}
+
+/// The configuration parser under verification, which falls back to the defaults for the
+/// variables that are missing or invalid.
+fn parse_config() -> Config {
+    let port = std::env::var("PORT").ok().and_then(|port| port.parse().ok()).unwrap_or(80);
+    let debug = matches!(std::env::var("DBG").as_deref(), Ok("1") | Ok("true"));
+    Config { port, debug }
+}
+
+/// Load `KEY=VALUE` lines, like a `.env` file, where the environment overrides the values of the
+/// file.
+fn load_dotenv(file: &str) -> BTreeMap<String, String> {
+    file.lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, default)| {
+            let value = std::env::var(key).unwrap_or_else(|_| default.to_string());
+            (key.to_string(), value)
+        })
+        .collect()
+}
+
+#[kani::proof]
+#[kani::stub(std::env::var, kani::stubs::env_var)]
+#[kani::unwind(6)]
+fn check_parse_config() {
+    let (key, value) = kani::any_env_var();
+    assert!(!key.is_empty() && !key.contains('=') && !key.contains('\0'));
+    assert!(value.is_ascii() && !value.contains('\0'));
+    kani::setup_env(&[(key.clone(), value.clone()), kani::any_env_var()]);
+    let config = parse_config();
+    if key == "DBG" && value == "1" {
+        assert!(config.debug);
+    }
+    kani::cover!(config.port != 80);
+    kani::cover!(config.debug);
+}
+
+#[kani::proof]
+#[kani::stub(std::env::var, kani::stubs::env_var)]
+#[kani::unwind(6)]
+fn check_parse_config_defaults() {
+    kani::setup_env(&[]);
+    assert_eq!(parse_config(), Config { port: 80, debug: false });
+}
+
+#[kani::proof]
+#[kani::stub(std::env::var, kani::stubs::env_var)]
+#[kani::unwind(12)]
+fn check_dotenv_override() {
+    let (key, value) = kani::any_env_var();
+    kani::setup_env(&[(key.clone(), value.clone())]);
+    let vars = load_dotenv("PORT=80\nLOG=info");
+    let expected =
+        |name: &str, default: &str| if key == name { value.clone() } else { default.to_string() };
+    assert_eq!(vars["PORT"], expected("PORT", "80"));
+    assert_eq!(vars["LOG"], expected("LOG", "info"));
+}
+
+#[kani::proof]
+#[kani::stub(std::env::var_os, kani::stubs::env_var_os)]
+#[kani::unwind(6)]
+fn check_var_os() {
+    let (_, value) = kani::any_env_var();
+    kani::setup_env(&[("HOME".to_string(), value.clone())]);
+    assert_eq!(std::env::var_os("HOME"), Some(value.into()));
+    assert_eq!(std::env::var_os("PATH"), None);
+}
@@ -0,0 +1,53 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check a point-in-convex-polygon test on the symbolic points generated by
+//! `kani::any_2d_point` and `kani::any_unit_square_point`.
+
+type Point = (f64, f64);
+
+/// A convex polygon whose vertices are in counter-clockwise order.
+const DIAMOND: [Point; 4] = [(0.5, 0.0), (1.0, 0.5), (0.5, 1.0), (0.0, 0.5)];
+
+/// Whether `point` is inside the convex `polygon`, or on its boundary.
+fn contains(polygon: &[Point], point: Point) -> bool {
+    (0..polygon.len()).all(|i| {
+        let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+        (b.0 - a.0) * (point.1 - a.1) - (b.1 - a.1) * (point.0 - a.0) >= 0.0
+    })
+}
+
+/// Rotate a point by 90° around the origin, which is exact in floating point.
+fn rotate_quarter(point: Point) -> Point {
+    (-point.1, point.0)
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_unit_square_point() {
+    let point = kani::any_unit_square_point();
+    let inside = contains(&DIAMOND, point);
+    kani::cover!(inside);
+    kani::cover!(!inside);
+    if point.0 == 0.5 && point.1 == 0.5 {
+        assert!(inside);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_rotation_invariance() {
+    let point = kani::any_2d_point(-2.0..2.0, -2.0..2.0);
+    let inside = contains(&DIAMOND, point);
+
+    // Rotating both the polygon and the point preserves whether the point is inside.
+    let mut polygon = DIAMOND;
+    let mut rotated = point;
+    for _ in 0..4 {
+        polygon = polygon.map(rotate_quarter);
+        rotated = rotate_quarter(rotated);
+        assert_eq!(contains(&polygon, rotated), inside);
+    }
+    // After a rotation by 360°, the point is back where it started.
+    assert_eq!(rotated, point);
+}
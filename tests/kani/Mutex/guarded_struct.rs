@@ -0,0 +1,79 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a struct guarded by a `Mutex` or an `RwLock` can be verified in a single-threaded
+//! harness: locks are acquired immediately, and they are never poisoned since a panic ends the
+//! verification path. See `poison.rs` for locks poisoned by the harness.
+
+use std::sync::{Mutex, RwLock, TryLockError};
+
+#[derive(Default)]
+struct Account {
+    balance: u32,
+    deposits: u32,
+}
+
+impl Account {
+    fn deposit(&mut self, amount: u32) -> Result<(), ()> {
+        self.balance = self.balance.checked_add(amount).ok_or(())?;
+        self.deposits += 1;
+        Ok(())
+    }
+}
+
+struct Bank {
+    account: Mutex<Account>,
+}
+
+impl Bank {
+    fn deposit(&self, amount: u32) -> Result<(), ()> {
+        self.account.lock().unwrap().deposit(amount)
+    }
+
+    fn balance(&self) -> u32 {
+        self.account.lock().unwrap().balance
+    }
+}
+
+#[kani::proof]
+fn check_mutex_guarded_struct() {
+    let bank = Bank { account: Mutex::new(Account::default()) };
+    let first: u32 = kani::any();
+    let second: u32 = kani::any();
+    let _ = bank.deposit(first);
+    let result = bank.deposit(second);
+    assert_eq!(result.is_ok(), first.checked_add(second).is_some());
+    assert!(!bank.account.is_poisoned());
+    let account = bank.account.into_inner().unwrap();
+    assert_eq!(account.balance, if result.is_ok() { first + second } else { first });
+    assert_eq!(account.deposits, if result.is_ok() { 2 } else { 1 });
+}
+
+#[kani::proof]
+fn check_mutex_try_lock() {
+    let mutex = Mutex::new(kani::any::<u8>());
+    let guard = mutex.try_lock().unwrap();
+    assert!(matches!(mutex.try_lock(), Err(TryLockError::WouldBlock)));
+    drop(guard);
+    assert!(mutex.try_lock().is_ok());
+}
+
+#[kani::proof]
+fn check_mutex_relock_after_drop() {
+    let bank = Bank { account: Mutex::new(Account::default()) };
+    let amount: u32 = kani::any();
+    bank.deposit(amount).unwrap();
+    assert_eq!(bank.balance(), amount);
+}
+
+#[kani::proof]
+fn check_rwlock_readers() {
+    let lock = RwLock::new(kani::any::<u16>());
+    let first = lock.read().unwrap();
+    let second = lock.read().unwrap();
+    assert_eq!(*first, *second);
+    assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+    drop((first, second));
+    *lock.write().unwrap() = 10;
+    assert_eq!(lock.into_inner().unwrap(), 10);
+}
@@ -0,0 +1,56 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a lock whose guard is dropped while panicking is poisoned, like with unwinding.
+
+use std::sync::{Mutex, RwLock, TryLockError};
+
+#[kani::proof]
+fn check_mutex_poison() {
+    let value: u8 = kani::any();
+    let mutex = Mutex::new(0);
+    let mut guard = mutex.lock().unwrap();
+    *guard = value;
+    kani::sync::drop_while_panicking(guard);
+
+    assert!(mutex.is_poisoned());
+    assert_eq!(*mutex.lock().unwrap_err().into_inner(), value);
+    assert!(matches!(mutex.try_lock(), Err(TryLockError::Poisoned(_))));
+
+    mutex.clear_poison();
+    assert!(!mutex.is_poisoned());
+    assert_eq!(*mutex.lock().unwrap(), value);
+}
+
+#[kani::proof]
+fn check_rwlock_poison() {
+    let lock = RwLock::new(1u8);
+    // A read guard doesn't poison the lock.
+    let guard = lock.read().unwrap();
+    drop(guard);
+    kani::sync::drop_while_panicking(lock.write().unwrap());
+
+    assert!(lock.is_poisoned());
+    assert_eq!(*lock.read().unwrap_err().into_inner(), 1);
+    assert!(matches!(lock.try_write(), Err(TryLockError::Poisoned(_))));
+    assert!(matches!(lock.try_read(), Err(TryLockError::Poisoned(_))));
+
+    lock.clear_poison();
+    assert!(lock.write().is_ok());
+}
+
+#[kani::proof]
+fn check_poisoned_get_mut_into_inner() {
+    let mut mutex = Mutex::new(2u8);
+    kani::sync::drop_while_panicking(mutex.lock().unwrap());
+    assert!(mutex.get_mut().is_err());
+    assert_eq!(mutex.into_inner().unwrap_err().into_inner(), 2);
+
+    let mut lock = RwLock::new(3u8);
+    kani::sync::drop_while_panicking(lock.write().unwrap());
+    assert!(lock.get_mut().is_err());
+    assert_eq!(lock.into_inner().unwrap_err().into_inner(), 3);
+
+    // Another lock isn't poisoned.
+    assert!(Mutex::new(4u8).into_inner().is_ok());
+}
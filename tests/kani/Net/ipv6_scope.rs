@@ -0,0 +1,76 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a router forwards packets and applies firewall rules based on the scope of the
+//! symbolic addresses generated by `kani::any_ipv6_addr_in_scope`.
+
+use kani::Ipv6Scope;
+use std::net::Ipv6Addr;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Route {
+    /// Deliver the packet to the host itself.
+    Local,
+    /// Deliver the packet on the link it was received on.
+    SameLink,
+    /// Deliver the packet to every subscribed interface.
+    Subscribers,
+    /// Forward the packet to the upstream router.
+    Upstream,
+}
+
+/// The router under verification: pick a route from the destination address.
+fn route(dst: Ipv6Addr) -> Route {
+    if dst.is_loopback() {
+        Route::Local
+    } else if dst.is_multicast() {
+        Route::Subscribers
+    } else if dst.segments()[0] & 0xffc0 == 0xfe80 {
+        Route::SameLink
+    } else {
+        Route::Upstream
+    }
+}
+
+/// The firewall under verification: drop the packets received from the upstream router whose
+/// source address could not have been routed to us.
+fn accepts_from_upstream(src: Ipv6Addr) -> bool {
+    !src.is_loopback() && !src.is_multicast() && !src.is_unicast_link_local()
+}
+
+#[kani::proof]
+fn check_route_by_scope() {
+    let scope = match kani::any::<u8>() % 4 {
+        0 => Ipv6Scope::Loopback,
+        1 => Ipv6Scope::LinkLocal,
+        2 => Ipv6Scope::Global,
+        _ => Ipv6Scope::Multicast,
+    };
+    let dst = kani::any_ipv6_addr_in_scope(scope);
+    assert!(scope.contains(&dst));
+    let expected = match scope {
+        Ipv6Scope::Loopback => Route::Local,
+        Ipv6Scope::LinkLocal => Route::SameLink,
+        Ipv6Scope::Global => Route::Upstream,
+        Ipv6Scope::Multicast => Route::Subscribers,
+    };
+    assert_eq!(route(dst), expected);
+}
+
+#[kani::proof]
+fn check_firewall_by_scope() {
+    assert!(accepts_from_upstream(kani::any_ipv6_addr_in_scope(Ipv6Scope::Global)));
+    assert!(!accepts_from_upstream(kani::any_ipv6_addr_in_scope(Ipv6Scope::Loopback)));
+    assert!(!accepts_from_upstream(kani::any_ipv6_addr_in_scope(Ipv6Scope::LinkLocal)));
+    assert!(!accepts_from_upstream(kani::any_ipv6_addr_in_scope(Ipv6Scope::Multicast)));
+}
+
+#[kani::proof]
+fn check_scopes_are_disjoint() {
+    let addr = kani::any_ipv6_addr();
+    let scopes =
+        [Ipv6Scope::Loopback, Ipv6Scope::LinkLocal, Ipv6Scope::Global, Ipv6Scope::Multicast];
+    let matching = scopes.iter().filter(|scope| scope.contains(&addr)).count();
+    assert!(matching <= 1);
+    kani::cover!(matching == 0, "address outside of the supported scopes");
+}
@@ -0,0 +1,52 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a UDP echo server replies with the same payload to the sender of every symbolic
+//! message generated by `kani::any_socket_message`.
+
+use std::net::SocketAddr;
+
+const MAX_PAYLOAD: usize = 4;
+
+/// A minimal model of a UDP socket that records the datagrams that were sent.
+#[derive(Default)]
+struct MockUdpSocket {
+    sent: Vec<(SocketAddr, Vec<u8>)>,
+}
+
+impl MockUdpSocket {
+    fn send_to(&mut self, buf: &[u8], addr: SocketAddr) -> usize {
+        self.sent.push((addr, buf.to_vec()));
+        buf.len()
+    }
+}
+
+/// The server under verification: echo every datagram back to its source.
+fn echo(socket: &mut MockUdpSocket, src: SocketAddr, payload: &[u8]) -> usize {
+    let mut buf = [0u8; MAX_PAYLOAD];
+    let len = payload.len().min(buf.len());
+    buf[..len].copy_from_slice(&payload[..len]);
+    socket.send_to(&buf[..len], src)
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_udp_echo() {
+    let (src, payload) = kani::any_socket_message(MAX_PAYLOAD);
+    assert!(payload.len() <= MAX_PAYLOAD);
+
+    let mut socket = MockUdpSocket::default();
+    let sent = echo(&mut socket, src, &payload);
+
+    assert_eq!(sent, payload.len());
+    assert_eq!(socket.sent.len(), 1);
+    assert_eq!(socket.sent[0].0, src);
+    assert_eq!(socket.sent[0].1, payload);
+}
+
+#[kani::proof]
+fn check_socket_addr_kinds() {
+    let addr = kani::any_socket_addr();
+    kani::cover!(addr.is_ipv4(), "IPv4 source");
+    kani::cover!(addr.is_ipv6(), "IPv6 source");
+}
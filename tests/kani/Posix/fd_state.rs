@@ -0,0 +1,79 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a wrapper around `read`, `write` and `close`-like system calls returns `EBADF` for
+//! the closed and bad descriptors generated by `kani::any_file_descriptor`, and succeeds for the
+//! valid ones.
+
+use kani::FdState;
+use std::io;
+
+const EBADF: i32 = 9;
+
+/// A mock system call that fails with `EBADF` unless `fd` is valid, and otherwise returns `ret`.
+fn mock_syscall(fd: i32, ret: isize) -> isize {
+    if FdState::of(fd) == FdState::Valid { ret } else { -(EBADF as isize) }
+}
+
+/// Convert the raw return value of a system call into an `io::Result`.
+fn check_ret(ret: isize) -> io::Result<usize> {
+    if ret < 0 { Err(io::Error::from_raw_os_error(-ret as i32)) } else { Ok(ret as usize) }
+}
+
+/// The file wrapper under verification.
+struct File {
+    fd: i32,
+}
+
+impl File {
+    fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
+        check_ret(mock_syscall(self.fd, buf.len() as isize))
+    }
+
+    fn write(&self, buf: &[u8]) -> io::Result<usize> {
+        check_ret(mock_syscall(self.fd, buf.len() as isize))
+    }
+
+    fn close(self) -> io::Result<()> {
+        check_ret(mock_syscall(self.fd, 0)).map(|_| ())
+    }
+}
+
+fn any_state() -> FdState {
+    match kani::any::<u8>() % 3 {
+        0 => FdState::Valid,
+        1 => FdState::Closed,
+        _ => FdState::BadNumber,
+    }
+}
+
+#[kani::proof]
+fn check_fd_ranges() {
+    let state = any_state();
+    let fd = kani::any_file_descriptor(state);
+    assert_eq!(FdState::of(fd), state);
+    match state {
+        FdState::Valid => assert!((3..=1023).contains(&fd)),
+        FdState::Closed => assert!(fd > 1023),
+        FdState::BadNumber => assert!(fd < 0),
+    }
+}
+
+#[kani::proof]
+fn check_wrapper_by_state() {
+    let state = any_state();
+    let file = File { fd: kani::any_file_descriptor(state) };
+    let mut buf = [0u8; 4];
+    let read = file.read(&mut buf);
+    let write = file.write(&buf[..2]);
+    let close = file.close();
+    if state == FdState::Valid {
+        assert_eq!(read.unwrap(), 4);
+        assert_eq!(write.unwrap(), 2);
+        assert!(close.is_ok());
+    } else {
+        for err in [read.unwrap_err(), write.unwrap_err(), close.unwrap_err()] {
+            assert_eq!(err.raw_os_error(), Some(EBADF));
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a wrapper around a `read`-like system call maps every symbolic error code
+//! generated by `kani::any_error_code` to the expected `io::ErrorKind` without panicking.
+
+use std::io;
+
+const EPERM: i32 = 1;
+const ENOENT: i32 = 2;
+const EINTR: i32 = 4;
+const EACCES: i32 = 13;
+const EINVAL: i32 = 22;
+
+/// A mock `read` system call that fails with the given error code.
+fn mock_read(fd: i32, _buf: &mut [u8], errno: Option<i32>) -> isize {
+    assert!(fd > 2, "reading from a standard stream");
+    match errno {
+        Some(code) => -(code as isize),
+        None => 0,
+    }
+}
+
+/// Convert the raw return value of the system call into an `io::Result`.
+fn read_file(fd: i32, buf: &mut [u8], errno: Option<i32>) -> io::Result<usize> {
+    let ret = mock_read(fd, buf, errno);
+    if ret < 0 { Err(io::Error::from_raw_os_error(-ret as i32)) } else { Ok(ret as usize) }
+}
+
+#[kani::proof]
+fn check_error_kinds() {
+    let fd = kani::any_successful_fd();
+    let code = kani::any_error_code();
+    let mut buf = [0u8; 4];
+    let err = read_file(fd, &mut buf, Some(code)).unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(code));
+    let kind = err.kind();
+    match code {
+        EPERM | EACCES => assert_eq!(kind, io::ErrorKind::PermissionDenied),
+        ENOENT => assert_eq!(kind, io::ErrorKind::NotFound),
+        EINTR => assert_eq!(kind, io::ErrorKind::Interrupted),
+        EINVAL => assert_eq!(kind, io::ErrorKind::InvalidInput),
+        _ => {}
+    }
+}
+
+#[kani::proof]
+fn check_successful_fd() {
+    let fd = kani::any_successful_fd();
+    assert!((3..=1023).contains(&fd));
+    let mut buf = [0u8; 4];
+    assert_eq!(read_file(fd, &mut buf, None).unwrap(), 0);
+}
@@ -0,0 +1,52 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check a Bernoulli sampler with the probabilities generated by `kani::any_probability` and
+//! `kani::any_probability_f32`.
+
+/// Returns `true` with probability `p`, given a sample drawn uniformly from `[0.0, 1.0)`.
+fn bernoulli(p: f64, sample: f64) -> bool {
+    sample < p
+}
+
+fn any_sample() -> f64 {
+    kani::any_where(|sample: &f64| 0.0 <= *sample && *sample < 1.0)
+}
+
+#[kani::proof]
+fn check_probability_range() {
+    let p = kani::any_probability();
+    assert!(p.is_finite() && (0.0..=1.0).contains(&p));
+    let p = kani::any_probability_f32();
+    assert!(p.is_finite() && (0.0..=1.0).contains(&p));
+}
+
+#[kani::proof]
+fn check_bernoulli_outcomes() {
+    let p = kani::any_probability();
+    kani::assume(p > 0.0 && p < 1.0);
+    let outcome = bernoulli(p, any_sample());
+    // Both outcomes are possible for any probability strictly between 0 and 1.
+    kani::cover!(outcome);
+    kani::cover!(!outcome);
+}
+
+#[kani::proof]
+fn check_bernoulli_certain_outcomes() {
+    let p = kani::any_probability();
+    let outcome = bernoulli(p, any_sample());
+    if p == 0.0 {
+        assert!(!outcome);
+    } else if p == 1.0 {
+        assert!(outcome);
+    }
+}
+
+#[kani::proof]
+fn check_bernoulli_monotonic() {
+    // A sample that succeeds for some probability also succeeds for any larger one.
+    let (low, high) = (kani::any_probability(), kani::any_probability());
+    kani::assume(low <= high);
+    let sample = any_sample();
+    assert!(!bernoulli(low, sample) || bernoulli(high, sample));
+}
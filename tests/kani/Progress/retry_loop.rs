@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assert_progress!` succeeds for loops whose measure decreases.
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_countdown() {
+    let mut retries: u8 = kani::any_where(|retries| *retries < 5);
+    loop {
+        kani::assert_progress!(retries);
+        if retries == 0 {
+            break;
+        }
+        retries -= 1;
+    }
+}
+
+/// The measure may be any integer expression, e.g. the length of the remaining input.
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_while_loop() {
+    let data: [u8; 4] = kani::any();
+    let mut remaining = &data[..];
+    while let Some((first, rest)) = remaining.split_first() {
+        kani::assert_progress!(remaining.len());
+        // Skip one more byte if the first one is odd.
+        remaining = if first % 2 == 1 && !rest.is_empty() { &rest[1..] } else { rest };
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check range queries on the symbolic ranges generated by `kani::any_sorted_pair` and
+//! `kani::any_strict_sorted_pair`.
+
+/// An inclusive range of keys.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Range {
+    lo: u8,
+    hi: u8,
+}
+
+impl Range {
+    fn any() -> Range {
+        let (lo, hi) = kani::any_sorted_pair();
+        Range { lo, hi }
+    }
+
+    fn contains(&self, key: u8) -> bool {
+        self.lo <= key && key <= self.hi
+    }
+
+    fn intersection(&self, other: &Range) -> Option<Range> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+        (lo <= hi).then_some(Range { lo, hi })
+    }
+
+    /// Merge two overlapping ranges, or return `None` if they don't overlap.
+    fn union(&self, other: &Range) -> Option<Range> {
+        self.intersection(other)?;
+        Some(Range { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) })
+    }
+}
+
+#[kani::proof]
+fn check_sorted_pairs() {
+    let (lo, hi) = kani::any_sorted_pair::<i32>();
+    assert!(lo <= hi);
+    kani::cover!(lo == hi);
+    let (lo, hi) = kani::any_strict_sorted_pair::<i32>();
+    assert!(lo < hi);
+    kani::cover!(lo == i32::MIN && hi == i32::MAX);
+}
+
+#[kani::proof]
+fn check_contains() {
+    let range = Range::any();
+    let key: u8 = kani::any();
+    assert_eq!(range.contains(key), (range.lo..=range.hi).contains(&key));
+    kani::cover!(range.contains(key));
+    kani::cover!(!range.contains(key));
+
+    let (lo, hi) = kani::any_strict_sorted_pair();
+    let range = Range { lo, hi };
+    assert!(range.contains(lo) && range.contains(hi));
+    assert!(!range.contains(hi.wrapping_add(1)) || hi == u8::MAX);
+}
+
+#[kani::proof]
+fn check_intersection() {
+    let first = Range::any();
+    let second = Range::any();
+    let key: u8 = kani::any();
+    let overlap = first.intersection(&second);
+    assert_eq!(
+        overlap.is_some_and(|range| range.contains(key)),
+        first.contains(key) && second.contains(key)
+    );
+    kani::cover!(overlap.is_none());
+    kani::cover!(overlap.is_some_and(|range| range != first && range != second));
+}
+
+#[kani::proof]
+fn check_union() {
+    let first = Range::any();
+    let second = Range::any();
+    let key: u8 = kani::any();
+    match first.union(&second) {
+        Some(merged) => {
+            assert_eq!(merged.contains(key), first.contains(key) || second.contains(key));
+        }
+        None => assert!(!(first.contains(key) && second.contains(key))),
+    }
+    kani::cover!(first.union(&second).is_some_and(|merged| merged != first && merged != second));
+}
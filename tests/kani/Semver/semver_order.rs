@@ -0,0 +1,105 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a hand-written semantic version comparison is a total order, and that it agrees
+//! with the versions generated by `kani::any_semver` and `kani::any_semver_range`.
+
+type Version = (u32, u32, u32);
+
+/// Whether `v2` is the same as or newer than `v1`.
+fn is_at_least(v2: Version, v1: Version) -> bool {
+    if v2.0 != v1.0 {
+        v2.0 > v1.0
+    } else if v2.1 != v1.1 {
+        v2.1 > v1.1
+    } else {
+        v2.2 >= v1.2
+    }
+}
+
+/// Whether `version` satisfies the caret requirement `^req`, i.e., it is at least `req` and
+/// doesn't change the left-most non-zero component.
+fn is_compatible(version: Version, req: Version) -> bool {
+    if !is_at_least(version, req) {
+        return false;
+    }
+    if req.0 != 0 {
+        version.0 == req.0
+    } else if req.1 != 0 {
+        version.0 == 0 && version.1 == req.1
+    } else {
+        version == req
+    }
+}
+
+#[kani::proof]
+fn check_newer_major() {
+    let v1 = kani::any_semver();
+    let v2 = kani::any_semver();
+    kani::assume(v2.0 > v1.0);
+    assert!(is_at_least(v2, v1));
+    assert!(!is_at_least(v1, v2));
+}
+
+#[kani::proof]
+fn check_newer_minor() {
+    let v1 = kani::any_semver();
+    let v2 = kani::any_semver();
+    kani::assume(v2.0 == v1.0 && v2.1 >= v1.1 && (v2.1 > v1.1 || v2.2 >= v1.2));
+    assert!(is_at_least(v2, v1));
+}
+
+#[kani::proof]
+fn check_matches_tuple_order() {
+    let v1 = kani::any_semver();
+    let v2 = kani::any_semver();
+    assert_eq!(is_at_least(v2, v1), v2 >= v1);
+}
+
+#[kani::proof]
+fn check_reflexive() {
+    let v = kani::any_semver();
+    assert!(is_at_least(v, v));
+}
+
+#[kani::proof]
+fn check_antisymmetric() {
+    let v1 = kani::any_semver();
+    let v2 = kani::any_semver();
+    if is_at_least(v1, v2) && is_at_least(v2, v1) {
+        assert_eq!(v1, v2);
+    }
+}
+
+#[kani::proof]
+fn check_transitive() {
+    let v1 = kani::any_semver();
+    let v2 = kani::any_semver();
+    let v3 = kani::any_semver();
+    if is_at_least(v3, v2) && is_at_least(v2, v1) {
+        assert!(is_at_least(v3, v1));
+    }
+}
+
+#[kani::proof]
+fn check_total() {
+    let v1 = kani::any_semver();
+    let v2 = kani::any_semver();
+    assert!(is_at_least(v1, v2) || is_at_least(v2, v1));
+}
+
+#[kani::proof]
+fn check_range() {
+    let (lo, hi) = kani::any_semver_range();
+    assert!(is_at_least(hi, lo));
+    kani::cover!(lo == hi);
+    kani::cover!(lo.0 < hi.0);
+}
+
+#[kani::proof]
+fn check_compatible_within_range() {
+    let (req, version) = kani::any_semver_range();
+    kani::assume(req.0 != 0 && version.0 == req.0);
+    assert!(is_compatible(version, req));
+    kani::cover!(version.1 > req.1);
+}
@@ -0,0 +1,101 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a TCP connection state machine never reaches an invalid combination of its state
+//! and flags, from any valid initial state and for any sequence of up to 5 events.
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, kani::Arbitrary)]
+enum TcpState {
+    Closed,
+    Listen,
+    SynSent,
+    SynReceived,
+    Established,
+    FinWait1,
+    FinWait2,
+    CloseWait,
+    LastAck,
+    TimeWait,
+}
+
+#[derive(Copy, Clone, Debug, kani::Arbitrary)]
+enum Event {
+    PassiveOpen,
+    ActiveOpen,
+    RecvSyn,
+    RecvSynAck,
+    RecvAck,
+    RecvFin,
+    Close,
+    Timeout,
+}
+
+#[derive(Debug, kani::Arbitrary)]
+struct Tcp {
+    state: TcpState,
+    fin_sent: bool,
+    fin_received: bool,
+}
+
+impl Tcp {
+    fn is_valid(&self) -> bool {
+        use TcpState::*;
+        let fin_sent = matches!(self.state, FinWait1 | FinWait2 | LastAck | TimeWait);
+        let fin_received = matches!(self.state, CloseWait | LastAck | TimeWait);
+        self.fin_sent == fin_sent && self.fin_received == fin_received
+    }
+
+    fn handle(&mut self, event: Event) {
+        use Event::*;
+        use TcpState::*;
+        self.state = match (self.state, event) {
+            (Closed, PassiveOpen) => Listen,
+            (Closed, ActiveOpen) => SynSent,
+            (Listen, RecvSyn) => SynReceived,
+            (Listen, Close) | (SynSent, Close | Timeout) => Closed,
+            (SynSent, RecvSynAck) | (SynReceived, RecvAck) => Established,
+            (SynReceived | Established, Close) => {
+                self.fin_sent = true;
+                FinWait1
+            }
+            (Established, RecvFin) => {
+                self.fin_received = true;
+                CloseWait
+            }
+            (FinWait1, RecvAck) => FinWait2,
+            (FinWait2, RecvFin) => {
+                self.fin_received = true;
+                TimeWait
+            }
+            (CloseWait, Close) => {
+                self.fin_sent = true;
+                LastAck
+            }
+            (LastAck, RecvAck) | (TimeWait, Timeout) => {
+                self.fin_sent = false;
+                self.fin_received = false;
+                Closed
+            }
+            (state, _) => state,
+        }
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_tcp_invariant() {
+    let mut tcp: Tcp = kani::any();
+    kani::assume(tcp.is_valid());
+    for event in kani::any_state_machine_trace::<Tcp, Event>(5) {
+        tcp.handle(event);
+        assert!(tcp.is_valid());
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_trace_length() {
+    let trace = kani::any_state_machine_trace::<TcpState, Event>(5);
+    assert!(trace.len() <= 5);
+    kani::cover!(trace.len() == 5);
+}
@@ -0,0 +1,103 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --default-unwind 5
+
+//! Check that `kani::any_ascii_identifier` generates well-formed identifiers
+//! and that it can be used to verify code that handles symbol names.
+
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, Hasher};
+
+const MAX_LEN: usize = 3;
+
+/// A symbol table that rejects reserved names and names that are too long.
+struct SymbolTable {
+    symbols: BTreeMap<String, u32>,
+}
+
+impl SymbolTable {
+    fn new() -> Self {
+        let mut symbols = BTreeMap::new();
+        symbols.insert("fn".to_string(), 0);
+        symbols.insert("_x1".to_string(), 1);
+        SymbolTable { symbols }
+    }
+
+    fn lookup(&self, name: &str) -> Option<u32> {
+        let first = name.chars().next()?;
+        if !(first.is_ascii_alphabetic() || first == '_') {
+            return None;
+        }
+        self.symbols.get(name).copied()
+    }
+}
+
+/// A hasher that encodes each identifier character in 6 bits, which is
+/// injective for identifiers of up to 10 characters.
+#[derive(Default)]
+struct IdentHasher(u64);
+
+impl Hasher for IdentHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            let code = match b {
+                b'A'..=b'Z' => b - b'A' + 1,
+                b'a'..=b'z' => b - b'a' + 27,
+                b'0'..=b'9' => b - b'0' + 53,
+                b'_' => 63,
+                // `str::hash` writes a terminator byte.
+                _ => 0,
+            };
+            self.0 = (self.0 << 6) | code as u64;
+        }
+    }
+}
+
+#[derive(Default)]
+struct IdentHasherBuilder;
+
+impl BuildHasher for IdentHasherBuilder {
+    type Hasher = IdentHasher;
+
+    fn build_hasher(&self) -> IdentHasher {
+        IdentHasher::default()
+    }
+}
+
+#[kani::proof]
+fn check_identifier_shape() {
+    let ident = kani::any_ascii_identifier(MAX_LEN);
+    let bytes = ident.as_bytes();
+    assert!(!bytes.is_empty() && bytes.len() <= MAX_LEN);
+    assert!(bytes[0].is_ascii_alphabetic() || bytes[0] == b'_');
+    assert!(bytes.iter().all(|b| b.is_ascii_alphanumeric() || *b == b'_'));
+}
+
+#[kani::proof]
+fn check_identifier_can_be_keyword() {
+    let ident = kani::any_ascii_identifier(MAX_LEN);
+    kani::cover!(ident == "fn");
+    kani::cover!(ident.starts_with('_'));
+    kani::cover!(ident.len() == MAX_LEN);
+}
+
+#[kani::proof]
+fn check_symbol_lookup() {
+    let table = SymbolTable::new();
+    let ident = kani::any_ascii_identifier(MAX_LEN);
+    let result = table.lookup(&ident);
+    assert!(result.is_none() || ident == "fn" || ident == "_x1");
+}
+
+#[kani::proof]
+fn check_distinct_hashes() {
+    let lhs = kani::any_ascii_identifier(MAX_LEN);
+    let rhs = kani::any_ascii_identifier(MAX_LEN);
+    kani::assume(lhs != rhs);
+    let builder = IdentHasherBuilder;
+    assert_ne!(builder.hash_one(&lhs), builder.hash_one(&rhs));
+}
@@ -0,0 +1,102 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --default-unwind 6
+
+//! Check that `kani::any_base64_string` generates canonical base64 strings, i.e., strings that
+//! decode without errors and that are re-encoded to the same string.
+
+const MAX_DECODED_LEN: usize = 6;
+
+/// A minimal base64 codec with the standard alphabet and padding.
+mod base64 {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    #[derive(Debug)]
+    pub enum DecodeError {
+        InvalidLength,
+        InvalidByte(usize),
+        InvalidPadding,
+    }
+
+    fn value(byte: u8) -> Option<u32> {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        Some(value as u32)
+    }
+
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let mut group = 0u32;
+            for (idx, byte) in chunk.iter().enumerate() {
+                group |= (*byte as u32) << (16 - 8 * idx);
+            }
+            for idx in 0..4 {
+                if idx <= chunk.len() {
+                    encoded.push(ALPHABET[((group >> (18 - 6 * idx)) & 0x3F) as usize] as char);
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+        encoded
+    }
+
+    pub fn decode(encoded: &str) -> Result<Vec<u8>, DecodeError> {
+        let encoded = encoded.as_bytes();
+        if encoded.len() % 4 != 0 {
+            return Err(DecodeError::InvalidLength);
+        }
+        let mut bytes = Vec::with_capacity(encoded.len() / 4 * 3);
+        let num_groups = encoded.len() / 4;
+        for (group_idx, chunk) in encoded.chunks(4).enumerate() {
+            let padding = chunk.iter().rev().take_while(|byte| **byte == b'=').count();
+            if padding > 2 || (padding > 0 && group_idx + 1 != num_groups) {
+                return Err(DecodeError::InvalidPadding);
+            }
+            let mut group = 0u32;
+            for (idx, byte) in chunk[..4 - padding].iter().enumerate() {
+                let value = value(*byte).ok_or(DecodeError::InvalidByte(group_idx * 4 + idx))?;
+                group |= value << (18 - 6 * idx);
+            }
+            // The bits that don't belong to a decoded byte must be zero.
+            if group & ((1 << (8 * padding)) - 1) != 0 {
+                return Err(DecodeError::InvalidPadding);
+            }
+            for idx in 0..3 - padding {
+                bytes.push((group >> (16 - 8 * idx)) as u8);
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+#[kani::proof]
+fn check_base64_shape() {
+    let encoded = kani::any_base64_string(MAX_DECODED_LEN);
+    assert_eq!(encoded.len() % 4, 0);
+    assert!(encoded.len() <= MAX_DECODED_LEN.div_ceil(3) * 4);
+    kani::cover!(encoded.ends_with("=="));
+    kani::cover!(encoded.ends_with('=') && !encoded.ends_with("=="));
+}
+
+#[kani::proof]
+fn check_base64_decode_never_fails() {
+    let encoded = kani::any_base64_string(MAX_DECODED_LEN);
+    let decoded = base64::decode(&encoded);
+    assert!(decoded.is_ok());
+    assert!(decoded.unwrap().len() <= MAX_DECODED_LEN);
+}
+
+#[kani::proof]
+fn check_base64_round_trip() {
+    let encoded = kani::any_base64_string(MAX_DECODED_LEN);
+    let decoded = base64::decode(&encoded).unwrap();
+    assert_eq!(base64::encode(&decoded), encoded);
+}
@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z c-ffi
+
+//! Check that a wrapper around C's `strlen` handles every string generated by
+//! `kani::any_cstr` and `kani::any_cstr_or_null`.
+
+use std::ffi::{CString, c_char};
+
+extern "C" {
+    fn strlen(s: *const c_char) -> usize;
+}
+
+/// Return the length of a C string, where `None` represents a null pointer.
+fn c_len(s: Option<&CString>) -> usize {
+    match s {
+        // SAFETY: A `CString` is NUL-terminated.
+        Some(s) => unsafe { strlen(s.as_ptr()) },
+        None => 0,
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_any_cstr() {
+    let s = kani::any_cstr(4);
+    assert!(!s.as_bytes().contains(&0));
+    assert_eq!(c_len(Some(&s)), s.as_bytes().len());
+    kani::cover!(s.as_bytes().is_empty());
+    kani::cover!(s.as_bytes().len() == 4);
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_any_cstr_or_null() {
+    let s = kani::any_cstr_or_null(4);
+    let len = c_len(s.as_ref());
+    assert!(len <= 4);
+    kani::cover!(s.is_none());
+    kani::cover!(len == 4);
+}
@@ -0,0 +1,84 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --default-unwind 9
+
+//! Check that `kani::any_hex_string` generates strings that a hex decoder accepts, and that
+//! decoding inverts encoding.
+
+const MAX_PAIRS: usize = 4;
+
+/// A minimal hex codec that encodes to lowercase and decodes both cases.
+mod hex {
+    const DIGITS: &[u8] = b"0123456789abcdef";
+
+    #[derive(Debug)]
+    pub enum DecodeError {
+        OddLength,
+        InvalidDigit(usize),
+    }
+
+    fn value(digit: u8) -> Option<u8> {
+        match digit {
+            b'0'..=b'9' => Some(digit - b'0'),
+            b'a'..=b'f' => Some(digit - b'a' + 10),
+            b'A'..=b'F' => Some(digit - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len() * 2);
+        for byte in bytes {
+            encoded.push(DIGITS[(byte >> 4) as usize] as char);
+            encoded.push(DIGITS[(byte & 0xF) as usize] as char);
+        }
+        encoded
+    }
+
+    pub fn decode(encoded: &str) -> Result<Vec<u8>, DecodeError> {
+        let encoded = encoded.as_bytes();
+        if encoded.len() % 2 != 0 {
+            return Err(DecodeError::OddLength);
+        }
+        let mut bytes = Vec::with_capacity(encoded.len() / 2);
+        for (idx, pair) in encoded.chunks(2).enumerate() {
+            let high = value(pair[0]).ok_or(DecodeError::InvalidDigit(2 * idx))?;
+            let low = value(pair[1]).ok_or(DecodeError::InvalidDigit(2 * idx + 1))?;
+            bytes.push((high << 4) | low);
+        }
+        Ok(bytes)
+    }
+}
+
+#[kani::proof]
+fn check_hex_shape() {
+    let encoded = kani::any_hex_string(MAX_PAIRS);
+    assert_eq!(encoded.len() % 2, 0);
+    assert!(encoded.len() <= 2 * MAX_PAIRS);
+    assert!(encoded.bytes().all(|digit| digit.is_ascii_hexdigit()));
+    kani::cover!(encoded.is_empty());
+    kani::cover!(encoded.len() == 2 * MAX_PAIRS);
+}
+
+#[kani::proof]
+fn check_hex_decode_never_fails() {
+    let encoded = kani::any_hex_string(MAX_PAIRS);
+    let decoded = hex::decode(&encoded);
+    assert!(decoded.is_ok());
+    assert_eq!(decoded.unwrap().len(), encoded.len() / 2);
+}
+
+#[kani::proof]
+fn check_hex_round_trip() {
+    let bytes: [u8; MAX_PAIRS] = kani::any();
+    let len: usize = kani::any_where(|len| *len <= MAX_PAIRS);
+    let encoded = hex::encode(&bytes[..len]);
+    assert_eq!(hex::decode(&encoded).unwrap(), &bytes[..len]);
+}
+
+#[kani::proof]
+fn check_hex_decode_encode() {
+    let encoded = kani::any_hex_string(MAX_PAIRS);
+    let decoded = hex::decode(&encoded).unwrap();
+    assert_eq!(hex::encode(&decoded), encoded.to_ascii_lowercase());
+}
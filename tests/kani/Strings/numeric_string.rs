@@ -0,0 +1,50 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the numeric strings generated by `kani::any_numeric_string`,
+//! `kani::any_signed_numeric_string` and `kani::any_float_string` can be parsed.
+
+use std::num::IntErrorKind;
+
+/// The decimal representation of `i64::MAX`, which has 19 digits.
+const I64_MAX: &str = "9223372036854775807";
+
+#[kani::proof]
+#[kani::unwind(19)]
+fn check_numeric_string_fits_i64() {
+    let number = kani::any_numeric_string(18);
+    assert!(number.len() >= 1 && number.len() <= 18);
+    assert!(number.bytes().all(|byte| byte.is_ascii_digit()));
+    assert!(number.parse::<i64>().is_ok());
+}
+
+#[kani::proof]
+#[kani::unwind(20)]
+fn check_numeric_string_overflow() {
+    let number = kani::any_numeric_string(19);
+    let overflows = number.len() == 19 && number.as_str() > I64_MAX;
+    match number.parse::<i64>() {
+        Ok(value) => assert!(!overflows && value >= 0),
+        Err(err) => assert!(overflows && *err.kind() == IntErrorKind::PosOverflow),
+    }
+    kani::cover!(overflows);
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_signed_numeric_string() {
+    let number = kani::any_signed_numeric_string(4);
+    let value = number.parse::<i16>().unwrap();
+    assert_eq!(value < 0, number.starts_with('-') && value != 0);
+    kani::cover!(number == "-0");
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_float_string() {
+    let number = kani::any_float_string(4);
+    let (int_part, frac_part) = number.split_once('.').unwrap();
+    assert!(!int_part.is_empty() && !frac_part.is_empty());
+    assert!(int_part.len() + frac_part.len() <= 4);
+    assert!(number.bytes().all(|byte| byte.is_ascii_digit() || byte == b'.'));
+}
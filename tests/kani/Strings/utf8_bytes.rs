@@ -0,0 +1,79 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_utf8_bytes` only generates valid UTF-8 sequences.
+
+const MAX_LEN: usize = 16;
+
+/// A minimal byte-at-a-time UTF-8 validator, similar to what a streaming decoder would do.
+struct Utf8Validator {
+    /// Number of continuation bytes still expected for the current character.
+    pending: u8,
+    /// Valid range for the next continuation byte.
+    next_range: (u8, u8),
+}
+
+impl Utf8Validator {
+    fn new() -> Self {
+        Utf8Validator { pending: 0, next_range: (0x80, 0xBF) }
+    }
+
+    /// Feed one byte to the validator. Returns false if the input is invalid.
+    fn push(&mut self, byte: u8) -> bool {
+        if self.pending > 0 {
+            if byte < self.next_range.0 || byte > self.next_range.1 {
+                return false;
+            }
+            self.pending -= 1;
+            self.next_range = (0x80, 0xBF);
+            return true;
+        }
+        let (pending, next_range) = match byte {
+            0x00..=0x7F => (0, (0x80, 0xBF)),
+            0xC2..=0xDF => (1, (0x80, 0xBF)),
+            0xE0 => (2, (0xA0, 0xBF)),
+            0xED => (2, (0x80, 0x9F)),
+            0xE1..=0xEF => (2, (0x80, 0xBF)),
+            0xF0 => (3, (0x90, 0xBF)),
+            0xF1..=0xF3 => (3, (0x80, 0xBF)),
+            0xF4 => (3, (0x80, 0x8F)),
+            _ => return false,
+        };
+        self.pending = pending;
+        self.next_range = next_range;
+        true
+    }
+
+    /// Whether the input ended at a character boundary.
+    fn finish(&self) -> bool {
+        self.pending == 0
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(17)]
+fn check_from_utf8() {
+    let bytes = kani::any_utf8_bytes(MAX_LEN);
+    assert!(bytes.len() <= MAX_LEN);
+    assert!(std::str::from_utf8(&bytes).is_ok());
+}
+
+#[kani::proof]
+#[kani::unwind(17)]
+fn check_streaming_validator() {
+    let bytes = kani::any_utf8_bytes(MAX_LEN);
+    let mut validator = Utf8Validator::new();
+    for byte in bytes.iter() {
+        assert!(validator.push(*byte));
+    }
+    assert!(validator.finish());
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_multi_byte_reachable() {
+    let bytes = kani::any_utf8_bytes(4);
+    kani::cover!(bytes.len() == 4 && bytes[0] >= 0xF0, "four byte character");
+    kani::cover!(bytes.len() == 3 && bytes[0] >= 0xE0, "three byte character");
+    kani::cover!(bytes.is_empty(), "empty sequence");
+}
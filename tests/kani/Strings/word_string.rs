@@ -0,0 +1,77 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_word_string` generates space-separated words and that
+//! it can be used to verify simple text processing functions.
+
+const MAX_WORDS: usize = 2;
+
+/// Count the words of a string, where words are separated by whitespace.
+fn word_count(text: &str) -> usize {
+    let mut count = 0;
+    let mut in_word = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            in_word = false;
+        } else if !in_word {
+            in_word = true;
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Capitalize the first character of each word.
+fn title_case(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut start = true;
+    for c in text.chars() {
+        if start {
+            result.push(c.to_ascii_uppercase());
+        } else {
+            result.push(c);
+        }
+        start = c == ' ';
+    }
+    result
+}
+
+#[kani::proof]
+#[kani::unwind(34)]
+fn check_word_string_shape() {
+    let text = kani::any_word_string(MAX_WORDS);
+    let bytes = text.as_bytes();
+    assert!(!bytes.is_empty() && bytes.len() < MAX_WORDS * (kani::strings::MAX_WORD_LEN + 1));
+    assert!(bytes[0] != b' ' && bytes[bytes.len() - 1] != b' ');
+    assert!(bytes.iter().all(|b| b.is_ascii_alphabetic() || *b == b' '));
+    assert!(!text.contains("  "));
+    kani::cover!(text.len() == kani::strings::MAX_WORD_LEN);
+    kani::cover!(text.contains(' '));
+}
+
+#[kani::proof]
+#[kani::unwind(34)]
+fn check_word_count() {
+    let text = kani::any_word_string(MAX_WORDS);
+    let spaces = text.bytes().filter(|b| *b == b' ').count();
+    let count = word_count(&text);
+    assert_eq!(count, spaces + 1);
+    assert!(count >= 1 && count <= MAX_WORDS);
+}
+
+#[kani::proof]
+#[kani::unwind(34)]
+fn check_title_case() {
+    let text = kani::any_word_string(MAX_WORDS);
+    let title = title_case(&text);
+    assert_eq!(title.len(), text.len());
+    let mut words = text.split(' ');
+    for title_word in title.split(' ') {
+        let word = words.next().unwrap();
+        let (first, rest) = title_word.split_at(1);
+        assert!(first.as_bytes()[0].is_ascii_uppercase());
+        assert!(first.eq_ignore_ascii_case(&word[..1]));
+        assert_eq!(rest, &word[1..]);
+    }
+    assert!(words.next().is_none());
+}
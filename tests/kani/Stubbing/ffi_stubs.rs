@@ -0,0 +1,40 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z ffi-stubs
+//
+//! Check that the functions of an `extern` block are replaced by the stubs declared with
+//! `#[kani::ffi_stubs(...)]` in every harness, including calls through function pointers.
+
+mod models {
+    pub unsafe extern "C" fn checksum(data: *const u8, len: usize) -> u32 {
+        let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+        bytes.iter().map(|byte| *byte as u32).sum()
+    }
+
+    pub extern "C" fn max_len() -> usize {
+        4
+    }
+}
+
+#[kani::ffi_stubs(checksum = models::checksum, max_len = models::max_len)]
+extern "C" {
+    fn checksum(data: *const u8, len: usize) -> u32;
+    fn max_len() -> usize;
+}
+
+#[kani::proof]
+fn check_direct_call() {
+    let data: [u8; 4] = kani::any();
+    let len = unsafe { max_len() };
+    assert_eq!(len, data.len());
+    let sum = unsafe { checksum(data.as_ptr(), len) };
+    assert!(sum <= 4 * u8::MAX as u32);
+}
+
+#[kani::proof]
+fn check_fn_ptr_call() {
+    let data = [1u8, 2, 3];
+    let func: unsafe extern "C" fn(*const u8, usize) -> u32 = checksum;
+    assert_eq!(unsafe { func(data.as_ptr(), data.len()) }, 6);
+}
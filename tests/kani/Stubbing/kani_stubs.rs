@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z stubbing
+//
+//! Check the stubs shipped in `kani::stubs` for non-deterministic `std` functions.
+
+use std::collections::HashSet;
+use std::collections::hash_map::RandomState;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[kani::proof]
+#[kani::stub(Instant::now, kani::stubs::instant_now)]
+fn check_instant_is_monotonic() {
+    let start = Instant::now();
+    let end = Instant::now();
+    assert!(end >= start);
+    assert!(end.checked_duration_since(start).is_some());
+    kani::cover!(end > start);
+    kani::cover!(end == start);
+}
+
+#[kani::proof]
+#[kani::stub(SystemTime::now, kani::stubs::system_time_now)]
+fn check_system_time() {
+    let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    assert!(since_epoch.as_secs() <= kani::stubs::MAX_SYSTEM_TIME_SECS);
+    kani::cover!(since_epoch == Duration::ZERO);
+}
+
+#[kani::proof]
+#[kani::stub(RandomState::new, kani::stubs::random_state_new)]
+#[kani::unwind(3)]
+fn check_hash_set() {
+    let mut set = HashSet::new();
+    let value: u8 = kani::any();
+    assert!(set.insert(value));
+    assert!(set.contains(&value));
+}
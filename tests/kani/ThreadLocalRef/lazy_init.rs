@@ -0,0 +1,50 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Checks that thread locals are lazily initialized on their first access, including the ones that
+// need to register a destructor.
+
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static INITIALIZED: AtomicU32 = AtomicU32::new(0);
+
+fn new_log() -> RefCell<Vec<u8>> {
+    INITIALIZED.fetch_add(1, Ordering::Relaxed);
+    RefCell::new(Vec::with_capacity(2))
+}
+
+thread_local! {
+    static LOG: RefCell<Vec<u8>> = new_log();
+    static NEXT_ID: Cell<u32> = const { Cell::new(1) };
+}
+
+fn record(byte: u8) {
+    LOG.with(|log| log.borrow_mut().push(byte));
+}
+
+fn next_id() -> u32 {
+    NEXT_ID.with(|id| {
+        let current = id.get();
+        id.set(current + 1);
+        current
+    })
+}
+
+#[kani::proof]
+#[kani::unwind(3)]
+fn test_lazy_init() {
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 0);
+    let byte: u8 = kani::any();
+    record(byte);
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 1);
+    record(byte);
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 1);
+    LOG.with(|log| assert_eq!(*log.borrow(), [byte, byte]));
+}
+
+#[kani::proof]
+fn test_const_init() {
+    assert_eq!(next_id(), 1);
+    assert_eq!(next_id(), 2);
+}
@@ -0,0 +1,93 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --default-unwind 5
+
+//! Check a hand-written UTC date formatter and time zone offset arithmetic against the
+//! timestamps generated by `kani::any_unix_timestamp` and
+//! `kani::any_unix_timestamp_around_boundary`.
+
+/// 1900-01-01T00:00:00Z
+const START_1900: i64 = -2_208_988_800;
+/// 2101-01-01T00:00:00Z, i.e., the end of 2100-12-31.
+const END_2100: i64 = 4_133_980_800;
+
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+/// The largest offset from UTC of any time zone, i.e., UTC+14:00.
+const MAX_TZ_OFFSET_SECS: i32 = 14 * 60 * 60;
+
+/// Convert a number of days since the Unix epoch into a `(year, month, day)` date of the
+/// proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Append `value` to `out` as a zero-padded decimal number of `width` digits.
+fn push_digits(out: &mut String, value: u32, width: u32) {
+    for idx in (0..width).rev() {
+        let digit = (value / 10u32.pow(idx)) % 10;
+        out.push(char::from(b'0' + digit as u8));
+    }
+}
+
+/// Format a timestamp as `YYYY-MM-DD hh:mm:ss`, or return `None` if the year doesn't have four
+/// digits.
+fn format_utc(timestamp: i64) -> Option<String> {
+    let (year, month, day) = civil_from_days(timestamp.div_euclid(SECS_PER_DAY));
+    if !(0..=9999).contains(&year) {
+        return None;
+    }
+    let secs_of_day = timestamp.rem_euclid(SECS_PER_DAY) as u32;
+    let mut out = String::with_capacity(19);
+    push_digits(&mut out, year as u32, 4);
+    out.push('-');
+    push_digits(&mut out, month, 2);
+    out.push('-');
+    push_digits(&mut out, day, 2);
+    out.push(' ');
+    push_digits(&mut out, secs_of_day / 3600, 2);
+    out.push(':');
+    push_digits(&mut out, secs_of_day / 60 % 60, 2);
+    out.push(':');
+    push_digits(&mut out, secs_of_day % 60, 2);
+    Some(out)
+}
+
+/// Convert a UTC timestamp to the local time of a time zone with the given offset.
+fn to_local(timestamp: i64, offset_secs: i32) -> i64 {
+    timestamp + offset_secs as i64
+}
+
+#[kani::proof]
+fn check_format_non_empty() {
+    let timestamp = kani::any_unix_timestamp(START_1900..END_2100);
+    let formatted = format_utc(timestamp).unwrap();
+    assert!(!formatted.is_empty());
+    assert_eq!(formatted.len(), 19);
+}
+
+#[kani::proof]
+fn check_tz_offset_no_overflow() {
+    let timestamp = kani::any_unix_timestamp(START_1900..END_2100);
+    let offset: i32 = kani::any_where(|offset: &i32| offset.abs() <= MAX_TZ_OFFSET_SECS);
+    let local = to_local(timestamp, offset);
+    assert!(local.abs_diff(timestamp) <= MAX_TZ_OFFSET_SECS as u64);
+}
+
+#[kani::proof]
+fn check_boundaries() {
+    let timestamp = kani::any_unix_timestamp_around_boundary();
+    let offset: i32 = kani::any_where(|offset: &i32| offset.abs() <= MAX_TZ_OFFSET_SECS);
+    assert!(format_utc(to_local(timestamp, offset)).is_some());
+    kani::cover!(timestamp < 0);
+    kani::cover!(i32::try_from(timestamp).is_err());
+}
@@ -0,0 +1,95 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a UUID-based LRU cache handles all symbolic UUIDs as keys, and that
+//! UUIDs generated by `kani::any_uuid_v4` are always formatted as valid RFC 4122
+//! version 4 strings.
+
+type Uuid = [u8; 16];
+
+/// A tiny LRU cache where the most recently used entry is at the end of `entries`.
+struct LruCache {
+    entries: Vec<(Uuid, u32)>,
+    capacity: usize,
+}
+
+impl LruCache {
+    fn new(capacity: usize) -> Self {
+        LruCache { entries: Vec::with_capacity(capacity), capacity }
+    }
+
+    fn get(&mut self, key: &Uuid) -> Option<u32> {
+        let idx = self.entries.iter().position(|(k, _)| k == key)?;
+        let entry = self.entries.remove(idx);
+        self.entries.push(entry);
+        Some(entry.1)
+    }
+
+    fn put(&mut self, key: Uuid, value: u32) {
+        if let Some(idx) = self.entries.iter().position(|(k, _)| *k == key) {
+            self.entries.remove(idx);
+        } else if self.entries.len() == self.capacity {
+            self.entries.remove(0);
+        }
+        self.entries.push((key, value));
+    }
+}
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+fn uuid_v4_to_string(uuid: &Uuid) -> String {
+    let mut out = String::with_capacity(36);
+    for (idx, byte) in uuid.iter().enumerate() {
+        if matches!(idx, 4 | 6 | 8 | 10) {
+            out.push('-');
+        }
+        out.push(HEX[(byte >> 4) as usize] as char);
+        out.push(HEX[(byte & 0xf) as usize] as char);
+    }
+    out
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_lru_cache() {
+    let first = kani::any_uuid();
+    let second = kani::any_uuid();
+    let third = kani::any_uuid();
+    kani::assume(first != third && second != third);
+
+    let mut cache = LruCache::new(2);
+    cache.put(first, 1);
+    cache.put(second, 2);
+    if first == second {
+        assert_eq!(cache.get(&first), Some(2));
+    } else {
+        assert_eq!(cache.get(&first), Some(1));
+    }
+    assert_eq!(cache.get(&second), Some(2));
+
+    // `first` is now the least recently used key, unless it's the same as `second`.
+    cache.put(third, 3);
+    assert_eq!(cache.get(&third), Some(3));
+    assert_eq!(cache.get(&second), Some(2));
+    if first != second {
+        assert_eq!(cache.get(&first), None);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(37)]
+fn check_uuid_v4_to_string() {
+    let uuid = kani::any_uuid_v4();
+    let text = uuid_v4_to_string(&uuid);
+    let bytes = text.as_bytes();
+    assert_eq!(bytes.len(), 36);
+    assert_eq!(bytes[14], b'4');
+    assert!(matches!(bytes[19], b'8' | b'9' | b'a' | b'b'));
+    for (idx, byte) in bytes.iter().enumerate() {
+        if matches!(idx, 8 | 13 | 18 | 23) {
+            assert_eq!(*byte, b'-');
+        } else {
+            assert!(byte.is_ascii_digit() || (b'a'..=b'f').contains(byte));
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Compare the cost of summing 16 symbolic elements of a vector built with
+//! `kani::vec::exact_vec` against one built by pushing the elements in a loop, which requires
+//! Kani to model the reallocations of the vector as it grows.
+
+const LEN: usize = 16;
+
+fn sum(values: &[u8]) -> u32 {
+    values.iter().map(|value| u32::from(*value)).sum()
+}
+
+#[kani::proof]
+#[kani::unwind(17)]
+fn check_sum_exact_vec() {
+    let values = kani::vec::exact_vec::<u8, LEN>();
+    assert_eq!(values.capacity(), LEN);
+    assert!(sum(&values) <= 255 * LEN as u32);
+}
+
+#[kani::proof]
+#[kani::unwind(17)]
+fn check_sum_push_loop() {
+    let mut values = Vec::new();
+    for _ in 0..LEN {
+        values.push(kani::any::<u8>());
+    }
+    assert!(sum(&values) <= 255 * LEN as u32);
+}
+
+#[kani::proof]
+#[kani::unwind(17)]
+fn check_same_sum() {
+    let exact = kani::vec::exact_vec::<u8, LEN>();
+    let mut pushed = Vec::new();
+    for value in &exact {
+        pushed.push(*value);
+    }
+    assert_eq!(sum(&exact), sum(&pushed));
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--check-feature-parity` warns about the features that are only enabled by
+# `cargo test`, and that the run summary records the features that each package was built with
+# for verification.
+
+set -e
+
+cargo kani -Z unstable-options --check-feature-parity --summary-json summary.json
+python3 -c "
+import json
+features = json.load(open('summary.json'))['features']
+print('helper was built with: [' + ', '.join(features['helper v0.1.0']) + ']')
+"
+
+rm -rf summary.json .kani
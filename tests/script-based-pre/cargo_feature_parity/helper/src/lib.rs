@@ -0,0 +1,6 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+pub fn double(x: u8) -> u8 {
+    if cfg!(feature = "extra") { x.wrapping_mul(2) } else { x.saturating_mul(2) }
+}
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `cargo kani list --fast` lists the harnesses, flags that the results were
+# collected without codegen, and doesn't generate any goto model.
+
+set -e
+
+rm -rf target
+cargo kani list --fast
+
+models=$(find target -name "*.symtab.out" | wc -l)
+if [ "$models" -ne 0 ]; then
+    echo "Expected no goto models, but found $models"
+    exit 1
+fi
+echo "No goto models were generated"
+rm -rf target
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Crate used to check that `cargo kani list --fast` lists harnesses without generating code.
+
+pub fn add(a: u32, b: u32) -> Option<u32> {
+    a.checked_add(b)
+}
+
+#[cfg(kani)]
+mod verify {
+    use super::*;
+
+    #[kani::proof]
+    fn check_add() {
+        let a: u32 = kani::any();
+        assert_eq!(add(a, 0), Some(a));
+    }
+
+    #[kani::proof(owner = "team-math")]
+    fn check_add_overflow() {
+        assert!(add(u32::MAX, 1).is_none());
+    }
+}
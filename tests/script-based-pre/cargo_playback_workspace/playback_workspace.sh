@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Check that playback from the root of a workspace runs the tests of the package that defines
+# them.
+
+set +e
+
+function check_playback {
+  local OUTPUT=output.log
+  cargo kani playback "${@}" >& $OUTPUT
+  # Sort output so we can rely on the order.
+  echo "$(grep "test verify::.* ok\|Running unittests\|error:" $OUTPUT | sort)"
+  echo
+  echo "======= Raw Output ======="
+  cat $OUTPUT
+  echo "=========================="
+  echo
+  rm $OUTPUT
+}
+
+pushd workspace > /dev/null
+cargo clean
+
+echo "[TEST] Only first tests..."
+check_playback -Z concrete-playback -- kani_concrete_playback_cover_first
+
+echo "[TEST] Only second tests..."
+check_playback -Z concrete-playback -- verify::kani_concrete_playback_cover_second_1234 --exact
+
+echo "[TEST] Ambiguous tests..."
+check_playback -Z concrete-playback -- kani_concrete_playback_cover_shared
+
+echo "[TEST] Selected package..."
+check_playback -Z concrete-playback -p second -- kani_concrete_playback_cover_shared
+
+cargo clean
+popd > /dev/null
@@ -0,0 +1,35 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Member of a workspace with playback tests generated by the concrete playback.
+
+#[cfg(kani)]
+mod verify {
+    #[kani::proof]
+    fn cover_second() {
+        kani::cover!(kani::any::<u8>() == 200u8);
+    }
+
+    #[kani::proof]
+    fn cover_shared() {
+        kani::cover!(kani::any::<u8>() == 100u8);
+    }
+
+    #[test]
+    fn kani_concrete_playback_cover_second_1234() {
+        let concrete_vals: Vec<Vec<u8>> = vec![
+            // 200
+            vec![200],
+        ];
+        kani::concrete_playback_run(concrete_vals, cover_second);
+    }
+
+    #[test]
+    fn kani_concrete_playback_cover_shared_1234() {
+        let concrete_vals: Vec<Vec<u8>> = vec![
+            // 100
+            vec![100],
+        ];
+        kani::concrete_playback_run(concrete_vals, cover_shared);
+    }
+}
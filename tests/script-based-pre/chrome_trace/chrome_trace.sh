@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--emit-chrome-trace` records the phases and harnesses of the run
+
+set -eu
+
+OUT_DIR=$(mktemp -d)
+TRACE=${OUT_DIR}/trace.json
+
+kani harnesses.rs --emit-chrome-trace ${TRACE} > /dev/null
+
+for event in '"name":"build","cat":"phase"' \
+             '"name":"verify","cat":"phase"' \
+             '"name":"first","cat":"harness"' \
+             '"name":"second","cat":"harness"'; do
+    if ! grep -q "${event}" ${TRACE}; then
+        echo "error: missing event ${event}"
+        exit 1
+    fi
+done
+echo "success: trace contains the expected events"
+
+rm -rf ${OUT_DIR}
@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::proof]
+fn first() {
+    let i: u8 = kani::any();
+    assert!(i.checked_add(0).is_some());
+}
+
+#[kani::proof]
+fn second() {
+    let i: u32 = kani::any();
+    kani::assume(i < 10);
+    assert!(i < 20);
+}
@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--dump-symtab` writes the symbol table of the selected harness in CBMC's JSON
+# symtab format without verifying it, and that it requires a single harness.
+
+set -u
+
+OUT_DIR=$(mktemp -d)
+
+echo "[TEST] Dump"
+kani harnesses.rs --harness check_manhattan --dump-symtab ${OUT_DIR}/symtab.json
+python3 -c "
+import json, sys
+symbols = json.load(open(sys.argv[1]))['symbolTable'].values()
+pretty_names = [str(symbol['prettyName']) for symbol in symbols]
+print('harness:', any(name.endswith('check_manhattan') for name in pretty_names))
+print('function:', any(name.endswith('::manhattan') or name == 'manhattan' for name in pretty_names))
+print('types:', any(symbol['isType'] for symbol in symbols))
+" ${OUT_DIR}/symtab.json
+
+echo "[TEST] Multiple harnesses"
+kani harnesses.rs --dump-symtab ${OUT_DIR}/all.json
+echo "exit code: $?"
+
+rm -rf ${OUT_DIR} .kani
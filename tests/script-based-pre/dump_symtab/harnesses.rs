@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn manhattan(p: &Point) -> u32 {
+    p.x.unsigned_abs() + p.y.unsigned_abs()
+}
+
+#[kani::proof]
+fn check_manhattan() {
+    let p = Point { x: kani::any_where(|x| *x < 10), y: 0 };
+    assert!(manhattan(&p) < 10 || p.x < 0);
+}
+
+#[kani::proof]
+fn check_origin() {
+    assert_eq!(manhattan(&Point { x: 0, y: 0 }), 0);
+}
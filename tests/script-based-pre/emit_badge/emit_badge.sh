@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--emit-badge` writes the shields.io endpoint JSON and the SVG image of the badge,
+# counting the contract harnesses separately, including when verification fails.
+
+set -eu
+
+OUT_DIR=$(mktemp -d)
+
+echo "[TEST] Passing run"
+kani harnesses.rs -Z function-contracts --harness check_pass --harness check_increment \
+    --emit-badge ${OUT_DIR}/badge.json > /dev/null
+cat ${OUT_DIR}/badge.json
+echo
+
+echo "[TEST] Failing run"
+if kani harnesses.rs -Z function-contracts --emit-badge ${OUT_DIR}/badge.json > /dev/null; then
+    echo "error: verification should have failed"
+    exit 1
+fi
+cat ${OUT_DIR}/badge.json
+echo
+grep -o 'aria-label="[^"]*"' ${OUT_DIR}/badge.svg
+
+rm -rf ${OUT_DIR} .kani
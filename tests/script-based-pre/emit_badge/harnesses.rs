@@ -0,0 +1,25 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::requires(x < 100)]
+#[kani::ensures(|result| *result > x)]
+fn increment(x: u8) -> u8 {
+    x + 1
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    increment(kani::any());
+}
+
+#[kani::proof]
+fn check_pass() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(0).is_some());
+}
+
+#[kani::proof]
+fn check_fail() {
+    let x: u8 = kani::any();
+    assert!(x < 200);
+}
@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--emit-reachable` writes the functions reachable from each harness, and that
+# diffing the files of two runs shows the functions that a change made reachable.
+
+set -eu
+
+OUT_DIR=$(mktemp -d)
+
+kani harnesses.rs --emit-reachable ${OUT_DIR}/before.json > /dev/null
+# Make `parse` call the slow path instead.
+sed 's/^    fast_path(x)$/    slow_path(x)/' harnesses.rs > ${OUT_DIR}/harnesses.rs
+kani ${OUT_DIR}/harnesses.rs --emit-reachable ${OUT_DIR}/after.json > /dev/null
+
+echo "[TEST] Reachable functions"
+python3 -c "
+import json, sys
+reachable = json.load(open(sys.argv[1]))
+for harness, functions in reachable.items():
+    print(harness, [f for f in functions if f in ('parse', 'fast_path', 'slow_path')])
+" ${OUT_DIR}/before.json
+
+echo "[TEST] Diff"
+diff ${OUT_DIR}/before.json ${OUT_DIR}/after.json | grep '^[<>] .*_path' || true
+
+rm -rf ${OUT_DIR} .kani
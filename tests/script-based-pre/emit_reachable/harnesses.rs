@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+fn fast_path(x: u8) -> u8 {
+    x / 2
+}
+
+#[allow(dead_code)]
+fn slow_path(x: u8) -> u8 {
+    (0..x).filter(|i| i % 2 == 1).count() as u8
+}
+
+fn parse(x: u8) -> u8 {
+    fast_path(x)
+}
+
+#[kani::proof]
+fn check_parse() {
+    let x: u8 = kani::any_where(|x| *x < 4);
+    assert!(parse(x) <= x);
+}
+
+#[kani::proof]
+fn check_fast_path() {
+    let x: u8 = kani::any();
+    assert!(fast_path(x) <= x);
+}
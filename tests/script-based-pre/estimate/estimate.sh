@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--estimate` reports the runtime of the harnesses recorded in the timing cache by
+# the previous runs, reports the other harnesses as unknown, and neither verifies nor generates
+# code for any harness.
+
+set -eu
+
+TARGET_DIR=$(mktemp -d)
+
+# Each run merges the runtime of the harnesses that it verified into the timing cache.
+kani harnesses.rs --target-dir ${TARGET_DIR} --harness first > /dev/null
+kani harnesses.rs --target-dir ${TARGET_DIR} --harness second > /dev/null
+
+OUTPUT=$(kani harnesses.rs --target-dir ${TARGET_DIR} -Z unstable-options --estimate -j 2 \
+    --output-format=terse)
+echo "${OUTPUT}"
+if echo "${OUTPUT}" | grep -q "VERIFICATION"; then
+    echo "error: --estimate verified the harnesses"
+    exit 1
+fi
+if ls ${TARGET_DIR}/*.out > /dev/null 2>&1; then
+    echo "error: --estimate generated code for the harnesses"
+    exit 1
+fi
+
+rm -rf ${TARGET_DIR}
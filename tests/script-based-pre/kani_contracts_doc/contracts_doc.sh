@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `kani contracts-doc` documents the contract clauses of each function, including
+# contracts spanning multiple attributes and generic functions, with the status of their
+# harnesses in the given run summary.
+
+set -e
+
+rm -rf kani-contracts-doc kani-contracts-doc.json summary.json
+
+# The harness of `buggy_inc` fails, so don't stop here.
+kani src/lib.rs -Z function-contracts --summary-json summary.json > /dev/null || true
+
+kani contracts-doc src/lib.rs -Z function-contracts --report summary.json > /dev/null
+for fragment in kani-contracts-doc/*.md; do
+    echo "== $(basename "$fragment")"
+    cat "$fragment"
+done
+
+kani contracts-doc src/lib.rs -Z function-contracts --report summary.json --format json \
+    > /dev/null
+cat kani-contracts-doc.json
+
+rm -rf kani-contracts-doc kani-contracts-doc.json summary.json .kani
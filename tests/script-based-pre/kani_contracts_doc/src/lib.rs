@@ -0,0 +1,54 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Functions with contracts documented by `kani contracts-doc`.
+
+pub mod ops {
+    #[kani::requires(divisor != 0)]
+    #[kani::ensures(|result: &u32| *result <= dividend)]
+    pub fn div(dividend: u32, divisor: u32) -> u32 {
+        dividend / divisor
+    }
+
+    #[kani::requires(*x < 100)]
+    #[kani::requires(*x > 2)]
+    #[kani::ensures(|_| old(*x + 1) == *x)]
+    #[kani::modifies(x)]
+    pub fn buggy_inc(x: &mut u32) {
+        *x += 2;
+    }
+
+    #[kani::ensures(|result: &T| *result == *x)]
+    pub fn copy<T: Copy + PartialEq>(x: &T) -> T {
+        *x
+    }
+
+    #[kani::requires(true)]
+    pub fn unverified() {}
+}
+
+#[cfg(kani)]
+mod verify {
+    use crate::ops;
+
+    #[kani::proof_for_contract(ops::div)]
+    fn check_div() {
+        ops::div(kani::any(), kani::any());
+    }
+
+    #[kani::proof_for_contract(ops::buggy_inc)]
+    fn check_buggy_inc() {
+        let mut x = kani::any();
+        ops::buggy_inc(&mut x);
+    }
+
+    #[kani::proof_for_contract(ops::copy)]
+    fn check_copy_u8() {
+        ops::copy(&kani::any::<u8>());
+    }
+
+    #[kani::proof_for_contract(ops::copy)]
+    fn check_copy_i64() {
+        ops::copy(&kani::any::<i64>());
+    }
+}
@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `kani-cov report --format lcov --output` writes a combined LCOV report in which each
+# branch of `div` is covered by one of the harnesses, and that `--output` requires `lcov`.
+
+set -eu
+
+OUTPUT=$(kani --coverage -Z source-coverage test.rs)
+COV_DIR=$(echo "${OUTPUT}" | grep "Coverage results saved to" | awk '{print $NF}')
+COV_NAME=$(basename ${COV_DIR})
+
+kani-cov merge ${COV_DIR}/*_kaniraw.json --output ${COV_DIR}/merged.json
+kani-cov report ${COV_DIR}/${COV_NAME}_kanimap.json --profile ${COV_DIR}/merged.json \
+    --format lcov --output ${COV_DIR}/kani.lcov
+cat ${COV_DIR}/kani.lcov
+
+if kani-cov report ${COV_DIR}/${COV_NAME}_kanimap.json --profile ${COV_DIR}/merged.json \
+    --format escapes --output ${COV_DIR}/escapes.txt 2>&1; then
+    echo "error: --output was accepted with the escapes format"
+    exit 1
+fi
+
+rm -rf ${COV_DIR}
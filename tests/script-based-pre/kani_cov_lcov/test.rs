@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+fn div(x: u16, y: u16) -> u16 {
+    if y != 0 { x / y } else { 0 }
+}
+
+fn unused() -> u16 {
+    1
+}
+
+#[kani::proof]
+fn nonzero() {
+    div(11, 3);
+}
+
+#[kani::proof]
+fn zero() {
+    div(11, 0);
+}
@@ -0,0 +1,9 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `kani list` reports the harnesses that `proof_for_contract_all_impls` generates
+# for every implementation of the trait.
+
+kani list -Z function-contracts src/lib.rs --format json > /dev/null
+cat kani-list.json
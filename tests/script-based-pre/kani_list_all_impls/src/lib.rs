@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that `kani list` lists one contract harness per implementation of the trait.
+
+trait Codec: Sized {
+    fn decode(byte: u8) -> Option<Self>;
+}
+
+#[derive(kani::Arbitrary)]
+struct Digit(u8);
+
+impl Codec for Digit {
+    #[kani::requires(byte < 10)]
+    #[kani::ensures(|result: &Option<Digit>| result.is_some())]
+    fn decode(byte: u8) -> Option<Self> {
+        (byte < 10).then_some(Digit(byte))
+    }
+}
+
+#[derive(kani::Arbitrary)]
+struct Flag(bool);
+
+impl Codec for Flag {
+    #[kani::ensures(|result: &Option<Flag>| result.is_some())]
+    fn decode(byte: u8) -> Option<Self> {
+        (byte <= 1).then_some(Flag(byte == 1))
+    }
+}
+
+/// Skipped, since it doesn't implement `Arbitrary`.
+struct Opaque(u8);
+
+impl Codec for Opaque {
+    #[kani::ensures(|result: &Option<Opaque>| result.is_some())]
+    fn decode(byte: u8) -> Option<Self> {
+        Some(Opaque(byte))
+    }
+}
+
+#[kani::proof_for_contract_all_impls(Codec::decode)]
+fn check_decode<T: Codec + kani::Arbitrary>() {
+    let _ = T::decode(kani::any());
+}
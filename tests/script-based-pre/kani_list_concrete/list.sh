@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `kani list` reports the harnesses without symbolic inputs, both in the terminal
+# output and in the JSON file.
+
+set -eu
+
+OUTPUT=$(kani list test.rs)
+echo "${OUTPUT}"
+if echo "${OUTPUT}" | grep -q "harness \`check_symbolic\` has no symbolic inputs"; then
+    echo "error: check_symbolic was reported as concrete"
+    exit 1
+fi
+kani list test.rs --format json > /dev/null
+cat kani-list.json
+rm kani-list.json
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A crate with a harness that only checks a single concrete execution.
+
+fn double(x: u8) -> u16 {
+    x as u16 * 2
+}
+
+#[kani::proof]
+fn check_concrete() {
+    assert_eq!(double(21), 42);
+}
+
+#[kani::proof]
+fn check_symbolic() {
+    let x: u8 = kani::any();
+    assert!(double(x) % 2 == 0);
+}
@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+fn max(a: u8, b: u8) -> u8 {
+    if a > b { a } else { b }
+}
+
+fn is_small(x: u8) -> bool {
+    x < 10
+}
+
+#[kani::proof]
+fn check_max() {
+    let a: u8 = kani::any();
+    let b: u8 = kani::any();
+    let result = max(a, b);
+    assert!(result >= a && result >= b);
+    assert!(result == a || result == b);
+}
+
+/// This harness doesn't check the boundary of `is_small`, so the off-by-one mutants survive.
+#[kani::proof]
+fn check_is_small() {
+    let x: u8 = kani::any();
+    kani::assume(x < 5);
+    assert!(is_small(x));
+}
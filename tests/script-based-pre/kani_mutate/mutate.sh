@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `kani mutate` kills the mutants detected by the harnesses, reports the ones that
+# survive, and doesn't modify the source file.
+
+set -eu
+
+BEFORE=$(cksum lib.rs)
+OUTPUT=$(kani mutate -Z mutate lib.rs --mutant-timeout 60s)
+echo "${OUTPUT}"
+
+if [[ "$(cksum lib.rs)" != "${BEFORE}" ]]; then
+    echo "error: the source file was modified"
+    exit 1
+fi
+
+check() {
+    if ! echo "${OUTPUT}" | grep -q "$1"; then
+        echo "error: missing $1"
+        exit 1
+    fi
+}
+
+check 'replace `>` with `<=` ... KILLED'
+check 'replace `<` with `>=` ... KILLED'
+check 'add one to `10` ... SURVIVED'
+check 'subtract one from `10` ... SURVIVED'
+echo "success: mutants were classified as expected"
+
+# The number of mutants checked can be limited.
+OUTPUT=$(kani mutate -Z mutate lib.rs --max-mutants 1)
+check 'Checking 1 of'
+check '\[1/1\]'
+echo "success: --max-mutants limits the number of mutants"
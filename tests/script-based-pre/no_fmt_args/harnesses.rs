@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Harness whose panics format custom types, which pulls their `Debug` implementations.
+
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+enum ParseError {
+    Empty,
+    Invalid { position: usize, found: char },
+    TooLong(Vec<u8>),
+}
+
+fn parse(input: &[u8; 4]) -> Result<u32, ParseError> {
+    let mut value = 0u32;
+    for (position, byte) in input.iter().enumerate() {
+        match byte {
+            b'0'..=b'9' => value = value * 10 + (byte - b'0') as u32,
+            0 if position == 0 => return Err(ParseError::Empty),
+            0 => break,
+            _ => return Err(ParseError::Invalid { position, found: *byte as char }),
+        }
+    }
+    if value > 1000 { Err(ParseError::TooLong(input.to_vec())) } else { Ok(value) }
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_parse() {
+    let input: [u8; 4] = kani::any();
+    kani::assume(input.iter().all(|b| b.is_ascii_digit() || *b == 0));
+    let mut cache = BTreeMap::new();
+    let value = parse(&input).expect("digits are always valid");
+    cache.insert(input, value);
+    assert!(value < 1000, "unexpected value {value} for {input:?} in {cache:?}");
+}
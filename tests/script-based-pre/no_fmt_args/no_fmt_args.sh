@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that removing the panic formatting arguments reduces the number of VCCs generated for a
+# harness whose panics format custom types, and that the panics are still detected.
+
+set -eu
+
+vccs() {
+    grep -o "Generated [0-9]* VCC(s)" | grep -o "[0-9]*" | head -1
+}
+
+DEFAULT_OUT=$(kani harnesses.rs || true)
+KEEP_OUT=$(kani harnesses.rs --keep-fmt || true)
+
+for out in "${DEFAULT_OUT}" "${KEEP_OUT}"; do
+    if ! echo "${out}" | grep -q "VERIFICATION:- FAILED"; then
+        echo "error: expected the harness to fail"
+        exit 1
+    fi
+    if ! echo "${out}" | grep -q "unexpected value"; then
+        echo "error: expected the panic message to be reported"
+        exit 1
+    fi
+done
+
+DEFAULT_VCCS=$(echo "${DEFAULT_OUT}" | vccs)
+KEEP_VCCS=$(echo "${KEEP_OUT}" | vccs)
+if [[ ${DEFAULT_VCCS} -ge ${KEEP_VCCS} ]]; then
+    echo "error: expected fewer VCCs without formatting (${DEFAULT_VCCS}) than with (${KEEP_VCCS})"
+    exit 1
+fi
+echo "success: removing the formatting arguments reduced the number of VCCs"
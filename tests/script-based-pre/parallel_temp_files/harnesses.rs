@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Two harnesses with virtual calls, so each of them needs its own vtable restrictions.
+
+trait Shape {
+    fn sides(&self) -> u8;
+}
+
+struct Triangle;
+struct Square;
+
+impl Shape for Triangle {
+    fn sides(&self) -> u8 {
+        3
+    }
+}
+
+impl Shape for Square {
+    fn sides(&self) -> u8 {
+        4
+    }
+}
+
+#[kani::proof]
+fn check_triangle() {
+    let shape: &dyn Shape = &Triangle;
+    assert_eq!(shape.sides(), 3);
+}
+
+#[kani::proof]
+fn check_square() {
+    let shape: Box<dyn Shape> = Box::new(Square);
+    assert_eq!(shape.sides(), 4);
+}
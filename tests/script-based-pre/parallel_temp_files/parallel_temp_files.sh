@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Test that the temporary files of two harnesses verified in parallel don't collide
+set -o pipefail
+set -o nounset
+
+OUT_DIR=temp_files_out
+rm -rf ${OUT_DIR}
+mkdir ${OUT_DIR}
+cp harnesses.rs ${OUT_DIR}
+cd ${OUT_DIR}
+
+echo "[TEST] Verify in parallel..."
+kani harnesses.rs -j 2 --output-format=terse -Z restrict-vtable --keep-temps --gen-c
+
+echo "[TEST] Count files..."
+echo "Restrictions: $(ls *.linked-restrictions.json | sort -u | wc -l) files"
+echo "Demangled C: $(ls *.demangled.c | sort -u | wc -l) files"
+
+# Cleanup
+cd ..
+rm -rf ${OUT_DIR}
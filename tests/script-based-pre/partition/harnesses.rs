@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::proof]
+fn check_add() {
+    let x: u8 = kani::any();
+    assert_eq!(x.wrapping_add(1).wrapping_sub(1), x);
+}
+
+#[kani::proof]
+fn check_mul() {
+    let x: u8 = kani::any_where(|x| *x < 16);
+    assert!(x.checked_mul(16).is_some());
+}
+
+#[kani::proof]
+fn check_shift() {
+    let x: u32 = kani::any();
+    assert_eq!(x.rotate_left(8).rotate_right(8), x);
+}
+
+#[kani::proof]
+fn check_neg() {
+    let x: i16 = kani::any_where(|x| *x != i16::MIN);
+    assert_eq!(-(-x), x);
+}
+
+#[kani::proof]
+fn check_max() {
+    let x: u16 = kani::any();
+    let y: u16 = kani::any();
+    assert!(x.max(y) >= x && x.max(y) >= y);
+}
+
+#[kani::proof]
+fn check_abs() {
+    let x: i8 = kani::any();
+    assert_eq!(x.unsigned_abs() as i16, (x as i16).abs());
+}
@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--partition N/M` splits the harnesses into disjoint shards that cover all of them,
+# and that rerunning a shard verifies the same harnesses.
+
+set -eu
+
+OUT_DIR=$(mktemp -d)
+
+for shard in 1 2 3; do
+    kani harnesses.rs --partition ${shard}/3 --output-format=terse > ${OUT_DIR}/shard${shard}.log
+    grep "^Partition" ${OUT_DIR}/shard${shard}.log | sed 's/verifying [0-9]* out/verifying _ out/'
+    grep -o "Checking harness [a-z_]*" ${OUT_DIR}/shard${shard}.log \
+        | sort > ${OUT_DIR}/shard${shard}.txt
+done
+
+kani harnesses.rs --partition 2/3 --output-format=terse \
+    | grep -o "Checking harness [a-z_]*" | sort > ${OUT_DIR}/rerun.txt
+if diff ${OUT_DIR}/shard2.txt ${OUT_DIR}/rerun.txt; then
+    echo "Rerunning a shard verified the same harnesses"
+fi
+
+ALL=$(cat ${OUT_DIR}/shard{1,2,3}.txt | sort)
+if [[ $(echo "${ALL}" | wc -l) -eq 6 && $(echo "${ALL}" | uniq | wc -l) -eq 6 ]]; then
+    echo "Each harness was verified by exactly one shard"
+fi
+
+rm -rf ${OUT_DIR}
@@ -20,5 +20,19 @@ kani playback -Z concrete-playback ${RS_FILE} -- kani_concrete_playback
 echo "[TEST] Json format..."
 kani playback -Z concrete-playback ${RS_FILE} --only-codegen --message-format=json -- kani_concrete_playback
 
+echo "[TEST] Json only..."
+kani playback -Z concrete-playback ${RS_FILE} --message-format=json -- kani_concrete_playback \
+  > stdout.log 2> stderr.log
+echo "Lines: $(wc -l < stdout.log)"
+grep -v '^{"artifact":' stdout.log || echo "Only JSON messages"
+grep "test result" stderr.log
+
+echo "[TEST] Json only quiet..."
+kani playback -Z concrete-playback ${RS_FILE} --message-format=json --quiet \
+  -- kani_concrete_playback > stdout.log 2> stderr.log
+echo "Lines: $(wc -l < stdout.log)"
+grep -v '^{"artifact":' stdout.log || echo "Only JSON messages"
+echo "Quiet lines: $(wc -l < stderr.log)"
+
 # Cleanup
-rm ${RS_FILE}
+rm ${RS_FILE} stdout.log stderr.log
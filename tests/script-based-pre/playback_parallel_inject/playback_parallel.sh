@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Test that concrete playback injects the tests of harnesses verified in parallel
+set -o pipefail
+set -o nounset
+
+RS_FILE="modified.rs"
+cp original.rs ${RS_FILE}
+
+echo "[TEST] Generate test..."
+kani ${RS_FILE} -j 2 --output-format=terse -Z concrete-playback --concrete-playback=inplace
+
+echo "[TEST] Count tests..."
+echo "Injected $(grep -c 'fn kani_concrete_playback' ${RS_FILE}) tests"
+
+echo "[TEST] Run test..."
+kani playback -Z concrete-playback ${RS_FILE} -- kani_concrete_playback
+
+# Cleanup
+rm ${RS_FILE}
@@ -0,0 +1,54 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the tests that Kani injects in place, when more harnesses than jobs are verified in
+//! parallel, are added to the right harness, and that they replay the values of that harness.
+//! The harnesses use inputs of different sizes, so the edits to the source file differ in length.
+
+extern crate kani;
+
+#[cfg(kani)]
+mod verify {
+    #[kani::proof]
+    fn check_u8() {
+        let val: u8 = kani::any();
+        kani::assume(val == 8);
+        kani::cover!(true, "u8");
+    }
+
+    #[kani::proof]
+    fn check_u16() {
+        let val: u16 = kani::any();
+        kani::assume(val == 1_600);
+        kani::cover!(true, "u16");
+    }
+
+    #[kani::proof]
+    fn check_u32() {
+        let val: u32 = kani::any();
+        kani::assume(val == 3_200_000);
+        kani::cover!(true, "u32");
+    }
+
+    #[kani::proof]
+    fn check_u64() {
+        let val: u64 = kani::any();
+        kani::assume(val == 6_400_000_000);
+        kani::cover!(true, "u64");
+    }
+
+    #[kani::proof]
+    fn check_pair() {
+        let first: u8 = kani::any();
+        let second: u32 = kani::any();
+        kani::assume(first == 1 && second == 2);
+        kani::cover!(true, "pair");
+    }
+
+    #[kani::proof]
+    fn check_array() {
+        let vals: [u16; 4] = kani::any();
+        kani::assume(vals == [1, 2, 3, 4]);
+        kani::cover!(true, "array");
+    }
+}
@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Test that concrete playback injects one test per harness, each one next to its own harness, when
+# more harnesses than jobs are verified in parallel.
+set -o pipefail
+set -o nounset
+
+RS_FILE="modified.rs"
+cp original.rs ${RS_FILE}
+
+echo "[TEST] Generate test..."
+kani ${RS_FILE} -j 4 --output-format=terse -Z concrete-playback --concrete-playback=inplace \
+    > /dev/null
+
+echo "[TEST] Count tests..."
+for harness in check_u8 check_u16 check_u32 check_u64 check_pair check_array; do
+    echo "${harness}: $(grep -c "fn kani_concrete_playback_${harness}_" ${RS_FILE}) test"
+done
+
+echo "[TEST] Check placement..."
+# Every test must directly follow the harness that it replays.
+awk '/fn check_/ { harness = $2; sub(/\(.*/, "", harness) }
+     /fn kani_concrete_playback_/ && index($2, "kani_concrete_playback_" harness "_") != 1 {
+         print "Misplaced " $2; bad = 1
+     }
+     END { if (!bad) print "All tests are placed after their harness" }' ${RS_FILE}
+
+echo "[TEST] Run test..."
+kani playback -Z concrete-playback ${RS_FILE} -- kani_concrete_playback
+
+# Cleanup
+rm ${RS_FILE}
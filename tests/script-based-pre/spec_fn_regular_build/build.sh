@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Test that specification functions are removed from regular builds, so a call from ordinary
+# code is a compile error instead of a panic at run time.
+
+set -u
+
+rm -rf target
+
+echo "[TEST] Regular build..."
+if cargo build 2>&1; then
+    echo "The regular build succeeded"
+fi
+
+echo "[TEST] Regular build with a misuse..."
+if ! cargo build --features misuse 2>&1; then
+    echo "The misuse was rejected"
+fi
+
+rm -rf target
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A specification function is only compiled with `--cfg kani`, so the contract that uses it
+//! builds, while a call from ordinary code fails to compile.
+
+#[kani::spec_fn]
+fn is_sorted(values: &[u32]) -> bool {
+    values.windows(2).all(|pair| pair[0] <= pair[1])
+}
+
+#[kani::requires(is_sorted(values))]
+pub fn first(values: &[u32]) -> Option<u32> {
+    values.first().copied()
+}
+
+#[cfg(feature = "misuse")]
+pub fn check_sorted(values: &[u32]) -> bool {
+    is_sorted(values)
+}
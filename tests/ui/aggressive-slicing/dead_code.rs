@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --aggressive-slicing --verbose
+
+//! Check that `--aggressive-slicing` removes code that cannot reach any property, reports it
+//! under `--verbose`, and preserves the verification results.
+
+fn expensive_cleanup(data: &mut [u32; 8]) {
+    for value in data.iter_mut() {
+        *value = value.wrapping_mul(31).rotate_left(3);
+    }
+}
+
+#[kani::proof]
+fn check_sliced_success() {
+    let x: u32 = kani::any();
+    kani::assume(x < 100);
+    assert!(x + 1 <= 100);
+    // Nothing below can reach a property.
+    let mut data = [x; 8];
+    expensive_cleanup(&mut data);
+}
+
+#[kani::proof]
+fn check_sliced_failure() {
+    let x: u8 = kani::any();
+    let y: u8 = kani::any();
+    assert!(x != y, "values may be equal");
+}
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_fn_ptr!` rejects functions whose signature doesn't match the
+//! function pointer type.
+
+fn double(x: u32) -> u32 {
+    x.wrapping_mul(2)
+}
+
+fn negate(x: i32) -> i32 {
+    -x
+}
+
+#[kani::proof]
+fn check_mismatch() {
+    let callback = kani::any_fn_ptr!(fn(u32) -> u32 from [double, negate]);
+    callback(kani::any());
+}
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --assertion-semantics=debug-ignore
+
+//! Check that with `--assertion-semantics=debug-ignore`, the harness below passes,
+//! since the division by zero panics.
+//! The same harness is verified with the other semantics in the other tests of this folder.
+
+fn average(values: &[u32]) -> u32 {
+    debug_assert!(!values.is_empty(), "average of an empty slice");
+    values.iter().sum::<u32>() / values.len() as u32
+}
+
+#[kani::proof]
+#[kani::should_panic]
+fn check_empty_average() {
+    let values = [1u32; 2];
+    let len: usize = kani::any_where(|len| *len <= 2);
+    average(&values[..len]);
+}
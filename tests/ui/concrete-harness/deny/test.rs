@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --deny-concrete-harnesses
+
+//! Check that `--deny-concrete-harnesses` turns harnesses without symbolic inputs into an error.
+
+#[kani::proof]
+fn check_concrete() {
+    let v = vec![1u8, 2, 3];
+    assert_eq!(v.len(), 3);
+}
+
+#[kani::proof]
+fn check_symbolic() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(1).is_some() || x == u8::MAX);
+}
@@ -0,0 +1,58 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that Kani reports the harnesses that don't reach any source of non-determinism, and that
+//! it detects symbolic values that come from helper functions and contract replacement.
+
+fn double(x: u32) -> u32 {
+    x.wrapping_mul(2)
+}
+
+fn symbolic_input() -> u32 {
+    kani::any()
+}
+
+#[kani::requires(*x < 100)]
+#[kani::modifies(x)]
+#[kani::ensures(|_| *x < 200)]
+fn increment(x: &mut u32) {
+    *x += 1;
+}
+
+#[kani::proof]
+fn check_concrete() {
+    assert_eq!(double(2), 4);
+}
+
+#[kani::proof]
+fn check_symbolic() {
+    let x: u32 = kani::any();
+    assert_eq!(double(x) % 2, 0);
+}
+
+#[kani::proof]
+fn check_symbolic_helper() {
+    assert_eq!(double(symbolic_input()) % 2, 0);
+}
+
+#[kani::proof]
+fn check_symbolic_slice() {
+    let arr: [u8; 4] = [1, 2, 3, 4];
+    let slice = kani::slice::any_slice_of_array(&arr);
+    assert!(slice.len() <= 4);
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    let mut x: u32 = kani::any();
+    increment(&mut x);
+}
+
+#[kani::proof]
+#[kani::stub_verified(increment)]
+fn check_replaced_contract() {
+    let mut x = 10;
+    increment(&mut x);
+    assert!(x < 200);
+}
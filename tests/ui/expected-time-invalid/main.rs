@@ -0,0 +1,12 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani rejects invalid arguments of `#[kani::expected_time]`.
+
+#[kani::proof]
+#[kani::expected_time(secs = 0)]
+fn check_zero() {}
+
+#[kani::proof]
+#[kani::expected_time(mins = 1)]
+fn check_unknown_unit() {}
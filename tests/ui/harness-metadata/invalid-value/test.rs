@@ -0,0 +1,7 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that harness metadata values must be string literals.
+
+#[kani::proof(owner = 42)]
+fn check() {}
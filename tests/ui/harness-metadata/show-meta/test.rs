@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --show-meta owner --show-meta tier
+
+//! Check that `--show-meta` adds the harness metadata to the failures in the final summary.
+
+#[kani::proof(owner = "team-storage", tier = "1")]
+fn check_storage() {
+    let x: u8 = kani::any();
+    assert!(x < 10);
+}
+
+#[kani::proof(owner = "team-network")]
+fn check_network() {
+    let x: u8 = kani::any();
+    assert!(x != 42);
+}
+
+#[kani::proof(owner = "team-other")]
+fn check_passes() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
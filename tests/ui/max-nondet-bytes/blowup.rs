@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --max-nondet-bytes 1024
+
+//! Check that `--max-nondet-bytes` fails the compilation of the harnesses that introduce too
+//! many nondeterministic bytes, and lists the largest contributors.
+
+#[kani::proof]
+fn check_small_input() {
+    let header: [u8; 16] = kani::any();
+    assert!(header.len() == 16);
+}
+
+#[kani::proof]
+fn check_large_input() {
+    let buffer: [u8; 4096] = kani::any();
+    let len: u32 = kani::any();
+    assert!(buffer.len() > len as usize || len >= 4096);
+}
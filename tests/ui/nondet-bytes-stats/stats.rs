@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --verbose
+
+//! Check that Kani reports the number of nondeterministic bytes introduced by each harness, and
+//! that they are counted for each call, not for each instance of `kani::any()`.
+
+#[kani::proof]
+fn check_header() {
+    let header: [u8; 16] = kani::any();
+    let flag: bool = kani::any();
+    assert!(header.len() == 16 || flag);
+}
+
+fn any_pair() -> (u32, u32) {
+    (kani::any(), kani::any())
+}
+
+#[kani::proof]
+fn check_pairs() {
+    let first = any_pair();
+    let second = any_pair();
+    assert_eq!(first.0.wrapping_add(second.1), second.1.wrapping_add(first.0));
+}
@@ -0,0 +1,10 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that the solver binary can be given by a string constant
+
+const SOLVER_BINARY: &str = "non_existing_solver";
+
+#[kani::proof]
+#[kani::solver(bin = SOLVER_BINARY)]
+fn check() {}
@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --deny-unchecked-harnesses
+
+//! Check that `--deny-unchecked-harnesses` turns harnesses without properties into an error.
+
+#[kani::proof]
+fn check_nothing() {
+    let x: u8 = kani::any();
+    let _ = x.wrapping_add(1);
+}
+
+#[kani::proof]
+fn check_assertion() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(1).is_some() || x == u8::MAX);
+}
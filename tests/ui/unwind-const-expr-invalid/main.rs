@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that Kani reports unwind values that are not constant expressions, or that don't fit
+//! in a `u32`.
+
+const BUF_SIZE: u32 = 4;
+
+fn buf_size() -> u32 {
+    BUF_SIZE
+}
+
+#[kani::proof]
+#[kani::unwind(buf_size() + 1)]
+fn check_call() {}
+
+#[kani::proof]
+#[kani::unwind(UNKNOWN_SIZE)]
+fn check_unknown() {}
+
+#[kani::proof]
+#[kani::unwind(BUF_SIZE * 1_000_000_000_000)]
+fn check_overflow() {}
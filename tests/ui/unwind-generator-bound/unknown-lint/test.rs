@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `#[kani::allow]` rejects unknown lints.
+
+#[kani::proof]
+#[kani::allow(unwind_too_small)]
+fn check_unknown_lint() {
+    let v: Vec<u8> = kani::bounded_any::<_, 4>();
+    assert!(v.len() <= 4);
+}
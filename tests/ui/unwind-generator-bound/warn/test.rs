@@ -0,0 +1,35 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani warns about harnesses whose unwind bound is not larger than the bound of a
+//! bounded generator, unless the harness acknowledges it.
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_short_unwind() {
+    let v: Vec<u8> = kani::bounded_any::<_, 16>();
+    assert!(v.len() <= 16);
+}
+
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_short_unwind_slice() {
+    let arr = [1u8, 2, 3, 4];
+    let slice = kani::slice::any_slice_of_array(&arr);
+    assert!(slice.len() <= 4);
+}
+
+#[kani::proof]
+#[kani::unwind(17)]
+fn check_long_unwind() {
+    let v: Vec<u8> = kani::bounded_any::<_, 16>();
+    assert!(v.len() <= 16);
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+#[kani::allow(unwind_smaller_than_bound)]
+fn check_allowed() {
+    let v: Vec<u8> = kani::bounded_any::<_, 16>();
+    assert!(v.len() <= 16);
+}
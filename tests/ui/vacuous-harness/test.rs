@@ -0,0 +1,26 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani warns about harnesses that start with `kani::assume(false)` and skips their
+//! verification, while harnesses whose assumptions are not statically false still get verified.
+
+#[kani::proof]
+fn check_vacuous() {
+    kani::assume(false);
+    let x: u8 = kani::any();
+    assert!(x > 10);
+}
+
+#[kani::proof]
+fn check_not_vacuous() {
+    let x: u8 = kani::any();
+    kani::assume(x > 10);
+    assert!(x > 5);
+}
+
+#[kani::proof]
+#[kani::should_panic]
+fn check_vacuous_should_panic() {
+    kani::assume(false);
+    panic!("unreachable");
+}
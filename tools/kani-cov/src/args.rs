@@ -44,6 +44,13 @@
 /// ```sh
 /// kani-cov report kanicov_2024-09-23_23-49/kanicov_2024-09-23_23-49_kanimap.json --profile default_kanicov.json
 /// ```
+///
+/// Since the "kanicov" file aggregates the results of all harnesses, the
+/// `lcov` report format produces a combined report in which a line is covered
+/// if at least one harness covers it:
+/// ```sh
+/// kani-cov report kanicov_2024-09-23_23-49/kanicov_2024-09-23_23-49_kanimap.json --profile default_kanicov.json --format lcov --output kani.lcov
+/// ```
 #[derive(Debug, clap::Subcommand)]
 pub enum Subcommand {
     Merge(MergeArgs),
@@ -115,12 +122,19 @@ pub struct ReportArgs {
     // The format of the report
     #[arg(long, short, value_parser = clap::value_parser!(ReportFormat), default_value = "terminal")]
     pub format: ReportFormat,
+    // The file where the report is written (only for the `lcov` format).
+    // By default, the report is printed to the standard output.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
 pub enum ReportFormat {
     Terminal,
     Escapes,
+    /// A single LCOV tracefile for all the source files, which can be consumed
+    /// by tools like `genhtml` to produce an HTML report.
+    Lcov,
 }
 
 /// Validate general arguments and delegate validation of command-specific
@@ -6,7 +6,7 @@
 use std::io::{BufRead, IsTerminal};
 use std::{fs::File, io::BufReader, path::PathBuf};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 
 use crate::args::ReportFormat;
 use crate::coverage::{
@@ -36,6 +36,16 @@ pub fn report_main(args: &ReportArgs) -> Result<()> {
 
     let checked_format = check_format(&args.format);
 
+    if checked_format == ReportFormat::Lcov {
+        let report = lcov_report(&source_files, &results);
+        if let Some(output) = &args.output {
+            std::fs::write(output, report)?;
+        } else {
+            print!("{report}");
+        }
+        return Ok(());
+    }
+
     for file in source_files {
         let fun_info = function_info_from_file(&file);
         let mut file_cov_info = Vec::new();
@@ -54,8 +64,10 @@ pub fn report_main(args: &ReportArgs) -> Result<()> {
 
 /// Validate arguments to the `report` subcommand in addition to clap's
 /// validation.
-pub fn validate_report_args(_args: &ReportArgs) -> Result<()> {
-    // No validation is done at the moment
+pub fn validate_report_args(args: &ReportArgs) -> Result<()> {
+    if args.output.is_some() && args.format != ReportFormat::Lcov {
+        bail!("the `--output` option is only supported with the `lcov` format");
+    }
     Ok(())
 }
 
@@ -76,7 +88,56 @@ fn check_format(format: &ReportFormat) -> ReportFormat {
             }
         }
         ReportFormat::Escapes => ReportFormat::Escapes,
+        ReportFormat::Lcov => ReportFormat::Lcov,
+    }
+}
+
+/// Produce an LCOV tracefile with the line and function coverage of all the
+/// source files.
+///
+/// The coverage profile aggregates the results of every harness, so the count
+/// of a line is the maximum number of harnesses covering any of its regions.
+/// In other words, a line is reported as covered if at least one harness
+/// covers it.
+fn lcov_report(source_files: &[PathBuf], results: &CombinedCoverageResults) -> String {
+    let mut report = String::new();
+    for file in source_files {
+        let fun_info = function_info_from_file(file);
+        let mut functions = Vec::new();
+        let mut lines = Vec::new();
+        for info in fun_info {
+            let cov_results = function_coverage_results(&info, file, results);
+            let line_coverage = line_coverage_results(&info, &cov_results);
+            let line_counts: Vec<(LineNumber, usize)> = (info.start.0..=info.end.0)
+                .zip(line_coverage)
+                .filter_map(|(line, result)| result.map(|(count, _)| (line, count)))
+                .collect();
+            let fun_count = line_counts.iter().map(|(_, count)| *count).max().unwrap_or(0);
+            functions.push((info.start.0, info.name, fun_count));
+            lines.extend(line_counts);
+        }
+
+        report.push_str("TN:\n");
+        report.push_str(&format!("SF:{}\n", file.display()));
+        for (line, name, _) in &functions {
+            report.push_str(&format!("FN:{line},{name}\n"));
+        }
+        for (_, name, count) in &functions {
+            report.push_str(&format!("FNDA:{count},{name}\n"));
+        }
+        report.push_str(&format!("FNF:{}\n", functions.len()));
+        report.push_str(&format!(
+            "FNH:{}\n",
+            functions.iter().filter(|(_, _, count)| *count > 0).count()
+        ));
+        for (line, count) in &lines {
+            report.push_str(&format!("DA:{line},{count}\n"));
+        }
+        report.push_str(&format!("LF:{}\n", lines.len()));
+        report.push_str(&format!("LH:{}\n", lines.iter().filter(|(_, count)| *count > 0).count()));
+        report.push_str("end_of_record\n");
     }
+    report
 }
 
 /// Output coverage results, and highlight regions or lines which are not
@@ -247,6 +308,7 @@ fn insert_escapes(str: &str, markers: Vec<(ColumnNumber, bool)>, format: &Report
     let (open_escape, close_escape) = match format {
         ReportFormat::Terminal => ("\x1b[41m", "\x1b[0m"),
         ReportFormat::Escapes => ("```", "'''"),
+        ReportFormat::Lcov => unreachable!("LCOV reports don't highlight source code"),
     };
 
     let mut escape_markers: Vec<(&ColumnNumber, &str)> =